@@ -0,0 +1,65 @@
+//! Frame times of a bank of audio knobs, like the mixer strip of an audio workstation,
+//! painted with the single mesh fill and with the legacy per-segment polygon fill.
+//!
+//! Run with `cargo bench -p egui_extras_xt --features knobs,legacy_polygon_fill`.
+
+use criterion::{criterion_group, criterion_main, Criterion};
+use egui::{vec2, CentralPanel, Context, FullOutput, Pos2, RawInput, Rect};
+use egui_extras_xt::common::{with_legacy_polygon_fill, WidgetShape};
+use egui_extras_xt::knobs::AudioKnob;
+
+const KNOB_COUNT: usize = 32;
+
+fn run_frame(ctx: &Context, values: &mut [f32], shape: &WidgetShape) -> FullOutput {
+    let raw_input = RawInput {
+        screen_rect: Some(Rect::from_min_size(Pos2::ZERO, vec2(1920.0, 1080.0))),
+        ..RawInput::default()
+    };
+
+    ctx.run(raw_input, |ctx| {
+        CentralPanel::default().show(ctx, |ui| {
+            ui.horizontal_wrapped(|ui| {
+                for value in values.iter_mut() {
+                    ui.add(
+                        AudioKnob::new(value)
+                            .diameter(48.0)
+                            .shape(shape.clone())
+                            .animated(false),
+                    );
+                }
+            });
+        });
+    })
+}
+
+fn knobs_benchmark(c: &mut Criterion) {
+    let mut group = c.benchmark_group("audio_knob_bank");
+
+    for shape in [
+        WidgetShape::Circle,
+        WidgetShape::Squircle(4.0),
+        WidgetShape::Star(5, 0.5),
+    ] {
+        let ctx = Context::default();
+        let mut values = (0..KNOB_COUNT)
+            .map(|index| index as f32 / KNOB_COUNT as f32)
+            .collect::<Vec<_>>();
+
+        group.bench_function(format!("{shape}/mesh"), |b| {
+            b.iter(|| {
+                run_frame(&ctx, &mut values, &shape);
+            });
+        });
+
+        group.bench_function(format!("{shape}/legacy"), |b| {
+            b.iter(|| {
+                with_legacy_polygon_fill(|| run_frame(&ctx, &mut values, &shape));
+            });
+        });
+    }
+
+    group.finish();
+}
+
+criterion_group!(benches, knobs_benchmark);
+criterion_main!(benches);
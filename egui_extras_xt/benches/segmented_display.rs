@@ -0,0 +1,101 @@
+//! Frame times of a dashboard of static segmented displays, parsing the strings every
+//! frame versus pushing prepared display buffers, compared to a dashboard whose values
+//! change every frame.
+//!
+//! Run with `cargo bench -p egui_extras_xt --features displays`.
+
+use criterion::{criterion_group, criterion_main, Criterion};
+use egui::{vec2, CentralPanel, Context, Pos2, RawInput, Rect, Ui};
+use egui_extras_xt::displays::segmented_display::DisplayBuffer;
+use egui_extras_xt::displays::{DisplayKind, SegmentedDisplayWidget};
+
+const DISPLAY_COUNT: usize = 40;
+
+fn display_value(index: usize) -> String {
+    format!(
+        "{:02}:{:02}:{:02}.{:02}",
+        index % 24,
+        index,
+        index * 3 % 60,
+        index
+    )
+}
+
+fn run_frame(ctx: &Context, add_contents: impl FnMut(&mut Ui)) {
+    let raw_input = RawInput {
+        screen_rect: Some(Rect::from_min_size(Pos2::ZERO, vec2(1920.0, 1080.0))),
+        ..RawInput::default()
+    };
+
+    let mut add_contents = add_contents;
+    let _ = ctx.run(raw_input, |ctx| {
+        CentralPanel::default().show(ctx, |ui| add_contents(ui));
+    });
+}
+
+fn segmented_display_benchmark(c: &mut Criterion) {
+    let mut group = c.benchmark_group("segmented_display_dashboard");
+
+    group.bench_function("push_string", |b| {
+        let ctx = Context::default();
+        let values = (0..DISPLAY_COUNT).map(display_value).collect::<Vec<_>>();
+
+        b.iter(|| {
+            run_frame(&ctx, |ui| {
+                for value in &values {
+                    ui.add(
+                        SegmentedDisplayWidget::new(DisplayKind::SevenSegment)
+                            .digit_height(16.0)
+                            .push_string(value),
+                    );
+                }
+            });
+        });
+    });
+
+    group.bench_function("push_string_changing", |b| {
+        let ctx = Context::default();
+        let mut frame = 0;
+
+        b.iter(|| {
+            frame += 1;
+            run_frame(&ctx, |ui| {
+                for index in 0..DISPLAY_COUNT {
+                    ui.add(
+                        SegmentedDisplayWidget::new(DisplayKind::SevenSegment)
+                            .digit_height(16.0)
+                            .push_string(display_value(index + frame)),
+                    );
+                }
+            });
+        });
+    });
+
+    group.bench_function("push_buffer", |b| {
+        let ctx = Context::default();
+        let buffers = (0..DISPLAY_COUNT)
+            .map(|index| {
+                SegmentedDisplayWidget::new(DisplayKind::SevenSegment)
+                    .push_string(display_value(index))
+                    .into_buffer()
+            })
+            .collect::<Vec<DisplayBuffer>>();
+
+        b.iter(|| {
+            run_frame(&ctx, |ui| {
+                for buffer in &buffers {
+                    ui.add(
+                        SegmentedDisplayWidget::new(DisplayKind::SevenSegment)
+                            .digit_height(16.0)
+                            .push_buffer(buffer),
+                    );
+                }
+            });
+        });
+    });
+
+    group.finish();
+}
+
+criterion_group!(benches, segmented_display_benchmark);
+criterion_main!(benches);
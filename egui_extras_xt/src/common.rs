@@ -1,43 +1,169 @@
-use std::f32::consts::TAU;
+#[cfg(feature = "legacy_polygon_fill")]
+use std::cell::Cell;
+use std::error::Error;
+use std::f32::consts::{PI, TAU};
+use std::fmt;
+use std::ops::{Add, Not, Sub};
+use std::str::FromStr;
+use std::sync::Arc;
 
 use egui::{Align2, FontId, Painter, Rect, Ui};
 use emath::{almost_equal, lerp, Pos2, Rot2, Vec2};
-use epaint::{Color32, Shape, Stroke, TextShape};
+use epaint::{Color32, Mesh, Shape, Stroke, TextShape};
 
 use itertools::Itertools;
 use strum::{Display, EnumIter};
 
 // ----------------------------------------------------------------------------
 
-#[derive(Clone, Copy, Debug, PartialEq, Display)]
+#[derive(Clone, Copy, Debug, PartialEq)]
 pub enum Orientation {
-    #[strum(to_string = "Top")]
     Top,
-
-    #[strum(to_string = "Bottom")]
     Bottom,
-
-    #[strum(to_string = "Left")]
     Left,
-
-    #[strum(to_string = "Right")]
     Right,
 
     Custom(f32),
 }
 
 impl Orientation {
+    const FIXED: [Orientation; 4] = [Self::Top, Self::Bottom, Self::Left, Self::Right];
+
+    /// Custom orientation from a screen angle in degrees, clockwise from the right.
+    /// The inverse of [`Orientation::angle_deg`].
+    #[must_use]
+    pub fn from_degrees(angle: f32) -> Orientation {
+        Self::Custom(angle.to_radians())
+    }
+
+    /// Iterates over the fixed orientations, leaving out [`Orientation::Custom`].
+    pub fn iter_fixed() -> impl Iterator<Item = Orientation> {
+        Self::FIXED.into_iter()
+    }
+
+    /// Screen angle of the orientation in radians, clockwise from the right.
+    #[must_use]
+    pub fn angle_rad(self) -> f32 {
+        match self {
+            Self::Right => TAU * 0.00,
+            Self::Bottom => TAU * 0.25,
+            Self::Left => TAU * 0.50,
+            Self::Top => TAU * 0.75,
+            Self::Custom(angle) => angle,
+        }
+    }
+
+    /// Screen angle of the orientation in degrees, clockwise from the right.
+    #[must_use]
+    pub fn angle_deg(self) -> f32 {
+        self.angle_rad().to_degrees()
+    }
+
+    /// Counterpart of [`Orientation::from_degrees`], same as [`Orientation::angle_deg`].
+    #[must_use]
+    pub fn to_degrees(self) -> f32 {
+        self.angle_deg()
+    }
+
+    /// Formats as `"top"`, `"bottom"`, `"left"`, `"right"` or `"custom(<radians>)"`,
+    /// parsed back by [`Orientation::from_str`] without loss. Meant for persisting
+    /// settings, use the [`Display`](fmt::Display) output as a label for humans.
+    #[must_use]
+    pub fn to_setting_string(self) -> String {
+        match self {
+            Self::Top => "top".to_owned(),
+            Self::Bottom => "bottom".to_owned(),
+            Self::Left => "left".to_owned(),
+            Self::Right => "right".to_owned(),
+            Self::Custom(angle) => format!("custom({angle})"),
+        }
+    }
+
     pub(crate) fn rot2(&self) -> Rot2 {
-        match *self {
-            Self::Right => Rot2::from_angle(TAU * 0.00),
-            Self::Bottom => Rot2::from_angle(TAU * 0.25),
-            Self::Left => Rot2::from_angle(TAU * 0.50),
-            Self::Top => Rot2::from_angle(TAU * 0.75),
-            Self::Custom(angle) => Rot2::from_angle(angle),
+        Rot2::from_angle(self.angle_rad())
+    }
+}
+
+impl fmt::Display for Orientation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            Self::Top => "Top",
+            Self::Bottom => "Bottom",
+            Self::Left => "Left",
+            Self::Right => "Right",
+            Self::Custom(_) => "Custom",
+        };
+
+        write!(f, "{name} ({:.1}\u{B0})", self.angle_deg())
+    }
+}
+
+#[non_exhaustive]
+#[derive(Clone, Debug, PartialEq)]
+pub enum ParseOrientationError {
+    UnknownOrientation(String),
+    InvalidAngle(String),
+}
+
+impl fmt::Display for ParseOrientationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseOrientationError::UnknownOrientation(text) => {
+                write!(f, "unknown orientation {text:?}")
+            }
+            ParseOrientationError::InvalidAngle(text) => {
+                write!(f, "invalid custom orientation angle {text:?}")
+            }
         }
     }
 }
 
+impl Error for ParseOrientationError {}
+
+/// Parses the output of [`Orientation::to_setting_string`], the names case-insensitively
+/// and custom angles in radians.
+impl FromStr for Orientation {
+    type Err = ParseOrientationError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let text = s.trim();
+
+        if let Some(orientation) = Self::iter_fixed()
+            .find(|orientation| orientation.to_setting_string().eq_ignore_ascii_case(text))
+        {
+            return Ok(orientation);
+        }
+
+        let angle = text
+            .get(.."custom(".len())
+            .filter(|prefix| prefix.eq_ignore_ascii_case("custom("))
+            .and_then(|_| text["custom(".len()..].strip_suffix(')'))
+            .ok_or_else(|| ParseOrientationError::UnknownOrientation(text.to_owned()))?;
+
+        angle
+            .trim()
+            .parse::<f32>()
+            .map(Self::Custom)
+            .map_err(|_| ParseOrientationError::InvalidAngle(angle.to_owned()))
+    }
+}
+
+impl Add<f32> for Orientation {
+    type Output = Orientation;
+
+    fn add(self, rhs: f32) -> Self::Output {
+        Orientation::Custom(self.angle_rad() + rhs)
+    }
+}
+
+impl Sub<f32> for Orientation {
+    type Output = Orientation;
+
+    fn sub(self, rhs: f32) -> Self::Output {
+        Orientation::Custom(self.angle_rad() - rhs)
+    }
+}
+
 // ----------------------------------------------------------------------------
 
 #[derive(Clone, Copy, Debug, Display, EnumIter, Eq, PartialEq)]
@@ -45,11 +171,20 @@ pub enum Winding {
     #[strum(to_string = "Clockwise")]
     Clockwise,
 
-    #[strum(to_string = "Counter-clockwise")]
+    #[strum(to_string = "Counterclockwise")]
     Counterclockwise,
 }
 
 impl Winding {
+    /// Returns the opposite winding.
+    #[must_use]
+    pub fn flip(self) -> Winding {
+        match self {
+            Self::Clockwise => Self::Counterclockwise,
+            Self::Counterclockwise => Self::Clockwise,
+        }
+    }
+
     pub(crate) fn to_float(self) -> f32 {
         match self {
             Self::Clockwise => 1.0,
@@ -58,6 +193,14 @@ impl Winding {
     }
 }
 
+impl Not for Winding {
+    type Output = Winding;
+
+    fn not(self) -> Self::Output {
+        self.flip()
+    }
+}
+
 // ----------------------------------------------------------------------------
 
 #[derive(Clone, Copy, Debug, Display, EnumIter, Eq, PartialEq)]
@@ -70,12 +213,68 @@ pub enum WrapMode {
 
     #[strum(to_string = "Unsigned")]
     Unsigned,
+
+    /// Hard stops at the ends of the range instead of wrapping around.
+    #[strum(to_string = "Clamped")]
+    Clamped,
+}
+
+impl WrapMode {
+    /// Wraps `value` into the `min..max` period.
+    ///
+    /// `None` returns the value unchanged, `Signed` wraps into `min..=max` keeping the sign
+    /// of overshoots (`max` for positive, `min` for negative whole periods, like
+    /// [`emath::normalized_angle`]), `Unsigned` wraps into `min..max`, `Clamped` clamps
+    /// into `min..=max`.
+    /// Empty or inverted ranges return the value unchanged.
+    #[must_use]
+    pub fn wrap(self, value: f32, min: f32, max: f32) -> f32 {
+        let period = max - min;
+
+        if period.is_nan() || period <= 0.0 {
+            return value;
+        }
+
+        match self {
+            WrapMode::None => value,
+            WrapMode::Signed => {
+                if (min..=max).contains(&value) {
+                    value
+                } else {
+                    let offset = (value - min).rem_euclid(period);
+                    if value > max && offset == 0.0 {
+                        max
+                    } else {
+                        min + offset
+                    }
+                }
+            }
+            WrapMode::Unsigned => {
+                // `rem_euclid` may round up to `period` for tiny negative values
+                let offset = (value - min).rem_euclid(period);
+                if offset >= period {
+                    min
+                } else {
+                    min + offset
+                }
+            }
+            WrapMode::Clamped => value.clamp(min, max),
+        }
+    }
 }
 
 // ----------------------------------------------------------------------------
 
+/// Polar function mapping an angle to a radius multiplier. Shared, so custom shapes can
+/// be kept in the application state and cloned into the widgets every frame.
+///
+/// Functions can't be compared, custom shapes are only equal to the clones of the same
+/// shape. Keep the shape around instead of calling [`WidgetShape::from_fn`] every frame
+/// when comparing them.
+pub type WidgetShapeFn = Arc<dyn Fn(f32) -> f32 + Send + Sync>;
+
 #[non_exhaustive]
-#[derive(Clone, Display, PartialEq)]
+#[derive(Clone, Display)]
 pub enum WidgetShape {
     #[strum(to_string = "Circle")]
     Circle,
@@ -92,6 +291,17 @@ pub enum WidgetShape {
     #[strum(to_string = "SuperPolygon")]
     SuperPolygon(usize, f32),
 
+    /// Star of `points` points, the inner vertices at `inner_ratio` of the radius of the
+    /// points.
+    #[strum(to_string = "Star")]
+    Star(usize, f32),
+
+    /// Polygon of `n` sides whose corners are rounded by arcs tangent to the sides. The
+    /// corner radius is relative to the inscribed circle, 1.0 rounding the polygon into
+    /// a circle.
+    #[strum(to_string = "RoundedPolygon")]
+    RoundedPolygon(usize, f32),
+
     #[strum(to_string = "Rotated")]
     Rotated(Box<WidgetShape>, f32),
 
@@ -106,38 +316,267 @@ pub enum WidgetShape {
 
     #[strum(to_string = "Maximum")]
     Max(Box<WidgetShape>, Box<WidgetShape>),
+
+    #[strum(to_string = "Custom")]
+    Custom(WidgetShapeFn),
+}
+
+/// Built-in shapes compare by their parameters, [`WidgetShape::Custom`] by identity.
+impl PartialEq for WidgetShape {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (WidgetShape::Circle, WidgetShape::Circle)
+            | (WidgetShape::Square, WidgetShape::Square) => true,
+            (WidgetShape::Squircle(a), WidgetShape::Squircle(b)) => a == b,
+            (WidgetShape::Polygon(a), WidgetShape::Polygon(b)) => a == b,
+            (WidgetShape::SuperPolygon(n_a, a), WidgetShape::SuperPolygon(n_b, b)) => {
+                n_a == n_b && a == b
+            }
+            (WidgetShape::Star(n_a, a), WidgetShape::Star(n_b, b))
+            | (WidgetShape::RoundedPolygon(n_a, a), WidgetShape::RoundedPolygon(n_b, b)) => {
+                n_a == n_b && a == b
+            }
+            (WidgetShape::Rotated(shape_a, a), WidgetShape::Rotated(shape_b, b))
+            | (WidgetShape::Scaled(shape_a, a), WidgetShape::Scaled(shape_b, b)) => {
+                shape_a == shape_b && a == b
+            }
+            (WidgetShape::Mix(a_1, a_2, a), WidgetShape::Mix(b_1, b_2, b)) => {
+                a_1 == b_1 && a_2 == b_2 && a == b
+            }
+            (WidgetShape::Min(a_1, a_2), WidgetShape::Min(b_1, b_2))
+            | (WidgetShape::Max(a_1, a_2), WidgetShape::Max(b_1, b_2)) => a_1 == b_1 && a_2 == b_2,
+            (WidgetShape::Custom(f_a), WidgetShape::Custom(f_b)) => Arc::ptr_eq(f_a, f_b),
+            _ => false,
+        }
+    }
+}
+
+impl fmt::Debug for WidgetShape {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            WidgetShape::Circle => f.write_str("Circle"),
+            WidgetShape::Square => f.write_str("Square"),
+            WidgetShape::Squircle(factor) => f.debug_tuple("Squircle").field(factor).finish(),
+            WidgetShape::Polygon(n) => f.debug_tuple("Polygon").field(n).finish(),
+            WidgetShape::SuperPolygon(n, factor) => f
+                .debug_tuple("SuperPolygon")
+                .field(n)
+                .field(factor)
+                .finish(),
+            WidgetShape::Star(n, inner_ratio) => {
+                f.debug_tuple("Star").field(n).field(inner_ratio).finish()
+            }
+            WidgetShape::RoundedPolygon(n, corner_radius) => f
+                .debug_tuple("RoundedPolygon")
+                .field(n)
+                .field(corner_radius)
+                .finish(),
+            WidgetShape::Rotated(shape, rotation) => f
+                .debug_tuple("Rotated")
+                .field(shape)
+                .field(rotation)
+                .finish(),
+            WidgetShape::Scaled(shape, scale) => {
+                f.debug_tuple("Scaled").field(shape).field(scale).finish()
+            }
+            WidgetShape::Mix(shape_a, shape_b, t) => f
+                .debug_tuple("Mix")
+                .field(shape_a)
+                .field(shape_b)
+                .field(t)
+                .finish(),
+            WidgetShape::Min(shape_a, shape_b) => {
+                f.debug_tuple("Min").field(shape_a).field(shape_b).finish()
+            }
+            WidgetShape::Max(shape_a, shape_b) => {
+                f.debug_tuple("Max").field(shape_a).field(shape_b).finish()
+            }
+            WidgetShape::Custom(_) => f.write_str("Custom(..)"),
+        }
+    }
 }
 
+#[non_exhaustive]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum WidgetShapeError {
+    PolygonTooFewSides(usize),
+    InvalidSquircleFactor(f32),
+    SuperPolygonFactorOutOfRange(f32),
+    StarTooFewPoints(usize),
+    StarInnerRatioOutOfRange(f32),
+    RoundedPolygonCornerRadiusOutOfRange(f32),
+}
+
+impl fmt::Display for WidgetShapeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            WidgetShapeError::PolygonTooFewSides(n) => {
+                write!(f, "polygon must have at least 3 sides, got {n}")
+            }
+            WidgetShapeError::InvalidSquircleFactor(factor) => {
+                write!(f, "squircle factor must be positive, got {factor}")
+            }
+            WidgetShapeError::SuperPolygonFactorOutOfRange(factor) => {
+                write!(
+                    f,
+                    "polygon factor must be in the (0.0..=2.0) range, got {factor}"
+                )
+            }
+            WidgetShapeError::StarTooFewPoints(n) => {
+                write!(f, "star must have at least 3 points, got {n}")
+            }
+            WidgetShapeError::StarInnerRatioOutOfRange(ratio) => {
+                write!(
+                    f,
+                    "star inner ratio must be in the (0.0..=1.0) range, got {ratio}"
+                )
+            }
+            WidgetShapeError::RoundedPolygonCornerRadiusOutOfRange(radius) => {
+                write!(
+                    f,
+                    "corner radius must be in the 0.0..=1.0 range, got {radius}"
+                )
+            }
+        }
+    }
+}
+
+impl Error for WidgetShapeError {}
+
 impl WidgetShape {
-    const RESOLUTION: usize = 32;
+    /// Bounds of the resolution derived from the radius.
+    const MIN_RESOLUTION: usize = 16;
+    const MAX_RESOLUTION: usize = 512;
+
+    /// Circumference length per outline vertex, in points.
+    const VERTEX_SPACING: f32 = 2.5;
+
+    /// Arcs thinner than this are painted as a polyline, in points.
+    const MIN_RING_THICKNESS: f32 = 0.5;
+
+    /// Mesh vertices closer than this are merged, in points.
+    const VERTEX_EPSILON: f32 = 1.0e-3;
+
+    /// Mesh triangles smaller than this are skipped, in square points.
+    const MIN_TRIANGLE_AREA: f32 = 1.0e-6;
+
+    /// Custom shape from a polar function, either a plain function or a closure.
+    ///
+    /// Every call makes a new shape, which is not equal to the shapes of earlier calls even
+    /// for the same function.
+    pub fn from_fn(f: impl Fn(f32) -> f32 + Send + Sync + 'static) -> WidgetShape {
+        WidgetShape::Custom(Arc::new(f))
+    }
+
+    /// Custom shape from a boxed polar function.
+    pub fn from_boxed_fn(f: Box<dyn Fn(f32) -> f32 + Send + Sync>) -> WidgetShape {
+        WidgetShape::Custom(Arc::from(f))
+    }
 
+    pub fn validate(&self) -> Result<(), WidgetShapeError> {
+        match self {
+            WidgetShape::Circle | WidgetShape::Square | WidgetShape::Custom(_) => Ok(()),
+            WidgetShape::Squircle(factor) => {
+                if *factor > 0.0 {
+                    Ok(())
+                } else {
+                    Err(WidgetShapeError::InvalidSquircleFactor(*factor))
+                }
+            }
+            WidgetShape::Polygon(n) => {
+                if *n >= 3 {
+                    Ok(())
+                } else {
+                    Err(WidgetShapeError::PolygonTooFewSides(*n))
+                }
+            }
+            WidgetShape::SuperPolygon(n, factor) => {
+                if *n < 3 {
+                    Err(WidgetShapeError::PolygonTooFewSides(*n))
+                } else if *factor > 0.0 && *factor <= 2.0 {
+                    Ok(())
+                } else {
+                    Err(WidgetShapeError::SuperPolygonFactorOutOfRange(*factor))
+                }
+            }
+            WidgetShape::Star(n, inner_ratio) => {
+                if *n < 3 {
+                    Err(WidgetShapeError::StarTooFewPoints(*n))
+                } else if *inner_ratio > 0.0 && *inner_ratio <= 1.0 {
+                    Ok(())
+                } else {
+                    Err(WidgetShapeError::StarInnerRatioOutOfRange(*inner_ratio))
+                }
+            }
+            WidgetShape::RoundedPolygon(n, corner_radius) => {
+                if *n < 3 {
+                    Err(WidgetShapeError::PolygonTooFewSides(*n))
+                } else if (0.0..=1.0).contains(corner_radius) {
+                    Ok(())
+                } else {
+                    Err(WidgetShapeError::RoundedPolygonCornerRadiusOutOfRange(
+                        *corner_radius,
+                    ))
+                }
+            }
+            WidgetShape::Rotated(shape, _) | WidgetShape::Scaled(shape, _) => shape.validate(),
+            WidgetShape::Mix(shape_a, shape_b, _)
+            | WidgetShape::Min(shape_a, shape_b)
+            | WidgetShape::Max(shape_a, shape_b) => {
+                shape_a.validate()?;
+                shape_b.validate()
+            }
+        }
+    }
+
+    /// Expects a shape that passed [`WidgetShape::validate`].
     pub(crate) fn eval(&self, theta: f32) -> f32 {
         match self {
             WidgetShape::Circle => 1.0,
             WidgetShape::Square => (1.0 / theta.cos().abs()).min(1.0 / theta.sin().abs()),
             WidgetShape::Squircle(factor) => {
-                assert!(*factor > 0.0, "squircle factor must be positive");
                 let a = theta.cos().abs().powf(*factor);
                 let b = theta.sin().abs().powf(*factor);
                 (a + b).powf(-1.0 / *factor)
             }
             WidgetShape::Polygon(n) => {
-                assert!(*n >= 3, "polygon must have at least 3 sides");
                 1.0 / ((*n as f32 / 2.0 * theta).cos().asin() * 2.0 / *n as f32).cos()
             }
             WidgetShape::SuperPolygon(n, factor) => {
-                assert!(*n >= 3, "polygon must have at least 3 sides");
-                assert!(*factor > 0.0, "polygon factor must be positive");
-                assert!(
-                    (0.0..=2.0).contains(factor),
-                    "polygon factor must be between 0.0 and 2.0"
-                );
-
                 // https://mathworld.wolfram.com/Superellipse.html
                 let a = (0.25 * (*n as f32) * theta).cos().abs().powf(*factor);
                 let b = (0.25 * (*n as f32) * theta).sin().abs().powf(*factor);
                 (a + b).powf(-1.0 / *factor)
             }
+            WidgetShape::Star(n, inner_ratio) => {
+                // Angle from the nearest point, the sides run to the inner vertices
+                // halfway between the points
+                let half_sector = PI / *n as f32;
+                let phi = ((theta + half_sector).rem_euclid(2.0 * half_sector) - half_sector).abs();
+
+                // Intersection of the ray with the side from (1, 0) to the inner vertex
+                let side = Vec2::angled(half_sector) * *inner_ratio - Vec2::X;
+                side.y / (phi.cos() * side.y - phi.sin() * side.x)
+            }
+            WidgetShape::RoundedPolygon(n, corner_radius) => {
+                let half_sector = PI / *n as f32;
+                let phi = ((theta + half_sector).rem_euclid(2.0 * half_sector) - half_sector).abs();
+
+                // Center of the corner arc on the ray of the vertex, tangent to both sides
+                // of the polygon with the inscribed circle of unit radius
+                let center_distance = (1.0 - corner_radius) / half_sector.cos();
+                let tangent_angle = (corner_radius * half_sector.sin())
+                    .atan2(center_distance + corner_radius * half_sector.cos());
+
+                if phi < tangent_angle {
+                    center_distance * phi.cos()
+                        + (corner_radius.powi(2) - (center_distance * phi.sin()).powi(2))
+                            .max(0.0)
+                            .sqrt()
+                } else {
+                    1.0 / (half_sector - phi).cos()
+                }
+            }
             WidgetShape::Rotated(shape, rotation) => shape.eval(theta - rotation),
             WidgetShape::Scaled(shape, scale) => shape.eval(theta) * scale,
             WidgetShape::Mix(shape_a, shape_b, t) => {
@@ -145,9 +584,159 @@ impl WidgetShape {
             }
             WidgetShape::Min(shape_a, shape_b) => shape_a.eval(theta).min(shape_b.eval(theta)),
             WidgetShape::Max(shape_a, shape_b) => shape_a.eval(theta).max(shape_b.eval(theta)),
+            WidgetShape::Custom(f) => f(theta),
         }
     }
 
+    /// Outline vertices per full turn for shapes of the given radius, about one vertex
+    /// per 2.5 points of the circumference.
+    #[must_use]
+    pub fn resolution_for_radius(radius: f32) -> usize {
+        ((TAU * radius.abs() / Self::VERTEX_SPACING).ceil() as usize)
+            .clamp(Self::MIN_RESOLUTION, Self::MAX_RESOLUTION)
+    }
+
+    /// Angles of the corners of the shape, where the outline has to be sampled exactly
+    /// to keep the corners sharp. Smooth shapes have none.
+    fn corner_angles(&self) -> Vec<f32> {
+        match self {
+            WidgetShape::Circle | WidgetShape::Custom(_) => Vec::new(),
+            WidgetShape::Square => (0..4).map(|i| TAU / 8.0 + i as f32 * TAU / 4.0).collect(),
+            // Diamond at 1.0, pinched into spikes below it
+            WidgetShape::Squircle(factor) if *factor <= 1.0 => {
+                (0..4).map(|i| i as f32 * TAU / 4.0).collect()
+            }
+            WidgetShape::Squircle(_) => Vec::new(),
+            WidgetShape::Polygon(n) | WidgetShape::SuperPolygon(n, _) => {
+                (0..*n).map(|i| i as f32 * TAU / *n as f32).collect()
+            }
+            // Both the points and the inner vertices
+            WidgetShape::Star(n, _) => (0..2 * *n).map(|i| i as f32 * PI / *n as f32).collect(),
+            WidgetShape::RoundedPolygon(n, corner_radius) if *corner_radius <= 0.0 => {
+                (0..*n).map(|i| i as f32 * TAU / *n as f32).collect()
+            }
+            WidgetShape::RoundedPolygon(..) => Vec::new(),
+            WidgetShape::Rotated(shape, rotation) => shape
+                .corner_angles()
+                .into_iter()
+                .map(|angle| angle + rotation)
+                .collect(),
+            WidgetShape::Scaled(shape, _) => shape.corner_angles(),
+            WidgetShape::Mix(shape_a, shape_b, _)
+            | WidgetShape::Min(shape_a, shape_b)
+            | WidgetShape::Max(shape_a, shape_b) => {
+                let mut angles = shape_a.corner_angles();
+                angles.extend(shape_b.corner_angles());
+                angles
+            }
+        }
+    }
+
+    /// Angles sampling the outline from `start_angle` to `end_angle`, both included.
+    /// Evenly spaced at `resolution` vertices per full turn, with the corners of the shape
+    /// falling between them added.
+    fn sample_angles(
+        &self,
+        start_angle: f32,
+        end_angle: f32,
+        resolution: usize,
+        rotation: Rot2,
+    ) -> Vec<f32> {
+        let span = end_angle - start_angle;
+        let steps = ((resolution as f32 * span.abs() / TAU).ceil() as usize).max(1);
+
+        let mut positions = (0..=steps).map(|i| i as f32 / steps as f32).collect_vec();
+
+        if span.abs() > f32::EPSILON {
+            let (min_angle, max_angle) = (start_angle.min(end_angle), start_angle.max(end_angle));
+            let rotation_angle = (rotation * Vec2::RIGHT).angle();
+
+            // Every turn of the corners within the arc
+            for corner_angle in self.corner_angles() {
+                let corner_angle = corner_angle + rotation_angle;
+                let first_turn = ((min_angle - corner_angle) / TAU).ceil() as i32;
+                let last_turn = ((max_angle - corner_angle) / TAU).floor() as i32;
+
+                positions.extend(
+                    (first_turn..=last_turn)
+                        .map(|turn| (corner_angle + turn as f32 * TAU - start_angle) / span)
+                        .filter(|position| *position > 0.0 && *position < 1.0),
+                );
+            }
+
+            positions.sort_by(f32::total_cmp);
+            positions.dedup_by(|a, b| (*a - *b).abs() < 1.0e-4);
+        }
+
+        positions
+            .into_iter()
+            .map(|position| lerp(start_angle..=end_angle, position))
+            .collect_vec()
+    }
+
+    fn outline_points(
+        &self,
+        center: Pos2,
+        radius: f32,
+        rotation: Rot2,
+        resolution: usize,
+    ) -> Vec<Pos2> {
+        let mut angles = self.sample_angles(0.0, TAU, resolution, rotation);
+
+        // The full turn ends where it started
+        angles.pop();
+
+        angles
+            .into_iter()
+            .map(|angle| {
+                let shape_radius = self.eval(angle - (rotation * Vec2::RIGHT).angle());
+                center + Vec2::angled(angle) * radius * shape_radius
+            })
+            .collect_vec()
+    }
+
+    /// Tight axis-aligned bounding box of the outline painted by `paint_shape`.
+    #[must_use]
+    pub fn bounding_box(&self, center: Pos2, radius: f32, rotation: Rot2) -> Rect {
+        Rect::from_points(&self.outline_points(
+            center,
+            radius,
+            rotation,
+            Self::resolution_for_radius(radius),
+        ))
+    }
+
+    /// Paints the shape with its bounding box fitted into the rect, keeping its aspect
+    /// ratio.
+    pub(crate) fn paint_shape_in_rect(
+        &self,
+        ui: &mut Ui,
+        rect: Rect,
+        fill: Color32,
+        stroke: Stroke,
+    ) {
+        let resolution = Self::resolution_for_radius(rect.size().min_elem() / 2.0);
+        let unit_rect =
+            Rect::from_points(&self.outline_points(Pos2::ZERO, 1.0, Rot2::IDENTITY, resolution));
+
+        if !unit_rect.is_positive() {
+            return;
+        }
+
+        let scale = (rect.size() / unit_rect.size()).min_elem();
+
+        self.paint_shape(
+            ui,
+            rect.center() - unit_rect.center().to_vec2() * scale,
+            scale,
+            fill,
+            stroke,
+            Rot2::IDENTITY,
+            Some(resolution),
+        );
+    }
+
+    #[allow(clippy::too_many_arguments)]
     pub(crate) fn paint_shape(
         &self,
         ui: &mut Ui,
@@ -156,28 +745,41 @@ impl WidgetShape {
         fill: Color32,
         stroke: Stroke,
         rotation: Rot2,
+        resolution: Option<usize>,
     ) {
-        let outline_points = (0..Self::RESOLUTION)
-            .map(move |i| {
-                let angle = (i as f32 / Self::RESOLUTION as f32) * TAU;
-                let shape_radius = self.eval(angle - (rotation * Vec2::RIGHT).angle());
-                center + Vec2::angled(angle) * radius * shape_radius
-            })
-            .collect_vec();
+        let resolution = resolution.unwrap_or_else(|| Self::resolution_for_radius(radius));
+        let outline_points = self.outline_points(center, radius, rotation, resolution);
 
-        // https://github.com/emilk/egui/issues/513
-        outline_points
-            .iter()
-            .circular_tuple_windows()
-            .for_each(|(point_1, point_2)| {
+        #[cfg(feature = "legacy_polygon_fill")]
+        if LEGACY_POLYGON_FILL.get() {
+            // https://github.com/emilk/egui/issues/513
+            for (point_1, point_2) in outline_points.iter().circular_tuple_windows() {
                 ui.painter().add(Shape::convex_polygon(
                     vec![center, *point_1, *point_2],
                     fill,
                     Stroke::new(1.0, fill),
                 ));
-            });
+            }
+
+            ui.painter().add(Shape::closed_line(outline_points, stroke));
+            return;
+        }
+
+        // Polar shapes are star-shaped around their center, so a triangle fan covers
+        // concave outlines too.
+        let mut mesh = Mesh::default();
+        mesh.colored_vertex(center, fill);
+
+        for point in &outline_points {
+            mesh.colored_vertex(*point, fill);
+        }
+
+        let outline_len = outline_points.len() as u32;
+        for i in 0..outline_len {
+            mesh.add_triangle(0, 1 + i, 1 + (i + 1) % outline_len);
+        }
 
-        ui.painter().add(Shape::closed_line(outline_points, stroke));
+        Self::paint_mesh_with_outline(ui, mesh, outline_points, fill, stroke);
     }
 
     #[allow(clippy::too_many_arguments)]
@@ -192,14 +794,13 @@ impl WidgetShape {
         fill: Color32,
         stroke: Stroke,
         rotation: Rot2,
+        resolution: Option<usize>,
     ) {
-        // NOTE: convex_polygon() is broken, spews rendering artifacts all over
-        //   the window when it tries to render degenerate polygons:
-        //     ∃(P1,P2) ∈ Poly (dist(P1,P2) ≈ 0)
+        let shape_radius_at = |angle: f32| self.eval(angle - (rotation * Vec2::RIGHT).angle());
 
-        // HACK: convex_polygon() workaround
+        // Arcs without sweep collapse into a radial line segment
         if almost_equal(start_angle, end_angle, 0.001) {
-            let shape_radius = self.eval(start_angle - (rotation * Vec2::RIGHT).angle());
+            let shape_radius = shape_radius_at(start_angle);
 
             ui.painter().add(Shape::line_segment(
                 [
@@ -211,48 +812,177 @@ impl WidgetShape {
             return;
         }
 
-        let generate_arc_points = |radius| {
-            (0..=Self::RESOLUTION).map(move |i| {
-                let angle = lerp(start_angle..=end_angle, i as f32 / Self::RESOLUTION as f32);
-                let shape_radius = self.eval(angle - (rotation * Vec2::RIGHT).angle());
-                center + Vec2::angled(angle) * radius * shape_radius
-            })
-        };
+        let resolution = resolution.unwrap_or_else(|| Self::resolution_for_radius(outer_radius));
+        let arc_angles = self.sample_angles(start_angle, end_angle, resolution, rotation);
 
-        // HACK: convex_polygon() workaround
-        let inner_radius = inner_radius.max(0.1);
+        // Custom shapes may evaluate to non-finite radii at some angles
+        let arc_points = |radius: f32| {
+            arc_angles
+                .iter()
+                .map(|&angle| center + Vec2::angled(angle) * radius * shape_radius_at(angle))
+                .filter(|point| point.is_finite())
+                .collect_vec()
+        };
 
-        let outer_arc = generate_arc_points(outer_radius).collect::<Vec<_>>();
-        let inner_arc = generate_arc_points(inner_radius).collect::<Vec<_>>();
+        // Rings too thin for a fill collapse into a polyline along their middle
+        if (outer_radius - inner_radius).abs() < Self::MIN_RING_THICKNESS {
+            ui.painter().add(Shape::line(
+                arc_points((inner_radius + outer_radius) / 2.0),
+                Self::outline_stroke(fill, stroke),
+            ));
+            return;
+        }
 
-        // https://github.com/emilk/egui/issues/513
-        outer_arc
+        let (outer_arc, inner_arc): (Vec<_>, Vec<_>) = arc_angles
             .iter()
-            .zip(inner_arc.iter())
-            .tuple_windows()
-            .for_each(|((outer_1, inner_1), (outer_2, inner_2))| {
+            .map(|&angle| {
+                let direction = Vec2::angled(angle) * shape_radius_at(angle);
+                (
+                    center + direction * outer_radius,
+                    center + direction * inner_radius,
+                )
+            })
+            .filter(|(outer_point, inner_point)| outer_point.is_finite() && inner_point.is_finite())
+            .unzip();
+
+        #[cfg(feature = "legacy_polygon_fill")]
+        if LEGACY_POLYGON_FILL.get() {
+            // https://github.com/emilk/egui/issues/513
+            for ((outer_1, inner_1), (outer_2, inner_2)) in
+                outer_arc.iter().zip(&inner_arc).tuple_windows()
+            {
                 ui.painter().add(Shape::convex_polygon(
                     vec![*outer_1, *inner_1, *inner_2, *outer_2],
                     fill,
                     Stroke::new(1.0, fill),
                 ));
-            });
+            }
 
-        let outline_points: Vec<Pos2> = outer_arc
-            .iter()
-            .chain(inner_arc.iter().rev())
-            .copied()
-            .collect();
+            let outline_points = outer_arc
+                .into_iter()
+                .chain(inner_arc.into_iter().rev())
+                .collect_vec();
+
+            ui.painter().add(Shape::closed_line(outline_points, stroke));
+            return;
+        }
+
+        let mesh = Self::arc_mesh(&outer_arc, &inner_arc, fill);
 
-        ui.painter().add(Shape::closed_line(outline_points, stroke));
+        let outline_points = outer_arc
+            .into_iter()
+            .chain(inner_arc.into_iter().rev())
+            .collect_vec();
 
-        // TODO: Remove hacks and paint the arc with a single call:
-        // Shape::concave_polygon(
-        //     outline_points, // outer_arc.chain(inner_arc.rev())
-        //     fill,
-        //     stroke,
-        // )
+        Self::paint_mesh_with_outline(ui, mesh, outline_points, fill, stroke);
     }
+
+    /// Strip of quads between the outer and the inner arc. Vertices closer than
+    /// [`Self::VERTEX_EPSILON`] to the previous vertex of their arc are merged, so a zero
+    /// inner radius shares a single center vertex, and triangles left without area are
+    /// skipped.
+    fn arc_mesh(outer_arc: &[Pos2], inner_arc: &[Pos2], fill: Color32) -> Mesh {
+        fn add_vertex(mesh: &mut Mesh, previous: Option<u32>, point: Pos2, fill: Color32) -> u32 {
+            match previous {
+                Some(index)
+                    if mesh.vertices[index as usize].pos.distance(point)
+                        < WidgetShape::VERTEX_EPSILON =>
+                {
+                    index
+                }
+                _ => {
+                    mesh.colored_vertex(point, fill);
+                    mesh.vertices.len() as u32 - 1
+                }
+            }
+        }
+
+        fn triangle_area(mesh: &Mesh, [a, b, c]: [u32; 3]) -> f32 {
+            let [a, b, c] = [a, b, c].map(|index| mesh.vertices[index as usize].pos);
+            let (ab, ac) = (b - a, c - a);
+            (ab.x * ac.y - ab.y * ac.x).abs() / 2.0
+        }
+
+        let mut mesh = Mesh::default();
+        let mut previous: Option<(u32, u32)> = None;
+
+        for (outer_point, inner_point) in outer_arc.iter().zip(inner_arc) {
+            let outer = add_vertex(
+                &mut mesh,
+                previous.map(|(outer, _)| outer),
+                *outer_point,
+                fill,
+            );
+            let inner = add_vertex(
+                &mut mesh,
+                previous.map(|(_, inner)| inner),
+                *inner_point,
+                fill,
+            );
+
+            if let Some((previous_outer, previous_inner)) = previous {
+                for triangle in [
+                    [previous_outer, previous_inner, outer],
+                    [previous_inner, inner, outer],
+                ] {
+                    if triangle_area(&mesh, triangle) > Self::MIN_TRIANGLE_AREA {
+                        mesh.add_triangle(triangle[0], triangle[1], triangle[2]);
+                    }
+                }
+            }
+
+            previous = Some((outer, inner));
+        }
+
+        mesh
+    }
+
+    /// Stroke of the outline, a hairline in the fill color when there's no stroke,
+    /// anti-aliasing the edges of the fill.
+    fn outline_stroke(fill: Color32, stroke: Stroke) -> Stroke {
+        if stroke.is_empty() {
+            Stroke::new(1.0, fill)
+        } else {
+            stroke
+        }
+    }
+
+    /// Paints the fill mesh, then its outline.
+    fn paint_mesh_with_outline(
+        ui: &mut Ui,
+        mesh: Mesh,
+        outline_points: Vec<Pos2>,
+        fill: Color32,
+        stroke: Stroke,
+    ) {
+        if fill != Color32::TRANSPARENT {
+            ui.painter().add(Shape::mesh(mesh));
+        }
+
+        ui.painter().add(Shape::closed_line(
+            outline_points,
+            Self::outline_stroke(fill, stroke),
+        ));
+    }
+}
+
+// ----------------------------------------------------------------------------
+
+#[cfg(feature = "legacy_polygon_fill")]
+thread_local! {
+    static LEGACY_POLYGON_FILL: Cell<bool> = const { Cell::new(false) };
+}
+
+/// Paints the widget shapes added by `add_contents` the way older versions did, with
+/// one convex polygon per outline segment instead of a single mesh. Only meant for
+/// comparing the two in benchmarks and in the demo, the regular builds leave it out.
+#[cfg(feature = "legacy_polygon_fill")]
+#[doc(hidden)]
+pub fn with_legacy_polygon_fill<R>(add_contents: impl FnOnce() -> R) -> R {
+    let previous = LEGACY_POLYGON_FILL.replace(true);
+    let result = add_contents();
+    LEGACY_POLYGON_FILL.set(previous);
+    result
 }
 
 // ----------------------------------------------------------------------------
@@ -294,11 +1024,15 @@ pub(crate) fn snap_wrap_constrain_angle(
         new_value = (new_value / snap_angle).round() * snap_angle;
     }
 
+    if wrap == WrapMode::Signed {
+        new_value = wrap.wrap(new_value, -PI, PI);
+    }
+
     if wrap == WrapMode::Unsigned {
-        new_value = normalized_angle_unsigned_excl(new_value);
+        new_value = wrap.wrap(new_value, 0.0, TAU);
     }
 
-    if wrap == WrapMode::None {
+    if wrap == WrapMode::None || wrap == WrapMode::Clamped {
         let prev_turns = (prev_value / TAU).round();
         new_value += prev_turns * TAU;
 
@@ -309,6 +1043,10 @@ pub(crate) fn snap_wrap_constrain_angle(
         }
     }
 
+    if wrap == WrapMode::Clamped {
+        new_value = wrap.wrap(new_value, 0.0, TAU);
+    }
+
     if let Some(min) = min {
         new_value = new_value.max(min);
     }
@@ -322,6 +1060,33 @@ pub(crate) fn snap_wrap_constrain_angle(
 
 // ----------------------------------------------------------------------------
 
+/// Wrap angle to `(-PI, PI]` range.
+#[must_use]
+pub fn normalized_angle_signed(angle: f32) -> f32 {
+    // The remainder is exact, unlike `normalized_angle_unsigned_excl`, which loses
+    // precision around the seam by adding `TAU` first.
+    let angle = angle % TAU;
+    if angle > PI {
+        angle - TAU
+    } else if angle <= -PI {
+        angle + TAU
+    } else {
+        angle
+    }
+}
+
+/// Shortest signed angular difference from `from` to `to`, in `(-PI, PI]` range.
+#[must_use]
+pub fn angle_delta(from: f32, to: f32) -> f32 {
+    normalized_angle_signed(to - from)
+}
+
+/// Interpolate between two angles along the shortest path.
+#[must_use]
+pub fn angle_lerp(from: f32, to: f32, t: f32) -> f32 {
+    from + angle_delta(from, to) * t
+}
+
 /// Wrap angle to `(0..TAU)` range.
 pub(crate) fn normalized_angle_unsigned_excl(angle: f32) -> f32 {
     ((angle % TAU) + TAU) % TAU
@@ -397,3 +1162,504 @@ impl RotatedText for Painter {
         rect
     }
 }
+
+// ----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    mod orientation {
+        use super::*;
+
+        #[test]
+        fn from_degrees_round_trips_through_to_degrees() {
+            for degrees in [0.0, 45.0, -90.0, 270.0, 1234.5] {
+                let orientation = Orientation::from_degrees(degrees);
+                assert!((orientation.to_degrees() - degrees).abs() < 1e-3);
+                assert_eq!(orientation.to_degrees(), orientation.angle_deg());
+            }
+
+            assert_eq!(Orientation::Top.to_degrees(), 270.0);
+        }
+
+        #[test]
+        fn setting_string_round_trips_custom_angles_losslessly() {
+            let angles = [
+                0.0,
+                -0.0,
+                1.0,
+                TAU / 3.0,
+                -PI,
+                123_456.79,
+                f32::EPSILON,
+                f32::MIN_POSITIVE,
+                f32::MIN_POSITIVE / 3.0,
+                f32::MAX,
+                f32::MIN,
+                f32::INFINITY,
+                f32::NEG_INFINITY,
+                f32::NAN,
+                47.0f32.to_radians(),
+            ];
+
+            for angle in angles {
+                let text = Orientation::Custom(angle).to_setting_string();
+                match text.parse::<Orientation>() {
+                    Ok(Orientation::Custom(parsed)) => assert_eq!(
+                        parsed.to_bits(),
+                        angle.to_bits(),
+                        "{text} parsed as {parsed}"
+                    ),
+                    other => panic!("{text} parsed as {other:?}"),
+                }
+            }
+        }
+
+        #[test]
+        fn setting_string_round_trips_fixed_orientations() {
+            for orientation in Orientation::iter_fixed() {
+                assert_eq!(orientation.to_setting_string().parse(), Ok(orientation));
+            }
+        }
+
+        #[test]
+        fn display_is_a_label_for_humans() {
+            assert_eq!(Orientation::Top.to_string(), "Top (270.0\u{B0})");
+            assert_eq!(Orientation::Right.to_string(), "Right (0.0\u{B0})");
+            assert_eq!(
+                Orientation::from_degrees(37.5).to_string(),
+                "Custom (37.5\u{B0})"
+            );
+        }
+    }
+
+    mod widget_shape {
+        use super::*;
+
+        fn unit(_theta: f32) -> f32 {
+            1.0
+        }
+
+        #[test]
+        fn custom_shapes_compare_by_identity() {
+            let shape = WidgetShape::from_fn(unit);
+
+            assert_eq!(shape, shape.clone());
+            assert_ne!(shape, WidgetShape::from_fn(unit));
+            assert_eq!(
+                WidgetShape::Rotated(Box::new(shape.clone()), 1.0),
+                WidgetShape::Rotated(Box::new(shape), 1.0)
+            );
+        }
+
+        #[test]
+        fn built_in_shapes_compare_by_parameters() {
+            assert_eq!(WidgetShape::Star(5, 0.5), WidgetShape::Star(5, 0.5));
+            assert_ne!(WidgetShape::Star(5, 0.5), WidgetShape::Star(5, 0.4));
+            assert_ne!(WidgetShape::Polygon(5), WidgetShape::Star(5, 0.5));
+        }
+    }
+
+    mod wrap_mode {
+        use super::*;
+
+        #[test]
+        fn none_returns_value_unchanged() {
+            for value in [-100.0, -TAU, 0.0, PI, TAU, 100.0, f32::INFINITY] {
+                assert_eq!(WrapMode::None.wrap(value, 0.0, TAU), value);
+            }
+        }
+
+        #[test]
+        fn signed_keeps_bounds_inclusive() {
+            assert_eq!(WrapMode::Signed.wrap(-PI, -PI, PI), -PI);
+            assert_eq!(WrapMode::Signed.wrap(PI, -PI, PI), PI);
+            assert_eq!(WrapMode::Signed.wrap(0.0, -PI, PI), 0.0);
+            assert_eq!(WrapMode::Signed.wrap(1.0, -2.0, 3.0), 1.0);
+        }
+
+        #[test]
+        fn signed_whole_periods_keep_sign() {
+            assert_eq!(WrapMode::Signed.wrap(5.0, -1.0, 1.0), 1.0);
+            assert_eq!(WrapMode::Signed.wrap(-5.0, -1.0, 1.0), -1.0);
+            assert_eq!(WrapMode::Signed.wrap(3.0, -1.0, 1.0), 1.0);
+            assert_eq!(WrapMode::Signed.wrap(-3.0, -1.0, 1.0), -1.0);
+        }
+
+        #[test]
+        fn signed_one_period_out() {
+            assert_eq!(WrapMode::Signed.wrap(2.5, -1.0, 1.0), 0.5);
+            assert_eq!(WrapMode::Signed.wrap(-2.5, -1.0, 1.0), -0.5);
+        }
+
+        #[test]
+        fn signed_far_out_of_range() {
+            assert_eq!(WrapMode::Signed.wrap(1000.5, -1.0, 1.0), 0.5);
+            assert_eq!(WrapMode::Signed.wrap(-1000.5, -1.0, 1.0), -0.5);
+        }
+
+        #[test]
+        fn unsigned_excludes_max() {
+            assert_eq!(WrapMode::Unsigned.wrap(0.0, 0.0, 4.0), 0.0);
+            assert_eq!(WrapMode::Unsigned.wrap(4.0, 0.0, 4.0), 0.0);
+            assert_eq!(WrapMode::Unsigned.wrap(3.5, 0.0, 4.0), 3.5);
+            assert_eq!(WrapMode::Unsigned.wrap(2.0, 1.0, 3.0), 2.0);
+        }
+
+        #[test]
+        fn unsigned_one_period_out() {
+            assert_eq!(WrapMode::Unsigned.wrap(5.0, 0.0, 4.0), 1.0);
+            assert_eq!(WrapMode::Unsigned.wrap(-1.0, 0.0, 4.0), 3.0);
+            assert_eq!(WrapMode::Unsigned.wrap(8.0, 0.0, 4.0), 0.0);
+            assert_eq!(WrapMode::Unsigned.wrap(-4.0, 0.0, 4.0), 0.0);
+        }
+
+        #[test]
+        fn unsigned_far_out_of_range() {
+            assert_eq!(WrapMode::Unsigned.wrap(1001.0, 0.0, 4.0), 1.0);
+            assert_eq!(WrapMode::Unsigned.wrap(-1001.0, 0.0, 4.0), 3.0);
+        }
+
+        #[test]
+        fn unsigned_multiples_of_tau() {
+            for turns in [-3.0, -1.0, 1.0, 2.0, 10.0] {
+                let value = WrapMode::Unsigned.wrap(turns * TAU, 0.0, TAU);
+                assert!(value < 1e-4 || TAU - value < 1e-4, "{turns} turns: {value}");
+                assert!((0.0..TAU).contains(&value), "{turns} turns: {value}");
+            }
+        }
+
+        #[test]
+        fn unsigned_tiny_negative_stays_below_max() {
+            let value = WrapMode::Unsigned.wrap(-f32::EPSILON * 1e-3, 0.0, TAU);
+            assert!((0.0..TAU).contains(&value));
+        }
+
+        #[test]
+        fn clamped() {
+            assert_eq!(WrapMode::Clamped.wrap(0.0, 0.0, TAU), 0.0);
+            assert_eq!(WrapMode::Clamped.wrap(TAU, 0.0, TAU), TAU);
+            assert_eq!(WrapMode::Clamped.wrap(TAU + 1.0, 0.0, TAU), TAU);
+            assert_eq!(WrapMode::Clamped.wrap(-1.0, 0.0, TAU), 0.0);
+            assert_eq!(WrapMode::Clamped.wrap(1e9, 0.0, TAU), TAU);
+            assert_eq!(WrapMode::Clamped.wrap(-1e9, 0.0, TAU), 0.0);
+        }
+
+        #[test]
+        fn nan_value_stays_nan() {
+            for wrap in [
+                WrapMode::None,
+                WrapMode::Signed,
+                WrapMode::Unsigned,
+                WrapMode::Clamped,
+            ] {
+                assert!(wrap.wrap(f32::NAN, 0.0, TAU).is_nan(), "{wrap}");
+            }
+        }
+
+        #[test]
+        fn invalid_ranges_return_value_unchanged() {
+            for wrap in [WrapMode::Signed, WrapMode::Unsigned, WrapMode::Clamped] {
+                assert_eq!(wrap.wrap(5.0, 1.0, 1.0), 5.0, "{wrap}");
+                assert_eq!(wrap.wrap(5.0, 2.0, 1.0), 5.0, "{wrap}");
+                assert_eq!(wrap.wrap(5.0, f32::NAN, 1.0), 5.0, "{wrap}");
+            }
+        }
+    }
+    mod angles {
+        use super::*;
+
+        fn assert_close(actual: f32, expected: f32) {
+            assert!(
+                (actual - expected).abs() < 1e-5,
+                "expected {expected}, got {actual}"
+            );
+        }
+
+        #[test]
+        fn signed_zero() {
+            assert_eq!(normalized_angle_signed(0.0), 0.0);
+        }
+
+        #[test]
+        fn signed_pi_maps_to_pi() {
+            assert_eq!(normalized_angle_signed(PI), PI);
+            assert_eq!(normalized_angle_signed(-PI), PI);
+
+            // Odd multiples of PI are rounded in `f32`, landing on either side of the seam
+            assert_close(normalized_angle_signed(3.0 * PI).abs(), PI);
+            assert_close(normalized_angle_signed(-3.0 * PI).abs(), PI);
+        }
+
+        #[test]
+        fn signed_whole_turns_map_to_zero() {
+            assert_eq!(normalized_angle_signed(TAU), 0.0);
+            assert_eq!(normalized_angle_signed(-TAU), 0.0);
+
+            for turns in [-5.0, -2.0, 2.0, 5.0] {
+                assert_close(normalized_angle_signed(turns * TAU), 0.0);
+            }
+        }
+
+        #[test]
+        fn signed_arbitrary_multiples() {
+            assert_close(normalized_angle_signed(TAU + 1.0), 1.0);
+            assert_close(normalized_angle_signed(-TAU - 1.0), -1.0);
+            assert_close(normalized_angle_signed(4.0 * TAU + 0.5), 0.5);
+            assert_close(normalized_angle_signed(-4.0 * TAU - 0.5), -0.5);
+        }
+
+        #[test]
+        fn signed_stays_in_range() {
+            for step in -1000..=1000 {
+                let angle = normalized_angle_signed(step as f32 * 0.0137);
+                assert!(angle > -PI && angle <= PI, "{angle}");
+            }
+        }
+
+        #[test]
+        fn signed_seam() {
+            assert_close(normalized_angle_signed(PI + 0.01), -PI + 0.01);
+            assert_close(normalized_angle_signed(-PI - 0.01), PI - 0.01);
+            assert_close(normalized_angle_signed(PI - 0.01), PI - 0.01);
+            assert_close(normalized_angle_signed(-PI + 0.01), -PI + 0.01);
+        }
+
+        #[test]
+        fn delta_across_seam() {
+            assert_close(angle_delta(PI - 0.1, -PI + 0.1), 0.2);
+            assert_close(angle_delta(-PI + 0.1, PI - 0.1), -0.2);
+            assert_close(angle_delta(TAU - 0.1, 0.1), 0.2);
+            assert_close(angle_delta(0.1, TAU - 0.1), -0.2);
+        }
+
+        #[test]
+        fn delta_takes_short_way() {
+            for from_step in -20..=20 {
+                for to_step in -20..=20 {
+                    let from = from_step as f32 * 0.61;
+                    let to = to_step as f32 * 0.47;
+                    let delta = angle_delta(from, to);
+
+                    assert!(delta > -PI - 1e-5 && delta <= PI + 1e-5, "{from} -> {to}");
+                    assert_close(normalized_angle_signed(from + delta - to), 0.0);
+                }
+            }
+        }
+
+        #[test]
+        fn delta_half_turn_is_positive() {
+            assert_close(angle_delta(0.0, PI), PI);
+            assert_close(angle_delta(PI, 0.0), PI);
+        }
+
+        #[test]
+        fn lerp_across_seam() {
+            assert_close(angle_lerp(TAU - 0.2, 0.2, 0.5), TAU);
+            assert_close(angle_lerp(0.2, TAU - 0.2, 0.5), 0.0);
+            assert_close(angle_lerp(PI - 0.2, -PI + 0.2, 0.25), PI - 0.1);
+        }
+
+        #[test]
+        fn lerp_endpoints() {
+            assert_close(angle_lerp(1.0, 2.0, 0.0), 1.0);
+            assert_close(angle_lerp(1.0, 2.0, 1.0), 2.0);
+            assert_close(normalized_angle_signed(angle_lerp(3.0, -3.0, 1.0)), -3.0);
+        }
+    }
+    mod paint_arc {
+        use egui::{CentralPanel, Context, RawInput};
+
+        use super::*;
+
+        const RADII: [(f32, f32); 9] = [
+            (0.0, 10.0),
+            (0.0, 0.0),
+            (5.0, 5.0),
+            (9.8, 10.0),
+            (10.0, 10.3),
+            (10.0, 10.6),
+            (1e-3, 50.0),
+            (50.0, 100.0),
+            (100.0, 50.0),
+        ];
+
+        const START_ANGLES: [f32; 4] = [0.0, -1.0, 3.0, 100.0];
+
+        const SWEEPS: [f32; 12] = [
+            0.0,
+            1e-4,
+            1.5e-3,
+            0.01,
+            0.1,
+            PI,
+            -PI,
+            TAU,
+            -TAU,
+            1.5 * TAU,
+            10.0 * TAU,
+            -10.0 * TAU,
+        ];
+
+        fn shapes() -> Vec<WidgetShape> {
+            vec![
+                WidgetShape::Circle,
+                WidgetShape::Square,
+                WidgetShape::Squircle(0.5),
+                WidgetShape::Polygon(5),
+                WidgetShape::Star(5, 0.5),
+                WidgetShape::RoundedPolygon(6, 0.3),
+                WidgetShape::Scaled(Box::new(WidgetShape::Circle), 0.0),
+                // Non-finite radii around the top of the arc
+                WidgetShape::from_fn(|theta| {
+                    if theta.sin() > 0.99 {
+                        f32::INFINITY
+                    } else {
+                        1.0
+                    }
+                }),
+            ]
+        }
+
+        /// Paints every radius, start angle and sweep combination of the shape into a
+        /// headless context, returning the tessellator input.
+        fn paint_sweep(shape: &WidgetShape, resolution: Option<usize>) -> Vec<Shape> {
+            let ctx = Context::default();
+
+            let output = ctx.run(RawInput::default(), |ctx| {
+                CentralPanel::default().show(ctx, |ui| {
+                    for (inner_radius, outer_radius) in RADII {
+                        for start_angle in START_ANGLES {
+                            for sweep in SWEEPS {
+                                shape.paint_arc(
+                                    ui,
+                                    Pos2::new(200.0, 200.0),
+                                    inner_radius,
+                                    outer_radius,
+                                    start_angle,
+                                    start_angle + sweep,
+                                    Color32::RED,
+                                    Stroke::new(1.0, Color32::WHITE),
+                                    Rot2::from_angle(0.3),
+                                    resolution,
+                                );
+                            }
+                        }
+                    }
+                });
+            });
+
+            let mut shapes = Vec::new();
+            for clipped_shape in output.shapes {
+                flatten_shape(clipped_shape.shape, &mut shapes);
+            }
+            shapes
+        }
+
+        fn flatten_shape(shape: Shape, shapes: &mut Vec<Shape>) {
+            if let Shape::Vec(children) = shape {
+                for child in children {
+                    flatten_shape(child, shapes);
+                }
+            } else {
+                shapes.push(shape);
+            }
+        }
+
+        fn triangle_area([a, b, c]: [Pos2; 3]) -> f32 {
+            let (ab, ac) = (b - a, c - a);
+            (ab.x * ac.y - ab.y * ac.x).abs() / 2.0
+        }
+
+        fn check_sweep(resolution: Option<usize>) {
+            for shape in shapes() {
+                let mut mesh_count = 0;
+
+                for painted_shape in paint_sweep(&shape, resolution) {
+                    match painted_shape {
+                        Shape::Mesh(mesh) => {
+                            mesh_count += 1;
+                            assert!(mesh.is_valid(), "{shape:?}: invalid mesh indices");
+
+                            for vertex in &mesh.vertices {
+                                assert!(vertex.pos.is_finite(), "{shape:?}: {:?}", vertex.pos);
+                            }
+
+                            for triangle in mesh.indices.chunks_exact(3) {
+                                let points =
+                                    [0, 1, 2].map(|i| mesh.vertices[triangle[i] as usize].pos);
+                                assert!(
+                                    triangle_area(points) > 0.0,
+                                    "{shape:?}: zero-area triangle {points:?}"
+                                );
+                            }
+                        }
+                        Shape::Path(path) => {
+                            for point in &path.points {
+                                assert!(point.is_finite(), "{shape:?}: {point:?}");
+                            }
+                        }
+                        Shape::LineSegment { points, .. } => {
+                            for point in &points {
+                                assert!(point.is_finite(), "{shape:?}: {point:?}");
+                            }
+                        }
+                        _ => {}
+                    }
+                }
+
+                assert!(mesh_count > 0, "{shape:?}: no arcs were filled");
+            }
+        }
+
+        #[test]
+        fn sweep_default_resolution() {
+            check_sweep(None);
+        }
+
+        #[test]
+        fn sweep_low_resolution() {
+            check_sweep(Some(3));
+        }
+
+        #[test]
+        fn sweep_high_resolution() {
+            check_sweep(Some(512));
+        }
+
+        #[test]
+        fn zero_sweep_is_radial_line() {
+            let shapes = {
+                let ctx = Context::default();
+                let output = ctx.run(RawInput::default(), |ctx| {
+                    CentralPanel::default().show(ctx, |ui| {
+                        WidgetShape::Circle.paint_arc(
+                            ui,
+                            Pos2::ZERO,
+                            5.0,
+                            10.0,
+                            1.0,
+                            1.0 + 1e-4,
+                            Color32::RED,
+                            Stroke::new(1.0, Color32::WHITE),
+                            Rot2::IDENTITY,
+                            None,
+                        );
+                    });
+                });
+
+                let mut shapes = Vec::new();
+                for clipped_shape in output.shapes {
+                    flatten_shape(clipped_shape.shape, &mut shapes);
+                }
+                shapes
+            };
+
+            assert!(shapes
+                .iter()
+                .any(|shape| matches!(shape, Shape::LineSegment { .. })));
+            assert!(!shapes.iter().any(|shape| matches!(shape, Shape::Mesh(_))));
+        }
+    }
+}
@@ -1,11 +1,17 @@
+use std::borrow::Cow;
+use std::cmp::Reverse;
 use std::f32::consts::TAU;
+use std::fmt;
+use std::ops::RangeInclusive;
 
 use ecolor::Hsva;
-use egui::{vec2, Align2, Color32, FontFamily, FontId, Rect, Shape, Stroke, Ui, Vec2};
+use egui::{
+    lerp, pos2, vec2, Align2, Color32, FontFamily, FontId, Rect, Shape, Stroke, TextureId, Ui, Vec2,
+};
 use itertools::Itertools;
 use strum::Display;
 
-use crate::common::normalized_angle_unsigned_excl;
+use crate::common::{normalized_angle_unsigned_excl, WidgetShape, WidgetShapeError};
 use crate::hash::PearsonHash;
 
 // ----------------------------------------------------------------------------
@@ -67,12 +73,15 @@ impl DefaultCompassMarkerColor {
 // ----------------------------------------------------------------------------
 
 #[must_use = "You should put this marker into a compass with `compass.markers(&[markers]);`"]
+#[derive(Clone)]
 pub struct CompassMarker<'a> {
     pub(crate) angle: f32,
     pub(crate) distance: Option<f32>,
     pub(crate) shape: Option<CompassMarkerShape>,
     pub(crate) label: Option<&'a str>,
     pub(crate) color: Option<Color32>,
+    pub(crate) priority: i32,
+    pub(crate) id: Option<usize>,
 }
 
 impl<'a> CompassMarker<'a> {
@@ -83,6 +92,8 @@ impl<'a> CompassMarker<'a> {
             shape: None,
             label: None,
             color: None,
+            priority: 0,
+            id: None,
         }
     }
 
@@ -105,42 +116,292 @@ impl<'a> CompassMarker<'a> {
         self.color = Some(color);
         self
     }
+
+    /// Markers with higher priority are drawn on top of the others and win
+    /// hit-testing, markers of equal priority keep their original order.
+    pub fn priority(mut self, priority: i32) -> Self {
+        self.priority = priority;
+        self
+    }
+
+    /// Identifies the marker for the selection, the clicks and the relative bearing readout
+    /// of the compass. Defaults to the index of the marker among the markers of the compass,
+    /// which is not stable for markers queried by range.
+    pub fn id(mut self, id: usize) -> Self {
+        self.id = Some(id);
+        self
+    }
+}
+
+// ----------------------------------------------------------------------------
+
+pub(crate) type CompassMarkerIter<'a> = Box<dyn 'a + Iterator<Item = CompassMarker<'a>>>;
+
+pub(crate) type CompassMarkerCallback<'a> =
+    Box<dyn 'a + FnOnce(RangeInclusive<f32>) -> CompassMarkerIter<'a>>;
+
+pub(crate) enum CompassMarkerSource<'a> {
+    Slice(&'a [CompassMarker<'a>]),
+    Iter(CompassMarkerIter<'a>),
+    Callback(CompassMarkerCallback<'a>),
+}
+
+impl<'a> CompassMarkerSource<'a> {
+    pub(crate) fn from_iter<I>(markers: I) -> Self
+    where
+        I: IntoIterator<Item = CompassMarker<'a>>,
+        I::IntoIter: 'a,
+    {
+        CompassMarkerSource::Iter(Box::new(markers.into_iter()))
+    }
+
+    pub(crate) fn from_callback<I>(markers: impl 'a + FnOnce(RangeInclusive<f32>) -> I) -> Self
+    where
+        I: IntoIterator<Item = CompassMarker<'a>>,
+        I::IntoIter: 'a,
+    {
+        CompassMarkerSource::Callback(Box::new(|visible_range| {
+            Box::new(markers(visible_range).into_iter())
+        }))
+    }
+
+    /// Iterators and callbacks are only consumed here, when the compass gets
+    /// painted, collecting their markers once for indexing and sorting.
+    pub(crate) fn resolve(
+        self,
+        visible_range: RangeInclusive<f32>,
+    ) -> Cow<'a, [CompassMarker<'a>]> {
+        match self {
+            CompassMarkerSource::Slice(markers) => Cow::Borrowed(markers),
+            CompassMarkerSource::Iter(markers) => Cow::Owned(markers.collect()),
+            CompassMarkerSource::Callback(callback) => {
+                Cow::Owned(callback(visible_range).collect())
+            }
+        }
+    }
+}
+
+// ----------------------------------------------------------------------------
+
+pub(crate) struct CompassMarkerCluster {
+    pub(crate) angle: f32,
+    pub(crate) members: Vec<usize>,
+}
+
+impl CompassMarkerCluster {
+    /// Groups markers falling into the same bearing bucket. The buckets tile the full
+    /// circle starting at north, each of them at most `threshold` radians wide, so
+    /// markers closer than the threshold may still end up in neighboring buckets.
+    /// Non-positive thresholds disable the grouping.
+    ///
+    /// The grouping of a marker only depends on its own bearing, never on the current
+    /// heading or on the other markers, so the clusters don't flicker as the compass
+    /// turns and markers queried by range enter or leave the tape.
+    ///
+    /// The `selected` marker is taken out of its cluster after the grouping, so
+    /// selecting a marker doesn't regroup the others, and is returned last as a
+    /// cluster of its own to keep it on top.
+    ///
+    /// Cluster members are ordered by descending priority, so the first member
+    /// represents the cluster. Clusters are returned in drawing order, by
+    /// ascending priority of their representatives, then by marker order.
+    pub(crate) fn from_markers(
+        markers: &[CompassMarker],
+        threshold: Option<f32>,
+        selected: Option<usize>,
+    ) -> Vec<CompassMarkerCluster> {
+        let mut groups: Vec<Vec<usize>> = match threshold.filter(|&threshold| threshold > 0.0) {
+            Some(threshold) => {
+                let bucket_count = (TAU / threshold).ceil() as usize;
+                let bucket_width = TAU / bucket_count as f32;
+
+                // Bearings are normalized, so the buckets never wrap around north
+                (0..markers.len())
+                    .into_group_map_by(|&index| {
+                        ((markers[index].angle / bucket_width) as usize).min(bucket_count - 1)
+                    })
+                    .into_iter()
+                    .sorted_by_key(|&(bucket, _)| bucket)
+                    .map(|(_, members)| members)
+                    .collect()
+            }
+            None => (0..markers.len()).map(|index| vec![index]).collect(),
+        };
+
+        let selected = selected.filter(|&index| index < markers.len());
+
+        if let Some(selected) = selected {
+            for group in &mut groups {
+                group.retain(|&index| index != selected);
+            }
+            groups.retain(|group| !group.is_empty());
+        }
+
+        groups
+            .into_iter()
+            .map(|mut members| {
+                let mean_angle = members
+                    .iter()
+                    .map(|&index| markers[index].angle)
+                    .sum::<f32>()
+                    / members.len() as f32;
+
+                members.sort_by_key(|&index| (Reverse(markers[index].priority), index));
+
+                CompassMarkerCluster {
+                    angle: mean_angle,
+                    members,
+                }
+            })
+            .sorted_by_key(|cluster| {
+                let representative = cluster.members[0];
+                (markers[representative].priority, representative)
+            })
+            .chain(selected.map(|selected| CompassMarkerCluster {
+                angle: normalized_angle_unsigned_excl(markers[selected].angle),
+                members: vec![selected],
+            }))
+            .collect_vec()
+    }
 }
 
 // ----------------------------------------------------------------------------
 
 #[non_exhaustive]
-#[derive(Clone, Copy, Debug, Display, PartialEq)]
+#[derive(Clone, Debug)]
 pub enum CompassMarkerShape {
-    #[strum(to_string = "Square")]
     Square,
 
-    #[strum(to_string = "Circle")]
     Circle,
 
-    #[strum(to_string = "Right arrow")]
+    #[deprecated(note = "use `CompassMarkerShape::RegularPolygon(3, 0.0)` instead")]
     RightArrow,
 
-    #[strum(to_string = "Up arrow")]
+    #[deprecated(note = "use `CompassMarkerShape::RegularPolygon(3, -TAU / 4.0)` instead")]
     UpArrow,
 
-    #[strum(to_string = "Left arrow")]
+    #[deprecated(note = "use `CompassMarkerShape::RegularPolygon(3, TAU / 2.0)` instead")]
     LeftArrow,
 
-    #[strum(to_string = "Down arrow")]
+    #[deprecated(note = "use `CompassMarkerShape::RegularPolygon(3, TAU / 4.0)` instead")]
     DownArrow,
 
-    #[strum(to_string = "Diamond")]
+    /// Regular polygon with the given number of sides, rotated so its first vertex
+    /// points in the direction of the given screen angle (`0.0` pointing right).
+    /// `RegularPolygon(3, TAU / 4.0)` is a downward pointing triangle.
+    RegularPolygon(usize, f32),
+
     Diamond,
 
-    #[strum(to_string = "Star")]
     Star(usize, f32),
 
-    #[strum(to_string = "Emoji")]
+    /// Diagonal cross inset by the given fraction of the marker size on each side,
+    /// drawn in the fill color with the width of the stroke.
+    Cross(f32),
+
+    /// Upright cross inset by the given fraction of the marker size on each side,
+    /// drawn in the fill color with the width of the stroke.
+    Plus(f32),
+
+    /// Map pin, a round head with its tip at the bottom center of the marker.
+    Pin,
+
+    /// Flag on a pole standing on the bottom left corner of the marker.
+    Flag,
+
     Emoji(char),
+
+    /// Text centered on the marker, such as a waypoint identifier.
+    /// Text overflowing the marker rect is clipped.
+    TextLabel(String, FontId),
+
+    Texture(TextureId, Option<Rect>),
+
+    /// Outline of a widget shape fitted into the marker, matching the shape of the
+    /// knobs of the application. Prefer [`CompassMarkerShape::custom`], which validates
+    /// the shape, invalid shapes are painted as a `\u{1F525}` error glyph.
+    Custom(WidgetShape),
+}
+
+#[allow(deprecated)]
+impl PartialEq for CompassMarkerShape {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (
+                CompassMarkerShape::Star(rays_a, ratio_a),
+                CompassMarkerShape::Star(rays_b, ratio_b),
+            ) => (rays_a == rays_b) && (ratio_a - ratio_b).abs() < f32::EPSILON,
+            (
+                CompassMarkerShape::RegularPolygon(sides_a, rotation_a),
+                CompassMarkerShape::RegularPolygon(sides_b, rotation_b),
+            ) => (sides_a == sides_b) && (rotation_a == rotation_b),
+            (CompassMarkerShape::Cross(margin_a), CompassMarkerShape::Cross(margin_b))
+            | (CompassMarkerShape::Plus(margin_a), CompassMarkerShape::Plus(margin_b)) => {
+                margin_a == margin_b
+            }
+            (CompassMarkerShape::Emoji(emoji_a), CompassMarkerShape::Emoji(emoji_b)) => {
+                emoji_a == emoji_b
+            }
+            (
+                CompassMarkerShape::TextLabel(text_a, font_id_a),
+                CompassMarkerShape::TextLabel(text_b, font_id_b),
+            ) => (text_a == text_b) && (font_id_a == font_id_b),
+            (
+                CompassMarkerShape::Texture(texture_id_a, uv_a),
+                CompassMarkerShape::Texture(texture_id_b, uv_b),
+            ) => (texture_id_a == texture_id_b) && (uv_a == uv_b),
+            (CompassMarkerShape::Custom(shape_a), CompassMarkerShape::Custom(shape_b)) => {
+                shape_a == shape_b
+            }
+            (CompassMarkerShape::Square, CompassMarkerShape::Square)
+            | (CompassMarkerShape::Circle, CompassMarkerShape::Circle)
+            | (CompassMarkerShape::RightArrow, CompassMarkerShape::RightArrow)
+            | (CompassMarkerShape::UpArrow, CompassMarkerShape::UpArrow)
+            | (CompassMarkerShape::LeftArrow, CompassMarkerShape::LeftArrow)
+            | (CompassMarkerShape::DownArrow, CompassMarkerShape::DownArrow)
+            | (CompassMarkerShape::Diamond, CompassMarkerShape::Diamond)
+            | (CompassMarkerShape::Pin, CompassMarkerShape::Pin)
+            | (CompassMarkerShape::Flag, CompassMarkerShape::Flag) => true,
+            _ => false,
+        }
+    }
+}
+
+// Implemented by hand, the derived impl would trip over the deprecated variants
+#[allow(deprecated)]
+impl fmt::Display for CompassMarkerShape {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            CompassMarkerShape::Square => "Square",
+            CompassMarkerShape::Circle => "Circle",
+            CompassMarkerShape::RightArrow => "Right arrow",
+            CompassMarkerShape::UpArrow => "Up arrow",
+            CompassMarkerShape::LeftArrow => "Left arrow",
+            CompassMarkerShape::DownArrow => "Down arrow",
+            CompassMarkerShape::RegularPolygon(..) => "Regular polygon",
+            CompassMarkerShape::Diamond => "Diamond",
+            CompassMarkerShape::Star(..) => "Star",
+            CompassMarkerShape::Cross(..) => "Cross",
+            CompassMarkerShape::Plus(..) => "Plus",
+            CompassMarkerShape::Pin => "Pin",
+            CompassMarkerShape::Flag => "Flag",
+            CompassMarkerShape::Emoji(..) => "Emoji",
+            CompassMarkerShape::TextLabel(..) => "Text label",
+            CompassMarkerShape::Texture(..) => "Texture",
+            CompassMarkerShape::Custom(..) => "Custom",
+        };
+
+        f.write_str(name)
+    }
 }
 
 impl CompassMarkerShape {
+    /// Custom marker shape, checked with [`WidgetShape::validate`].
+    pub fn custom(shape: WidgetShape) -> Result<CompassMarkerShape, WidgetShapeError> {
+        shape.validate()?;
+        Ok(CompassMarkerShape::Custom(shape))
+    }
+
     pub(crate) fn paint(&self, ui: &mut Ui, rect: Rect, fill: Color32, stroke: Stroke) {
         match *self {
             CompassMarkerShape::Square => {
@@ -149,50 +410,42 @@ impl CompassMarkerShape {
             CompassMarkerShape::Circle => {
                 ui.painter().rect(rect, rect.width() / 2.0, fill, stroke);
             }
+            #[allow(deprecated)]
             CompassMarkerShape::RightArrow => {
-                let rect = Rect::from_center_size(
-                    rect.center(),
-                    rect.size() * vec2(3.0f32.sqrt() / 2.0, 1.0),
-                );
-
-                ui.painter().add(Shape::convex_polygon(
-                    vec![rect.right_center(), rect.left_bottom(), rect.left_top()],
-                    fill,
-                    stroke,
-                ));
+                CompassMarkerShape::RegularPolygon(3, 0.0).paint(ui, rect, fill, stroke);
             }
+            #[allow(deprecated)]
             CompassMarkerShape::UpArrow => {
-                let rect = Rect::from_center_size(
-                    rect.center(),
-                    rect.size() * vec2(1.0, 3.0f32.sqrt() / 2.0),
-                );
-
-                ui.painter().add(Shape::convex_polygon(
-                    vec![rect.center_top(), rect.right_bottom(), rect.left_bottom()],
-                    fill,
-                    stroke,
-                ));
+                CompassMarkerShape::RegularPolygon(3, -TAU / 4.0).paint(ui, rect, fill, stroke);
             }
+            #[allow(deprecated)]
             CompassMarkerShape::LeftArrow => {
-                let rect = Rect::from_center_size(
-                    rect.center(),
-                    rect.size() * vec2(3.0f32.sqrt() / 2.0, 1.0),
-                );
-
-                ui.painter().add(Shape::convex_polygon(
-                    vec![rect.left_center(), rect.right_top(), rect.right_bottom()],
-                    fill,
-                    stroke,
-                ));
+                CompassMarkerShape::RegularPolygon(3, TAU / 2.0).paint(ui, rect, fill, stroke);
             }
+            #[allow(deprecated)]
             CompassMarkerShape::DownArrow => {
-                let rect = Rect::from_center_size(
-                    rect.center(),
-                    rect.size() * vec2(1.0, 3.0f32.sqrt() / 2.0),
-                );
+                CompassMarkerShape::RegularPolygon(3, TAU / 4.0).paint(ui, rect, fill, stroke);
+            }
+            CompassMarkerShape::RegularPolygon(sides, rotation) => {
+                assert!(sides >= 3, "polygon markers must have at least 3 sides");
+
+                let unit_points = (0..sides)
+                    .map(|point_index| {
+                        pos2(0.0, 0.0)
+                            + Vec2::angled(rotation + TAU * (point_index as f32 / sides as f32))
+                    })
+                    .collect_vec();
+
+                // Fit the bounding box of the polygon into the marker rect,
+                // so triangles pointing sideways fill the rect like squares do.
+                let unit_rect = Rect::from_points(&unit_points);
+                let scale = (rect.size() / unit_rect.size()).min_elem();
 
                 ui.painter().add(Shape::convex_polygon(
-                    vec![rect.left_top(), rect.right_top(), rect.center_bottom()],
+                    unit_points
+                        .into_iter()
+                        .map(|point| rect.center() + (point - unit_rect.center()) * scale)
+                        .collect_vec(),
                     fill,
                     stroke,
                 ));
@@ -242,6 +495,65 @@ impl CompassMarkerShape {
                     stroke,
                 ));
             }
+            CompassMarkerShape::Cross(margin) => {
+                let rect = rect.shrink2(rect.size() * margin.clamp(0.0, 0.5));
+                let stroke = Stroke::new(stroke.width, fill);
+
+                ui.painter()
+                    .line_segment([rect.left_top(), rect.right_bottom()], stroke);
+                ui.painter()
+                    .line_segment([rect.right_top(), rect.left_bottom()], stroke);
+            }
+            CompassMarkerShape::Plus(margin) => {
+                let rect = rect.shrink2(rect.size() * margin.clamp(0.0, 0.5));
+                let stroke = Stroke::new(stroke.width, fill);
+
+                ui.painter()
+                    .line_segment([rect.center_top(), rect.center_bottom()], stroke);
+                ui.painter()
+                    .line_segment([rect.left_center(), rect.right_center()], stroke);
+            }
+            CompassMarkerShape::Pin => {
+                const HEAD_VERTICES: usize = 24;
+
+                let tip = rect.center_bottom();
+                let head_radius = (rect.width() / 2.0).min(rect.height() / 3.0);
+                let head_center = rect.center_top() + vec2(0.0, head_radius);
+
+                // The sides of the tail are tangent to the head, the head outline runs
+                // between the tangent points over the top
+                let tangent_angle = (head_radius / head_center.distance(tip)).acos();
+                let start_angle = TAU / 4.0 + tangent_angle;
+                let end_angle = TAU / 4.0 - tangent_angle + TAU;
+
+                let outline = (0..=HEAD_VERTICES)
+                    .map(|i| {
+                        let angle = lerp(start_angle..=end_angle, i as f32 / HEAD_VERTICES as f32);
+                        head_center + Vec2::angled(angle) * head_radius
+                    })
+                    .chain(std::iter::once(tip))
+                    .collect_vec();
+
+                ui.painter()
+                    .add(Shape::convex_polygon(outline, fill, stroke));
+            }
+            CompassMarkerShape::Flag => {
+                let pole_rect = Rect::from_min_max(
+                    rect.left_top(),
+                    pos2(rect.left() + rect.width() / 8.0, rect.bottom()),
+                );
+
+                ui.painter().rect(pole_rect, 0.0, fill, stroke);
+                ui.painter().add(Shape::convex_polygon(
+                    vec![
+                        pole_rect.right_top(),
+                        pos2(rect.right(), rect.top() + rect.height() * 0.3),
+                        pos2(pole_rect.right(), rect.top() + rect.height() * 0.6),
+                    ],
+                    fill,
+                    stroke,
+                ));
+            }
             CompassMarkerShape::Emoji(emoji) => {
                 ui.painter().text(
                     rect.center(),
@@ -251,6 +563,219 @@ impl CompassMarkerShape {
                     fill,
                 );
             }
+            CompassMarkerShape::TextLabel(ref text, ref font_id) => {
+                let clip_rect = rect.intersect(ui.clip_rect());
+
+                ui.painter().with_clip_rect(clip_rect).text(
+                    rect.center(),
+                    Align2::CENTER_CENTER,
+                    text,
+                    font_id.clone(),
+                    fill,
+                );
+            }
+            CompassMarkerShape::Texture(texture_id, uv) => {
+                let uv = uv.unwrap_or(Rect::from_min_max(pos2(0.0, 0.0), pos2(1.0, 1.0)));
+
+                // Keep the aspect ratio of the texture region, unknown textures are drawn square
+                let image_size = ui
+                    .ctx()
+                    .tex_manager()
+                    .read()
+                    .meta(texture_id)
+                    .map(|meta| vec2(meta.size[0] as f32, meta.size[1] as f32) * uv.size())
+                    .filter(|image_size| image_size.min_elem() > 0.0)
+                    .unwrap_or(Vec2::splat(1.0));
+
+                let image_scale = (rect.size() / image_size).min_elem();
+
+                ui.painter().image(
+                    texture_id,
+                    Rect::from_center_size(rect.center(), image_size * image_scale),
+                    uv,
+                    fill,
+                );
+            }
+            CompassMarkerShape::Custom(ref shape) => {
+                if shape.validate().is_ok() {
+                    shape.paint_shape_in_rect(ui, rect, fill, stroke);
+                } else {
+                    let error_color = ui.style().visuals.error_fg_color;
+                    CompassMarkerShape::Emoji('\u{1F525}').paint(ui, rect, error_color, stroke);
+                }
+            }
         }
     }
 }
+
+// ----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use egui::{CentralPanel, Context, RawInput};
+
+    use super::*;
+    use crate::common::angle_delta;
+
+    #[test]
+    fn custom_validates_shape() {
+        assert!(CompassMarkerShape::custom(WidgetShape::Star(6, 0.6)).is_ok());
+        assert_eq!(
+            CompassMarkerShape::custom(WidgetShape::Polygon(2)),
+            Err(WidgetShapeError::PolygonTooFewSides(2))
+        );
+    }
+
+    #[test]
+    fn invalid_custom_shape_paints_error_glyph() {
+        let ctx = Context::default();
+
+        let _ = ctx.run(RawInput::default(), |ctx| {
+            CentralPanel::default().show(ctx, |ui| {
+                CompassMarkerShape::Custom(WidgetShape::Squircle(0.0)).paint(
+                    ui,
+                    Rect::from_min_size(pos2(10.0, 10.0), Vec2::splat(12.0)),
+                    Color32::RED,
+                    Stroke::NONE,
+                );
+            });
+        });
+    }
+    fn markers_at(degrees: &[f32]) -> Vec<CompassMarker<'static>> {
+        degrees
+            .iter()
+            .map(|angle| CompassMarker::new(angle.to_radians()))
+            .collect()
+    }
+
+    fn cluster_members(clusters: &[CompassMarkerCluster]) -> Vec<Vec<usize>> {
+        clusters
+            .iter()
+            .map(|cluster| cluster.members.iter().copied().sorted().collect())
+            .sorted()
+            .collect()
+    }
+
+    #[test]
+    fn clusters_are_anchored_on_bearing_buckets() {
+        let markers = markers_at(&[3.0, 7.0, 12.0, 180.0, 355.0, 359.0]);
+        let threshold = Some(10.0f32.to_radians());
+
+        let clusters = CompassMarkerCluster::from_markers(&markers, threshold, None);
+        assert_eq!(
+            cluster_members(&clusters),
+            vec![vec![0, 1], vec![2], vec![3], vec![4, 5]]
+        );
+
+        let north_cluster = clusters.iter().find(|cluster| cluster.members == [0, 1]);
+        let angle = north_cluster.unwrap().angle;
+        assert!(
+            (angle - 5.0f32.to_radians()).abs() < 1e-4,
+            "cluster angle {angle}"
+        );
+    }
+
+    #[test]
+    fn clusters_dont_depend_on_the_other_markers() {
+        let bearings = [1.0, 4.0, 8.0, 9.5, 11.0, 14.0, 19.0, 21.0, 358.0];
+        let threshold = Some(5.0f32.to_radians());
+
+        let clusters_of = |bearings: &[f32]| {
+            let markers = markers_at(bearings);
+            CompassMarkerCluster::from_markers(&markers, threshold, None)
+                .into_iter()
+                .map(|cluster| {
+                    cluster
+                        .members
+                        .iter()
+                        .map(|&index| bearings[index].to_bits())
+                        .sorted()
+                        .collect_vec()
+                })
+                .collect_vec()
+        };
+        let all_clusters = clusters_of(&bearings);
+
+        // Markers entering or leaving the tape one by one, from either side
+        for visible in (0..bearings.len())
+            .map(|start| start..bearings.len())
+            .chain((1..=bearings.len()).map(|end| 0..end))
+        {
+            for cluster in clusters_of(&bearings[visible]) {
+                assert!(
+                    all_clusters
+                        .iter()
+                        .any(|all_cluster| cluster.iter().all(|bits| all_cluster.contains(bits))),
+                    "cluster {cluster:?} is not part of {all_clusters:?}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn wrapped_clusters_never_exceed_threshold() {
+        // Both groups fit the threshold on their own, but not when merged
+        let markers = markers_at(&[2.0, 8.0, 352.0, 358.0, 90.0]);
+        let threshold = 10.0f32.to_radians();
+        let clusters = CompassMarkerCluster::from_markers(&markers, Some(threshold), None);
+        assert_eq!(
+            cluster_members(&clusters),
+            vec![vec![0, 1], vec![2, 3], vec![4]]
+        );
+
+        for threshold_degrees in [1.0f32, 5.0, 10.0, 15.0, 45.0] {
+            let markers = markers_at(&[0.5, 4.0, 9.0, 12.0, 340.0, 347.0, 351.0, 356.0, 359.5]);
+            let threshold = threshold_degrees.to_radians();
+
+            for cluster in CompassMarkerCluster::from_markers(&markers, Some(threshold), None) {
+                let extent = cluster
+                    .members
+                    .iter()
+                    .map(|&index| angle_delta(cluster.angle, markers[index].angle))
+                    .minmax()
+                    .into_option()
+                    .map_or(0.0, |(min, max)| max - min);
+                assert!(
+                    extent <= threshold + 1e-4,
+                    "cluster {:?} spans {extent} with threshold {threshold}",
+                    cluster.members
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn selected_marker_is_clustered_on_its_own_last() {
+        let markers = markers_at(&[10.0, 11.0, 12.0, 200.0]);
+        let threshold = Some(5.0f32.to_radians());
+
+        let clusters = CompassMarkerCluster::from_markers(&markers, threshold, Some(1));
+        assert_eq!(clusters.last().unwrap().members, vec![1]);
+        assert_eq!(
+            cluster_members(&clusters),
+            vec![vec![0, 2], vec![1], vec![3]]
+        );
+
+        // Selecting a marker doesn't regroup the other markers
+        let unselected = CompassMarkerCluster::from_markers(&markers, threshold, None);
+        assert_eq!(cluster_members(&unselected), vec![vec![0, 1, 2], vec![3]]);
+
+        // Out of range selections are ignored
+        let clusters = CompassMarkerCluster::from_markers(&markers, threshold, Some(4));
+        assert_eq!(cluster_members(&clusters), cluster_members(&unselected));
+    }
+
+    #[test]
+    fn clusters_are_ordered_by_priority() {
+        let markers = vec![
+            CompassMarker::new(0.0).priority(1),
+            CompassMarker::new(0.01).priority(5),
+            CompassMarker::new(3.0),
+        ];
+        let clusters = CompassMarkerCluster::from_markers(&markers, Some(0.1), None);
+
+        // Clusters are drawn by ascending priority, highest priority members first
+        assert_eq!(clusters[0].members, vec![2]);
+        assert_eq!(clusters[1].members, vec![1, 0]);
+    }
+}
@@ -0,0 +1,30 @@
+use egui::Color32;
+
+/// Appearance of the reciprocal heading (heading ± 180°) indicator.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct CompassReciprocalStyle {
+    /// Color of the indicator, dimmed cursor color when `None`.
+    pub color: Option<Color32>,
+
+    /// Size of the indicator relative to the primary cursor.
+    pub scale: f32,
+
+    /// Whether to label the indicator with the reciprocal bearing.
+    pub show_label: bool,
+}
+
+impl Default for CompassReciprocalStyle {
+    fn default() -> Self {
+        Self {
+            color: None,
+            scale: 0.75,
+            show_label: true,
+        }
+    }
+}
+
+impl CompassReciprocalStyle {
+    pub(crate) fn dimmed(&self, color: Color32) -> Color32 {
+        self.color.unwrap_or_else(|| color.linear_multiply(0.5))
+    }
+}
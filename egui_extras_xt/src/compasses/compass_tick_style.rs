@@ -0,0 +1,27 @@
+use egui::Stroke;
+
+/// Appearance of one class (major or minor) of compass ticks.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct CompassTickStyle {
+    /// Stroke of the ticks, the non-interactive foreground stroke when `None`.
+    pub stroke: Option<Stroke>,
+
+    /// Length of the ticks relative to the tick area of the widget.
+    pub length: f32,
+}
+
+impl CompassTickStyle {
+    pub fn major() -> Self {
+        Self {
+            stroke: None,
+            length: 1.0,
+        }
+    }
+
+    pub fn minor() -> Self {
+        Self {
+            stroke: None,
+            length: 0.5,
+        }
+    }
+}
@@ -1,13 +1,18 @@
-use std::f32::consts::TAU;
+use std::f32::consts::{PI, TAU};
+use std::ops::RangeInclusive;
 
 use ecolor::tint_color_towards;
-use egui::{self, Response, Sense, Ui, Widget};
-use emath::{normalized_angle, pos2, vec2, Align2, Rect, Vec2};
+use egui::{self, Response, RichText, Sense, Ui, Widget};
+use emath::{pos2, vec2, Align2, Rect, Vec2};
 use epaint::{Color32, FontFamily, FontId, Stroke};
+use itertools::Itertools;
 
-use crate::common::{normalized_angle_unsigned_incl, Winding, WrapMode};
+use crate::common::{
+    angle_delta, normalized_angle_unsigned_excl, normalized_angle_unsigned_incl, Winding, WrapMode,
+};
 use crate::compasses::{
-    CompassAxisLabels, CompassMarker, CompassMarkerShape, DefaultCompassMarkerColor,
+    CompassAxisLabels, CompassMarker, CompassMarkerCluster, CompassMarkerShape,
+    CompassMarkerSource, CompassReciprocalStyle, CompassTickStyle, DefaultCompassMarkerColor,
 };
 
 // ----------------------------------------------------------------------------
@@ -26,6 +31,11 @@ fn set(get_set_value: &mut GetSetValue<'_>, value: f32) {
 
 // ----------------------------------------------------------------------------
 
+/// Minor ticks closer to each other than this (in points) are not painted.
+const MIN_MINOR_TICK_SPACING: f32 = 4.0;
+
+// ----------------------------------------------------------------------------
+
 #[must_use = "You should put this widget in an ui with `ui.add(widget);`"]
 pub struct LinearCompass<'a> {
     get_set_value: GetSetValue<'a>,
@@ -33,6 +43,7 @@ pub struct LinearCompass<'a> {
     wrap: WrapMode,
     winding: Winding,
     width: f32,
+    fill_width: bool,
     height: f32,
     spread: f32,
     axis_labels: CompassAxisLabels,
@@ -42,9 +53,20 @@ pub struct LinearCompass<'a> {
     max: Option<f32>,
     animated: bool,
     show_cursor: bool,
+    show_reciprocal: bool,
+    reciprocal_style: CompassReciprocalStyle,
     show_ticks: bool,
+    ticks: Option<(f32, f32)>,
+    major_tick_style: CompassTickStyle,
+    minor_tick_style: CompassTickStyle,
     show_axes: bool,
-    markers: &'a [CompassMarker<'a>],
+    markers: CompassMarkerSource<'a>,
+    cluster_threshold: Option<f32>,
+    selected_marker: Option<usize>,
+    clicked_marker: Option<&'a mut Option<usize>>,
+    track_on_marker_click: bool,
+    relative_bearing_to: Option<usize>,
+    show_relative_bearing_arrow: bool,
     default_marker_color: DefaultCompassMarkerColor,
     default_marker_shape: CompassMarkerShape,
 }
@@ -66,6 +88,7 @@ impl<'a> LinearCompass<'a> {
             wrap: WrapMode::Unsigned,
             winding: Winding::Clockwise,
             width: 512.0,
+            fill_width: false,
             height: 48.0,
             spread: 180.0f32.to_radians(),
             axis_labels: ["N", "E", "S", "W"].into(),
@@ -75,9 +98,20 @@ impl<'a> LinearCompass<'a> {
             max: None,
             animated: false,
             show_cursor: true,
+            show_reciprocal: false,
+            reciprocal_style: CompassReciprocalStyle::default(),
             show_ticks: true,
+            ticks: None,
+            major_tick_style: CompassTickStyle::major(),
+            minor_tick_style: CompassTickStyle::minor(),
             show_axes: true,
-            markers: &[],
+            markers: CompassMarkerSource::Slice(&[]),
+            cluster_threshold: None,
+            selected_marker: None,
+            clicked_marker: None,
+            track_on_marker_click: false,
+            relative_bearing_to: None,
+            show_relative_bearing_arrow: true,
             default_marker_color: DefaultCompassMarkerColor::HsvByAngle {
                 hue_phase: 0.0,
                 saturation: 1.0,
@@ -87,6 +121,8 @@ impl<'a> LinearCompass<'a> {
         }
     }
 
+    /// Read-only compasses ignore user input, but keep their full-color
+    /// appearance and marker tooltips, for indicators fed by external data.
     pub fn interactive(mut self, interactive: bool) -> Self {
         self.interactive = interactive;
         self
@@ -97,6 +133,9 @@ impl<'a> LinearCompass<'a> {
         self
     }
 
+    /// Direction of increasing bearings on the tape, `Counterclockwise` mirrors the
+    /// scale with bearings increasing to the left. Ticks, labels, markers and the
+    /// drag direction all follow the winding.
     pub fn winding(mut self, winding: Winding) -> Self {
         self.winding = winding;
         self
@@ -107,6 +146,13 @@ impl<'a> LinearCompass<'a> {
         self
     }
 
+    /// Stretches the compass to the available width of the ui, overriding `width`.
+    pub fn fill_width(mut self, fill_width: bool) -> Self {
+        self.fill_width = fill_width;
+        self
+    }
+
+    /// Tick, label and marker sizes are all proportional to the height.
     pub fn height(mut self, height: impl Into<f32>) -> Self {
         self.height = height.into();
         self
@@ -152,18 +198,136 @@ impl<'a> LinearCompass<'a> {
         self
     }
 
+    pub fn show_reciprocal(mut self, show_reciprocal: bool) -> Self {
+        self.show_reciprocal = show_reciprocal;
+        self
+    }
+
+    pub fn reciprocal_style(mut self, reciprocal_style: CompassReciprocalStyle) -> Self {
+        self.reciprocal_style = reciprocal_style;
+        self
+    }
+
     pub fn show_ticks(mut self, show_ticks: bool) -> Self {
         self.show_ticks = show_ticks;
         self
     }
 
+    /// Spacing of the major and minor ticks in degrees, replacing the default tick scale.
+    ///
+    /// Major ticks are labeled with their bearing, or with the axis label on multiples of 90°.
+    /// Minor ticks are dropped when they would be too dense at the current spread, or when
+    /// `minor_step_degrees` does not evenly divide `major_step_degrees`. Non-positive major
+    /// steps fall back to the default tick scale.
+    pub fn ticks(mut self, major_step_degrees: f32, minor_step_degrees: f32) -> Self {
+        self.ticks = Some((major_step_degrees, minor_step_degrees));
+        self
+    }
+
+    /// Appearance of the major ticks configured with [`LinearCompass::ticks`].
+    pub fn major_tick_style(mut self, major_tick_style: CompassTickStyle) -> Self {
+        self.major_tick_style = major_tick_style;
+        self
+    }
+
+    /// Appearance of the minor ticks configured with [`LinearCompass::ticks`].
+    pub fn minor_tick_style(mut self, minor_tick_style: CompassTickStyle) -> Self {
+        self.minor_tick_style = minor_tick_style;
+        self
+    }
+
     pub fn show_axes(mut self, show_axes: bool) -> Self {
         self.show_axes = show_axes;
         self
     }
 
     pub fn markers(mut self, markers: &'a [CompassMarker]) -> Self {
-        self.markers = markers;
+        self.markers = CompassMarkerSource::Slice(markers);
+        self
+    }
+
+    /// The markers are only consumed when the compass gets painted.
+    pub fn markers_from<I>(mut self, markers: I) -> Self
+    where
+        I: IntoIterator<Item = CompassMarker<'a>>,
+        I::IntoIter: 'a,
+    {
+        self.markers = CompassMarkerSource::from_iter(markers);
+        self
+    }
+
+    /// Queries the markers of the visible part of the compass only. The callback is
+    /// only called when the compass gets painted, and may return any iterator.
+    ///
+    /// The callback receives the heading range `value - |spread| / 2.0..=value + |spread| / 2.0`,
+    /// where `value` is the currently displayed (possibly animated, possibly unwrapped)
+    /// heading. The range is not normalized, near the 0°/360° seam it extends below `0.0`
+    /// or above `TAU`, and it covers more than one turn when the spread exceeds 360°.
+    /// Markers are painted at every whole turn of their bearing (`angle + n * TAU`)
+    /// falling into this range, so callers should compare bearings modulo `TAU`.
+    ///
+    /// The returned markers change as the compass turns, give them stable
+    /// [`id`](CompassMarker::id)s when selecting or tracking them. Markers outside of the
+    /// range are not painted, but still count for [`selected_marker`](Self::selected_marker)
+    /// and [`relative_bearing_to`](Self::relative_bearing_to): return the tracked marker
+    /// regardless of the range to keep its readout when it leaves the tape.
+    pub fn markers_in_range<I>(
+        mut self,
+        markers: impl 'a + FnOnce(RangeInclusive<f32>) -> I,
+    ) -> Self
+    where
+        I: IntoIterator<Item = CompassMarker<'a>>,
+        I::IntoIter: 'a,
+    {
+        self.markers = CompassMarkerSource::from_callback(markers);
+        self
+    }
+
+    /// Groups markers into clusters by bearing, in buckets of at most the given width in
+    /// radians. The buckets are fixed around the compass rose, so clusters don't regroup
+    /// as the compass turns.
+    pub fn cluster_threshold(mut self, cluster_threshold: Option<f32>) -> Self {
+        self.cluster_threshold = cluster_threshold;
+        self
+    }
+
+    /// The selected marker is never merged into a cluster and is always drawn
+    /// on top of the other markers. Markers are identified by their
+    /// [`id`](CompassMarker::id), or by their index when they have none.
+    pub fn selected_marker(mut self, selected_marker: Option<usize>) -> Self {
+        self.selected_marker = selected_marker;
+        self
+    }
+
+    /// Receives the [`id`](CompassMarker::id) of the marker clicked in this frame, or
+    /// its index when it has none.
+    ///
+    /// Hovered clusters expand into a row of their members, clicks report the
+    /// member under the pointer. Clicks are reported even when the compass is
+    /// not interactive.
+    pub fn clicked_marker(mut self, clicked_marker: &'a mut Option<usize>) -> Self {
+        self.clicked_marker = Some(clicked_marker);
+        self
+    }
+
+    /// Clicking a marker turns the compass to the bearing of the marker,
+    /// subject to snapping and limits. Has no effect on non-interactive compasses.
+    pub fn track_on_marker_click(mut self, track_on_marker_click: bool) -> Self {
+        self.track_on_marker_click = track_on_marker_click;
+        self
+    }
+
+    /// Shows the signed bearing of the marker with the given [`id`](CompassMarker::id)
+    /// or index relative to the current heading. The readout is hidden when there is no
+    /// such marker.
+    pub fn relative_bearing_to(mut self, relative_bearing_to: Option<usize>) -> Self {
+        self.relative_bearing_to = relative_bearing_to;
+        self
+    }
+
+    /// Hints the shorter turning direction next to the relative bearing readout.
+    pub fn show_relative_bearing_arrow(mut self, show_relative_bearing_arrow: bool) -> Self {
+        self.show_relative_bearing_arrow = show_relative_bearing_arrow;
         self
     }
 
@@ -176,10 +340,21 @@ impl<'a> LinearCompass<'a> {
         self.default_marker_shape = default_marker_shape;
         self
     }
+
+    /// Size of the marker rects in points, useful for pre-rasterizing
+    /// [`CompassMarkerShape::Texture`] icons at the right resolution.
+    #[must_use]
+    pub fn marker_size(&self) -> f32 {
+        self.height * 0.25
+    }
 }
 
 impl<'a> Widget for LinearCompass<'a> {
     fn ui(mut self, ui: &mut Ui) -> Response {
+        if self.fill_width {
+            self.width = ui.available_width();
+        }
+
         let desired_size = egui::vec2(self.width, self.height);
 
         let (rect, mut response) = ui.allocate_exact_size(
@@ -187,7 +362,8 @@ impl<'a> Widget for LinearCompass<'a> {
             if self.interactive {
                 Sense::click_and_drag()
             } else {
-                Sense::hover()
+                // Marker clicks are still reported in read-only mode
+                Sense::click()
             },
         );
 
@@ -197,7 +373,7 @@ impl<'a> Widget for LinearCompass<'a> {
         let constrain_value = |mut value| {
             if self.wrap == WrapMode::Signed {
                 // Animations require inclusive normalization bounds (-PI..=PI)
-                value = normalized_angle(value);
+                value = self.wrap.wrap(value, -PI, PI);
             }
 
             if self.wrap == WrapMode::Unsigned {
@@ -205,6 +381,10 @@ impl<'a> Widget for LinearCompass<'a> {
                 value = normalized_angle_unsigned_incl(value);
             }
 
+            if self.wrap == WrapMode::Clamped {
+                value = self.wrap.wrap(value, 0.0, TAU);
+            }
+
             if let Some(min) = self.min {
                 value = value.max(min);
             }
@@ -216,14 +396,30 @@ impl<'a> Widget for LinearCompass<'a> {
             value
         };
 
+        if response.drag_started() {
+            let value_before_drag = get(&mut self.get_set_value);
+            ui.memory_mut(|memory| memory.data.insert_temp(response.id, value_before_drag));
+        }
+
         if response.dragged() {
-            let new_value = get(&mut self.get_set_value)
-                - response.drag_delta().x / rect.width() * (self.spread * self.winding.to_float());
+            // Offsetting the value at the start of the drag by the total pointer movement,
+            // rather than accumulating the per-frame deltas on top of the already wrapped value,
+            // keeps the heading continuous when dragging across the 0°/360° seam.
+            let value_before_drag =
+                ui.memory_mut(|memory| memory.data.get_temp::<f32>(response.id).unwrap());
+
+            let drag_distance = response.interact_pointer_pos().unwrap().x
+                - ui.input(|input| input.pointer.press_origin().unwrap()).x;
+
+            let new_value = value_before_drag
+                - drag_distance / rect.width() * (self.spread * self.winding.to_float());
             set(&mut self.get_set_value, constrain_value(new_value));
             response.mark_changed();
         }
 
         if response.drag_stopped() {
+            ui.memory_mut(|memory| memory.data.remove::<f32>(response.id));
+
             if self.animated {
                 child_ui.ctx().clear_animations();
                 child_ui.ctx().animate_value_with_time(
@@ -249,7 +445,12 @@ impl<'a> Widget for LinearCompass<'a> {
         }
 
         if child_ui.is_rect_visible(rect) {
-            let visuals = *child_ui.style().interact(&response);
+            let visuals = if self.interactive {
+                *child_ui.style().interact(&response)
+            } else {
+                // Non-interactive responses would get the dimmed non-interactive visuals
+                child_ui.style().visuals.widgets.inactive
+            };
 
             let value = if self.animated && !response.dragged() {
                 child_ui.ctx().animate_value_with_time(
@@ -275,11 +476,29 @@ impl<'a> Widget for LinearCompass<'a> {
             );
 
             {
+                let markers = std::mem::replace(&mut self.markers, CompassMarkerSource::Slice(&[]))
+                    .resolve(
+                        (value - (self.spread.abs() / 2.0))..=(value + (self.spread.abs() / 2.0)),
+                    );
+
+                // Indices into `markers` stay internal, the outside sees stable ids
+                let marker_id = |marker_index: usize| -> usize {
+                    markers[marker_index].id.unwrap_or(marker_index)
+                };
+                let marker_index_of = |id: usize| -> Option<usize> {
+                    (0..markers.len()).find(|&marker_index| marker_id(marker_index) == id)
+                };
+
+                let marker_rect = |angle: f32| {
+                    let center = pos2(map_angle_to_screen(angle), rect.top() + self.height * 0.375);
+                    Rect::from_center_size(center, Vec2::splat(self.marker_size()))
+                };
+
                 let paint_marker = |child_ui: &mut Ui,
                                     angle: f32,
                                     label: Option<&str>,
                                     text_color: Color32,
-                                    shape: CompassMarkerShape,
+                                    shape: &CompassMarkerShape,
                                     fill: Color32,
                                     stroke: Stroke| {
                     // Early exit when the marker is outside of the bounds of the widget,
@@ -295,13 +514,7 @@ impl<'a> Widget for LinearCompass<'a> {
 
                     // Draw marker shape
                     {
-                        let marker_rect = {
-                            let center =
-                                pos2(map_angle_to_screen(angle), rect.top() + self.height * 0.375);
-                            Rect::from_center_size(center, Vec2::splat(self.height * 0.25))
-                        };
-
-                        shape.paint(child_ui, marker_rect, fill, stroke);
+                        shape.paint(child_ui, marker_rect(angle), fill, stroke);
                     }
 
                     // Draw marker text label
@@ -321,34 +534,249 @@ impl<'a> Widget for LinearCompass<'a> {
                     }
                 };
 
-                let start_tau = ((value - (self.spread.abs() / 2.0)) / TAU).floor() as isize;
-                let end_tau = ((value + (self.spread.abs() / 2.0)) / TAU).ceil() as isize;
+                let marker_colors = |child_ui: &Ui, marker: &CompassMarker| {
+                    let marker_color = marker
+                        .color
+                        .unwrap_or_else(|| self.default_marker_color.color(child_ui, marker));
 
-                for tau in start_tau..=end_tau {
-                    for marker in self.markers.iter() {
-                        let marker_color = marker
-                            .color
-                            .unwrap_or_else(|| self.default_marker_color.color(&child_ui, marker));
+                    let marker_stroke = {
+                        let stroke_color =
+                            tint_color_towards(marker_color, child_ui.style().visuals.text_color());
+                        Stroke::new(1.0, stroke_color)
+                    };
 
-                        let marker_stroke = {
-                            let stroke_color = tint_color_towards(
-                                marker_color,
-                                child_ui.style().visuals.text_color(),
-                            );
-                            Stroke::new(1.0, stroke_color)
-                        };
+                    (marker_color, marker_stroke)
+                };
+
+                let paint_cluster =
+                    |child_ui: &mut Ui, tau: isize, cluster: &CompassMarkerCluster| {
+                        // Clusters are represented by their first member
+                        let marker = &markers[cluster.members[0]];
+                        let (marker_color, marker_stroke) = marker_colors(child_ui, marker);
+                        let marker_shape =
+                            marker.shape.as_ref().unwrap_or(&self.default_marker_shape);
 
-                        let marker_shape = marker.shape.unwrap_or(self.default_marker_shape);
+                        let cluster_label = format!("\u{D7}{}", cluster.members.len());
 
                         paint_marker(
-                            &mut child_ui,
-                            (tau as f32 * TAU) + marker.angle,
-                            marker.label,
+                            child_ui,
+                            (tau as f32 * TAU) + cluster.angle,
+                            if cluster.members.len() > 1 {
+                                Some(&cluster_label)
+                            } else {
+                                marker.label
+                            },
                             marker_color,
                             marker_shape,
                             marker_color,
                             marker_stroke,
                         );
+                    };
+
+                let start_tau = ((value - (self.spread.abs() / 2.0)) / TAU).floor() as isize;
+                let end_tau = ((value + (self.spread.abs() / 2.0)) / TAU).ceil() as isize;
+
+                let marker_clusters = CompassMarkerCluster::from_markers(
+                    &markers,
+                    self.cluster_threshold,
+                    self.selected_marker.and_then(marker_index_of),
+                );
+
+                // Expanded clusters lay out their members in a row centered on the cluster,
+                // in the on-screen order of their bearings
+                let expanded_rects = |tau: isize, cluster: &CompassMarkerCluster| {
+                    let cluster_angle = (tau as f32 * TAU) + cluster.angle;
+                    let member_x = |marker_index: usize| {
+                        map_angle_to_screen(
+                            cluster_angle + angle_delta(cluster.angle, markers[marker_index].angle),
+                        )
+                    };
+
+                    let spacing = self.marker_size() * 1.25;
+                    let first_offset = -((cluster.members.len() - 1) as f32 * spacing) / 2.0;
+
+                    cluster
+                        .members
+                        .iter()
+                        .copied()
+                        .sorted_by(|&a, &b| member_x(a).total_cmp(&member_x(b)).then(a.cmp(&b)))
+                        .enumerate()
+                        .map(|(slot, marker_index)| {
+                            let offset = vec2(first_offset + slot as f32 * spacing, 0.0);
+                            (marker_index, marker_rect(cluster_angle).translate(offset))
+                        })
+                        .collect_vec()
+                };
+
+                let expanded_bounds = |member_rects: &[(usize, Rect)]| {
+                    member_rects
+                        .iter()
+                        .fold(Rect::NOTHING, |bounds, &(_, member_rect)| {
+                            bounds.union(member_rect)
+                        })
+                        .expand(self.marker_size() * 0.125)
+                };
+
+                // Expanded clusters stay open while the pointer is over their row,
+                // keyed by their lowest marker id
+                let expanded_cluster_id = response.id.with("expanded_cluster");
+
+                let kept_expanded = response.hover_pos().and_then(|hover_pos| {
+                    let (tau, key) = child_ui
+                        .data(|data| data.get_temp::<(isize, usize)>(expanded_cluster_id))?;
+                    let cluster_index = marker_clusters.iter().position(|cluster| {
+                        cluster.members.len() > 1
+                            && cluster.members.iter().copied().map(marker_id).min() == Some(key)
+                    })?;
+
+                    expanded_bounds(&expanded_rects(tau, &marker_clusters[cluster_index]))
+                        .contains(hover_pos)
+                        .then_some((tau, cluster_index))
+                });
+
+                // Clusters drawn last are on top, so they are hit-tested first
+                let hovered_cluster = kept_expanded.or_else(|| {
+                    response.hover_pos().and_then(|hover_pos| {
+                        (0..marker_clusters.len())
+                            .rev()
+                            .cartesian_product(start_tau..=end_tau)
+                            .map(|(cluster_index, tau)| (tau, cluster_index))
+                            .find(|&(tau, cluster_index)| {
+                                let cluster_angle =
+                                    (tau as f32 * TAU) + marker_clusters[cluster_index].angle;
+                                marker_rect(cluster_angle).contains(hover_pos)
+                            })
+                    })
+                });
+
+                let expanded_cluster = hovered_cluster
+                    .filter(|&(_, cluster_index)| marker_clusters[cluster_index].members.len() > 1)
+                    .map(|(tau, cluster_index)| {
+                        let member_rects = expanded_rects(tau, &marker_clusters[cluster_index]);
+                        (tau, cluster_index, member_rects)
+                    });
+
+                child_ui.data_mut(|data| match &expanded_cluster {
+                    Some((tau, cluster_index, _)) => {
+                        let key = marker_clusters[*cluster_index]
+                            .members
+                            .iter()
+                            .copied()
+                            .map(marker_id)
+                            .min();
+                        data.insert_temp(expanded_cluster_id, (*tau, key.unwrap()));
+                    }
+                    None => data.remove::<(isize, usize)>(expanded_cluster_id),
+                });
+
+                let hovered_marker = match &expanded_cluster {
+                    Some((_, _, member_rects)) => response.hover_pos().and_then(|hover_pos| {
+                        member_rects
+                            .iter()
+                            .find(|(_, member_rect)| member_rect.contains(hover_pos))
+                            .map(|&(marker_index, _)| marker_index)
+                    }),
+                    None => hovered_cluster
+                        .map(|(_, cluster_index)| marker_clusters[cluster_index].members[0]),
+                };
+
+                for (cluster_index, cluster) in marker_clusters.iter().enumerate() {
+                    for tau in start_tau..=end_tau {
+                        if hovered_cluster != Some((tau, cluster_index)) {
+                            paint_cluster(&mut child_ui, tau, cluster);
+                        }
+                    }
+                }
+
+                // Hovered markers are drawn last to keep them on top of the others
+                if let Some((_, cluster_index, member_rects)) = &expanded_cluster {
+                    child_ui.painter().rect(
+                        expanded_bounds(member_rects),
+                        visuals.rounding,
+                        child_ui.style().visuals.extreme_bg_color,
+                        visuals.fg_stroke,
+                    );
+
+                    for &(marker_index, member_rect) in member_rects {
+                        let marker = &markers[marker_index];
+                        let (marker_color, marker_stroke) = marker_colors(&child_ui, marker);
+                        let marker_shape =
+                            marker.shape.as_ref().unwrap_or(&self.default_marker_shape);
+
+                        let member_rect = if hovered_marker == Some(marker_index) {
+                            member_rect.expand(self.marker_size() * 0.125)
+                        } else {
+                            member_rect
+                        };
+
+                        marker_shape.paint(&mut child_ui, member_rect, marker_color, marker_stroke);
+                    }
+
+                    // Listed below the compass, a tooltip at the pointer would cover the row
+                    let cluster = &marker_clusters[*cluster_index];
+                    response = response.on_hover_ui(|ui| {
+                        for &marker_index in &cluster.members {
+                            let marker = &markers[marker_index];
+                            let text = RichText::new(format!(
+                                "{} ({:.0}°)",
+                                marker.label.unwrap_or("\u{2014}"),
+                                marker.angle.to_degrees()
+                            ));
+
+                            ui.label(if hovered_marker == Some(marker_index) {
+                                text.strong()
+                            } else {
+                                text
+                            });
+                        }
+                    });
+                } else if let Some((tau, cluster_index)) = hovered_cluster {
+                    paint_cluster(&mut child_ui, tau, &marker_clusters[cluster_index]);
+                }
+
+                if self.show_reciprocal {
+                    let reciprocal_size = self.marker_size() * self.reciprocal_style.scale;
+
+                    // Both sides are painted, wide spreads can show the reciprocal twice
+                    for reciprocal in [value - (TAU / 2.0), value + (TAU / 2.0)] {
+                        let reciprocal_x = map_angle_to_screen(reciprocal);
+
+                        if !rect
+                            .expand(reciprocal_size)
+                            .x_range()
+                            .contains(reciprocal_x)
+                        {
+                            continue;
+                        }
+
+                        CompassMarkerShape::RegularPolygon(3, TAU / 4.0).paint(
+                            &mut child_ui,
+                            Rect::from_center_size(
+                                pos2(reciprocal_x, rect.top() + self.height * 0.375),
+                                Vec2::splat(reciprocal_size),
+                            ),
+                            self.reciprocal_style.dimmed(visuals.bg_fill),
+                            Stroke::new(
+                                visuals.fg_stroke.width,
+                                self.reciprocal_style.dimmed(visuals.fg_stroke.color),
+                            ),
+                        );
+
+                        if self.reciprocal_style.show_label {
+                            child_ui.painter().text(
+                                pos2(reciprocal_x, rect.top() + self.height * 0.125),
+                                Align2::CENTER_CENTER,
+                                format!(
+                                    "{:.0}°",
+                                    normalized_angle_unsigned_excl(reciprocal).to_degrees()
+                                ),
+                                FontId::new(
+                                    (self.height / 4.0) * self.reciprocal_style.scale,
+                                    FontFamily::Proportional,
+                                ),
+                                self.reciprocal_style.dimmed(visuals.text_color()),
+                            );
+                        }
                     }
                 }
 
@@ -358,14 +786,167 @@ impl<'a> Widget for LinearCompass<'a> {
                         value,
                         Some(&format!("{:.0}°", value.to_degrees())),
                         visuals.text_color(),
-                        CompassMarkerShape::DownArrow,
+                        &CompassMarkerShape::RegularPolygon(3, TAU / 4.0),
                         visuals.bg_fill,
                         visuals.fg_stroke,
                     );
                 }
+
+                if let Some(marker_index) = hovered_marker.filter(|_| response.clicked()) {
+                    if let Some(clicked_marker) = self.clicked_marker.as_deref_mut() {
+                        *clicked_marker = Some(marker_id(marker_index));
+                    }
+
+                    if self.track_on_marker_click && self.interactive {
+                        let bearing = markers[marker_index].angle;
+                        let prev_value = get(&mut self.get_set_value);
+
+                        // Unwrapped values turn the shorter way around
+                        let mut new_value = if self.wrap == WrapMode::None {
+                            prev_value + angle_delta(prev_value, bearing)
+                        } else {
+                            bearing
+                        };
+
+                        if let Some(snap_angle) = self.snap {
+                            assert!(
+                                snap_angle > 0.0,
+                                "non-positive snap angles are not supported"
+                            );
+                            new_value = (new_value / snap_angle).round() * snap_angle;
+                        }
+
+                        set(&mut self.get_set_value, constrain_value(new_value));
+                        response.mark_changed();
+                    }
+                }
+
+                if let Some(marker) = self
+                    .relative_bearing_to
+                    .and_then(marker_index_of)
+                    .map(|marker_index| &markers[marker_index])
+                {
+                    let relative_bearing = angle_delta(value, marker.angle);
+
+                    let galley = child_ui.painter().layout_no_wrap(
+                        format!(
+                            "{} {:+04.0}°",
+                            marker.label.unwrap_or("TGT"),
+                            relative_bearing.to_degrees()
+                        ),
+                        FontId::new(self.height / 4.0, FontFamily::Proportional),
+                        visuals.text_color(),
+                    );
+
+                    let readout_rect = Align2::RIGHT_TOP.anchor_size(
+                        rect.right_top() + vec2(-self.height * 0.125, self.height * 0.0625),
+                        galley.size(),
+                    );
+
+                    child_ui.painter().rect_filled(
+                        readout_rect.expand(2.0),
+                        visuals.rounding,
+                        child_ui.style().visuals.extreme_bg_color,
+                    );
+                    child_ui
+                        .painter()
+                        .galley(readout_rect.min, galley, visuals.text_color());
+
+                    if self.show_relative_bearing_arrow && relative_bearing != 0.0 {
+                        let arrow_shape = if relative_bearing * self.winding.to_float() > 0.0 {
+                            CompassMarkerShape::RegularPolygon(3, 0.0)
+                        } else {
+                            CompassMarkerShape::RegularPolygon(3, TAU / 2.0)
+                        };
+
+                        arrow_shape.paint(
+                            &mut child_ui,
+                            Rect::from_center_size(
+                                readout_rect.left_center() - vec2(self.height * 0.25, 0.0),
+                                Vec2::splat(self.height * 0.25),
+                            ),
+                            visuals.bg_fill,
+                            visuals.fg_stroke,
+                        );
+                    }
+                }
             }
 
-            {
+            let tick_steps = self.ticks.and_then(|(major_step, minor_step)| {
+                let degrees_to_screen = rect.width() / self.spread.to_degrees().abs();
+
+                // At least a point between major ticks, to keep the tick count sane
+                if !(major_step.is_finite() && major_step * degrees_to_screen >= 1.0) {
+                    return None;
+                }
+
+                let minor_step = Some(minor_step).filter(|&minor_step| {
+                    minor_step.is_finite()
+                        && minor_step > 0.0
+                        && minor_step < major_step
+                        && is_multiple_of(major_step, minor_step)
+                        && minor_step * degrees_to_screen >= MIN_MINOR_TICK_SPACING
+                });
+
+                Some((major_step, minor_step))
+            });
+
+            if let Some((major_step, minor_step)) = tick_steps {
+                let step = minor_step.unwrap_or(major_step);
+
+                let start_index =
+                    ((value - (self.spread.abs() / 2.0)).to_degrees() / step).floor() as isize;
+                let end_index =
+                    ((value + (self.spread.abs() / 2.0)).to_degrees() / step).ceil() as isize;
+
+                for index in start_index..=end_index {
+                    let degree = index as f32 * step;
+                    let tick_x = map_angle_to_screen(degree.to_radians());
+
+                    let is_major_tick = is_multiple_of(degree, major_step);
+                    let is_axis_tick = is_major_tick && is_multiple_of(degree, 90.0);
+
+                    let tick_style = if is_major_tick {
+                        self.major_tick_style
+                    } else {
+                        self.minor_tick_style
+                    };
+
+                    if self.show_ticks || (self.show_axes && is_axis_tick) {
+                        let tick_position = pos2(tick_x, rect.top() + (self.height * 0.5));
+                        let tick_size = vec2(0.0, self.height * 0.25 * tick_style.length);
+
+                        child_ui.painter().line_segment(
+                            [tick_position, tick_position + tick_size],
+                            tick_style
+                                .stroke
+                                .unwrap_or(child_ui.style().visuals.noninteractive().fg_stroke),
+                        );
+                    }
+
+                    let tick_label = if is_axis_tick && self.show_axes {
+                        let axis_label_index = ((degree / 90.0).round() as isize).rem_euclid(4);
+                        Some(self.axis_labels.inner[axis_label_index as usize].clone())
+                    } else if is_major_tick && self.show_ticks {
+                        Some(format!(
+                            "{:.0}",
+                            normalized_angle_unsigned_excl(degree.to_radians()).to_degrees()
+                        ))
+                    } else {
+                        None
+                    };
+
+                    if let Some(tick_label) = tick_label {
+                        child_ui.painter().text(
+                            pos2(tick_x, rect.top() + (self.height * 0.875)),
+                            Align2::CENTER_CENTER,
+                            tick_label,
+                            FontId::new(self.height / 4.0, FontFamily::Proportional),
+                            child_ui.style().visuals.text_color(),
+                        );
+                    }
+                }
+            } else {
                 let round_bounds_to = 10.0;
 
                 let start_degrees =
@@ -441,3 +1022,524 @@ impl<'a> Widget for LinearCompass<'a> {
         response
     }
 }
+
+fn is_multiple_of(value: f32, step: f32) -> bool {
+    let ratio = value / step;
+    (ratio - ratio.round()).abs() < 1e-3
+}
+
+// ----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use egui::{CentralPanel, Context, Event, Modifiers, PointerButton, RawInput};
+    use emath::{vec2, Pos2};
+    use epaint::Shape;
+
+    use super::*;
+
+    /// Runs a single frame with the compass in the central panel, returning its rect.
+    fn run_frame(ctx: &Context, heading: &mut f32, frame: usize, events: Vec<Event>) -> Rect {
+        let input = RawInput {
+            screen_rect: Some(Rect::from_min_size(Pos2::ZERO, vec2(800.0, 200.0))),
+            time: Some(frame as f64 / 60.0),
+            events,
+            ..Default::default()
+        };
+
+        let mut compass_rect = Rect::NOTHING;
+        let _ = ctx.run(input, |ctx| {
+            CentralPanel::default().show(ctx, |ui| {
+                compass_rect = ui.add(LinearCompass::new(heading)).rect;
+            });
+        });
+        compass_rect
+    }
+
+    fn pointer_button(pos: Pos2, pressed: bool) -> Event {
+        Event::PointerButton {
+            pos,
+            button: PointerButton::Primary,
+            pressed,
+            modifiers: Modifiers::NONE,
+        }
+    }
+
+    /// Drags the tape horizontally in small steps, checking the heading change of every
+    /// frame against the pointer movement since the heading last changed. The drag only
+    /// starts after the pointer left the click distance, so the first change catches up
+    /// with the movement of several frames. Returns the heading after the drag.
+    fn drag_through(start_heading: f32, step: f32, steps: usize) -> f32 {
+        let ctx = Context::default();
+        let mut heading = start_heading;
+
+        let rect = run_frame(&ctx, &mut heading, 0, Vec::new());
+        let radians_per_point = 180.0f32.to_radians() / rect.width();
+
+        let mut pointer = rect.center();
+        run_frame(
+            &ctx,
+            &mut heading,
+            1,
+            vec![Event::PointerMoved(pointer), pointer_button(pointer, true)],
+        );
+
+        let mut pending_movement = 0.0;
+
+        for frame in 2..(steps + 2) {
+            let previous_heading = heading;
+            pointer.x += step;
+            pending_movement += step.abs();
+            run_frame(
+                &ctx,
+                &mut heading,
+                frame,
+                vec![Event::PointerMoved(pointer)],
+            );
+
+            let delta = angle_delta(previous_heading, heading);
+            assert!(
+                delta.abs() <= pending_movement * radians_per_point + 1e-4,
+                "heading jumped from {previous_heading} to {heading} in frame {frame}"
+            );
+
+            if delta != 0.0 {
+                pending_movement = 0.0;
+            }
+        }
+
+        run_frame(
+            &ctx,
+            &mut heading,
+            steps + 2,
+            vec![pointer_button(pointer, false)],
+        );
+        heading
+    }
+
+    #[test]
+    fn drag_across_north_decreasing() {
+        // Dragging the tape to the right turns the heading counterclockwise
+        let heading = drag_through(5.0f32.to_radians(), 2.0, 60);
+        assert!((0.0..=TAU).contains(&heading));
+        assert!(
+            angle_delta(heading, 5.0f32.to_radians()) > 30.0f32.to_radians(),
+            "the drag did not cross north, ended at {heading}"
+        );
+    }
+
+    #[test]
+    fn drag_across_north_increasing() {
+        let heading = drag_through(355.0f32.to_radians(), -2.0, 60);
+        assert!((0.0..=TAU).contains(&heading));
+        assert!(
+            angle_delta(355.0f32.to_radians(), heading) > 30.0f32.to_radians(),
+            "the drag did not cross north, ended at {heading}"
+        );
+    }
+    /// Horizontal centers of the square markers painted by a compass with the given
+    /// winding, in the order of the markers, and the center of the compass.
+    fn marker_positions(
+        heading: f32,
+        winding: Winding,
+        markers: &[CompassMarker],
+    ) -> (f32, Vec<f32>) {
+        let ctx = Context::default();
+        let mut heading = heading;
+        let mut compass_rect = Rect::NOTHING;
+
+        let output = ctx.run(RawInput::default(), |ctx| {
+            CentralPanel::default().show(ctx, |ui| {
+                compass_rect = ui
+                    .add(
+                        LinearCompass::new(&mut heading)
+                            .winding(winding)
+                            .markers(markers),
+                    )
+                    .rect;
+            });
+        });
+
+        let positions = markers
+            .iter()
+            .map(|marker| {
+                output
+                    .shapes
+                    .iter()
+                    .find_map(|clipped_shape| match &clipped_shape.shape {
+                        Shape::Rect(rect_shape) if Some(rect_shape.fill) == marker.color => {
+                            Some(rect_shape.rect.center().x)
+                        }
+                        _ => None,
+                    })
+                    .expect("marker was not painted")
+            })
+            .collect();
+
+        (compass_rect.center().x, positions)
+    }
+
+    fn test_markers(offsets_degrees: &[f32], heading: f32) -> Vec<CompassMarker<'static>> {
+        offsets_degrees
+            .iter()
+            .enumerate()
+            .map(|(index, offset)| {
+                CompassMarker::new(heading + offset.to_radians()).color(Color32::from_rgb(
+                    1,
+                    2,
+                    10 + index as u8,
+                ))
+            })
+            .collect()
+    }
+
+    const MARKER_OFFSETS: [f32; 6] = [-80.0, -30.0, -5.0, 0.0, 12.5, 60.0];
+
+    #[test]
+    fn counterclockwise_mirrors_markers() {
+        for heading in [0.0, 2.0, 355.0f32.to_radians()] {
+            let markers = test_markers(&MARKER_OFFSETS, heading);
+
+            let (center, clockwise) = marker_positions(heading, Winding::Clockwise, &markers);
+            let (_, counterclockwise) =
+                marker_positions(heading, Winding::Counterclockwise, &markers);
+
+            for (x_clockwise, x_counterclockwise) in clockwise.iter().zip(&counterclockwise) {
+                assert!(
+                    (x_clockwise + x_counterclockwise - 2.0 * center).abs() < 0.01,
+                    "{x_clockwise} and {x_counterclockwise} are not mirrored around {center}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn counterclockwise_matches_opposite_bearings() {
+        let heading = 1.0;
+        let mirrored_offsets = MARKER_OFFSETS.map(|offset| -offset);
+
+        let (_, counterclockwise) = marker_positions(
+            heading,
+            Winding::Counterclockwise,
+            &test_markers(&MARKER_OFFSETS, heading),
+        );
+        let (_, clockwise) = marker_positions(
+            heading,
+            Winding::Clockwise,
+            &test_markers(&mirrored_offsets, heading),
+        );
+
+        for (x_counterclockwise, x_clockwise) in counterclockwise.iter().zip(&clockwise) {
+            assert!((x_counterclockwise - x_clockwise).abs() < 0.01);
+        }
+    }
+
+    #[test]
+    fn mirroring_twice_is_identity() {
+        let heading = 1.0;
+        let markers = test_markers(&MARKER_OFFSETS, heading);
+
+        let (_, clockwise) = marker_positions(heading, Winding::Clockwise, &markers);
+        let (_, flipped_twice) = marker_positions(heading, !!Winding::Clockwise, &markers);
+
+        assert_eq!(clockwise, flipped_twice);
+    }
+    fn stacked_markers() -> Vec<CompassMarker<'static>> {
+        [Color32::RED, Color32::GREEN, Color32::BLUE]
+            .into_iter()
+            .map(|color| CompassMarker::new(0.0).color(color))
+            .collect()
+    }
+
+    /// Runs a frame of a clustering compass, returning its rect, the clicked marker
+    /// and the painted shapes.
+    fn run_cluster_frame(
+        ctx: &Context,
+        markers: &[CompassMarker],
+        selected_marker: Option<usize>,
+        frame: usize,
+        events: Vec<Event>,
+    ) -> (Rect, Option<usize>, Vec<Shape>) {
+        let input = RawInput {
+            screen_rect: Some(Rect::from_min_size(Pos2::ZERO, vec2(800.0, 200.0))),
+            time: Some(frame as f64 / 60.0),
+            events,
+            ..Default::default()
+        };
+
+        let mut heading = 0.0;
+        let mut clicked_marker = None;
+        let mut compass_rect = Rect::NOTHING;
+
+        let output = ctx.run(input, |ctx| {
+            CentralPanel::default().show(ctx, |ui| {
+                compass_rect = ui
+                    .add(
+                        LinearCompass::new(&mut heading)
+                            .cluster_threshold(Some(5.0f32.to_radians()))
+                            .selected_marker(selected_marker)
+                            .clicked_marker(&mut clicked_marker)
+                            .markers(markers),
+                    )
+                    .rect;
+            });
+        });
+
+        let shapes = output
+            .shapes
+            .into_iter()
+            .map(|clipped_shape| clipped_shape.shape)
+            .collect();
+        (compass_rect, clicked_marker, shapes)
+    }
+
+    #[test]
+    fn clicks_on_expanded_clusters_report_the_member_under_the_pointer() {
+        let markers = stacked_markers();
+        let ctx = Context::default();
+
+        let (rect, _, _) = run_cluster_frame(&ctx, &markers, None, 0, Vec::new());
+        let marker_size = rect.height() * 0.25;
+        let cluster_center = pos2(rect.center().x, rect.top() + rect.height() * 0.375);
+
+        // Hovering the cluster glyph expands it, the row stays open past the glyph
+        let (_, _, shapes) = run_cluster_frame(
+            &ctx,
+            &markers,
+            None,
+            1,
+            vec![Event::PointerMoved(cluster_center)],
+        );
+
+        // The collapsed glyph only shows the first member, the row shows all of them
+        assert!(shapes.iter().any(
+            |shape| matches!(shape, Shape::Rect(rect_shape) if rect_shape.fill == Color32::BLUE)
+        ));
+
+        // Members sharing a bearing are laid out in marker order
+        for (click, (marker_index, slot)) in [(2, 1.0), (0, -1.0), (1, 0.0)].into_iter().enumerate()
+        {
+            let frame = 2 + click * 3;
+            let member_center = cluster_center + vec2(slot * marker_size * 1.25, 0.0);
+
+            run_cluster_frame(
+                &ctx,
+                &markers,
+                None,
+                frame,
+                vec![Event::PointerMoved(member_center)],
+            );
+            run_cluster_frame(
+                &ctx,
+                &markers,
+                None,
+                frame + 1,
+                vec![pointer_button(member_center, true)],
+            );
+            let (_, clicked_marker, _) = run_cluster_frame(
+                &ctx,
+                &markers,
+                None,
+                frame + 2,
+                vec![pointer_button(member_center, false)],
+            );
+
+            assert_eq!(clicked_marker, Some(marker_index));
+        }
+    }
+
+    #[test]
+    fn selected_marker_is_painted_on_top() {
+        let markers = stacked_markers();
+
+        for selected_marker in 0..markers.len() {
+            let ctx = Context::default();
+            let (_, _, shapes) =
+                run_cluster_frame(&ctx, &markers, Some(selected_marker), 0, Vec::new());
+
+            let last_marker_fill = shapes
+                .iter()
+                .rev()
+                .find_map(|shape| match shape {
+                    Shape::Rect(rect_shape)
+                        if markers
+                            .iter()
+                            .any(|marker| marker.color == Some(rect_shape.fill)) =>
+                    {
+                        Some(rect_shape.fill)
+                    }
+                    _ => None,
+                })
+                .expect("no marker was painted");
+
+            assert_eq!(Some(last_marker_fill), markers[selected_marker].color);
+        }
+    }
+    /// Runs a frame of a compass querying its markers with `markers_in_range`, returning
+    /// the range passed to the callback, if it was called, and the painted shapes.
+    fn run_query_frame(
+        heading: f32,
+        spread: f32,
+        visible: bool,
+    ) -> (Option<RangeInclusive<f32>>, Vec<Shape>) {
+        let ctx = Context::default();
+        let mut heading = heading;
+        let mut queried_range = None;
+
+        let output = ctx.run(RawInput::default(), |ctx| {
+            CentralPanel::default().show(ctx, |ui| {
+                if !visible {
+                    ui.set_clip_rect(Rect::NOTHING);
+                }
+
+                ui.add(
+                    LinearCompass::new(&mut heading)
+                        .spread(spread)
+                        .markers_in_range(|visible_range| {
+                            queried_range = Some(visible_range.clone());
+
+                            // Any iterator will do, the markers are not collected by the caller
+                            [0.0f32, 90.0, 180.0, 270.0]
+                                .into_iter()
+                                .map(f32::to_radians)
+                                .filter(move |bearing| {
+                                    let turns = ((visible_range.start() - bearing) / TAU).ceil();
+                                    visible_range.contains(&(bearing + turns * TAU))
+                                })
+                                .map(|bearing| CompassMarker::new(bearing).color(Color32::RED))
+                        }),
+                );
+            });
+        });
+
+        let shapes = output
+            .shapes
+            .into_iter()
+            .map(|clipped_shape| clipped_shape.shape)
+            .collect();
+        (queried_range, shapes)
+    }
+
+    #[test]
+    fn markers_in_range_receives_the_unnormalized_visible_range() {
+        let (queried_range, shapes) =
+            run_query_frame(350.0f32.to_radians(), 90.0f32.to_radians(), true);
+
+        let queried_range = queried_range.expect("the callback was not called");
+        assert!((queried_range.start() - 305.0f32.to_radians()).abs() < 1e-4);
+        assert!((queried_range.end() - 395.0f32.to_radians()).abs() < 1e-4);
+
+        // Only the marker at north falls into the range, across the seam
+        let painted_markers = shapes
+            .iter()
+            .filter(
+                |shape| matches!(shape, Shape::Rect(rect_shape) if rect_shape.fill == Color32::RED),
+            )
+            .count();
+        assert_eq!(painted_markers, 1);
+    }
+
+    #[test]
+    fn markers_in_range_is_not_called_when_hidden() {
+        let (queried_range, _) = run_query_frame(0.0, 90.0f32.to_radians(), false);
+        assert_eq!(queried_range, None);
+    }
+
+    const RANGED_COLORS: [Color32; 4] =
+        [Color32::RED, Color32::GREEN, Color32::BLUE, Color32::YELLOW];
+
+    /// Runs a frame of a compass querying cardinal markers by range, identified by their
+    /// index among the cardinal directions. The `always_included` marker is returned even
+    /// when it is outside of the range. Returns the painted shapes.
+    fn run_ranged_frame(
+        heading: f32,
+        selected_marker: Option<usize>,
+        relative_bearing_to: Option<usize>,
+        always_included: Option<usize>,
+    ) -> Vec<Shape> {
+        let ctx = Context::default();
+        let mut heading = heading;
+
+        let output = ctx.run(RawInput::default(), |ctx| {
+            CentralPanel::default().show(ctx, |ui| {
+                ui.add(
+                    LinearCompass::new(&mut heading)
+                        .spread(180.0f32.to_radians())
+                        .selected_marker(selected_marker)
+                        .relative_bearing_to(relative_bearing_to)
+                        .markers_in_range(move |visible_range| {
+                            ["N", "E", "S", "W"]
+                                .into_iter()
+                                .zip(RANGED_COLORS)
+                                .enumerate()
+                                .filter(move |&(index, _)| {
+                                    let bearing = (index as f32 * 90.0).to_radians();
+                                    let turns = ((visible_range.start() - bearing) / TAU).ceil();
+                                    always_included == Some(index)
+                                        || visible_range.contains(&(bearing + turns * TAU))
+                                })
+                                .map(|(index, (label, color))| {
+                                    CompassMarker::new((index as f32 * 90.0).to_radians())
+                                        .label(label)
+                                        .color(color)
+                                        .id(index)
+                                })
+                        }),
+                );
+            });
+        });
+
+        output
+            .shapes
+            .into_iter()
+            .map(|clipped_shape| clipped_shape.shape)
+            .collect()
+    }
+
+    #[test]
+    fn ranged_markers_are_selected_by_id() {
+        // East is the second marker of the first range and the first of the second one
+        for heading in [45.0f32, 135.0] {
+            let shapes = run_ranged_frame(heading.to_radians(), Some(1), None, None);
+
+            let last_marker_fill = shapes
+                .iter()
+                .rev()
+                .find_map(|shape| match shape {
+                    Shape::Rect(rect_shape) if RANGED_COLORS.contains(&rect_shape.fill) => {
+                        Some(rect_shape.fill)
+                    }
+                    _ => None,
+                })
+                .expect("no marker was painted");
+
+            assert_eq!(last_marker_fill, Color32::GREEN, "heading {heading}");
+        }
+    }
+
+    #[test]
+    fn relative_bearing_readout_follows_markers_off_the_tape() {
+        let readout = |shapes: &[Shape]| {
+            shapes
+                .iter()
+                .filter_map(|shape| match shape {
+                    Shape::Text(text_shape) if text_shape.galley.text().ends_with('\u{B0}') => {
+                        Some(text_shape.galley.text().to_owned())
+                    }
+                    _ => None,
+                })
+                .find(|text| text.starts_with("W "))
+        };
+
+        let shapes = run_ranged_frame(60.0f32.to_radians(), None, Some(3), Some(3));
+        assert_eq!(readout(&shapes).as_deref(), Some("W -150\u{B0}"));
+
+        // West is not painted, it's outside of the tape
+        assert!(!shapes.iter().any(
+            |shape| matches!(shape, Shape::Rect(rect_shape) if rect_shape.fill == Color32::YELLOW)
+        ));
+
+        let shapes = run_ranged_frame(60.0f32.to_radians(), None, Some(3), None);
+        assert_eq!(readout(&shapes), None);
+    }
+}
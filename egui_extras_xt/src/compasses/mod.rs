@@ -1,9 +1,15 @@
 mod compass_axis_labels;
 mod compass_marker;
+mod compass_reciprocal_style;
+mod compass_tick_style;
 mod linear_compass;
 mod polar_compass;
 
 pub use compass_axis_labels::CompassAxisLabels;
 pub use compass_marker::{CompassMarker, CompassMarkerShape, DefaultCompassMarkerColor};
+pub use compass_reciprocal_style::CompassReciprocalStyle;
+pub use compass_tick_style::CompassTickStyle;
+
+pub(crate) use compass_marker::{CompassMarkerCluster, CompassMarkerSource};
 pub use linear_compass::LinearCompass;
 pub use polar_compass::{PolarCompass, PolarCompassOverflow};
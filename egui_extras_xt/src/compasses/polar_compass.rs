@@ -1,10 +1,12 @@
 use std::f32::consts::TAU;
+use std::ops::RangeInclusive;
 
 use ecolor::tint_color_towards;
 use egui::{
     lerp, Align2, FontFamily, FontId, Pos2, Rect, Response, Sense, Shape, Stroke, Ui, Vec2, Widget,
 };
 use emath::normalized_angle;
+use itertools::Itertools;
 
 use strum::{Display, EnumIter};
 
@@ -12,7 +14,8 @@ use crate::common::{
     snap_wrap_constrain_angle, Orientation, RotatedText, SymLog, Winding, WrapMode,
 };
 use crate::compasses::{
-    CompassAxisLabels, CompassMarker, CompassMarkerShape, DefaultCompassMarkerColor,
+    CompassAxisLabels, CompassMarker, CompassMarkerShape, CompassMarkerSource,
+    CompassReciprocalStyle, DefaultCompassMarkerColor,
 };
 
 // ----------------------------------------------------------------------------
@@ -66,9 +69,11 @@ pub struct PolarCompass<'a> {
     show_axes: bool,
     show_rings: bool,
     show_cursor: bool,
+    show_reciprocal: bool,
+    reciprocal_style: CompassReciprocalStyle,
     show_marker_labels: bool,
     show_marker_lines: bool,
-    markers: &'a [CompassMarker<'a>],
+    markers: CompassMarkerSource<'a>,
     default_marker_color: DefaultCompassMarkerColor,
     default_marker_shape: CompassMarkerShape,
 }
@@ -107,9 +112,11 @@ impl<'a> PolarCompass<'a> {
             show_axes: true,
             show_rings: true,
             show_cursor: true,
+            show_reciprocal: false,
+            reciprocal_style: CompassReciprocalStyle::default(),
             show_marker_labels: true,
             show_marker_lines: true,
-            markers: &[],
+            markers: CompassMarkerSource::Slice(&[]),
             default_marker_color: DefaultCompassMarkerColor::HsvByAngle {
                 hue_phase: 0.0,
                 saturation: 1.0,
@@ -119,6 +126,8 @@ impl<'a> PolarCompass<'a> {
         }
     }
 
+    /// Read-only compasses ignore user input, but keep their full-color
+    /// appearance and marker tooltips, for indicators fed by external data.
     pub fn interactive(mut self, interactive: bool) -> Self {
         self.interactive = interactive;
         self
@@ -238,6 +247,16 @@ impl<'a> PolarCompass<'a> {
         self
     }
 
+    pub fn show_reciprocal(mut self, show_reciprocal: bool) -> Self {
+        self.show_reciprocal = show_reciprocal;
+        self
+    }
+
+    pub fn reciprocal_style(mut self, reciprocal_style: CompassReciprocalStyle) -> Self {
+        self.reciprocal_style = reciprocal_style;
+        self
+    }
+
     pub fn show_marker_labels(mut self, show_marker_labels: bool) -> Self {
         self.show_marker_labels = show_marker_labels;
         self
@@ -249,7 +268,37 @@ impl<'a> PolarCompass<'a> {
     }
 
     pub fn markers(mut self, markers: &'a [CompassMarker<'a>]) -> Self {
-        self.markers = markers;
+        self.markers = CompassMarkerSource::Slice(markers);
+        self
+    }
+
+    /// The markers are only consumed when the compass gets painted.
+    pub fn markers_from<I>(mut self, markers: I) -> Self
+    where
+        I: IntoIterator<Item = CompassMarker<'a>>,
+        I::IntoIter: 'a,
+    {
+        self.markers = CompassMarkerSource::from_iter(markers);
+        self
+    }
+
+    /// Queries the markers lazily. The callback is only called when the compass
+    /// gets painted, and may return any iterator.
+    ///
+    /// Unlike on [`LinearCompass`](crate::compasses::LinearCompass), the whole circle
+    /// is visible regardless of the heading, so there is no range to compute: the
+    /// callback always receives `0.0..=TAU`. Bearings outside of this range are still
+    /// painted in their direction. Markers beyond [`PolarCompass::max_distance`] are
+    /// handled by [`PolarCompass::overflow`], the range doesn't account for distances.
+    pub fn markers_in_range<I>(
+        mut self,
+        markers: impl 'a + FnOnce(RangeInclusive<f32>) -> I,
+    ) -> Self
+    where
+        I: IntoIterator<Item = CompassMarker<'a>>,
+        I::IntoIter: 'a,
+    {
+        self.markers = CompassMarkerSource::from_callback(markers);
         self
     }
 
@@ -262,6 +311,22 @@ impl<'a> PolarCompass<'a> {
         self.default_marker_shape = default_marker_shape;
         self
     }
+
+    fn marker_distance_t(&self, distance: f32) -> f32 {
+        let max_log = (self.max_distance / self.scale_log_mult).symlog(self.scale_log_base);
+        let marker_log = (distance / self.scale_log_mult).symlog(self.scale_log_base);
+        (marker_log / max_log).clamp(0.0, 1.0)
+    }
+
+    /// Size of the marker rect in points for a marker at the given distance,
+    /// useful for pre-rasterizing [`CompassMarkerShape::Texture`] icons.
+    #[must_use]
+    pub fn marker_size(&self, distance: f32) -> f32 {
+        lerp(
+            self.marker_near_size..=self.marker_far_size,
+            self.marker_distance_t(distance),
+        )
+    }
 }
 
 impl<'a> Widget for PolarCompass<'a> {
@@ -321,7 +386,12 @@ impl<'a> Widget for PolarCompass<'a> {
         }
 
         if ui.is_rect_visible(rect) {
-            let visuals = *ui.style().interact(&response);
+            let visuals = if self.interactive {
+                *ui.style().interact(&response)
+            } else {
+                // Non-interactive responses would get the dimmed non-interactive visuals
+                ui.style().visuals.widgets.inactive
+            };
             let radius = self.diameter / 2.0;
 
             let value = if self.animated {
@@ -374,6 +444,24 @@ impl<'a> Widget for PolarCompass<'a> {
                 ));
             }
 
+            if self.show_reciprocal {
+                let reciprocal_stroke = {
+                    let stroke = ui.style().visuals.noninteractive().fg_stroke;
+                    Stroke::new(stroke.width, self.reciprocal_style.dimmed(stroke.color))
+                };
+
+                ui.painter().add(Shape::dashed_line(
+                    &[
+                        rect.center(),
+                        rect.center()
+                            + rotation_matrix * Vec2::LEFT * radius * self.reciprocal_style.scale,
+                    ],
+                    reciprocal_stroke,
+                    2.0,
+                    2.0,
+                ));
+            }
+
             if self.show_axes {
                 for (axis_index, axis_label) in self.axis_labels.inner.iter().enumerate() {
                     let axis_angle =
@@ -400,7 +488,10 @@ impl<'a> Widget for PolarCompass<'a> {
                 }
             }
 
-            for marker in self.markers {
+            let markers = std::mem::replace(&mut self.markers, CompassMarkerSource::Slice(&[]))
+                .resolve(0.0..=TAU);
+
+            for marker in markers.iter().sorted_by_key(|marker| marker.priority) {
                 let marker_distance = marker.distance.expect("marker has no distance");
 
                 if (marker_distance > self.max_distance)
@@ -419,14 +510,11 @@ impl<'a> Widget for PolarCompass<'a> {
                     Stroke::new(1.0, stroke_color)
                 };
 
-                let max_log = (self.max_distance / self.scale_log_mult).symlog(self.scale_log_base);
-                let marker_log =
-                    (marker_distance / self.scale_log_mult).symlog(self.scale_log_base);
-                let marker_t = (marker_log / max_log).clamp(0.0, 1.0);
+                let marker_t = self.marker_distance_t(marker_distance);
 
                 let marker_center =
                     rect.center() + angle_to_direction(marker.angle) * (radius * marker_t);
-                let marker_size = lerp(self.marker_near_size..=self.marker_far_size, marker_t);
+                let marker_size = self.marker_size(marker_distance);
 
                 if self.show_marker_lines {
                     ui.painter().add(Shape::dashed_line(
@@ -437,7 +525,7 @@ impl<'a> Widget for PolarCompass<'a> {
                     ));
                 }
 
-                let marker_shape = marker.shape.unwrap_or(self.default_marker_shape);
+                let marker_shape = marker.shape.as_ref().unwrap_or(&self.default_marker_shape);
 
                 marker_shape.paint(
                     ui,
@@ -0,0 +1,121 @@
+use egui::{
+    self, pos2, vec2, Align2, Color32, Rect, Response, Sense, Stroke, TextStyle, Ui, Widget,
+};
+
+use crate::indicators::LedShape;
+
+// ----------------------------------------------------------------------------
+
+/// Row of lights showing the bits of an integer, from the most significant bit on the left
+/// to the least significant bit on the right.
+#[must_use = "You should put this widget in an ui with `ui.add(widget);`"]
+pub struct BinaryDisplay {
+    value: u64,
+    bit_count: usize,
+    bit_size: f32,
+    bit_gap: f32,
+    shape: LedShape,
+    one_color: Color32,
+    zero_color: Color32,
+    show_bit_labels: bool,
+}
+
+impl BinaryDisplay {
+    /// Shows the lowest `bit_count` bits of `value`, up to 64 bits.
+    pub fn new(value: u64, bit_count: usize) -> Self {
+        Self {
+            value,
+            bit_count: bit_count.min(u64::BITS as usize),
+            bit_size: 12.0,
+            bit_gap: 4.0,
+            shape: LedShape::Circle,
+            one_color: Color32::from_rgb(0x40, 0xE0, 0x40),
+            zero_color: Color32::from_rgb(0x10, 0x30, 0x10),
+            show_bit_labels: false,
+        }
+    }
+
+    pub fn bit_size(mut self, bit_size: impl Into<f32>) -> Self {
+        self.bit_size = bit_size.into();
+        self
+    }
+
+    pub fn bit_gap(mut self, bit_gap: impl Into<f32>) -> Self {
+        self.bit_gap = bit_gap.into();
+        self
+    }
+
+    pub fn shape(mut self, shape: LedShape) -> Self {
+        self.shape = shape;
+        self
+    }
+
+    pub fn one_color(mut self, one_color: Color32) -> Self {
+        self.one_color = one_color;
+        self
+    }
+
+    pub fn zero_color(mut self, zero_color: Color32) -> Self {
+        self.zero_color = zero_color;
+        self
+    }
+
+    /// Shows the index of each bit below it.
+    pub fn show_bit_labels(mut self, show_bit_labels: bool) -> Self {
+        self.show_bit_labels = show_bit_labels;
+        self
+    }
+}
+
+impl Widget for BinaryDisplay {
+    fn ui(self, ui: &mut Ui) -> Response {
+        let font_id = TextStyle::Small.resolve(ui.style());
+
+        let label_height = if self.show_bit_labels {
+            ui.fonts(|fonts| fonts.row_height(&font_id)) + self.bit_gap
+        } else {
+            0.0
+        };
+
+        let desired_size = vec2(
+            (self.bit_size + self.bit_gap) * self.bit_count as f32 - self.bit_gap,
+            self.bit_size + label_height,
+        )
+        .max(vec2(0.0, 0.0));
+
+        let (rect, response) = ui.allocate_exact_size(desired_size, Sense::hover());
+
+        if ui.is_rect_visible(rect) {
+            let stroke = Stroke::new(1.0, ui.style().visuals.window_stroke().color);
+
+            for position in 0..self.bit_count {
+                let bit_index = self.bit_count - 1 - position;
+                let bit = (self.value >> bit_index) & 0x01 != 0;
+
+                let bit_rect = Rect::from_min_size(
+                    rect.min + vec2((self.bit_size + self.bit_gap) * position as f32, 0.0),
+                    vec2(self.bit_size, self.bit_size),
+                );
+
+                self.shape.paint(
+                    ui.painter(),
+                    bit_rect,
+                    if bit { self.one_color } else { self.zero_color },
+                    stroke,
+                );
+
+                if self.show_bit_labels {
+                    ui.painter().text(
+                        pos2(bit_rect.center().x, rect.bottom()),
+                        Align2::CENTER_BOTTOM,
+                        bit_index.to_string(),
+                        font_id.clone(),
+                        ui.style().visuals.weak_text_color(),
+                    );
+                }
+            }
+        }
+
+        response
+    }
+}
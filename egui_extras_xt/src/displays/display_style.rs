@@ -3,7 +3,7 @@ use strum::{Display, EnumIter};
 
 // ----------------------------------------------------------------------------
 
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy, Debug, Hash)]
 pub struct DisplayStyle {
     pub background_color: Color32,
     pub active_foreground_color: Color32,
@@ -54,6 +54,27 @@ impl DisplayStyle {
         )
     }
 
+    /// Blends the active colors towards the inactive colors, and the background color
+    /// towards black. `brightness` is clamped to the `0.0..=1.0` range.
+    #[must_use]
+    pub fn dimmed(&self, brightness: f32) -> Self {
+        let brightness = brightness.clamp(0.0, 1.0);
+
+        // Keeps the opacity of the background
+        let background_color = {
+            let [r, g, b, a] = self.background_color.to_array();
+            let dim = |c: u8| (c as f32 * brightness).round() as u8;
+            Color32::from_rgba_premultiplied(dim(r), dim(g), dim(b), a)
+        };
+
+        DisplayStyle {
+            background_color,
+            active_foreground_color: self.foreground_color_blend(brightness),
+            active_foreground_stroke: self.foreground_stroke_blend(brightness),
+            ..*self
+        }
+    }
+
     #[must_use]
     pub fn system_style(ui: &Ui) -> Self {
         DisplayStyle {
@@ -74,6 +95,34 @@ impl Default for DisplayStyle {
 
 // ----------------------------------------------------------------------------
 
+/// Translucent halo painted around the active segments of a [`SegmentedDisplayWidget`],
+/// imitating the glow of vacuum-fluorescent and gas-discharge tubes. The halo takes the
+/// active foreground color of the digits.
+///
+/// [`SegmentedDisplayWidget`]: crate::displays::SegmentedDisplayWidget
+#[non_exhaustive]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct DisplayGlow {
+    /// Width of the halo relative to the segment thickness.
+    pub width: f32,
+    pub opacity: f32,
+}
+
+impl DisplayGlow {
+    #[must_use]
+    pub fn new(width: f32, opacity: f32) -> Self {
+        Self { width, opacity }
+    }
+}
+
+impl Default for DisplayGlow {
+    fn default() -> Self {
+        Self::new(0.5, 0.2)
+    }
+}
+
+// ----------------------------------------------------------------------------
+
 #[non_exhaustive]
 #[derive(Clone, Copy, Debug, Display, EnumIter, Eq, PartialEq)]
 pub enum DisplayStylePreset {
@@ -112,9 +161,28 @@ pub enum DisplayStylePreset {
 
     #[strum(to_string = "Dracula")]
     Dracula,
+
+    #[strum(to_string = "Vacuum Fluorescent")]
+    VacuumFluorescent,
+
+    #[strum(to_string = "Gas Discharge")]
+    GasDischarge,
+
+    #[strum(to_string = "Reflective LCD")]
+    ReflectiveLcd,
 }
 
 impl DisplayStylePreset {
+    /// Glow of the segments for the presets imitating glowing tubes.
+    #[must_use]
+    pub fn glow(&self) -> Option<DisplayGlow> {
+        match *self {
+            DisplayStylePreset::VacuumFluorescent => Some(DisplayGlow::new(0.5, 0.19)),
+            DisplayStylePreset::GasDischarge => Some(DisplayGlow::new(0.375, 0.16)),
+            _ => None,
+        }
+    }
+
     #[must_use]
     pub fn style(&self) -> DisplayStyle {
         match *self {
@@ -202,6 +270,27 @@ impl DisplayStylePreset {
                 inactive_foreground_color: Color32::from_rgb(0x41, 0x1F, 0x33), // Dracula pink, HSV(.., .., 25%)
                 inactive_foreground_stroke: Stroke::NONE,
             },
+            DisplayStylePreset::VacuumFluorescent => DisplayStyle {
+                background_color: Color32::from_rgb(0x04, 0x10, 0x12),
+                active_foreground_color: Color32::from_rgb(0x7A, 0xF8, 0xEE),
+                active_foreground_stroke: Stroke::NONE,
+                inactive_foreground_color: Color32::from_rgb(0x0A, 0x22, 0x24),
+                inactive_foreground_stroke: Stroke::NONE,
+            },
+            DisplayStylePreset::GasDischarge => DisplayStyle {
+                background_color: Color32::from_rgb(0x12, 0x07, 0x02),
+                active_foreground_color: Color32::from_rgb(0xFF, 0x8A, 0x1C),
+                active_foreground_stroke: Stroke::NONE,
+                inactive_foreground_color: Color32::from_rgb(0x2A, 0x12, 0x06),
+                inactive_foreground_stroke: Stroke::NONE,
+            },
+            DisplayStylePreset::ReflectiveLcd => DisplayStyle {
+                background_color: Color32::from_rgb(0xB2, 0xC2, 0x9A),
+                active_foreground_color: Color32::from_rgb(0x1C, 0x24, 0x18),
+                active_foreground_stroke: Stroke::NONE,
+                inactive_foreground_color: Color32::from_rgb(0xA2, 0xB2, 0x8C),
+                inactive_foreground_stroke: Stroke::NONE,
+            },
         }
     }
 }
@@ -0,0 +1,23 @@
+/// Dot-matrix display metrics, relative to the size of the dots.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct DotMatrixMetrics {
+    pub dot_spacing: f32,
+    pub round_dots: bool,
+
+    pub character_spacing: f32,
+
+    pub margin_horizontal: f32,
+    pub margin_vertical: f32,
+}
+
+impl Default for DotMatrixMetrics {
+    fn default() -> Self {
+        Self {
+            dot_spacing: 0.15,
+            round_dots: false,
+            character_spacing: 0.8,
+            margin_horizontal: 1.5,
+            margin_vertical: 1.5,
+        }
+    }
+}
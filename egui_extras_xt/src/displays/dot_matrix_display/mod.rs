@@ -0,0 +1,43 @@
+mod dot_matrix_font;
+mod dot_matrix_metrics;
+mod widget;
+
+use strum::{Display, EnumIter};
+
+pub use dot_matrix_metrics::DotMatrixMetrics;
+pub use widget::DotMatrixDisplayWidget;
+
+// ----------------------------------------------------------------------------
+
+/// Rows of a dot-matrix character from top to bottom, the five least
+/// significant bits of a row are its dots from left to right (bit 4 is the
+/// leftmost dot), like the custom character RAM of HD44780 controllers.
+pub type DotMatrixGlyph = [u8; 8];
+
+// ----------------------------------------------------------------------------
+
+#[non_exhaustive]
+#[derive(Clone, Copy, Debug, Display, EnumIter, Eq, PartialEq)]
+pub enum DotMatrixKind {
+    #[strum(to_string = "5\u{D7}7")]
+    FiveBySeven,
+
+    /// The eighth row is usually the cursor line.
+    #[strum(to_string = "5\u{D7}8")]
+    FiveByEight,
+}
+
+impl DotMatrixKind {
+    #[must_use]
+    pub fn columns(&self) -> usize {
+        5
+    }
+
+    #[must_use]
+    pub fn rows(&self) -> usize {
+        match *self {
+            DotMatrixKind::FiveBySeven => 7,
+            DotMatrixKind::FiveByEight => 8,
+        }
+    }
+}
@@ -0,0 +1,188 @@
+use std::f32::consts::TAU;
+
+use egui::{vec2, Color32, Mesh, Pos2, Rect, Response, Sense, Shape, Stroke, Ui, Vec2, Widget};
+
+use crate::displays::dot_matrix_display::{
+    dot_matrix_font, DotMatrixGlyph, DotMatrixKind, DotMatrixMetrics,
+};
+use crate::displays::{DisplayStyle, DisplayStylePreset};
+
+// ----------------------------------------------------------------------------
+
+fn add_dot(mesh: &mut Mesh, center: Pos2, size: f32, color: Color32, round: bool) {
+    if round {
+        const DOT_RESOLUTION: u32 = 12;
+
+        let center_index = mesh.vertices.len() as u32;
+        mesh.colored_vertex(center, color);
+
+        for i in 0..DOT_RESOLUTION {
+            let angle = (i as f32 / DOT_RESOLUTION as f32) * TAU;
+            mesh.colored_vertex(center + Vec2::angled(angle) * (size / 2.0), color);
+            mesh.add_triangle(
+                center_index,
+                center_index + 1 + i,
+                center_index + 1 + ((i + 1) % DOT_RESOLUTION),
+            );
+        }
+    } else {
+        mesh.add_colored_rect(Rect::from_center_size(center, Vec2::splat(size)), color);
+    }
+}
+
+// ----------------------------------------------------------------------------
+
+#[must_use = "You should put this widget in an ui with `ui.add(widget);`"]
+pub struct DotMatrixDisplayWidget {
+    display_kind: DotMatrixKind,
+    characters: Vec<DotMatrixGlyph>,
+    custom_glyphs: Vec<(char, DotMatrixGlyph)>,
+    dot_size: f32,
+    metrics: DotMatrixMetrics,
+    style: DisplayStyle,
+}
+
+impl DotMatrixDisplayWidget {
+    pub fn new(display_kind: DotMatrixKind) -> Self {
+        Self {
+            display_kind,
+            characters: Vec::new(),
+            custom_glyphs: Vec::new(),
+            dot_size: 4.0,
+            metrics: DotMatrixMetrics::default(),
+            style: DisplayStylePreset::Default.style(),
+        }
+    }
+
+    pub fn five_by_seven<T: AsRef<str>>(value: T) -> Self {
+        Self::new(DotMatrixKind::FiveBySeven).push_string(value.as_ref())
+    }
+
+    pub fn five_by_eight<T: AsRef<str>>(value: T) -> Self {
+        Self::new(DotMatrixKind::FiveByEight).push_string(value.as_ref())
+    }
+
+    /// Defines a glyph for the given character, taking precedence over the built-in
+    /// font in subsequent `push_string` calls.
+    pub fn custom_glyph(mut self, c: char, glyph: DotMatrixGlyph) -> Self {
+        self.custom_glyphs.retain(|&(k, _)| k != c);
+        self.custom_glyphs.push((c, glyph));
+        self
+    }
+
+    pub fn push_string<T: AsRef<str>>(mut self, value: T) -> Self {
+        for c in value.as_ref().chars() {
+            let glyph = self
+                .custom_glyphs
+                .iter()
+                .find(|&&(k, _)| k == c)
+                .map(|&(_, glyph)| glyph)
+                .or_else(|| dot_matrix_font::glyph(c));
+
+            if let Some(glyph) = glyph {
+                self.characters.push(glyph);
+            }
+        }
+        self
+    }
+
+    pub fn push_glyph(mut self, glyph: DotMatrixGlyph) -> Self {
+        self.characters.push(glyph);
+        self
+    }
+
+    pub fn dot_size(mut self, dot_size: impl Into<f32>) -> Self {
+        self.dot_size = dot_size.into();
+        self
+    }
+
+    pub fn metrics(mut self, metrics: DotMatrixMetrics) -> Self {
+        self.metrics = metrics;
+        self
+    }
+
+    pub fn style(mut self, style: DisplayStyle) -> Self {
+        self.style = style;
+        self
+    }
+
+    pub fn style_preset(mut self, preset: DisplayStylePreset) -> Self {
+        self.style = preset.style();
+        self
+    }
+}
+
+impl Widget for DotMatrixDisplayWidget {
+    fn ui(self, ui: &mut Ui) -> Response {
+        let columns = self.display_kind.columns();
+        let rows = self.display_kind.rows();
+
+        // Turn relative metrics to absolute metrics
+        let dot_size = self.dot_size;
+        let dot_pitch = dot_size * (1.0 + self.metrics.dot_spacing);
+        let character_spacing = self.metrics.character_spacing * dot_size;
+        let margin_horizontal = self.metrics.margin_horizontal * dot_size;
+        let margin_vertical = self.metrics.margin_vertical * dot_size;
+
+        let character_size = vec2(
+            (dot_pitch * columns as f32) - (dot_pitch - dot_size),
+            (dot_pitch * rows as f32) - (dot_pitch - dot_size),
+        );
+
+        let desired_size = vec2(
+            (character_size.x * self.characters.len() as f32)
+                + (character_spacing * (self.characters.len().saturating_sub(1)) as f32)
+                + (2.0 * margin_horizontal),
+            character_size.y + (2.0 * margin_vertical),
+        );
+
+        let (rect, response) = ui.allocate_exact_size(desired_size, Sense::click());
+
+        if ui.is_rect_visible(rect) {
+            ui.painter().rect(
+                rect,
+                ui.style().visuals.noninteractive().rounding,
+                self.style.background_color,
+                Stroke::NONE,
+            );
+
+            // All dots of the same color are batched into a single mesh
+            let mut active_mesh = Mesh::default();
+            let mut inactive_mesh = Mesh::default();
+
+            for (character_index, glyph) in self.characters.iter().enumerate() {
+                let character_origin = rect.left_top()
+                    + vec2(
+                        margin_horizontal
+                            + (character_size.x + character_spacing) * character_index as f32,
+                        margin_vertical,
+                    );
+
+                for (row, column) in itertools::iproduct!(0..rows, 0..columns) {
+                    let dot_active = (glyph[row] >> (columns - 1 - column)) & 0x01 != 0x00;
+
+                    let dot_center = character_origin
+                        + vec2(column as f32, row as f32) * dot_pitch
+                        + Vec2::splat(dot_size / 2.0);
+
+                    add_dot(
+                        if dot_active {
+                            &mut active_mesh
+                        } else {
+                            &mut inactive_mesh
+                        },
+                        dot_center,
+                        dot_size,
+                        self.style.foreground_color(dot_active),
+                        self.metrics.round_dots,
+                    );
+                }
+            }
+
+            ui.painter().add(Shape::mesh(inactive_mesh));
+            ui.painter().add(Shape::mesh(active_mesh));
+        }
+
+        response
+    }
+}
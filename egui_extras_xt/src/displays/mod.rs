@@ -1,12 +1,18 @@
+mod binary_display;
 mod display_style;
 mod indicator_button;
 mod led_display;
+mod oscilloscope_widget;
 mod waveform_display;
 
+pub mod dot_matrix_display;
 pub mod segmented_display;
 
-pub use display_style::{DisplayStyle, DisplayStylePreset};
+pub use binary_display::BinaryDisplay;
+pub use display_style::{DisplayGlow, DisplayStyle, DisplayStylePreset};
+pub use dot_matrix_display::{DotMatrixDisplayWidget, DotMatrixKind, DotMatrixMetrics};
 pub use indicator_button::{IndicatorButton, IndicatorButtonBehavior};
 pub use led_display::LedDisplay;
+pub use oscilloscope_widget::{OscilloscopeMode, OscilloscopeWidget};
 pub use segmented_display::{DisplayKind, DisplayMetrics, SegmentedDisplayWidget};
 pub use waveform_display::{BufferLayout, SignalEdge, WaveformDisplayWidget};
@@ -0,0 +1,308 @@
+use std::ops::RangeInclusive;
+use std::sync::Arc;
+
+use egui::{self, vec2, Color32, Response, Sense, Shape, Stroke, Ui, Widget};
+use emath::{remap, Pos2};
+use itertools::Itertools;
+
+// ----------------------------------------------------------------------------
+
+/// Signal displayed by an [`OscilloscopeWidget`].
+#[derive(Clone, Copy, Debug)]
+pub enum OscilloscopeMode<'a> {
+    /// Samples plotted against time, from left to right.
+    Waveform(&'a [f32]),
+
+    /// Sample pairs plotted against each other, drawing Lissajous figures.
+    /// Excess samples of the longer channel are ignored.
+    XY(&'a [f32], &'a [f32]),
+}
+
+// ----------------------------------------------------------------------------
+
+/// Previous trace kept around for the persistence effect, in coordinates
+/// normalized to the unit square so the widget can move around freely.
+#[derive(Clone)]
+struct PersistentTrace {
+    /// Time when the trace was replaced by a newer one and started fading out.
+    time: f64,
+    points: Arc<Vec<Pos2>>,
+}
+
+/// Traces of an oscilloscope with persistence, stored between frames.
+#[derive(Clone, Default)]
+struct PersistentTraces {
+    current: Arc<Vec<Pos2>>,
+    previous: Vec<PersistentTrace>,
+}
+
+// ----------------------------------------------------------------------------
+
+#[must_use = "You should put this widget in an ui with `ui.add(widget);`"]
+pub struct OscilloscopeWidget<'a> {
+    mode: OscilloscopeMode<'a>,
+    range: RangeInclusive<f32>,
+    width: f32,
+    height: f32,
+    background_color: Color32,
+    trace_stroke: Stroke,
+    show_grid: bool,
+    grid_divisions: (usize, usize),
+    persistence: f32,
+}
+
+impl<'a> OscilloscopeWidget<'a> {
+    pub fn new(mode: OscilloscopeMode<'a>) -> Self {
+        Self {
+            mode,
+            range: -1.0..=1.0,
+            width: 256.0,
+            height: 192.0,
+            background_color: Color32::from_rgb(0x0A, 0x12, 0x0C),
+            trace_stroke: Stroke::new(1.5, Color32::from_rgb(0x33, 0xFF, 0x66)),
+            show_grid: true,
+            grid_divisions: (10, 8),
+            persistence: 0.0,
+        }
+    }
+
+    /// Sample values mapped to the edges of the screen, on both axes.
+    pub fn range(mut self, range: RangeInclusive<f32>) -> Self {
+        self.range = range;
+        self
+    }
+
+    pub fn width(mut self, width: impl Into<f32>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    pub fn height(mut self, height: impl Into<f32>) -> Self {
+        self.height = height.into();
+        self
+    }
+
+    pub fn background_color(mut self, background_color: Color32) -> Self {
+        self.background_color = background_color;
+        self
+    }
+
+    pub fn trace_stroke(mut self, trace_stroke: impl Into<Stroke>) -> Self {
+        self.trace_stroke = trace_stroke.into();
+        self
+    }
+
+    pub fn show_grid(mut self, show_grid: bool) -> Self {
+        self.show_grid = show_grid;
+        self
+    }
+
+    /// Number of horizontal and vertical divisions of the graticule.
+    pub fn grid_divisions(mut self, horizontal: usize, vertical: usize) -> Self {
+        self.grid_divisions = (horizontal, vertical);
+        self
+    }
+
+    /// Time in seconds it takes for previous traces to fade out. Zero disables the effect.
+    pub fn persistence(mut self, persistence: f32) -> Self {
+        self.persistence = persistence;
+        self
+    }
+}
+
+impl<'a> Widget for OscilloscopeWidget<'a> {
+    fn ui(self, ui: &mut Ui) -> Response {
+        let desired_size = vec2(self.width, self.height);
+        let (rect, response) = ui.allocate_exact_size(desired_size, Sense::hover());
+
+        let normalize = |sample: f32| remap(sample, self.range.clone(), 0.0..=1.0);
+
+        let points = match self.mode {
+            OscilloscopeMode::Waveform(samples) => samples
+                .iter()
+                .enumerate()
+                .map(|(index, &sample)| {
+                    Pos2::new(
+                        index as f32 / samples.len().saturating_sub(1).max(1) as f32,
+                        normalize(sample),
+                    )
+                })
+                .collect_vec(),
+            OscilloscopeMode::XY(x_samples, y_samples) => x_samples
+                .iter()
+                .zip(y_samples)
+                .map(|(&x, &y)| Pos2::new(normalize(x), normalize(y)))
+                .collect_vec(),
+        };
+
+        let persistent_traces = if self.persistence > 0.0 {
+            let time = ui.input(|input| input.time);
+            let persistence = self.persistence as f64;
+
+            let traces = ui.memory_mut(|memory| {
+                let traces = memory
+                    .data
+                    .get_temp_mut_or_default::<PersistentTraces>(response.id);
+
+                traces
+                    .previous
+                    .retain(|trace| time - trace.time < persistence);
+
+                // Unchanged samples keep the current trace lit, only replaced ones fade out
+                if *traces.current != points {
+                    let replaced = std::mem::replace(&mut traces.current, Arc::new(points));
+                    if !replaced.is_empty() {
+                        traces.previous.push(PersistentTrace {
+                            time,
+                            points: replaced,
+                        });
+                    }
+                }
+
+                traces.clone()
+            });
+
+            if !traces.previous.is_empty() {
+                ui.ctx().request_repaint();
+            }
+
+            traces
+                .previous
+                .into_iter()
+                .map(|trace| {
+                    let opacity = 1.0 - ((time - trace.time) / persistence) as f32;
+                    (opacity, trace.points)
+                })
+                .chain(std::iter::once((1.0, traces.current)))
+                .collect_vec()
+        } else {
+            ui.memory_mut(|memory| memory.data.remove::<PersistentTraces>(response.id));
+            vec![(1.0, Arc::new(points))]
+        };
+
+        if ui.is_rect_visible(rect) {
+            let painter = ui.painter_at(rect);
+            let visuals = ui.style().noninteractive();
+
+            painter.rect(rect, visuals.rounding, self.background_color, Stroke::NONE);
+
+            if self.show_grid {
+                let (horizontal, vertical) =
+                    (self.grid_divisions.0.max(1), self.grid_divisions.1.max(1));
+
+                let grid_stroke = Stroke::new(1.0, self.trace_stroke.color.gamma_multiply(0.15));
+                let axis_stroke = Stroke::new(1.0, self.trace_stroke.color.gamma_multiply(0.3));
+
+                for index in 1..horizontal {
+                    let x = remap(index as f32, 0.0..=horizontal as f32, rect.x_range());
+                    let stroke = if index * 2 == horizontal {
+                        axis_stroke
+                    } else {
+                        grid_stroke
+                    };
+
+                    painter.vline(x, rect.y_range(), stroke);
+                }
+
+                for index in 1..vertical {
+                    let y = remap(index as f32, 0.0..=vertical as f32, rect.y_range());
+                    let stroke = if index * 2 == vertical {
+                        axis_stroke
+                    } else {
+                        grid_stroke
+                    };
+
+                    painter.hline(rect.x_range(), y, stroke);
+                }
+            }
+
+            let to_screen = |point: &Pos2| {
+                Pos2::new(
+                    remap(point.x, 0.0..=1.0, rect.x_range()),
+                    remap(point.y, 0.0..=1.0, rect.bottom_up_range()),
+                )
+            };
+
+            for (opacity, points) in persistent_traces {
+                painter.add(Shape::line(
+                    points.iter().map(to_screen).collect_vec(),
+                    Stroke::new(
+                        self.trace_stroke.width,
+                        self.trace_stroke.color.gamma_multiply(opacity),
+                    ),
+                ));
+            }
+
+            painter.rect_stroke(rect, visuals.rounding, visuals.bg_stroke);
+        }
+
+        response
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use egui::{CentralPanel, Context, RawInput, ViewportId};
+
+    use super::*;
+
+    /// Runs a frame with an oscilloscope showing `samples`, returning the number of
+    /// painted traces and whether another frame was requested right away.
+    fn run_frame(ctx: &Context, time: f64, samples: &[f32]) -> (usize, bool) {
+        let input = RawInput {
+            time: Some(time),
+            ..Default::default()
+        };
+
+        let output = ctx.run(input, |ctx| {
+            CentralPanel::default().show(ctx, |ui| {
+                ui.add(
+                    OscilloscopeWidget::new(OscilloscopeMode::Waveform(samples))
+                        .show_grid(false)
+                        .persistence(0.5),
+                );
+            });
+        });
+
+        let trace_count = output
+            .shapes
+            .iter()
+            .filter(|clipped_shape| matches!(clipped_shape.shape, Shape::Path(_)))
+            .count();
+        let repaint = output.viewport_output[&ViewportId::ROOT]
+            .repaint_delay
+            .is_zero();
+        (trace_count, repaint)
+    }
+
+    #[test]
+    fn unchanged_samples_dont_animate() {
+        let ctx = Context::default();
+        let samples = [0.0, 0.5, -0.5, 0.0];
+
+        // Let egui settle its own first frame repaints
+        for frame in 0..3 {
+            run_frame(&ctx, frame as f64 / 60.0, &samples);
+        }
+
+        for frame in 3..10 {
+            assert_eq!(run_frame(&ctx, frame as f64 / 60.0, &samples), (1, false));
+        }
+    }
+
+    #[test]
+    fn replaced_traces_fade_out() {
+        let ctx = Context::default();
+
+        for frame in 0..3 {
+            run_frame(&ctx, frame as f64 / 60.0, &[0.0, 0.5]);
+        }
+
+        assert_eq!(run_frame(&ctx, 0.1, &[0.0, -0.5]), (2, true));
+        assert_eq!(run_frame(&ctx, 0.5, &[0.0, -0.5]), (2, true));
+        assert_eq!(run_frame(&ctx, 0.6, &[0.0, -0.5]).0, 1);
+
+        // egui repaints one more frame after the last request
+        assert_eq!(run_frame(&ctx, 0.7, &[0.0, -0.5]), (1, false));
+    }
+}
@@ -0,0 +1,44 @@
+use std::sync::Arc;
+
+use crate::displays::segmented_display::DisplayDigit;
+
+/// Digits of a segmented display parsed once, for displays whose contents rarely change.
+///
+/// Pushing strings looks up the glyph of every character each frame. Static displays can
+/// prepare their digits once with [`SegmentedDisplayWidget::into_buffer`] and push them
+/// with [`SegmentedDisplayWidget::push_buffer`] instead. Clones share the digits, so
+/// buffers are cheap to keep in the application state and to push every frame.
+///
+/// The glyphs are specific to the display kind and font of the widget that prepared them.
+///
+/// [`SegmentedDisplayWidget::into_buffer`]: crate::displays::SegmentedDisplayWidget::into_buffer
+/// [`SegmentedDisplayWidget::push_buffer`]: crate::displays::SegmentedDisplayWidget::push_buffer
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
+pub struct DisplayBuffer {
+    pub(crate) digits: Arc<Vec<DisplayDigit>>,
+}
+
+impl DisplayBuffer {
+    #[must_use]
+    pub fn digits(&self) -> &[DisplayDigit] {
+        &self.digits
+    }
+
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.digits.len()
+    }
+
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.digits.is_empty()
+    }
+}
+
+impl FromIterator<DisplayDigit> for DisplayBuffer {
+    fn from_iter<I: IntoIterator<Item = DisplayDigit>>(iter: I) -> Self {
+        Self {
+            digits: Arc::new(iter.into_iter().collect()),
+        }
+    }
+}
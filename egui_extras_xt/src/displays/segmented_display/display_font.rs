@@ -0,0 +1,233 @@
+use std::collections::BTreeMap;
+use std::error::Error;
+use std::fmt;
+
+use crate::displays::segmented_display::{DisplayGlyph, DisplayKind};
+
+// ----------------------------------------------------------------------------
+
+#[non_exhaustive]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum DisplayFontError {
+    GlyphOutOfRange {
+        character: char,
+        glyph: DisplayGlyph,
+        segment_count: usize,
+    },
+    DisplayKindMismatch {
+        expected: DisplayKind,
+        found: DisplayKind,
+    },
+}
+
+impl fmt::Display for DisplayFontError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            DisplayFontError::GlyphOutOfRange {
+                character,
+                glyph,
+                segment_count,
+            } => {
+                write!(
+                    f,
+                    "glyph of {character:?} (0x{glyph:04X}) has more than {segment_count} segments"
+                )
+            }
+            DisplayFontError::DisplayKindMismatch { expected, found } => {
+                write!(f, "expected a {expected} font, found a {found} font")
+            }
+        }
+    }
+}
+
+impl Error for DisplayFontError {}
+
+// ----------------------------------------------------------------------------
+
+/// Character to glyph mapping of a segmented display, see [`DisplayFontBuilder`].
+///
+/// With the `serde` feature enabled fonts can be stored in data files, the glyphs
+/// get validated when deserializing them.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(try_from = "DisplayFontData", into = "DisplayFontData")
+)]
+pub struct DisplayFont {
+    display_kind: DisplayKind,
+    glyphs: BTreeMap<char, DisplayGlyph>,
+}
+
+impl DisplayFont {
+    /// The built-in font of the display kind, the same glyphs the widget uses without a
+    /// custom font.
+    pub fn builtin(display_kind: DisplayKind) -> Self {
+        Self {
+            display_kind,
+            glyphs: display_kind
+                .display_impl()
+                .glyph_data()
+                .iter()
+                .copied()
+                .collect(),
+        }
+    }
+
+    #[must_use]
+    pub fn display_kind(&self) -> DisplayKind {
+        self.display_kind
+    }
+
+    #[must_use]
+    pub fn glyph(&self, c: char) -> Option<DisplayGlyph> {
+        self.glyphs.get(&c).copied()
+    }
+
+    #[must_use]
+    pub fn supports(&self, c: char) -> bool {
+        self.glyphs.contains_key(&c)
+    }
+
+    /// The characters of the font in ascending order.
+    pub fn chars(&self) -> impl Iterator<Item = char> + '_ {
+        self.glyphs.keys().copied()
+    }
+
+    pub fn glyphs(&self) -> impl Iterator<Item = (char, DisplayGlyph)> + '_ {
+        self.glyphs.iter().map(|(&c, &glyph)| (c, glyph))
+    }
+
+    /// Number of characters in the font.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.glyphs.len()
+    }
+
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.glyphs.is_empty()
+    }
+
+    /// Combines the glyphs of both fonts, the glyphs of `other` replace the glyphs of the
+    /// same characters in this font. The fonts have to be of the same display kind, since
+    /// the bit order of the segments differs between the kinds.
+    pub fn merge(&self, other: &DisplayFont) -> Result<DisplayFont, DisplayFontError> {
+        if other.display_kind != self.display_kind {
+            return Err(DisplayFontError::DisplayKindMismatch {
+                expected: self.display_kind,
+                found: other.display_kind,
+            });
+        }
+
+        Ok(DisplayFont {
+            display_kind: self.display_kind,
+            glyphs: self
+                .glyphs
+                .iter()
+                .chain(&other.glyphs)
+                .map(|(&c, &glyph)| (c, glyph))
+                .collect(),
+        })
+    }
+}
+
+// ----------------------------------------------------------------------------
+
+#[cfg(feature = "serde")]
+#[derive(Clone, serde::Serialize, serde::Deserialize)]
+struct DisplayFontData {
+    display_kind: DisplayKind,
+    glyphs: BTreeMap<char, DisplayGlyph>,
+}
+
+#[cfg(feature = "serde")]
+impl TryFrom<DisplayFontData> for DisplayFont {
+    type Error = DisplayFontError;
+
+    fn try_from(data: DisplayFontData) -> Result<Self, Self::Error> {
+        DisplayFontBuilder::empty(data.display_kind)
+            .glyphs(data.glyphs)
+            .build()
+    }
+}
+
+#[cfg(feature = "serde")]
+impl From<DisplayFont> for DisplayFontData {
+    fn from(font: DisplayFont) -> Self {
+        Self {
+            display_kind: font.display_kind,
+            glyphs: font.glyphs,
+        }
+    }
+}
+
+// ----------------------------------------------------------------------------
+
+/// Builds custom fonts for segmented displays, starting from an existing font.
+#[must_use]
+pub struct DisplayFontBuilder {
+    display_kind: DisplayKind,
+    glyphs: BTreeMap<char, DisplayGlyph>,
+}
+
+impl DisplayFontBuilder {
+    /// Starts from the built-in font of the display kind.
+    pub fn new(display_kind: DisplayKind) -> Self {
+        Self::from_font(DisplayFont::builtin(display_kind))
+    }
+
+    /// Starts from a font without any glyphs.
+    pub fn empty(display_kind: DisplayKind) -> Self {
+        Self {
+            display_kind,
+            glyphs: BTreeMap::new(),
+        }
+    }
+
+    pub fn from_font(font: DisplayFont) -> Self {
+        Self {
+            display_kind: font.display_kind,
+            glyphs: font.glyphs,
+        }
+    }
+
+    /// Inserts a glyph, replacing the existing glyph of the character.
+    pub fn glyph(mut self, c: char, glyph: DisplayGlyph) -> Self {
+        self.glyphs.insert(c, glyph);
+        self
+    }
+
+    pub fn glyphs(mut self, glyphs: impl IntoIterator<Item = (char, DisplayGlyph)>) -> Self {
+        self.glyphs.extend(glyphs);
+        self
+    }
+
+    pub fn remove_glyph(mut self, c: char) -> Self {
+        self.glyphs.remove(&c);
+        self
+    }
+
+    /// Checks the glyphs against the segment count of the display kind.
+    pub fn build(self) -> Result<DisplayFont, DisplayFontError> {
+        let segment_count = self.display_kind.segment_count();
+        let segment_mask = (1_u32 << segment_count) - 1;
+
+        if let Some((&character, &glyph)) = self
+            .glyphs
+            .iter()
+            .find(|(_, &glyph)| u32::from(glyph) & !segment_mask != 0)
+        {
+            return Err(DisplayFontError::GlyphOutOfRange {
+                character,
+                glyph,
+                segment_count,
+            });
+        }
+
+        Ok(DisplayFont {
+            display_kind: self.display_kind,
+            glyphs: self.glyphs,
+        })
+    }
+}
@@ -1,19 +1,148 @@
+use std::f32::consts::PI;
+
+use egui::{vec2, Pos2, Vec2};
+use itertools::Itertools;
 use strum::{Display, EnumIter};
 
+// ----------------------------------------------------------------------------
+
+/// Shape of the ends of the segments.
+#[non_exhaustive]
+#[derive(Clone, Copy, Debug, Display, EnumIter, Eq, Hash, PartialEq)]
+pub enum SegmentCapStyle {
+    /// Pointed ends, meeting the neighboring segments at mitered joints.
+    #[strum(to_string = "Sharp")]
+    Sharp,
+
+    /// Flat ends, leaving wider gaps at the joints, like the segments of LCDs.
+    #[strum(to_string = "Square")]
+    Square,
+
+    /// Semicircular ends, like the segments of LED and VFD displays.
+    #[strum(to_string = "Rounded")]
+    Rounded,
+}
+
+impl SegmentCapStyle {
+    /// Vertices approximating the semicircle of a rounded cap.
+    const ROUNDED_CAP_VERTICES: usize = 7;
+
+    /// Reshapes the outline of a segment to a bar of `segment_thickness` along its longest
+    /// extent. Sharp caps keep the outline as it is.
+    pub(crate) fn apply(&self, outline: Vec<Pos2>, segment_thickness: f32) -> Vec<Pos2> {
+        if *self == SegmentCapStyle::Sharp {
+            return outline;
+        }
+
+        // Long axis through the two farthest points of the outline
+        let Some((start, end)) = outline
+            .iter()
+            .tuple_combinations()
+            .max_by(|(a1, b1), (a2, b2)| a1.distance_sq(**b1).total_cmp(&a2.distance_sq(**b2)))
+            .map(|(a, b)| (*a, *b))
+        else {
+            return outline;
+        };
+
+        let axis = (end - start).normalized();
+        let normal = axis.rot90();
+
+        let (along_min, along_max) = outline
+            .iter()
+            .map(|point| (*point - start).dot(axis))
+            .minmax()
+            .into_option()
+            .unwrap_or_default();
+        let (across_min, across_max) = outline
+            .iter()
+            .map(|point| (*point - start).dot(normal))
+            .minmax()
+            .into_option()
+            .unwrap_or_default();
+
+        // Centers of the caps, the caps of the sharp outline are half thickness long
+        let half_thickness = segment_thickness / 2.0;
+        let across_center = (across_min + across_max) / 2.0;
+        let along_center = (along_min + along_max) / 2.0;
+        let cap_start =
+            start + axis * (along_min + half_thickness).min(along_center) + normal * across_center;
+        let cap_end =
+            start + axis * (along_max - half_thickness).max(along_center) + normal * across_center;
+
+        match *self {
+            SegmentCapStyle::Sharp => outline,
+            SegmentCapStyle::Square => vec![
+                cap_start - normal * half_thickness,
+                cap_end - normal * half_thickness,
+                cap_end + normal * half_thickness,
+                cap_start + normal * half_thickness,
+            ],
+            SegmentCapStyle::Rounded => {
+                let cap = |center: Pos2, direction: Vec2| {
+                    (0..Self::ROUNDED_CAP_VERTICES).map(move |n| {
+                        let angle = PI * n as f32 / (Self::ROUNDED_CAP_VERTICES - 1) as f32;
+                        center
+                            + (-direction.rot90() * angle.cos() + direction * angle.sin())
+                                * half_thickness
+                    })
+                };
+
+                cap(cap_end, axis).chain(cap(cap_start, -axis)).collect()
+            }
+        }
+    }
+}
+
+// ----------------------------------------------------------------------------
+
+/// Proportions of a segmented display. The fields are ratios, so the same metrics scale
+/// with the digit height. Presets from [`DisplayMetricsPreset`] are meant as starting
+/// points, their fields can be adjusted individually.
 #[derive(Clone, Copy)]
 pub struct DisplayMetrics {
+    /// Gap between neighboring segments, relative to the digit height.
     pub segment_spacing: f32,
+    /// Relative to the digit height.
     pub segment_thickness: f32,
 
+    /// Vertical offset of the middle segments, relative to half of the digit height.
     pub digit_median: f32,
+    /// Digit width divided by the digit height.
     pub digit_ratio: f32,
+    /// Horizontal offset of the top of the digits (slant), relative to the digit width.
     pub digit_shearing: f32,
+    /// Gap between neighboring digits, relative to the digit width.
     pub digit_spacing: f32,
 
+    /// Relative to the digit width.
     pub margin_horizontal: f32,
+    /// Relative to the digit height.
     pub margin_vertical: f32,
 
+    /// Distance of the colon dots from the median, relative to half of the digit height.
     pub colon_separation: f32,
+
+    /// Shape of the segment ends, the only field that is not a ratio.
+    pub segment_cap_style: SegmentCapStyle,
+}
+
+impl DisplayMetrics {
+    /// Size of a display with `digit_count` digits of the given height, including the
+    /// margins. This is the size allocated by [`SegmentedDisplayWidget`].
+    ///
+    /// [`SegmentedDisplayWidget`]: crate::displays::SegmentedDisplayWidget
+    #[must_use]
+    pub fn display_size(&self, digit_height: f32, digit_count: usize) -> Vec2 {
+        let digit_width = digit_height * self.digit_ratio;
+
+        vec2(
+            (digit_width * digit_count as f32)
+                + (self.digit_spacing * digit_width * (digit_count.saturating_sub(1)) as f32)
+                + (2.0 * self.margin_horizontal * digit_width)
+                + (2.0 * (self.digit_shearing * digit_width).abs()),
+            digit_height + (2.0 * self.margin_vertical * digit_height),
+        )
+    }
 }
 
 impl Default for DisplayMetrics {
@@ -51,6 +180,7 @@ impl DisplayMetricsPreset {
                 margin_horizontal: 0.3,
                 margin_vertical: 0.1,
                 colon_separation: 0.25,
+                segment_cap_style: SegmentCapStyle::Sharp,
             },
             DisplayMetricsPreset::Wide => DisplayMetrics {
                 segment_spacing: 0.02,
@@ -62,6 +192,7 @@ impl DisplayMetricsPreset {
                 margin_horizontal: 0.3,
                 margin_vertical: 0.1,
                 colon_separation: 0.25,
+                segment_cap_style: SegmentCapStyle::Sharp,
             },
             DisplayMetricsPreset::Calculator => DisplayMetrics {
                 segment_spacing: 0.01,
@@ -73,6 +204,7 @@ impl DisplayMetricsPreset {
                 margin_horizontal: 0.3,
                 margin_vertical: 0.1,
                 colon_separation: 0.25,
+                segment_cap_style: SegmentCapStyle::Sharp,
             },
         }
     }
@@ -1,14 +1,22 @@
+mod display_buffer;
+mod display_font;
 mod display_metrics;
 mod widget;
 
+mod fourteen_segment;
 mod nine_segment;
 mod seven_segment;
 mod sixteen_segment;
 
+use itertools::Itertools;
 use strum::{Display, EnumIter};
 
-pub use display_metrics::{DisplayMetrics, DisplayMetricsPreset};
-pub use widget::SegmentedDisplayWidget;
+pub use display_buffer::DisplayBuffer;
+pub use display_font::{DisplayFont, DisplayFontBuilder, DisplayFontError};
+pub use display_metrics::{DisplayMetrics, DisplayMetricsPreset, SegmentCapStyle};
+pub use widget::{
+    DigitTransition, FillDirection, MarqueeMode, Radix, SegmentedDisplayWidget, UnknownCharPolicy,
+};
 
 use egui::Pos2;
 
@@ -16,25 +24,89 @@ use egui::Pos2;
 
 pub type DisplayGlyph = u16;
 
-#[derive(Clone, Copy, Debug, Default)]
+/// A single digit of a segmented display.
+///
+/// New fields may be added in later releases, build digits with [`DisplayDigit::new`]
+/// and its builder methods instead of struct literals.
+#[non_exhaustive]
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
 pub struct DisplayDigit {
     pub glyph: DisplayGlyph,
     pub dot: bool,
     pub colon: bool,
     pub apostrophe: bool,
+
+    /// Blinking digits show all of their segments inactive during the off phase
+    /// of the blink period.
+    pub blink: bool,
+}
+
+impl DisplayDigit {
+    #[must_use]
+    pub const fn new(glyph: DisplayGlyph) -> Self {
+        Self {
+            glyph,
+            dot: false,
+            colon: false,
+            apostrophe: false,
+            blink: false,
+        }
+    }
+
+    #[must_use]
+    pub const fn dot(mut self, dot: bool) -> Self {
+        self.dot = dot;
+        self
+    }
+
+    #[must_use]
+    pub const fn colon(mut self, colon: bool) -> Self {
+        self.colon = colon;
+        self
+    }
+
+    #[must_use]
+    pub const fn apostrophe(mut self, apostrophe: bool) -> Self {
+        self.apostrophe = apostrophe;
+        self
+    }
+
+    #[must_use]
+    pub const fn blink(mut self, blink: bool) -> Self {
+        self.blink = blink;
+        self
+    }
 }
 
 // ----------------------------------------------------------------------------
 
+/// Kinds of segmented displays.
+///
+/// The bit order of the segments in a [`DisplayGlyph`] is listed for each kind, starting
+/// from the least significant bit. Segments of the outer frame go clockwise from the top,
+/// diagonals are named after the corner they point to.
 #[non_exhaustive]
-#[derive(Clone, Copy, Debug, Display, EnumIter, Eq, PartialEq)]
+#[derive(Clone, Copy, Debug, Display, EnumIter, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum DisplayKind {
+    /// Top, upper right, lower right, bottom, lower left, upper left, middle.
     #[strum(to_string = "7-segment")]
     SevenSegment,
 
+    /// Top, upper right, lower right, bottom, lower left, upper left, middle,
+    /// upper right diagonal, lower left diagonal.
     #[strum(to_string = "9-segment")]
     NineSegment,
 
+    /// Top, upper right, lower right, bottom, lower left, upper left,
+    /// upper left diagonal, upper middle, upper right diagonal, middle right,
+    /// lower right diagonal, lower middle, lower left diagonal, middle left.
+    #[strum(to_string = "14-segment")]
+    FourteenSegment,
+
+    /// Top left, top right, upper right, lower right, bottom right, bottom left,
+    /// lower left, upper left, upper left diagonal, upper middle, upper right diagonal,
+    /// middle right, lower right diagonal, lower middle, lower left diagonal, middle left.
     #[strum(to_string = "16-segment")]
     SixteenSegment,
 }
@@ -45,6 +117,7 @@ impl DisplayKind {
         match *self {
             DisplayKind::SevenSegment => Box::new(seven_segment::SevenSegment),
             DisplayKind::NineSegment => Box::new(nine_segment::NineSegment),
+            DisplayKind::FourteenSegment => Box::new(fourteen_segment::FourteenSegment),
             DisplayKind::SixteenSegment => Box::new(sixteen_segment::SixteenSegment),
         }
     }
@@ -60,7 +133,23 @@ impl DisplayKind {
 pub(crate) trait DisplayImpl {
     fn segment_count(&self) -> usize;
 
-    fn glyph(&self, c: char) -> Option<DisplayGlyph>;
+    /// Built-in glyphs of the display kind, sorted by character.
+    fn glyph_data(&self) -> &'static [(char, DisplayGlyph)];
+
+    fn glyph(&self, c: char) -> Option<DisplayGlyph> {
+        let glyph_data = self.glyph_data();
+
+        debug_assert!(glyph_data
+            .iter()
+            .map(|(k, _)| k)
+            .tuple_windows()
+            .all(|(k1, k2)| k1 < k2)); // is_sorted()
+
+        glyph_data
+            .binary_search_by_key(&c, |(k, _)| *k)
+            .ok()
+            .map(|index| glyph_data[index].1)
+    }
 
     fn geometry(
         &self,
@@ -1,5 +1,4 @@
 use egui::{pos2, Pos2};
-use itertools::Itertools;
 
 use crate::displays::segmented_display::{DisplayGlyph, DisplayImpl};
 
@@ -13,7 +12,7 @@ impl DisplayImpl for NineSegment {
         9
     }
 
-    fn glyph(&self, c: char) -> Option<DisplayGlyph> {
+    fn glyph_data(&self) -> &'static [(char, DisplayGlyph)] {
         #[rustfmt::skip]
         const GLYPH_DATA: &[(char, DisplayGlyph)] = &[
             // Basic Latin
@@ -43,16 +42,7 @@ impl DisplayImpl for NineSegment {
             ('|',  0x0030), ('}',  0x0070), ('~',  0x0040),
         ];
 
-        debug_assert!(GLYPH_DATA
-            .iter()
-            .map(|(k, _)| k)
-            .tuple_windows()
-            .all(|(k1, k2)| k1 < k2)); // is_sorted()
-
         GLYPH_DATA
-            .binary_search_by_key(&c, |(k, _)| *k)
-            .ok()
-            .map(|index| GLYPH_DATA[index].1)
     }
 
     #[rustfmt::skip]
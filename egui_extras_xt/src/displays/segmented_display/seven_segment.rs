@@ -1,5 +1,4 @@
 use egui::{pos2, Pos2};
-use itertools::Itertools;
 
 use crate::displays::segmented_display::{DisplayGlyph, DisplayImpl};
 
@@ -13,7 +12,7 @@ impl DisplayImpl for SevenSegment {
         7
     }
 
-    fn glyph(&self, c: char) -> Option<DisplayGlyph> {
+    fn glyph_data(&self) -> &'static [(char, DisplayGlyph)] {
         #[rustfmt::skip]
         const GLYPH_DATA: &[(char, DisplayGlyph)] = &[
             // Basic Latin
@@ -41,18 +40,13 @@ impl DisplayImpl for SevenSegment {
             ('t',  0x0078), ('u',  0x001C), ('v',  0x001C), ('w',  0x007E),
             ('x',  0x0076), ('y',  0x006E), ('z',  0x005B), ('{',  0x0046),
             ('|',  0x0030), ('}',  0x0070), ('~',  0x0040),
+            // Latin-1 Supplement
+            ('°',  0x0063),
+            // Mathematical Operators
+            ('≡',  0x0049),
         ];
 
-        debug_assert!(GLYPH_DATA
-            .iter()
-            .map(|(k, _)| k)
-            .tuple_windows()
-            .all(|(k1, k2)| k1 < k2)); // is_sorted()
-
         GLYPH_DATA
-            .binary_search_by_key(&c, |(k, _)| *k)
-            .ok()
-            .map(|index| GLYPH_DATA[index].1)
     }
 
     #[rustfmt::skip]
@@ -1,34 +1,308 @@
-use egui::{pos2, vec2, Pos2, Response, Sense, Shape, Stroke, Ui, Widget};
+use std::cell::{Cell, OnceCell};
+use std::ops::{Range, RangeInclusive};
+use std::sync::Arc;
+
+use egui::epaint::{CircleShape, Tessellator, Vertex};
+use egui::{
+    lerp, pos2, vec2, Align, Color32, CursorIcon, Id, Mesh, Pos2, Rect, Response, Sense, Shape,
+    Stroke, Ui, Vec2, Widget,
+};
 use itertools::Itertools;
+use strum::{Display, EnumIter};
 
 use crate::displays::segmented_display::{
-    DisplayDigit, DisplayKind, DisplayMetrics, DisplayMetricsPreset,
+    DisplayBuffer, DisplayDigit, DisplayFont, DisplayGlyph, DisplayKind, DisplayMetrics,
+    DisplayMetricsPreset, SegmentCapStyle,
 };
-use crate::displays::{DisplayStyle, DisplayStylePreset};
+use crate::displays::{DisplayGlow, DisplayStyle, DisplayStylePreset};
+
+// ----------------------------------------------------------------------------
+
+/// Scrolling behavior of strings longer than the digit count of the display.
+#[non_exhaustive]
+#[derive(Clone, Copy, Debug, Display, PartialEq)]
+pub enum MarqueeMode {
+    #[strum(to_string = "Off")]
+    Off,
+
+    /// Scrolls the digits to the left, continuing with the start of the string after
+    /// `gap` blank digits.
+    #[strum(to_string = "Loop")]
+    Loop {
+        speed_chars_per_sec: f32,
+        gap: usize,
+    },
+
+    /// Scrolls the digits back and forth between the ends of the string.
+    #[strum(to_string = "Bounce")]
+    Bounce { speed_chars_per_sec: f32 },
+}
+
+impl MarqueeMode {
+    fn speed_chars_per_sec(&self) -> Option<f32> {
+        match *self {
+            MarqueeMode::Off => None,
+            MarqueeMode::Loop {
+                speed_chars_per_sec,
+                ..
+            }
+            | MarqueeMode::Bounce {
+                speed_chars_per_sec,
+            } => Some(speed_chars_per_sec).filter(|&speed| speed > 0.0),
+        }
+    }
+}
+
+/// Numeral system of the integers shown by [`SegmentedDisplayWidget::from_value`].
+#[non_exhaustive]
+#[derive(Clone, Copy, Debug, Display, EnumIter, Eq, PartialEq)]
+pub enum Radix {
+    #[strum(to_string = "Binary")]
+    Binary,
+
+    #[strum(to_string = "Octal")]
+    Octal,
+
+    #[strum(to_string = "Decimal")]
+    Decimal,
+
+    #[strum(to_string = "Hexadecimal")]
+    Hexadecimal,
+}
+
+impl Radix {
+    fn format(&self, value: u64) -> String {
+        match *self {
+            Radix::Binary => format!("{value:b}"),
+            Radix::Octal => format!("{value:o}"),
+            Radix::Decimal => format!("{value}"),
+            Radix::Hexadecimal => format!("{value:X}"),
+        }
+    }
+}
+
+/// Side of the display the digits accumulate from.
+#[non_exhaustive]
+#[derive(Clone, Copy, Debug, Display, EnumIter, PartialEq)]
+pub enum FillDirection {
+    /// Digits are laid out according to the alignment, long strings are handled
+    /// by the overflow pattern or the marquee.
+    #[strum(to_string = "Left to right")]
+    LeftToRight,
+
+    /// Digits accumulate from the right edge like on calculators, older digits shift
+    /// to the left and fall off once the [`digit_count`] of the display is exceeded.
+    ///
+    /// [`digit_count`]: SegmentedDisplayWidget::digit_count
+    #[strum(to_string = "Right to left")]
+    RightToLeft,
+}
+
+/// Animation of the digits changing their value.
+#[non_exhaustive]
+#[derive(Clone, Copy, Debug, Display, PartialEq)]
+pub enum DigitTransition {
+    /// Changed digits switch to their new value instantly.
+    #[strum(to_string = "None")]
+    None,
+
+    /// Changed digits cross-fade from the old value to the new one.
+    #[strum(to_string = "Fade")]
+    Fade { duration: f32 },
+
+    /// Changed digits roll upwards like the wheels of an odometer, the old value leaving
+    /// the digit at the top while the new one enters from the bottom.
+    #[strum(to_string = "Roll")]
+    Roll { duration: f32 },
+}
+
+impl DigitTransition {
+    fn duration(&self) -> Option<f32> {
+        match *self {
+            DigitTransition::None => None,
+            DigitTransition::Fade { duration } | DigitTransition::Roll { duration } => {
+                Some(duration).filter(|&duration| duration > 0.0)
+            }
+        }
+    }
+}
+
+/// Handling of the characters without a glyph in the font of the display.
+#[non_exhaustive]
+#[derive(Clone, Copy, Debug, Display, PartialEq)]
+pub enum UnknownCharPolicy {
+    /// Leaves the character out of the display.
+    #[strum(to_string = "Skip")]
+    Skip,
+
+    /// Shows a digit with all segments off.
+    #[strum(to_string = "Blank")]
+    Blank,
+
+    /// Shows the glyph of the given character instead, or a blank digit when the
+    /// replacement is unknown as well.
+    #[strum(to_string = "Replace")]
+    Replace(char),
+
+    /// Shows a digit with all segments on.
+    #[strum(to_string = "All segments")]
+    AllSegments,
+}
+
+/// Uppercase form of the character, characters turning into multiple ones (like `ß`)
+/// are kept as they are.
+fn fold_to_uppercase(c: char) -> char {
+    let mut uppercase = c.to_uppercase();
+    match (uppercase.next(), uppercase.next()) {
+        (Some(upper), None) => upper,
+        _ => c,
+    }
+}
+
+#[derive(Clone, Copy)]
+struct MarqueeState {
+    digits_hash: u64,
+    position: f64,
+    last_time: f64,
+}
+
+const DEFAULT_DIGIT_HEIGHT: f32 = 80.0;
+
+/// Overflow pattern of negative numbers leaving no room for their sign, when the
+/// display has no overflow pattern of its own.
+const DEFAULT_OVERFLOW_PATTERN: char = '-';
+
+// Lit elements of a digit, the segments are followed by the dot, the colon and the apostrophe
+const DOT_ELEMENT: usize = DisplayGlyph::BITS as usize;
+const COLON_ELEMENT: usize = DOT_ELEMENT + 1;
+const APOSTROPHE_ELEMENT: usize = DOT_ELEMENT + 2;
+const ELEMENT_COUNT: usize = DOT_ELEMENT + 3;
+
+/// Brightness of the elements of a digit, from `0` for inactive to `u8::MAX` for active.
+type ElementLevels = [u8; ELEMENT_COUNT];
+
+fn element_levels(digit: &DisplayDigit) -> ElementLevels {
+    let mut levels = [0; ELEMENT_COUNT];
+    for (segment_index, level) in levels[..DOT_ELEMENT].iter_mut().enumerate() {
+        if ((digit.glyph >> segment_index) & 0x01) != 0x00 {
+            *level = u8::MAX;
+        }
+    }
+    for (element, active) in [
+        (DOT_ELEMENT, digit.dot),
+        (COLON_ELEMENT, digit.colon),
+        (APOSTROPHE_ELEMENT, digit.apostrophe),
+    ] {
+        if active {
+            levels[element] = u8::MAX;
+        }
+    }
+    levels
+}
+
+/// Time each element of each digit was last seen active, for the persistence effect.
+#[derive(Clone, Default)]
+struct PersistenceState {
+    last_active: Vec<[f64; ELEMENT_COUNT]>,
+}
+
+/// Digits of the previous frames, for the digit transitions.
+#[derive(Clone, Default)]
+struct TransitionState {
+    digits: Vec<DisplayDigit>,
+    previous_digits: Vec<DisplayDigit>,
+    start_times: Vec<f64>,
+}
+
+/// Painted segments of a display that doesn't change over time by itself, reused as long
+/// as its digits and settings stay the same. The mesh is already moved to the position
+/// of the widget.
+#[derive(Clone)]
+struct StaticFrame {
+    digits: Arc<Vec<DisplayDigit>>,
+    settings_hash: u64,
+    mesh: Arc<Mesh>,
+}
+
+thread_local! {
+    /// Number of times a segmented display had to tessellate its segments on this
+    /// thread, see [`SegmentedDisplayWidget::geometry_rebuild_count`].
+    static GEOMETRY_REBUILD_COUNT: Cell<usize> = const { Cell::new(0) };
+}
+
+// ----------------------------------------------------------------------------
 
 #[must_use = "You should put this widget in an ui with `ui.add(widget);`"]
-pub struct SegmentedDisplayWidget {
+pub struct SegmentedDisplayWidget<'a> {
     display_kind: DisplayKind,
-    digits: Vec<DisplayDigit>,
-    digit_height: f32,
+    digits: Arc<Vec<DisplayDigit>>,
+    negative: bool,
+    digit_height: Option<f32>,
+    auto_size: bool,
+    auto_size_range: RangeInclusive<f32>,
     metrics: DisplayMetrics,
     style: DisplayStyle,
+    glow: Option<DisplayGlow>,
     show_dots: bool,
     show_colons: bool,
     show_apostrophes: bool,
+    blinking: bool,
+    blink_colons: bool,
+    blink_period: f32,
+    blink_duty_cycle: f32,
+    brightness: f32,
+    dim_background: bool,
+    persistence: f32,
+    transition: DigitTransition,
+    digit_count: Option<usize>,
+    marquee: MarqueeMode,
+    marquee_pause_on_hover: bool,
+    align: Align,
+    fill_direction: FillDirection,
+    pad_with: char,
+    digit_styles: Vec<(usize, DisplayStyle)>,
+    overflow_pattern: Option<char>,
+    font: Option<DisplayFont>,
+    unknown_char: UnknownCharPolicy,
+    uppercase_input: bool,
+    editable_glyphs: Option<&'a mut Vec<DisplayGlyph>>,
 }
 
-impl SegmentedDisplayWidget {
+impl<'a> SegmentedDisplayWidget<'a> {
     pub fn new(display_kind: DisplayKind) -> Self {
         Self {
             display_kind,
-            digits: Vec::new(),
-            digit_height: 80.0,
+            digits: Arc::default(),
+            negative: false,
+            digit_height: None,
+            auto_size: false,
+            auto_size_range: 0.0..=f32::INFINITY,
             metrics: DisplayMetrics::default(),
             style: DisplayStylePreset::Default.style(),
+            glow: None,
             show_dots: true,
             show_colons: true,
             show_apostrophes: true,
+            blinking: false,
+            blink_colons: false,
+            blink_period: 1.0,
+            blink_duty_cycle: 0.5,
+            brightness: 1.0,
+            dim_background: false,
+            persistence: 0.0,
+            transition: DigitTransition::None,
+            digit_count: None,
+            marquee: MarqueeMode::Off,
+            marquee_pause_on_hover: true,
+            align: Align::Min,
+            fill_direction: FillDirection::LeftToRight,
+            pad_with: ' ',
+            digit_styles: Vec::new(),
+            overflow_pattern: None,
+            font: None,
+            unknown_char: UnknownCharPolicy::Skip,
+            uppercase_input: false,
+            editable_glyphs: None,
         }
     }
 
@@ -40,42 +314,295 @@ impl SegmentedDisplayWidget {
         Self::new(DisplayKind::NineSegment).push_string(value.as_ref())
     }
 
+    pub fn fourteen_segment<T: AsRef<str>>(value: T) -> Self {
+        Self::new(DisplayKind::FourteenSegment).push_string(value.as_ref())
+    }
+
     pub fn sixteen_segment<T: AsRef<str>>(value: T) -> Self {
         Self::new(DisplayKind::SixteenSegment).push_string(value.as_ref())
     }
 
+    /// Seven-segment display showing the value rounded to the given number of decimals.
+    ///
+    /// The number is right-aligned, and shows dashes instead when it doesn't fit the
+    /// [`digit_count`](Self::digit_count) of the display. See [`negative`](Self::negative)
+    /// for the placement of the minus sign.
+    pub fn from_float(value: f64, decimals: usize) -> Self {
+        let value_str = format!("{:.decimals$}", value.abs());
+
+        // Values rounding to zero are shown without a sign
+        let negative =
+            value.is_sign_negative() && value_str.chars().any(|c| matches!(c, '1'..='9'));
+
+        Self::new(DisplayKind::SevenSegment)
+            .align(Align::Max)
+            .overflow_pattern(Some('-'))
+            .negative(negative)
+            .push_string(value_str)
+    }
+
+    /// Seven-segment display showing the integer value.
+    ///
+    /// The number is right-aligned, and shows dashes instead when it doesn't fit the
+    /// [`digit_count`](Self::digit_count) of the display. See [`negative`](Self::negative)
+    /// for the placement of the minus sign.
+    pub fn from_int(value: i64) -> Self {
+        Self::new(DisplayKind::SevenSegment)
+            .align(Align::Max)
+            .overflow_pattern(Some('-'))
+            .negative(value < 0)
+            .push_string(value.unsigned_abs().to_string())
+    }
+
+    /// Seven-segment display showing the unsigned value in the given radix on
+    /// `digit_count` digits, like the register views of debuggers.
+    ///
+    /// The number is right-aligned and padded with leading zeros, use
+    /// [`pad_with`](Self::pad_with) to pad with blanks instead. Shows dashes when the
+    /// value doesn't fit the display.
+    pub fn from_value(value: u64, radix: Radix, digit_count: usize) -> Self {
+        Self::new(DisplayKind::SevenSegment)
+            .digit_count(Some(digit_count))
+            .align(Align::Max)
+            .pad_with('0')
+            .overflow_pattern(Some('-'))
+            .push_string(radix.format(value))
+    }
+
+    /// Appends the characters of the string as digits.
+    ///
+    /// Decimal points (`.` and `,`) light the dot of the preceding digit, colons and
+    /// apostrophes light the separator in front of the following digit, as long as
+    /// the corresponding `show_dots`, `show_colons` and `show_apostrophes` flags are
+    /// enabled. Disable them before pushing strings to use the glyphs of these
+    /// characters instead.
     pub fn push_string<T: AsRef<str>>(mut self, value: T) -> Self {
-        let display_impl = self.display_kind.display_impl();
+        let mut colon = false;
+        let mut apostrophe = false;
 
-        self.digits.extend(
-            [None]
-                .into_iter()
-                .chain(value.as_ref().chars().map(Some))
-                .chain([None])
-                .tuple_windows()
-                .filter_map(|(prev, curr, next)| match curr {
-                    Some('.') if self.show_dots => None,
-                    Some(':') if self.show_colons => None,
-                    Some('\'') if self.show_apostrophes => None,
-                    Some(c) if display_impl.glyph(c).is_some() => Some(DisplayDigit {
-                        glyph: display_impl.glyph(c).unwrap(),
-                        dot: (next == Some('.')) && self.show_dots,
-                        colon: (prev == Some(':')) && self.show_colons,
-                        apostrophe: (prev == Some('\'')) && self.show_apostrophes,
+        for c in value.as_ref().chars() {
+            match c {
+                '.' | ',' if self.show_dots => match Arc::make_mut(&mut self.digits).last_mut() {
+                    Some(digit) if !digit.dot && !colon && !apostrophe => {
+                        digit.dot = true;
+                    }
+                    // Dots without a digit of their own get a blank one
+                    _ => Arc::make_mut(&mut self.digits).push(DisplayDigit {
+                        dot: true,
+                        colon: std::mem::take(&mut colon),
+                        apostrophe: std::mem::take(&mut apostrophe),
+                        ..DisplayDigit::default()
                     }),
-                    _ => None,
-                }),
-        );
+                },
+                ':' if self.show_colons => colon = true,
+                '\'' if self.show_apostrophes => apostrophe = true,
+                c => {
+                    let c = if self.uppercase_input {
+                        fold_to_uppercase(c)
+                    } else {
+                        c
+                    };
+
+                    let glyph = self.glyph(c).or(match self.unknown_char {
+                        UnknownCharPolicy::Skip => None,
+                        UnknownCharPolicy::Blank => Some(0),
+                        UnknownCharPolicy::Replace(replacement) => {
+                            Some(self.glyph(replacement).unwrap_or_default())
+                        }
+                        UnknownCharPolicy::AllSegments => Some(self.segment_mask()),
+                    });
+
+                    if let Some(glyph) = glyph {
+                        Arc::make_mut(&mut self.digits).push(DisplayDigit {
+                            glyph,
+                            dot: false,
+                            colon: std::mem::take(&mut colon),
+                            apostrophe: std::mem::take(&mut apostrophe),
+                            blink: false,
+                        });
+                    }
+                }
+            }
+        }
+
+        self
+    }
+
+    /// Shows a minus sign in front of the pushed digits, which are treated as the
+    /// magnitude of a number.
+    ///
+    /// The sign takes the leftmost digit of the display, and the digits are aligned and
+    /// padded within the remaining digits. When the digits leave no room for the sign,
+    /// the display shows the [`overflow_pattern`](Self::overflow_pattern) instead, or
+    /// dashes when there is no overflow pattern, as cutting off either the sign or the
+    /// digits would show a wrong number. Displays without a
+    /// [`digit_count`](Self::digit_count) get an extra digit for the sign.
+    pub fn negative(mut self, negative: bool) -> Self {
+        self.negative = negative;
+        self
+    }
+
+    /// Shows the bound glyphs instead of the pushed digits, one digit per glyph, and
+    /// toggles their segments when clicked. Changes are reported by [`Response::changed`].
+    ///
+    /// Editable displays ignore the digit count, marquee, blinking and overflow settings.
+    pub fn editable(mut self, glyphs: &'a mut Vec<DisplayGlyph>) -> Self {
+        self.editable_glyphs = Some(glyphs);
+        self
+    }
+
+    /// Handling of the characters without glyphs in the strings pushed after this call,
+    /// [`UnknownCharPolicy::Skip`] by default.
+    ///
+    /// Use [`DisplayFont::supports`] to check the strings beforehand.
+    pub fn unknown_char(mut self, unknown_char: UnknownCharPolicy) -> Self {
+        self.unknown_char = unknown_char;
+        self
+    }
+
+    /// Converts the strings pushed after this call to uppercase before looking up their
+    /// glyphs, for fonts without lowercase glyphs or for displays meant to show capitals
+    /// only. Disabled by default.
+    pub fn uppercase_input(mut self, uppercase_input: bool) -> Self {
+        self.uppercase_input = uppercase_input;
+        self
+    }
+
+    /// Uses a custom font for the strings pushed after this call.
+    ///
+    /// The display kind of the widget is switched to the display kind of the font.
+    pub fn font(mut self, font: DisplayFont) -> Self {
+        self.display_kind = font.display_kind();
+        self.font = Some(font);
         self
     }
 
+    /// Total number of times the segment meshes of segmented displays were rebuilt
+    /// on the current thread.
+    ///
+    /// The meshes are cached between frames and only get rebuilt when the displayed
+    /// digits, the style, the metrics or the size of a display change. Useful for
+    /// profiling, a static display shouldn't increase this counter.
+    #[must_use]
+    pub fn geometry_rebuild_count() -> usize {
+        GEOMETRY_REBUILD_COUNT.get()
+    }
+
+    /// Size the display will allocate when added to `ui`, useful for reserving space
+    /// or aligning the display before adding it.
+    #[must_use]
+    pub fn measure(&self, ui: &Ui) -> Vec2 {
+        let digit_count = match self.editable_glyphs {
+            Some(ref glyphs) => glyphs.len(),
+            None => self.digit_count.unwrap_or(self.content_len()),
+        };
+
+        self.metrics
+            .display_size(self.resolve_digit_height(ui, digit_count), digit_count)
+    }
+
+    /// Digit height from the explicit height, or from the available width of `ui` when
+    /// [`auto_size`](Self::auto_size) is enabled.
+    fn resolve_digit_height(&self, ui: &Ui, digit_count: usize) -> f32 {
+        match self.digit_height {
+            Some(digit_height) => digit_height,
+            None if self.auto_size => {
+                Some(ui.available_width() / self.metrics.display_size(1.0, digit_count).x)
+                    .filter(|digit_height| digit_height.is_finite())
+                    .map_or(DEFAULT_DIGIT_HEIGHT, |digit_height| {
+                        digit_height
+                            .min(*self.auto_size_range.end())
+                            .max(*self.auto_size_range.start())
+                    })
+            }
+            None => DEFAULT_DIGIT_HEIGHT,
+        }
+    }
+
+    /// Number of digits needed to show the pushed digits, including the sign.
+    fn content_len(&self) -> usize {
+        self.digits.len() + usize::from(self.negative)
+    }
+
+    fn glyph(&self, c: char) -> Option<DisplayGlyph> {
+        match self.font {
+            Some(ref font) => font.glyph(c),
+            None => self.display_kind.display_impl().glyph(c),
+        }
+    }
+
     pub fn push_digit(mut self, digit: DisplayDigit) -> Self {
-        self.digits.push(digit);
+        let glyph = self.truncate_glyph(digit.glyph);
+        Arc::make_mut(&mut self.digits).push(DisplayDigit { glyph, ..digit });
         self
     }
 
+    /// Appends the digits of a prepared buffer. Pushing a buffer to a widget without
+    /// digits shares the digits of the buffer instead of copying them.
+    pub fn push_buffer(mut self, buffer: &DisplayBuffer) -> Self {
+        if self.digits.is_empty() {
+            self.digits = Arc::clone(&buffer.digits);
+        } else {
+            Arc::make_mut(&mut self.digits).extend_from_slice(&buffer.digits);
+        }
+        self
+    }
+
+    /// Takes the pushed digits for showing them by later widgets with
+    /// [`push_buffer`](Self::push_buffer). The sign and the display settings are not
+    /// part of the buffer.
+    #[must_use]
+    pub fn into_buffer(self) -> DisplayBuffer {
+        DisplayBuffer {
+            digits: self.digits,
+        }
+    }
+
+    /// Pushes a digit with the given raw segment states, see [`DisplayKind`]
+    /// for the bit order of the segments.
+    pub fn push_glyph(self, glyph: DisplayGlyph) -> Self {
+        self.push_digit(DisplayDigit::new(glyph))
+    }
+
+    pub fn push_glyphs(self, glyphs: &[DisplayGlyph]) -> Self {
+        glyphs
+            .iter()
+            .fold(self, |display, &glyph| display.push_glyph(glyph))
+    }
+
+    /// Glyph with all segments of the display kind lit.
+    fn segment_mask(&self) -> DisplayGlyph {
+        ((1_u32 << self.display_kind.segment_count()) - 1) as DisplayGlyph
+    }
+
+    fn truncate_glyph(&self, glyph: DisplayGlyph) -> DisplayGlyph {
+        let segment_mask = self.segment_mask();
+
+        debug_assert!(
+            glyph & !segment_mask == 0,
+            "glyph has more segments than the display kind"
+        );
+
+        glyph & segment_mask
+    }
+
+    /// Height of the digits in points, 80 by default. Takes precedence over
+    /// [`auto_size`](Self::auto_size).
     pub fn digit_height(mut self, digit_height: impl Into<f32>) -> Self {
-        self.digit_height = digit_height.into();
+        self.digit_height = Some(digit_height.into());
+        self
+    }
+
+    /// Picks the largest digit height which fits the display into the available width
+    /// of the ui.
+    pub fn auto_size(mut self, auto_size: bool) -> Self {
+        self.auto_size = auto_size;
+        self
+    }
+
+    /// Limits the digit heights picked by [`auto_size`](Self::auto_size).
+    pub fn auto_size_range(mut self, auto_size_range: RangeInclusive<f32>) -> Self {
+        self.auto_size_range = auto_size_range;
         self
     }
 
@@ -84,8 +611,26 @@ impl SegmentedDisplayWidget {
         self
     }
 
+    /// Sets the style and the glow of the preset.
     pub fn style_preset(mut self, preset: DisplayStylePreset) -> Self {
         self.style = preset.style();
+        self.glow = preset.glow();
+        self
+    }
+
+    /// Paints a halo around the active segments, scaling with the segment thickness.
+    pub fn glow(mut self, glow: Option<DisplayGlow>) -> Self {
+        self.glow = glow;
+        self
+    }
+
+    /// Overrides the style of the digit at the given position of the display, including
+    /// its dot, colon and apostrophe. The background color of the override is not used.
+    ///
+    /// Positions are counted from the left edge of the display, after the digits got
+    /// aligned or scrolled.
+    pub fn digit_style(mut self, index: usize, style: DisplayStyle) -> Self {
+        self.digit_styles.push((index, style));
         self
     }
 
@@ -99,6 +644,44 @@ impl SegmentedDisplayWidget {
         self
     }
 
+    /// Overrides [`DisplayMetrics::segment_spacing`] of the current metrics. Call it after
+    /// [`metrics`](Self::metrics) or [`metrics_preset`](Self::metrics_preset).
+    pub fn segment_spacing(mut self, segment_spacing: f32) -> Self {
+        self.metrics.segment_spacing = segment_spacing;
+        self
+    }
+
+    /// Overrides [`DisplayMetrics::segment_thickness`] of the current metrics.
+    pub fn segment_thickness(mut self, segment_thickness: f32) -> Self {
+        self.metrics.segment_thickness = segment_thickness;
+        self
+    }
+
+    /// Overrides [`DisplayMetrics::digit_ratio`] of the current metrics.
+    pub fn digit_ratio(mut self, digit_ratio: f32) -> Self {
+        self.metrics.digit_ratio = digit_ratio;
+        self
+    }
+
+    /// Overrides [`DisplayMetrics::digit_spacing`] of the current metrics.
+    pub fn digit_spacing(mut self, digit_spacing: f32) -> Self {
+        self.metrics.digit_spacing = digit_spacing;
+        self
+    }
+
+    /// Overrides [`DisplayMetrics::digit_shearing`] of the current metrics, zero for
+    /// upright digits.
+    pub fn slant(mut self, slant: f32) -> Self {
+        self.metrics.digit_shearing = slant;
+        self
+    }
+
+    /// Overrides [`DisplayMetrics::segment_cap_style`] of the current metrics.
+    pub fn segment_cap_style(mut self, segment_cap_style: SegmentCapStyle) -> Self {
+        self.metrics.segment_cap_style = segment_cap_style;
+        self
+    }
+
     pub fn show_dots(mut self, show_dots: bool) -> Self {
         self.show_dots = show_dots;
         self
@@ -113,136 +696,1670 @@ impl SegmentedDisplayWidget {
         self.show_apostrophes = show_apostrophes;
         self
     }
-}
 
-impl Widget for SegmentedDisplayWidget {
-    fn ui(self, ui: &mut Ui) -> Response {
-        let display_impl = self.display_kind.display_impl();
+    /// Blinks the whole display, regardless of the `blink` flags of the digits.
+    pub fn blinking(mut self, blinking: bool) -> Self {
+        self.blinking = blinking;
+        self
+    }
 
-        let digit_height = self.digit_height;
-        let digit_width = digit_height * self.metrics.digit_ratio;
+    /// Blinks only the colons while the digits stay lit, like the separators of clocks.
+    pub fn blink_colons(mut self, blink_colons: bool) -> Self {
+        self.blink_colons = blink_colons;
+        self
+    }
 
-        // Turn relative metrics to absolute metrics
-        let segment_thickness = self.metrics.segment_thickness * digit_height;
-        let segment_spacing = self.metrics.segment_spacing * digit_height;
-        let digit_shearing = self.metrics.digit_shearing * digit_width;
-        let digit_spacing = self.metrics.digit_spacing * digit_width;
-        let margin_horizontal = self.metrics.margin_horizontal * digit_width;
-        let margin_vertical = self.metrics.margin_vertical * digit_height;
-        let digit_median = self.metrics.digit_median * (digit_height / 2.0);
-        let colon_separation = self.metrics.colon_separation * (digit_height / 2.0);
+    /// Sets the `blink` flag of the already pushed digits in the given range.
+    pub fn blink_digits(mut self, range: Range<usize>) -> Self {
+        let range = range.start.min(self.digits.len())..range.end.min(self.digits.len());
 
-        let desired_size = vec2(
-            (digit_width * self.digits.len() as f32)
-                + (digit_spacing * (self.digits.len().saturating_sub(1)) as f32)
-                + (2.0 * margin_horizontal)
-                + (2.0 * digit_shearing.abs()),
-            digit_height + (2.0 * margin_vertical),
-        );
+        for digit in &mut Arc::make_mut(&mut self.digits)[range] {
+            digit.blink = true;
+        }
+        self
+    }
 
-        let (rect, response) = ui.allocate_exact_size(desired_size, Sense::click());
+    /// Length of a full on-off blink cycle in seconds.
+    ///
+    /// The blink phase is derived from the time of the egui context, so all displays
+    /// with the same period blink in sync.
+    pub fn blink_period(mut self, blink_period: f32) -> Self {
+        self.blink_period = blink_period;
+        self
+    }
 
-        let mut child_ui = ui.child_ui(rect, *ui.layout(), None);
-        child_ui.set_clip_rect(child_ui.clip_rect().intersect(rect));
+    /// Fraction of the blink period the blinking digits spend in the on phase.
+    pub fn blink_duty_cycle(mut self, blink_duty_cycle: f32) -> Self {
+        self.blink_duty_cycle = blink_duty_cycle;
+        self
+    }
 
-        if child_ui.is_rect_visible(rect) {
-            // Draw the widget background without clipping
-            ui.painter().rect(
-                rect,
-                ui.style().visuals.noninteractive().rounding,
-                self.style.background_color,
-                Stroke::NONE,
-            );
+    /// Dims the active segments towards the inactive color of the style, including the
+    /// per-digit style overrides. Clamped to the `0.0..=1.0` range.
+    pub fn brightness(mut self, brightness: f32) -> Self {
+        self.brightness = brightness;
+        self
+    }
 
-            let segment_geometry = display_impl.geometry(
-                digit_width,
-                digit_height,
-                segment_thickness,
-                segment_spacing,
-                digit_median,
-            );
-            assert_eq!(segment_geometry.len(), display_impl.segment_count());
+    /// Dims the background color towards black along with the segments.
+    pub fn dim_background(mut self, dim_background: bool) -> Self {
+        self.dim_background = dim_background;
+        self
+    }
 
-            #[rustfmt::skip]
-            let apostrophe_points: Vec<Pos2> = vec![
-                pos2(-(digit_width / 2.0) - (digit_spacing / 2.0) - (segment_thickness / 2.0), -(digit_height / 2.0)                            ),
-                pos2(-(digit_width / 2.0) - (digit_spacing / 2.0) + (segment_thickness / 2.0), -(digit_height / 2.0)                            ),
-                pos2(-(digit_width / 2.0) - (digit_spacing / 2.0) - (segment_thickness / 2.0), -(digit_height / 2.0) + (segment_thickness * 2.0)),
-            ];
+    /// Time in seconds it takes for segments to fade out after turning off, imitating
+    /// the afterglow of phosphors. Zero switches the segments off instantly.
+    pub fn persistence(mut self, persistence: f32) -> Self {
+        self.persistence = persistence;
+        self
+    }
 
-            #[rustfmt::skip]
-            let (colon_top_pos, colon_bottom_pos, dot_pos) = (
-                pos2(-(digit_width / 2.0) - (digit_spacing / 2.0), digit_median - colon_separation),
-                pos2(-(digit_width / 2.0) - (digit_spacing / 2.0), digit_median + colon_separation),
-                pos2( (digit_width / 2.0) + (digit_spacing / 2.0), (digit_height / 2.0) - (segment_thickness / 2.0))
-            );
+    /// Animates the digits changing their value, [`DigitTransition::None`] by default.
+    ///
+    /// Only the changed digits are animated. Digits changing again during their transition
+    /// restart it from their previous value. Blinking digits still blink while animated,
+    /// the steps of a scrolling [`marquee`](Self::marquee) are not animated.
+    pub fn transition(mut self, transition: DigitTransition) -> Self {
+        self.transition = transition;
+        self
+    }
 
-            let paint_digit = |digit: &DisplayDigit, digit_center: Pos2| {
-                let transform = |&Pos2 { x, y }| {
-                    digit_center + vec2(x, y)
-                        - vec2((y / (digit_height / 2.0)) * digit_shearing, 0.0)
-                };
+    /// Fixed number of digits to display, regardless of the length of the pushed string.
+    /// Shorter strings are padded, longer ones are cut off according to the alignment.
+    pub fn digit_count(mut self, digit_count: Option<usize>) -> Self {
+        self.digit_count = digit_count;
+        self
+    }
 
-                for (segment_index, segment_points) in segment_geometry.iter().enumerate() {
-                    let segment_active = ((digit.glyph >> segment_index) & 0x01) != 0x00;
+    /// Alignment of the digits within the [`digit_count`](Self::digit_count) of the display.
+    ///
+    /// Strings not fitting on the display keep their digits at the aligned side, so
+    /// right-aligned numbers keep their least significant digits.
+    pub fn align(mut self, align: Align) -> Self {
+        self.align = align;
+        self
+    }
 
-                    // TODO: concave_polygon
-                    // https://github.com/emilk/egui/issues/513
-                    child_ui.painter().add(Shape::convex_polygon(
-                        segment_points.iter().map(transform).collect_vec(),
-                        self.style.foreground_color(segment_active),
-                        self.style.foreground_stroke(segment_active),
-                    ));
-                }
+    /// Right to left filling overrides the alignment, the overflow pattern and the marquee.
+    /// Dots, colons and apostrophes stay attached to their digits as they shift.
+    pub fn fill_direction(mut self, fill_direction: FillDirection) -> Self {
+        self.fill_direction = fill_direction;
+        self
+    }
 
-                if self.show_dots {
-                    child_ui.painter().circle(
-                        transform(&dot_pos),
-                        segment_thickness / 2.0,
-                        self.style.foreground_color(digit.dot),
-                        self.style.foreground_stroke(digit.dot),
-                    );
-                }
+    /// Character filling the unused digits of the display, like `'0'` for leading zeros.
+    pub fn pad_with(mut self, pad_with: char) -> Self {
+        self.pad_with = pad_with;
+        self
+    }
 
-                if self.show_colons {
-                    child_ui.painter().circle(
-                        transform(&colon_top_pos),
-                        segment_thickness / 2.0,
-                        self.style.foreground_color(digit.colon),
-                        self.style.foreground_stroke(digit.colon),
-                    );
+    /// Character filling the whole display when the digits don't fit its
+    /// [`digit_count`](Self::digit_count), like `'E'` for an error display.
+    /// When unset, the digits are cut off according to the alignment.
+    pub fn overflow_pattern(mut self, overflow_pattern: Option<char>) -> Self {
+        self.overflow_pattern = overflow_pattern;
+        self
+    }
 
-                    child_ui.painter().circle(
-                        transform(&colon_bottom_pos),
-                        segment_thickness / 2.0,
-                        self.style.foreground_color(digit.colon),
-                        self.style.foreground_stroke(digit.colon),
-                    );
-                }
+    /// Scrolls strings longer than the [`digit_count`](Self::digit_count) of the display.
+    ///
+    /// The scroll position is kept in the memory of the ui and restarts from the beginning
+    /// whenever the displayed digits change.
+    pub fn marquee(mut self, marquee: MarqueeMode) -> Self {
+        self.marquee = marquee;
+        self
+    }
 
-                if self.show_apostrophes {
-                    child_ui.painter().add(Shape::convex_polygon(
-                        apostrophe_points.iter().map(transform).collect_vec(),
-                        self.style.foreground_color(digit.apostrophe),
-                        self.style.foreground_stroke(digit.apostrophe),
-                    ));
-                }
-            };
+    pub fn marquee_pause_on_hover(mut self, marquee_pause_on_hover: bool) -> Self {
+        self.marquee_pause_on_hover = marquee_pause_on_hover;
+        self
+    }
 
-            for (digit_index, digit) in self.digits.iter().enumerate() {
-                let digit_center = rect.left_center()
-                    + vec2(
-                        margin_horizontal
-                            + digit_shearing.abs()
-                            + ((digit_width + digit_spacing) * digit_index as f32)
-                            + (digit_width / 2.0),
-                        0.0,
-                    );
+    /// Hash of the settings the painted segments depend on besides the digits, or `None`
+    /// when the display changes over time by itself and has to be painted from scratch.
+    fn static_settings_hash(&self, rect: Rect, geometry_bits: &[u32]) -> Option<u64> {
+        let scrolling = self.marquee.speed_chars_per_sec().is_some()
+            && self
+                .digit_count
+                .is_some_and(|digit_count| self.content_len() > digit_count);
+
+        let animated = self.blinking
+            || self.blink_colons
+            || self.persistence > 0.0
+            || self.transition != DigitTransition::None
+            || scrolling
+            || self.digits.iter().any(|digit| digit.blink);
+
+        (!animated).then(|| {
+            egui::util::hash((
+                self.display_kind,
+                self.negative,
+                self.digit_count,
+                self.align,
+                std::mem::discriminant(&self.fill_direction),
+                // The font only matters for the glyphs the widget adds on its own
+                [
+                    self.glyph(self.pad_with),
+                    self.glyph(self.overflow_pattern.unwrap_or(DEFAULT_OVERFLOW_PATTERN)),
+                    self.glyph('-'),
+                ],
+                self.overflow_pattern.is_some(),
+                (
+                    &self.style,
+                    &self.digit_styles,
+                    self.glow_bits(),
+                    self.brightness.to_bits(),
+                ),
+                [self.show_dots, self.show_colons, self.show_apostrophes],
+                self.metrics.segment_cap_style,
+                geometry_bits,
+                [rect.min.x, rect.min.y].map(f32::to_bits),
+            ))
+        })
+    }
+
+    fn glow_bits(&self) -> Option<[u32; 2]> {
+        self.glow
+            .map(|glow| [glow.width, glow.opacity].map(f32::to_bits))
+    }
 
-                paint_digit(digit, digit_center);
+    /// Picks the digits visible in the `digit_count` window, advancing the marquee.
+    fn visible_digits(&self, ui: &Ui, id: Id, paused: bool) -> Vec<DisplayDigit> {
+        if !self.negative {
+            return self.window_digits(ui, id, paused, self.digit_count);
+        }
+
+        let sign_digit = DisplayDigit {
+            glyph: self.glyph('-').unwrap_or_default(),
+            ..DisplayDigit::default()
+        };
+
+        if let Some(digit_count) = self.digit_count {
+            // No digit left for the sign
+            if self.content_len() > digit_count {
+                let overflow_pattern = self.overflow_pattern.unwrap_or(DEFAULT_OVERFLOW_PATTERN);
+                let overflow_digit = DisplayDigit {
+                    glyph: self.glyph(overflow_pattern).unwrap_or_default(),
+                    ..DisplayDigit::default()
+                };
+
+                return vec![overflow_digit; digit_count];
             }
         }
 
-        response
+        std::iter::once(sign_digit)
+            .chain(self.window_digits(
+                ui,
+                id,
+                paused,
+                self.digit_count.map(|n| n.saturating_sub(1)),
+            ))
+            .take(self.digit_count.unwrap_or(usize::MAX))
+            .collect_vec()
+    }
+
+    /// Picks the pushed digits visible in a window of `digit_count` digits.
+    fn window_digits(
+        &self,
+        ui: &Ui,
+        id: Id,
+        paused: bool,
+        digit_count: Option<usize>,
+    ) -> Vec<DisplayDigit> {
+        let Some(digit_count) = digit_count else {
+            return self.digits.to_vec();
+        };
+
+        let right_to_left = self.fill_direction == FillDirection::RightToLeft;
+
+        if let Some(overflow_pattern) = self.overflow_pattern.filter(|_| !right_to_left) {
+            if self.digits.len() > digit_count {
+                let overflow_digit = DisplayDigit {
+                    glyph: self.glyph(overflow_pattern).unwrap_or_default(),
+                    ..DisplayDigit::default()
+                };
+
+                return vec![overflow_digit; digit_count];
+            }
+        }
+
+        let window_offset = match self.marquee.speed_chars_per_sec() {
+            Some(speed) if !right_to_left && self.digits.len() > digit_count => {
+                let time = ui.input(|input| input.time);
+                let digits_hash = egui::util::hash(&self.digits);
+
+                let mut state = ui
+                    .memory(|memory| memory.data.get_temp::<MarqueeState>(id))
+                    .filter(|state| state.digits_hash == digits_hash)
+                    .unwrap_or(MarqueeState {
+                        digits_hash,
+                        position: 0.0,
+                        last_time: time,
+                    });
+
+                if !paused {
+                    state.position += (time - state.last_time) * speed as f64;
+
+                    // Only wake up when the window moves by a whole digit
+                    let next_step = (state.position.floor() + 1.0 - state.position) / speed as f64;
+                    ui.ctx().request_repaint_after_secs(next_step as f32);
+                }
+                state.last_time = time;
+
+                ui.memory_mut(|memory| memory.data.insert_temp(id, state));
+
+                let step = state.position.floor() as usize;
+
+                match self.marquee {
+                    MarqueeMode::Loop { gap, .. } => Some(step % (self.digits.len() + gap)),
+                    MarqueeMode::Bounce { .. } => {
+                        let travel = self.digits.len() - digit_count;
+                        let step = step % (2 * travel);
+                        Some(if step <= travel {
+                            step
+                        } else {
+                            2 * travel - step
+                        })
+                    }
+                    MarqueeMode::Off => None,
+                }
+            }
+            _ => None,
+        };
+
+        match (window_offset, self.marquee) {
+            (Some(window_offset), MarqueeMode::Loop { gap, .. }) => (0..digit_count)
+                .map(|index| {
+                    self.digits
+                        .get((window_offset + index) % (self.digits.len() + gap))
+                        .copied()
+                        .unwrap_or_default()
+                })
+                .collect_vec(),
+            (Some(window_offset), _) => {
+                self.digits[window_offset..window_offset + digit_count].to_vec()
+            }
+            (None, _) => {
+                let pad_digit = DisplayDigit {
+                    glyph: self.glyph(self.pad_with).unwrap_or_default(),
+                    ..DisplayDigit::default()
+                };
+
+                // Negative offsets pad the start of the display
+                let window_offset = {
+                    let excess = self.digits.len() as isize - digit_count as isize;
+                    match (self.fill_direction, self.align) {
+                        (FillDirection::RightToLeft, _) | (_, Align::Max) => excess,
+                        (_, Align::Min) => 0,
+                        (_, Align::Center) => excess.div_euclid(2),
+                    }
+                };
+
+                (0..digit_count)
+                    .map(|index| {
+                        usize::try_from(window_offset + index as isize)
+                            .ok()
+                            .and_then(|index| self.digits.get(index))
+                            .copied()
+                            .unwrap_or(pad_digit)
+                    })
+                    .collect_vec()
+            }
+        }
+    }
+}
+
+impl<'a> Widget for SegmentedDisplayWidget<'a> {
+    fn ui(mut self, ui: &mut Ui) -> Response {
+        let display_impl = self.display_kind.display_impl();
+
+        // Editable displays show the bound glyphs as they are
+        let editable_glyphs = self.editable_glyphs.take();
+        if let Some(glyphs) = &editable_glyphs {
+            self.digits = Arc::new(
+                glyphs
+                    .iter()
+                    .map(|&glyph| DisplayDigit {
+                        glyph: self.truncate_glyph(glyph),
+                        ..DisplayDigit::default()
+                    })
+                    .collect_vec(),
+            );
+            self.digit_count = None;
+            self.marquee = MarqueeMode::Off;
+            self.blinking = false;
+            self.blink_colons = false;
+            self.overflow_pattern = None;
+            self.negative = false;
+            self.transition = DigitTransition::None;
+        }
+
+        let digit_count = self.digit_count.unwrap_or(self.content_len());
+        let digit_height = self.resolve_digit_height(ui, digit_count);
+        let digit_width = digit_height * self.metrics.digit_ratio;
+
+        // Turn relative metrics to absolute metrics
+        let segment_thickness = self.metrics.segment_thickness * digit_height;
+        let segment_spacing = self.metrics.segment_spacing * digit_height;
+        let digit_shearing = self.metrics.digit_shearing * digit_width;
+        let digit_spacing = self.metrics.digit_spacing * digit_width;
+        let margin_horizontal = self.metrics.margin_horizontal * digit_width;
+        let margin_vertical = self.metrics.margin_vertical * digit_height;
+        let digit_median = self.metrics.digit_median * (digit_height / 2.0);
+        let colon_separation = self.metrics.colon_separation * (digit_height / 2.0);
+
+        let pixels_per_point = ui.ctx().pixels_per_point();
+
+        let geometry_bits = [
+            digit_width,
+            digit_height,
+            segment_thickness,
+            segment_spacing,
+            digit_shearing,
+            digit_spacing,
+            margin_horizontal,
+            margin_vertical,
+            digit_median,
+            colon_separation,
+            pixels_per_point,
+        ]
+        .map(f32::to_bits);
+
+        let desired_size = self.metrics.display_size(digit_height, digit_count);
+
+        let (rect, mut response) = ui.allocate_exact_size(desired_size, Sense::click());
+
+        // Positions relative to the top left corner of the widget
+        let digit_center = |digit_index: usize| {
+            pos2(
+                margin_horizontal
+                    + digit_shearing.abs()
+                    + ((digit_width + digit_spacing) * digit_index as f32)
+                    + (digit_width / 2.0),
+                rect.height() / 2.0,
+            )
+        };
+
+        let digit_transform = |digit_center: Pos2, &Pos2 { x, y }: &Pos2| {
+            digit_center + vec2(x, y) - vec2((y / (digit_height / 2.0)) * digit_shearing, 0.0)
+        };
+
+        let mut child_ui = ui.child_ui(rect, *ui.layout(), None);
+        child_ui.set_clip_rect(child_ui.clip_rect().intersect(rect));
+
+        if child_ui.is_rect_visible(rect) {
+            // Draw the widget background without clipping
+            ui.painter().rect(
+                rect,
+                ui.style().visuals.noninteractive().rounding,
+                if self.dim_background {
+                    self.style.dimmed(self.brightness).background_color
+                } else {
+                    self.style.background_color
+                },
+                Stroke::NONE,
+            );
+
+            // Static displays skip picking the digits and their levels altogether
+            let static_frame_id = response.id.with("static_frame");
+            let static_settings_hash = editable_glyphs
+                .is_none()
+                .then(|| self.static_settings_hash(rect, &geometry_bits))
+                .flatten();
+
+            if let Some(settings_hash) = static_settings_hash {
+                let static_frame = ui
+                    .memory(|memory| memory.data.get_temp::<StaticFrame>(static_frame_id))
+                    .filter(|frame| {
+                        frame.settings_hash == settings_hash
+                            && (Arc::ptr_eq(&frame.digits, &self.digits)
+                                || frame.digits == self.digits)
+                    });
+
+                if let Some(static_frame) = static_frame {
+                    // `Shape::Mesh` owns its mesh, so a single copy is still needed
+                    child_ui
+                        .painter()
+                        .add(Shape::mesh(Mesh::clone(&static_frame.mesh)));
+                    return response;
+                }
+            }
+
+            let digits = self.visible_digits(
+                ui,
+                response.id,
+                self.marquee_pause_on_hover && response.hovered(),
+            );
+
+            let blink_off = if self.blinking
+                || (self.blink_colons && digits.iter().any(|digit| digit.colon))
+                || digits.iter().any(|digit| digit.blink)
+            {
+                let blink_period = self.blink_period.max(f32::EPSILON) as f64;
+                let blink_on_time = self.blink_duty_cycle.clamp(0.0, 1.0) as f64 * blink_period;
+                let blink_time = ui.input(|input| input.time).rem_euclid(blink_period);
+
+                // Wake up for the next phase change only
+                let next_phase_change = if blink_time < blink_on_time {
+                    blink_on_time - blink_time
+                } else {
+                    blink_period - blink_time
+                };
+                ui.ctx()
+                    .request_repaint_after_secs(next_phase_change as f32);
+
+                blink_time >= blink_on_time
+            } else {
+                false
+            };
+
+            // Transitions follow the digits before blinking, the blink phases don't count
+            // as changes of the digits
+            let transition_id = response.id.with("transition");
+
+            // Scrolling marquees shift all digits at every step, which is not animated
+            let marquee_scrolling = self.marquee.speed_chars_per_sec().is_some()
+                && self.overflow_pattern.is_none()
+                && self.fill_direction == FillDirection::LeftToRight
+                && self
+                    .digit_count
+                    .is_some_and(|digit_count| self.content_len() > digit_count);
+
+            let transitions = match self.transition.duration() {
+                Some(duration) => {
+                    let time = ui.input(|input| input.time);
+
+                    let transitions = ui.memory_mut(|memory| {
+                        let state = memory
+                            .data
+                            .get_temp_mut_or_default::<TransitionState>(transition_id);
+
+                        // Displays changing their digit count start over without transitions
+                        if state.digits.len() != digits.len() {
+                            *state = TransitionState {
+                                digits: digits.clone(),
+                                previous_digits: digits.clone(),
+                                start_times: vec![f64::NEG_INFINITY; digits.len()],
+                            };
+                        }
+
+                        itertools::izip!(
+                            &digits,
+                            &mut state.digits,
+                            &mut state.previous_digits,
+                            &mut state.start_times
+                        )
+                        .map(|(digit, last_digit, previous_digit, start_time)| {
+                            if digit != last_digit {
+                                *previous_digit = *last_digit;
+                                *last_digit = *digit;
+                                *start_time = if marquee_scrolling {
+                                    f64::NEG_INFINITY
+                                } else {
+                                    time
+                                };
+                            }
+
+                            let progress = ((time - *start_time) / duration as f64) as f32;
+                            (progress < 1.0).then_some((*previous_digit, progress))
+                        })
+                        .collect_vec()
+                    });
+
+                    if transitions.iter().any(Option::is_some) {
+                        ui.ctx().request_repaint();
+                    }
+
+                    transitions
+                }
+                None => {
+                    ui.memory_mut(|memory| memory.data.remove::<TransitionState>(transition_id));
+                    vec![None; digits.len()]
+                }
+            };
+
+            // Digits and styles as they appear in this frame
+            let (digits, transitions): (Vec<_>, Vec<_>) = digits
+                .into_iter()
+                .zip(transitions)
+                .map(|(digit, transition)| {
+                    if blink_off && (self.blinking || digit.blink) {
+                        (DisplayDigit::default(), None)
+                    } else if blink_off && self.blink_colons {
+                        let without_colon = |digit| DisplayDigit {
+                            colon: false,
+                            ..digit
+                        };
+
+                        (
+                            without_colon(digit),
+                            transition.map(|(previous_digit, progress)| {
+                                (without_colon(previous_digit), progress)
+                            }),
+                        )
+                    } else {
+                        (digit, transition)
+                    }
+                })
+                .unzip();
+
+            let digit_styles = (0..digits.len())
+                .map(|digit_index| {
+                    self.digit_styles
+                        .iter()
+                        .rev()
+                        .find(|&&(index, _)| index == digit_index)
+                        .map_or(self.style, |&(_, style)| style)
+                        .dimmed(self.brightness)
+                })
+                .collect_vec();
+
+            let mut digit_levels = digits.iter().map(element_levels).collect_vec();
+
+            if let DigitTransition::Fade { .. } = self.transition {
+                for (levels, transition) in digit_levels.iter_mut().zip(&transitions) {
+                    if let Some((previous_digit, progress)) = transition {
+                        for (level, previous_level) in
+                            levels.iter_mut().zip(element_levels(previous_digit))
+                        {
+                            *level = lerp(previous_level as f32..=*level as f32, *progress).round()
+                                as u8;
+                        }
+                    }
+                }
+            }
+
+            let persistence_id = response.id.with("persistence");
+
+            if self.persistence > 0.0 {
+                let time = ui.input(|input| input.time);
+                let persistence = self.persistence as f64;
+
+                let fading = ui.memory_mut(|memory| {
+                    let state = memory
+                        .data
+                        .get_temp_mut_or_default::<PersistenceState>(persistence_id);
+                    state
+                        .last_active
+                        .resize(digit_levels.len(), [f64::NEG_INFINITY; ELEMENT_COUNT]);
+
+                    let mut fading = false;
+                    for (levels, last_active) in digit_levels.iter_mut().zip(&mut state.last_active)
+                    {
+                        for (level, last_active) in levels.iter_mut().zip(last_active.iter_mut()) {
+                            if *level == u8::MAX {
+                                *last_active = time;
+                            } else {
+                                let afterglow = 1.0 - (time - *last_active) / persistence;
+                                if afterglow > 0.0 {
+                                    *level = (afterglow * u8::MAX as f64).round() as u8;
+                                    fading = true;
+                                }
+                            }
+                        }
+                    }
+                    fading
+                });
+
+                if fading {
+                    ui.ctx().request_repaint();
+                }
+            } else {
+                ui.memory_mut(|memory| memory.data.remove::<PersistenceState>(persistence_id));
+            }
+
+            // Rolling digits are drawn on their own, only their inactive segments and their
+            // separators remain in the cached mesh
+            let rolling_digits = match self.transition {
+                DigitTransition::Roll { .. } => transitions
+                    .iter()
+                    .enumerate()
+                    .filter_map(|(digit_index, transition)| {
+                        transition.map(|(previous_digit, progress)| {
+                            (digit_index, previous_digit, progress)
+                        })
+                    })
+                    .collect_vec(),
+                _ => Vec::new(),
+            };
+
+            for &(digit_index, _, _) in &rolling_digits {
+                digit_levels[digit_index][..DOT_ELEMENT].fill(0);
+            }
+
+            let geometry_hash = egui::util::hash((
+                self.display_kind,
+                &digit_levels,
+                &digit_styles,
+                self.glow_bits(),
+                [self.show_dots, self.show_colons, self.show_apostrophes],
+                self.metrics.segment_cap_style,
+                geometry_bits,
+            ));
+
+            // Only built when the cached mesh is rebuilt or digits are rolling
+            let segment_geometry_cell = OnceCell::new();
+            let segment_geometry = || {
+                segment_geometry_cell.get_or_init(|| {
+                    let segment_geometry = display_impl
+                        .geometry(
+                            digit_width,
+                            digit_height,
+                            segment_thickness,
+                            segment_spacing,
+                            digit_median,
+                        )
+                        .into_iter()
+                        .map(|outline| {
+                            self.metrics
+                                .segment_cap_style
+                                .apply(outline, segment_thickness)
+                        })
+                        .collect_vec();
+                    assert_eq!(segment_geometry.len(), display_impl.segment_count());
+                    segment_geometry
+                })
+            };
+
+            #[rustfmt::skip]
+            let apostrophe_points: Vec<Pos2> = vec![
+                pos2(-(digit_width / 2.0) - (digit_spacing / 2.0) - (segment_thickness / 2.0), -(digit_height / 2.0)                            ),
+                pos2(-(digit_width / 2.0) - (digit_spacing / 2.0) + (segment_thickness / 2.0), -(digit_height / 2.0)                            ),
+                pos2(-(digit_width / 2.0) - (digit_spacing / 2.0) - (segment_thickness / 2.0), -(digit_height / 2.0) + (segment_thickness * 2.0)),
+            ];
+
+            #[rustfmt::skip]
+            let (colon_top_pos, colon_bottom_pos, dot_pos) = (
+                pos2(-(digit_width / 2.0) - (digit_spacing / 2.0), digit_median - colon_separation),
+                pos2(-(digit_width / 2.0) - (digit_spacing / 2.0), digit_median + colon_separation),
+                pos2( (digit_width / 2.0) + (digit_spacing / 2.0), (digit_height / 2.0) - (segment_thickness / 2.0))
+            );
+
+            // Shapes of the elements of a digit, skipping the inactive ones when `lit_only` is set
+            let push_digit_shapes = |shapes: &mut Vec<Shape>,
+                                     digit_center: Pos2,
+                                     levels: &ElementLevels,
+                                     style: &DisplayStyle,
+                                     lit_only: bool| {
+                let transform = |point: &Pos2| digit_transform(digit_center, point);
+
+                // Extra translucent pass around the active segments, drawn below them
+                let glow_stroke = self.glow.map(|glow| {
+                    Stroke::new(
+                        glow.width * segment_thickness,
+                        style
+                            .active_foreground_color
+                            .gamma_multiply(glow.opacity * self.brightness.clamp(0.0, 1.0)),
+                    )
+                });
+
+                // Fading elements of persistent displays are blended between the inactive
+                // and the active colors
+                let element_look = |level: u8| {
+                    let value = level as f32 / u8::MAX as f32;
+                    let glow_stroke = glow_stroke
+                        .filter(|_| level > 0)
+                        .map(|s| Stroke::new(s.width, s.color.gamma_multiply(value)));
+
+                    let (fill, stroke) = match level {
+                        0 | u8::MAX => (
+                            style.foreground_color(level > 0),
+                            style.foreground_stroke(level > 0),
+                        ),
+                        _ => (
+                            style.foreground_color_blend(value),
+                            style.foreground_stroke_blend(value),
+                        ),
+                    };
+
+                    (fill, stroke, glow_stroke)
+                };
+
+                let push_polygon = |shapes: &mut Vec<Shape>, level: u8, points: &[Pos2]| {
+                    if lit_only && level == 0 {
+                        return;
+                    }
+
+                    let points = points.iter().map(transform).collect_vec();
+                    let (fill, stroke, glow_stroke) = element_look(level);
+
+                    if let Some(glow_stroke) = glow_stroke {
+                        shapes.push(Shape::convex_polygon(
+                            points.clone(),
+                            Color32::TRANSPARENT,
+                            glow_stroke,
+                        ));
+                    }
+
+                    // TODO: concave_polygon
+                    // https://github.com/emilk/egui/issues/513
+                    shapes.push(Shape::convex_polygon(points, fill, stroke));
+                };
+
+                let push_circle = |shapes: &mut Vec<Shape>, level: u8, center: &Pos2| {
+                    if lit_only && level == 0 {
+                        return;
+                    }
+
+                    let center = transform(center);
+                    let (fill, stroke, glow_stroke) = element_look(level);
+
+                    if let Some(glow_stroke) = glow_stroke {
+                        shapes.push(Shape::circle_stroke(
+                            center,
+                            segment_thickness / 2.0,
+                            glow_stroke,
+                        ));
+                    }
+
+                    shapes.push(Shape::Circle(CircleShape {
+                        center,
+                        radius: segment_thickness / 2.0,
+                        fill,
+                        stroke,
+                    }));
+                };
+
+                for (segment_points, &level) in segment_geometry().iter().zip(levels) {
+                    push_polygon(shapes, level, segment_points);
+                }
+
+                if self.show_dots {
+                    push_circle(shapes, levels[DOT_ELEMENT], &dot_pos);
+                }
+
+                if self.show_colons {
+                    push_circle(shapes, levels[COLON_ELEMENT], &colon_top_pos);
+                    push_circle(shapes, levels[COLON_ELEMENT], &colon_bottom_pos);
+                }
+
+                if self.show_apostrophes {
+                    push_polygon(shapes, levels[APOSTROPHE_ELEMENT], &apostrophe_points);
+                }
+            };
+
+            // The segments get tessellated relative to the top left corner of the widget
+            let build_mesh = || {
+                let mut shapes: Vec<Shape> = Vec::new();
+
+                for (digit_index, (levels, style)) in
+                    digit_levels.iter().zip(&digit_styles).enumerate()
+                {
+                    push_digit_shapes(&mut shapes, digit_center(digit_index), levels, style, false);
+                }
+
+                let mut tessellator = Tessellator::new(
+                    pixels_per_point,
+                    ui.ctx().tessellation_options(|options| *options),
+                    ui.fonts(|fonts| fonts.font_image_size()),
+                    Vec::new(),
+                );
+
+                let mut mesh = Mesh::default();
+                for shape in shapes {
+                    tessellator.tessellate_shape(shape, &mut mesh);
+                }
+
+                GEOMETRY_REBUILD_COUNT.set(GEOMETRY_REBUILD_COUNT.get() + 1);
+                Arc::new(mesh)
+            };
+
+            let mesh_id = response.id.with("mesh");
+
+            let mesh = ui
+                .memory(|memory| memory.data.get_temp::<(u64, Arc<Mesh>)>(mesh_id))
+                .filter(|(hash, _)| *hash == geometry_hash)
+                .map(|(_, mesh)| mesh)
+                .unwrap_or_else(|| {
+                    let mesh = build_mesh();
+                    ui.memory_mut(|memory| {
+                        memory
+                            .data
+                            .insert_temp(mesh_id, (geometry_hash, Arc::clone(&mesh)));
+                    });
+                    mesh
+                });
+
+            // Copied and moved to the position of the widget in a single pass
+            let offset = rect.min.to_vec2();
+            let positioned_mesh = Mesh {
+                indices: mesh.indices.clone(),
+                vertices: mesh
+                    .vertices
+                    .iter()
+                    .map(|vertex| Vertex {
+                        pos: vertex.pos + offset,
+                        ..*vertex
+                    })
+                    .collect(),
+                texture_id: mesh.texture_id,
+            };
+
+            ui.memory_mut(|memory| match static_settings_hash {
+                Some(settings_hash) => memory.data.insert_temp(
+                    static_frame_id,
+                    StaticFrame {
+                        digits: Arc::clone(&self.digits),
+                        settings_hash,
+                        mesh: Arc::new(positioned_mesh.clone()),
+                    },
+                ),
+                None => memory.data.remove::<StaticFrame>(static_frame_id),
+            });
+
+            child_ui.painter().add(Shape::mesh(positioned_mesh));
+
+            for (digit_index, previous_digit, progress) in rolling_digits {
+                let digit_center = rect.min + digit_center(digit_index).to_vec2();
+
+                // The old glyph leaves the digit cell at the top, the new one enters at the bottom
+                let mut shapes = Vec::new();
+                for (digit, offset) in [
+                    (previous_digit, -progress),
+                    (digits[digit_index], 1.0 - progress),
+                ] {
+                    let mut levels = element_levels(&digit);
+                    levels[DOT_ELEMENT..].fill(0);
+
+                    push_digit_shapes(
+                        &mut shapes,
+                        digit_center + vec2(0.0, offset * digit_height),
+                        &levels,
+                        &digit_styles[digit_index],
+                        true,
+                    );
+                }
+
+                let cell_rect = Rect::from_center_size(
+                    digit_center,
+                    vec2(
+                        digit_width + 2.0 * digit_shearing.abs() + segment_thickness,
+                        digit_height,
+                    ),
+                );
+
+                child_ui
+                    .painter()
+                    .with_clip_rect(cell_rect.intersect(child_ui.clip_rect()))
+                    .extend(shapes);
+            }
+        }
+
+        if let Some(glyphs) = editable_glyphs {
+            let segment_geometry = display_impl
+                .geometry(
+                    digit_width,
+                    digit_height,
+                    segment_thickness,
+                    segment_spacing,
+                    digit_median,
+                )
+                .into_iter()
+                .map(|outline| {
+                    self.metrics
+                        .segment_cap_style
+                        .apply(outline, segment_thickness)
+                })
+                .collect_vec();
+
+            let segment_outline = |digit_index: usize, segment_index: usize| {
+                segment_geometry[segment_index]
+                    .iter()
+                    .map(|point| {
+                        digit_transform(digit_center(digit_index), point) + rect.min.to_vec2()
+                    })
+                    .collect_vec()
+            };
+
+            let hovered_segment = response.hover_pos().and_then(|hover_pos| {
+                (0..glyphs.len())
+                    .cartesian_product(0..segment_geometry.len())
+                    .find(|&(digit_index, segment_index)| {
+                        polygon_contains(&segment_outline(digit_index, segment_index), hover_pos)
+                    })
+            });
+
+            if let Some((digit_index, segment_index)) = hovered_segment {
+                ui.ctx().set_cursor_icon(CursorIcon::PointingHand);
+
+                if response.clicked() {
+                    glyphs[digit_index] ^= 1 << segment_index;
+                    response.mark_changed();
+                    ui.ctx().request_repaint();
+                }
+
+                child_ui.painter().add(Shape::closed_line(
+                    segment_outline(digit_index, segment_index),
+                    ui.style().visuals.selection.stroke,
+                ));
+            }
+        }
+
+        response
+    }
+}
+
+/// Even-odd rule point in polygon test, works with concave polygons too.
+fn polygon_contains(polygon: &[Pos2], point: Pos2) -> bool {
+    polygon
+        .iter()
+        .circular_tuple_windows()
+        .filter(|(a, b)| {
+            ((a.y > point.y) != (b.y > point.y))
+                && (point.x < a.x + (b.x - a.x) * (point.y - a.y) / (b.y - a.y))
+        })
+        .count()
+        % 2
+        == 1
+}
+
+// ----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use egui::{CentralPanel, Context, RawInput};
+
+    use super::*;
+
+    /// Digits shown by the display in its first frame.
+    fn shown_digits(display: &SegmentedDisplayWidget) -> Vec<DisplayDigit> {
+        let ctx = Context::default();
+        let mut digits = Vec::new();
+
+        let _ = ctx.run(RawInput::default(), |ctx| {
+            CentralPanel::default().show(ctx, |ui| {
+                digits = display.visible_digits(ui, Id::new("display"), false);
+            });
+        });
+        digits
+    }
+
+    /// Digits of the string on a seven-segment display.
+    fn seven_segment_digits(value: &str) -> Vec<DisplayDigit> {
+        SegmentedDisplayWidget::seven_segment(value).digits.to_vec()
+    }
+
+    fn assert_shows(display: SegmentedDisplayWidget, expected: &str) {
+        assert_eq!(shown_digits(&display), seven_segment_digits(expected));
+    }
+
+    /// Segment meshes painted by the display in the next frame, with the id of the widget.
+    fn painted_meshes(
+        ctx: &Context,
+        frame: u32,
+        display: SegmentedDisplayWidget,
+    ) -> (Vec<Mesh>, Id) {
+        let mut id = Id::NULL;
+        let output = ctx.run(
+            RawInput {
+                time: Some(frame as f64 / 60.0),
+                ..RawInput::default()
+            },
+            |ctx| {
+                CentralPanel::default().show(ctx, |ui| id = ui.add(display).id);
+            },
+        );
+
+        let meshes = output
+            .shapes
+            .into_iter()
+            .filter_map(|clipped_shape| match clipped_shape.shape {
+                Shape::Mesh(mesh) => Some(mesh),
+                _ => None,
+            })
+            .collect_vec();
+        (meshes, id)
+    }
+
+    mod numeric_constructors {
+        use super::*;
+
+        #[test]
+        fn float_rounds_to_decimals() {
+            assert_shows(SegmentedDisplayWidget::from_float(1.25, 1), "1.2");
+            assert_shows(SegmentedDisplayWidget::from_float(1.26, 1), "1.3");
+            assert_shows(SegmentedDisplayWidget::from_float(2.5, 0), "2");
+            assert_shows(SegmentedDisplayWidget::from_float(0.125, 2), "0.12");
+        }
+
+        #[test]
+        fn float_rounding_carries_into_new_digit() {
+            assert_shows(SegmentedDisplayWidget::from_float(9.99, 1), "10.0");
+            assert_shows(SegmentedDisplayWidget::from_float(-9.99, 1), "-10.0");
+            assert_shows(
+                SegmentedDisplayWidget::from_float(9.99, 1).digit_count(Some(4)),
+                " 10.0",
+            );
+        }
+
+        #[test]
+        fn float_right_aligned_and_padded() {
+            assert_shows(
+                SegmentedDisplayWidget::from_float(3.5, 1).digit_count(Some(4)),
+                "  3.5",
+            );
+        }
+
+        #[test]
+        fn float_negative_values() {
+            assert_shows(SegmentedDisplayWidget::from_float(-12.5, 1), "-12.5");
+            assert_shows(
+                SegmentedDisplayWidget::from_float(-12.5, 1).digit_count(Some(5)),
+                "- 12.5",
+            );
+        }
+
+        #[test]
+        fn float_negative_zero_has_no_sign() {
+            assert_shows(SegmentedDisplayWidget::from_float(-0.0, 1), "0.0");
+            assert_shows(SegmentedDisplayWidget::from_float(-0.04, 1), "0.0");
+            assert_shows(SegmentedDisplayWidget::from_float(-0.05001, 1), "-0.1");
+        }
+
+        #[test]
+        fn float_exact_fit() {
+            assert_shows(
+                SegmentedDisplayWidget::from_float(123.4, 1).digit_count(Some(4)),
+                "123.4",
+            );
+            assert_shows(
+                SegmentedDisplayWidget::from_float(-23.4, 1).digit_count(Some(4)),
+                "-23.4",
+            );
+        }
+
+        #[test]
+        fn float_decimals_overflow() {
+            assert_shows(
+                SegmentedDisplayWidget::from_float(1.2345, 4).digit_count(Some(4)),
+                "----",
+            );
+            assert_shows(
+                SegmentedDisplayWidget::from_float(1234.5, 1).digit_count(Some(4)),
+                "----",
+            );
+            assert_shows(
+                SegmentedDisplayWidget::from_float(-123.4, 1).digit_count(Some(4)),
+                "----",
+            );
+        }
+
+        #[test]
+        fn int_positive_and_zero() {
+            assert_shows(SegmentedDisplayWidget::from_int(0), "0");
+            assert_shows(SegmentedDisplayWidget::from_int(42), "42");
+            assert_shows(
+                SegmentedDisplayWidget::from_int(42).digit_count(Some(4)),
+                "  42",
+            );
+        }
+
+        #[test]
+        fn int_negative_values() {
+            assert_shows(SegmentedDisplayWidget::from_int(-42), "-42");
+            assert_shows(
+                SegmentedDisplayWidget::from_int(-42).digit_count(Some(4)),
+                "- 42",
+            );
+        }
+
+        #[test]
+        fn int_exact_fit() {
+            assert_shows(
+                SegmentedDisplayWidget::from_int(1234).digit_count(Some(4)),
+                "1234",
+            );
+            assert_shows(
+                SegmentedDisplayWidget::from_int(12345).digit_count(Some(4)),
+                "----",
+            );
+            assert_shows(
+                SegmentedDisplayWidget::from_int(-123).digit_count(Some(4)),
+                "-123",
+            );
+            assert_shows(
+                SegmentedDisplayWidget::from_int(-1234).digit_count(Some(4)),
+                "----",
+            );
+        }
+
+        #[test]
+        fn int_extremes() {
+            assert_shows(
+                SegmentedDisplayWidget::from_int(i64::MIN),
+                "-9223372036854775808",
+            );
+            assert_shows(
+                SegmentedDisplayWidget::from_int(i64::MIN).digit_count(Some(20)),
+                "-9223372036854775808",
+            );
+            assert_shows(
+                SegmentedDisplayWidget::from_int(i64::MIN).digit_count(Some(19)),
+                &"-".repeat(19),
+            );
+            assert_shows(
+                SegmentedDisplayWidget::from_int(i64::MAX).digit_count(Some(19)),
+                "9223372036854775807",
+            );
+        }
+
+        #[test]
+        fn custom_overflow_pattern() {
+            assert_shows(
+                SegmentedDisplayWidget::from_int(12345)
+                    .digit_count(Some(4))
+                    .overflow_pattern(Some('E')),
+                "EEEE",
+            );
+        }
+    }
+
+    mod unknown_chars {
+        use super::*;
+
+        fn display(policy: UnknownCharPolicy) -> SegmentedDisplayWidget<'static> {
+            SegmentedDisplayWidget::new(DisplayKind::SevenSegment)
+                .unknown_char(policy)
+                .push_string("1\u{2603}2")
+        }
+
+        #[test]
+        fn skipped() {
+            assert_shows(display(UnknownCharPolicy::Skip), "12");
+        }
+
+        #[test]
+        fn blank() {
+            assert_shows(display(UnknownCharPolicy::Blank), "1 2");
+        }
+
+        #[test]
+        fn replaced() {
+            assert_shows(display(UnknownCharPolicy::Replace('E')), "1E2");
+        }
+
+        #[test]
+        fn unknown_replacement_is_blank() {
+            assert_shows(display(UnknownCharPolicy::Replace('\u{2603}')), "1 2");
+        }
+
+        #[test]
+        fn all_segments() {
+            assert_shows(display(UnknownCharPolicy::AllSegments), "182");
+        }
+    }
+    mod seven_segment_font {
+        use super::*;
+
+        fn assert_no_blank_digits(value: &str) {
+            let digits = shown_digits(&SegmentedDisplayWidget::seven_segment(value));
+
+            assert_eq!(digits.len(), value.chars().count(), "{value:?}");
+            assert!(
+                digits.iter().all(|digit| digit.glyph != 0),
+                "{value:?} has blank digits"
+            );
+        }
+
+        #[test]
+        fn hexadecimal_words() {
+            assert_no_blank_digits("DEADBEEF");
+            assert_no_blank_digits("0x1F");
+            assert_no_blank_digits("0123456789ABCDEF");
+            assert_no_blank_digits("0123456789abcdef");
+        }
+
+        #[test]
+        fn extra_glyphs() {
+            assert_no_blank_digits("HLPU");
+            assert_no_blank_digits("25\u{B0}C");
+            assert_no_blank_digits("\u{2261}");
+        }
+    }
+    mod measure {
+        use super::*;
+
+        /// Measured and allocated size of the display, in the same frame.
+        fn measured_and_allocated(display: SegmentedDisplayWidget) -> (Vec2, Vec2) {
+            let ctx = Context::default();
+            let mut sizes = (Vec2::ZERO, Vec2::ZERO);
+
+            let _ = ctx.run(RawInput::default(), |ctx| {
+                CentralPanel::default().show(ctx, |ui| {
+                    let measured = display.measure(ui);
+                    let allocated = ui.add(display).rect.size();
+                    sizes = (measured, allocated);
+                });
+            });
+            sizes
+        }
+
+        fn assert_measure_matches(display: SegmentedDisplayWidget) {
+            let (measured, allocated) = measured_and_allocated(display);
+            assert!(
+                (measured - allocated).length() < 1e-3,
+                "measured {measured:?}, allocated {allocated:?}"
+            );
+        }
+
+        #[test]
+        fn default_metrics() {
+            assert_measure_matches(SegmentedDisplayWidget::seven_segment("12:34"));
+            assert_measure_matches(SegmentedDisplayWidget::sixteen_segment("Hello"));
+        }
+
+        #[test]
+        fn digit_height_spacing_and_slant() {
+            for metrics_preset in [
+                DisplayMetricsPreset::Default,
+                DisplayMetricsPreset::Wide,
+                DisplayMetricsPreset::Calculator,
+            ] {
+                assert_measure_matches(
+                    SegmentedDisplayWidget::seven_segment("3.14159")
+                        .metrics_preset(metrics_preset)
+                        .digit_height(37.5)
+                        .digit_spacing(0.7)
+                        .slant(-0.3),
+                );
+            }
+        }
+
+        #[test]
+        fn digit_count_and_sign() {
+            assert_measure_matches(SegmentedDisplayWidget::from_int(-42));
+            assert_measure_matches(SegmentedDisplayWidget::from_int(-42).digit_count(Some(8)));
+            assert_measure_matches(SegmentedDisplayWidget::seven_segment("").digit_count(Some(0)));
+        }
+
+        #[test]
+        fn auto_size() {
+            assert_measure_matches(SegmentedDisplayWidget::seven_segment("88:88").auto_size(true));
+            assert_measure_matches(
+                SegmentedDisplayWidget::seven_segment("88:88")
+                    .auto_size(true)
+                    .auto_size_range(10.0..=20.0),
+            );
+        }
+
+        #[test]
+        fn editable() {
+            let mut glyphs = vec![0x3F, 0x06, 0x5B];
+            assert_measure_matches(
+                SegmentedDisplayWidget::new(DisplayKind::SevenSegment).editable(&mut glyphs),
+            );
+        }
+    }
+    mod radix {
+        use super::*;
+
+        #[test]
+        fn max_values_fit_exactly() {
+            for (radix, digits) in [
+                (Radix::Binary, "1".repeat(64)),
+                (Radix::Octal, "1777777777777777777777".to_owned()),
+                (Radix::Decimal, "18446744073709551615".to_owned()),
+                (Radix::Hexadecimal, "FFFFFFFFFFFFFFFF".to_owned()),
+            ] {
+                let digit_count = digits.len();
+
+                assert_shows(
+                    SegmentedDisplayWidget::from_value(u64::MAX, radix, digit_count),
+                    &digits,
+                );
+                assert_shows(
+                    SegmentedDisplayWidget::from_value(u64::MAX, radix, digit_count - 1),
+                    &"-".repeat(digit_count - 1),
+                );
+            }
+        }
+
+        #[test]
+        fn max_values_of_digit_count() {
+            assert_shows(
+                SegmentedDisplayWidget::from_value(0b1111, Radix::Binary, 4),
+                "1111",
+            );
+            assert_shows(
+                SegmentedDisplayWidget::from_value(0b10000, Radix::Binary, 4),
+                "----",
+            );
+            assert_shows(
+                SegmentedDisplayWidget::from_value(0o7777, Radix::Octal, 4),
+                "7777",
+            );
+            assert_shows(
+                SegmentedDisplayWidget::from_value(0o10000, Radix::Octal, 4),
+                "----",
+            );
+            assert_shows(
+                SegmentedDisplayWidget::from_value(9999, Radix::Decimal, 4),
+                "9999",
+            );
+            assert_shows(
+                SegmentedDisplayWidget::from_value(10000, Radix::Decimal, 4),
+                "----",
+            );
+            assert_shows(
+                SegmentedDisplayWidget::from_value(0xFFFF, Radix::Hexadecimal, 4),
+                "FFFF",
+            );
+            assert_shows(
+                SegmentedDisplayWidget::from_value(0x10000, Radix::Hexadecimal, 4),
+                "----",
+            );
+        }
+
+        #[test]
+        fn zero_padded() {
+            assert_shows(
+                SegmentedDisplayWidget::from_value(0, Radix::Binary, 4),
+                "0000",
+            );
+            assert_shows(
+                SegmentedDisplayWidget::from_value(0x1F, Radix::Hexadecimal, 4),
+                "001F",
+            );
+        }
+
+        #[test]
+        fn blank_padded() {
+            assert_shows(
+                SegmentedDisplayWidget::from_value(0x1F, Radix::Hexadecimal, 4).pad_with(' '),
+                "  1F",
+            );
+            assert_shows(
+                SegmentedDisplayWidget::from_value(5, Radix::Binary, 4).pad_with(' '),
+                " 101",
+            );
+        }
+
+        #[test]
+        fn binary_digits_render_as_numerals() {
+            let digits = shown_digits(&SegmentedDisplayWidget::from_value(0b10, Radix::Binary, 2));
+            assert_eq!(digits, seven_segment_digits("10"));
+            assert!(digits.iter().all(|digit| digit.glyph != 0));
+        }
+    }
+    mod negative {
+        use super::*;
+
+        #[test]
+        fn half() {
+            assert_shows(SegmentedDisplayWidget::from_float(-0.5, 1), "-0.5");
+            assert_shows(
+                SegmentedDisplayWidget::from_float(-0.5, 1).digit_count(Some(3)),
+                "-0.5",
+            );
+            assert_shows(
+                SegmentedDisplayWidget::from_float(-0.5, 1).digit_count(Some(4)),
+                "- 0.5",
+            );
+        }
+
+        #[test]
+        fn no_room_for_sign_overflows() {
+            assert_shows(
+                SegmentedDisplayWidget::from_int(-999).digit_count(Some(3)),
+                "---",
+            );
+            assert_shows(
+                SegmentedDisplayWidget::from_int(-999)
+                    .digit_count(Some(3))
+                    .overflow_pattern(Some('E')),
+                "EEE",
+            );
+        }
+
+        #[test]
+        fn no_room_for_sign_without_overflow_pattern_shows_dashes() {
+            assert_shows(
+                SegmentedDisplayWidget::from_int(-999)
+                    .digit_count(Some(3))
+                    .overflow_pattern(None),
+                "---",
+            );
+            assert_shows(
+                SegmentedDisplayWidget::seven_segment("12")
+                    .negative(true)
+                    .digit_count(Some(2)),
+                "--",
+            );
+
+            // Magnitudes fitting next to the sign are still shown
+            assert_shows(
+                SegmentedDisplayWidget::from_int(-99)
+                    .digit_count(Some(3))
+                    .overflow_pattern(None),
+                "-99",
+            );
+        }
+
+        #[test]
+        fn i32_min() {
+            let value = i64::from(i32::MIN);
+
+            assert_shows(SegmentedDisplayWidget::from_int(value), "-2147483648");
+            assert_shows(
+                SegmentedDisplayWidget::from_int(value).digit_count(Some(11)),
+                "-2147483648",
+            );
+            assert_shows(
+                SegmentedDisplayWidget::from_int(value).digit_count(Some(10)),
+                &"-".repeat(10),
+            );
+        }
+
+        #[test]
+        fn sign_takes_leftmost_digit() {
+            for align in [Align::Min, Align::Center, Align::Max] {
+                let digits = shown_digits(
+                    &SegmentedDisplayWidget::from_int(-7)
+                        .align(align)
+                        .digit_count(Some(5)),
+                );
+                assert_eq!(digits[0], seven_segment_digits("-")[0], "{align:?}");
+            }
+        }
+
+        #[test]
+        fn sign_gets_extra_digit_without_digit_count() {
+            let display = SegmentedDisplayWidget::new(DisplayKind::SevenSegment)
+                .negative(true)
+                .push_string("12");
+            assert_eq!(display.content_len(), 3);
+            assert_shows(display, "-12");
+        }
+    }
+    mod sixteen_segment_font {
+        use super::*;
+
+        fn printable_ascii() -> String {
+            (' '..='~').collect()
+        }
+
+        fn sixteen_segment(uppercase_input: bool) -> SegmentedDisplayWidget<'static> {
+            SegmentedDisplayWidget::new(DisplayKind::SixteenSegment)
+                .show_dots(false)
+                .show_colons(false)
+                .show_apostrophes(false)
+                .uppercase_input(uppercase_input)
+                .unknown_char(UnknownCharPolicy::Skip)
+        }
+
+        #[test]
+        fn printable_ascii_has_no_unexpected_blanks() {
+            let text = printable_ascii();
+            let digits = shown_digits(&sixteen_segment(false).push_string(&text));
+
+            assert_eq!(digits.len(), text.len(), "characters were skipped");
+
+            for (c, digit) in text.chars().zip(&digits) {
+                assert_eq!(digit.glyph == 0, c == ' ', "{c:?}");
+            }
+        }
+
+        #[test]
+        fn lowercase_differs_from_uppercase() {
+            let lowercase =
+                shown_digits(&sixteen_segment(false).push_string("abcdefghijklmnopqrstuvwxyz"));
+            let uppercase =
+                shown_digits(&sixteen_segment(false).push_string("ABCDEFGHIJKLMNOPQRSTUVWXYZ"));
+
+            let differing = lowercase
+                .iter()
+                .zip(&uppercase)
+                .filter(|(lower, upper)| lower.glyph != upper.glyph)
+                .count();
+            assert!(differing > 20, "only {differing} lowercase glyphs differ");
+        }
+
+        #[test]
+        fn uppercase_input_folds_before_lookup() {
+            assert_eq!(
+                shown_digits(&sixteen_segment(true).push_string("hello, world")),
+                shown_digits(&sixteen_segment(false).push_string("HELLO, WORLD")),
+            );
+        }
+
+        #[test]
+        fn uppercase_input_keeps_multi_char_uppercase() {
+            assert_eq!(fold_to_uppercase('\u{DF}'), '\u{DF}');
+            assert_eq!(fold_to_uppercase('q'), 'Q');
+            assert_eq!(fold_to_uppercase('7'), '7');
+        }
+    }
+
+    mod static_frames {
+        use super::*;
+
+        fn static_frame(ctx: &Context, id: Id) -> Option<StaticFrame> {
+            ctx.memory(|memory| memory.data.get_temp::<StaticFrame>(id.with("static_frame")))
+        }
+
+        #[test]
+        fn static_display_reuses_its_mesh() {
+            let ctx = Context::default();
+            let buffer = SegmentedDisplayWidget::seven_segment("12:34").into_buffer();
+            let display = || SegmentedDisplayWidget::seven_segment("").push_buffer(&buffer);
+
+            let (first_meshes, id) = painted_meshes(&ctx, 0, display());
+            let first_frame = static_frame(&ctx, id).expect("static frame is cached");
+
+            for frame in 1..4 {
+                let (meshes, _) = painted_meshes(&ctx, frame, display());
+                assert_eq!(meshes, first_meshes);
+            }
+
+            let last_frame = static_frame(&ctx, id).expect("static frame is cached");
+            assert!(Arc::ptr_eq(&first_frame.mesh, &last_frame.mesh));
+        }
+
+        #[test]
+        fn changes_repaint_the_display() {
+            let ctx = Context::default();
+            let (meshes, _) = painted_meshes(&ctx, 0, SegmentedDisplayWidget::seven_segment("12"));
+
+            let changed_displays: [fn() -> SegmentedDisplayWidget<'static>; 4] = [
+                || SegmentedDisplayWidget::seven_segment("13"),
+                || SegmentedDisplayWidget::seven_segment("12").brightness(0.5),
+                || SegmentedDisplayWidget::seven_segment("12").negative(true),
+                || SegmentedDisplayWidget::seven_segment("12").digit_count(Some(3)),
+            ];
+
+            for (frame, display) in changed_displays.into_iter().enumerate() {
+                let expected = painted_meshes(&Context::default(), 0, display()).0;
+                let (changed, _) = painted_meshes(&ctx, frame as u32 * 2 + 1, display());
+                assert_eq!(changed, expected);
+                assert_ne!(changed, meshes);
+
+                let (restored, _) = painted_meshes(
+                    &ctx,
+                    frame as u32 * 2 + 2,
+                    SegmentedDisplayWidget::seven_segment("12"),
+                );
+                assert_eq!(restored, meshes);
+            }
+        }
+
+        #[test]
+        fn static_display_doesnt_rebuild_its_geometry() {
+            let ctx = Context::default();
+            let rebuilds_after = |frame, text| {
+                let _ = painted_meshes(&ctx, frame, SegmentedDisplayWidget::seven_segment(text));
+                SegmentedDisplayWidget::geometry_rebuild_count()
+            };
+
+            let initial_count = rebuilds_after(0, "12:34");
+            for frame in 1..4 {
+                assert_eq!(rebuilds_after(frame, "12:34"), initial_count);
+            }
+
+            assert_eq!(rebuilds_after(4, "12:35"), initial_count + 1);
+            assert_eq!(rebuilds_after(5, "12:35"), initial_count + 1);
+        }
+
+        #[test]
+        fn animated_display_is_not_cached() {
+            let ctx = Context::default();
+            let (_, id) = painted_meshes(&ctx, 0, SegmentedDisplayWidget::seven_segment("12"));
+            assert!(static_frame(&ctx, id).is_some());
+
+            let (_, id) = painted_meshes(
+                &ctx,
+                1,
+                SegmentedDisplayWidget::seven_segment("12").blinking(true),
+            );
+            assert!(static_frame(&ctx, id).is_none());
+        }
+    }
+
+    mod glow {
+        use super::*;
+
+        /// Width of the painted segments of an eight on a seven-segment display.
+        fn painted_width(digit_height: f32, glow: Option<DisplayGlow>) -> f32 {
+            let display = SegmentedDisplayWidget::seven_segment("8")
+                .show_dots(false)
+                .show_colons(false)
+                .show_apostrophes(false)
+                .digit_height(digit_height)
+                .glow(glow);
+
+            let (meshes, _) = painted_meshes(&Context::default(), 0, display);
+            meshes
+                .iter()
+                .fold(Rect::NOTHING, |bounds, mesh| {
+                    bounds.union(mesh.calc_bounds())
+                })
+                .width()
+        }
+
+        #[test]
+        fn glow_scales_with_segment_thickness() {
+            let glow = DisplayStylePreset::VacuumFluorescent.glow();
+            assert_eq!(glow, Some(DisplayGlow::new(0.5, 0.19)));
+
+            for digit_height in [40.0, 80.0, 160.0] {
+                let segment_thickness = DisplayMetrics::default().segment_thickness * digit_height;
+                let halo = painted_width(digit_height, glow) - painted_width(digit_height, None);
+
+                // Strokes are centered on the outline, half of the width is outside
+                assert!(
+                    (halo - 0.5 * segment_thickness).abs() < 0.1 * segment_thickness,
+                    "halo of {halo} at digit height {digit_height}"
+                );
+            }
+        }
+
+        #[test]
+        fn style_preset_sets_the_glow() {
+            let display = SegmentedDisplayWidget::seven_segment("8")
+                .style_preset(DisplayStylePreset::GasDischarge);
+            assert_eq!(display.glow, DisplayStylePreset::GasDischarge.glow());
+
+            let display = display.style_preset(DisplayStylePreset::Calculator);
+            assert_eq!(display.glow, None);
+        }
     }
 }
@@ -1,11 +1,270 @@
+use std::collections::VecDeque;
 use std::ffi::OsStr;
-use std::path::{Path, PathBuf};
+use std::fmt;
+use std::hash::Hash;
+use std::path::{Component, Path, PathBuf};
 
-use egui::{Label, Response, Sense, Ui, Widget};
+use egui::text::{CCursor, CCursorRange};
+use egui::text_edit::TextEditState;
+use egui::{
+    vec2, Area, Button, Color32, FontId, Frame, Id, Key, Label, Modifiers, Order, Response,
+    RichText, ScrollArea, Sense, Stroke, TextEdit, TextStyle, Ui, Widget,
+};
 use itertools::Itertools;
 
 use crate::filesystem::path_symbol::PathSymbol;
-use crate::filesystem::{DirectoryContextMenu, DirectoryFilter, DirectoryHoverUi};
+use crate::filesystem::{DirectoryContextMenu, DirectoryFilter, DirectoryHoverUi, PathCompletion};
+
+// ----------------------------------------------------------------------------
+
+/// Text of the path being edited inline.
+#[derive(Clone)]
+struct EditState {
+    text: String,
+    request_focus: bool,
+
+    /// Time of the last change of the text, completions are queried once typing pauses.
+    last_change: f64,
+    /// Text the current completions were queried for.
+    completed_text: Option<String>,
+    completions: Vec<PathBuf>,
+    highlighted: usize,
+}
+
+impl EditState {
+    fn new(text: String) -> Self {
+        Self {
+            text,
+            request_focus: true,
+            last_change: f64::NEG_INFINITY,
+            completed_text: None,
+            completions: Vec::new(),
+            highlighted: 0,
+        }
+    }
+
+    /// Replaces the text, placing the cursor at its end.
+    fn apply_completion(&mut self, ui: &Ui, text_id: Id, completion: &Path) {
+        self.text = completion.display().to_string();
+        self.completed_text = None;
+        self.completions.clear();
+        self.highlighted = 0;
+
+        if let Some(mut text_edit_state) = TextEditState::load(ui.ctx(), text_id) {
+            let cursor = CCursor::new(self.text.chars().count());
+            text_edit_state
+                .cursor
+                .set_char_range(Some(CCursorRange::one(cursor)));
+            text_edit_state.store(ui.ctx(), text_id);
+        }
+    }
+}
+
+/// Segments always shown, even when the bar overflows.
+const MIN_VISIBLE_SEGMENTS: usize = 2;
+
+/// Pause in typing after which the completions get queried, in seconds.
+const COMPLETION_DELAY: f64 = 0.3;
+
+// ----------------------------------------------------------------------------
+
+/// Symbol drawn between the segments of a [`BreadcrumbBar`].
+#[non_exhaustive]
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum BreadcrumbSeparator {
+    /// `/`, like the paths of Unix systems.
+    #[default]
+    Slash,
+
+    /// `\`, like the paths of Windows.
+    Backslash,
+
+    /// `⏵`, like the breadcrumbs of file managers and web pages.
+    Chevron,
+
+    Custom(String),
+}
+
+impl BreadcrumbSeparator {
+    pub fn symbol(&self) -> &str {
+        match self {
+            BreadcrumbSeparator::Slash => "/",
+            BreadcrumbSeparator::Backslash => "\\",
+            BreadcrumbSeparator::Chevron => "\u{23F5}",
+            BreadcrumbSeparator::Custom(symbol) => symbol,
+        }
+    }
+}
+
+impl fmt::Display for BreadcrumbSeparator {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.symbol())
+    }
+}
+
+/// Separator with its appearance, shared by the breadcrumb widgets.
+#[derive(Default)]
+struct SeparatorStyle {
+    separator: BreadcrumbSeparator,
+    color: Option<Color32>,
+    size: Option<f32>,
+}
+
+impl SeparatorStyle {
+    fn font_id(&self, ui: &Ui) -> FontId {
+        let mut font_id = TextStyle::Body.resolve(ui.style());
+
+        if let Some(size) = self.size {
+            font_id.size = size;
+        }
+
+        font_id
+    }
+
+    fn ui(&self, ui: &mut Ui) {
+        let mut text = RichText::new(self.separator.symbol()).font(self.font_id(ui));
+
+        if let Some(color) = self.color {
+            text = text.color(color);
+        }
+
+        ui.add(Label::new(text).selectable(false));
+    }
+}
+
+// ----------------------------------------------------------------------------
+
+/// Paths of the segments from the root directory to the path. The drive prefixes of
+/// Windows paths are merged into the root segment following them.
+fn path_segments(path: &Path, root_directory: &Path) -> Vec<PathBuf> {
+    let components = path.components().collect_vec();
+
+    (0..components.len())
+        .filter(|&n| {
+            !(matches!(components[n], Component::Prefix(_))
+                && matches!(components.get(n + 1), Some(Component::RootDir)))
+        })
+        .map(|n| PathBuf::from_iter(&components[..=n]))
+        .filter(|path_prefix| path_prefix.starts_with(root_directory))
+        .collect()
+}
+
+fn segment_label(path_prefix: &Path) -> String {
+    let component_symbol = path_prefix.symbol();
+    let component_name = path_prefix
+        .file_name()
+        .map(OsStr::to_string_lossy)
+        .unwrap_or_default();
+    format!("{component_symbol} {component_name}")
+}
+
+// ----------------------------------------------------------------------------
+
+/// Navigation history of a [`BreadcrumbBar`], enabling its back and forward buttons.
+///
+/// Kept by the application between frames, either in its own state or in
+/// [`egui::Memory`]. The oldest paths are dropped beyond the capacity of the history.
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct BreadcrumbBarState {
+    entries: VecDeque<PathBuf>,
+    current: usize,
+    capacity: usize,
+}
+
+impl Default for BreadcrumbBarState {
+    fn default() -> Self {
+        Self::new(32)
+    }
+}
+
+impl BreadcrumbBarState {
+    /// Empty history remembering at most `capacity` paths.
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            entries: VecDeque::new(),
+            current: 0,
+            capacity: capacity.max(1),
+        }
+    }
+
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Changes the number of paths remembered, dropping the oldest ones not fitting.
+    pub fn set_capacity(&mut self, capacity: usize) {
+        self.capacity = capacity.max(1);
+        self.trim();
+    }
+
+    pub fn can_go_back(&self) -> bool {
+        self.current > 0
+    }
+
+    pub fn can_go_forward(&self) -> bool {
+        self.current + 1 < self.entries.len()
+    }
+
+    /// Records a visited path, discarding the paths ahead of the current one.
+    /// Visiting the current path again does nothing.
+    pub fn visit(&mut self, path: &Path) {
+        if self
+            .entries
+            .get(self.current)
+            .is_some_and(|entry| entry == path)
+        {
+            return;
+        }
+
+        self.entries.truncate(self.current + 1);
+        self.entries.push_back(path.to_owned());
+        self.trim();
+        self.current = self.entries.len() - 1;
+    }
+
+    /// Steps back in the history, returning the previous path.
+    pub fn go_back(&mut self) -> Option<&Path> {
+        if self.can_go_back() {
+            self.current -= 1;
+            self.entries.get(self.current).map(PathBuf::as_path)
+        } else {
+            None
+        }
+    }
+
+    /// Steps forward in the history, returning the next path.
+    pub fn go_forward(&mut self) -> Option<&Path> {
+        if self.can_go_forward() {
+            self.current += 1;
+            self.entries.get(self.current).map(PathBuf::as_path)
+        } else {
+            None
+        }
+    }
+
+    fn trim(&mut self) {
+        while self.entries.len() > self.capacity {
+            self.entries.pop_front();
+            self.current = self.current.saturating_sub(1);
+        }
+    }
+}
+
+// ----------------------------------------------------------------------------
+
+/// Response of [`BreadcrumbBar::show`].
+pub struct BreadcrumbBarResponse {
+    pub response: Response,
+
+    /// Path up to and including the segment hovered in this frame, for showing previews
+    /// or metadata of the hovered path. Includes the segments listed in the overflow menu.
+    pub hovered_segment: Option<PathBuf>,
+
+    /// Path up to and including the segment clicked in this frame.
+    pub clicked_segment: Option<PathBuf>,
+}
 
 // ----------------------------------------------------------------------------
 
@@ -13,8 +272,13 @@ use crate::filesystem::{DirectoryContextMenu, DirectoryFilter, DirectoryHoverUi}
 pub struct BreadcrumbBar<'a> {
     selected_path: &'a mut PathBuf,
     root_directory: &'a Path,
+    id_source: Option<Id>,
     hide_file_extensions: bool,
     allow_navigation: bool,
+    path_validator: Option<DirectoryFilter<'a>>,
+    path_completion: Option<PathCompletion<'a>>,
+    history: Option<&'a mut BreadcrumbBarState>,
+    separator_style: SeparatorStyle,
 
     file_filter: Option<DirectoryFilter<'a>>,
     file_context_menu: Option<DirectoryContextMenu<'a>>,
@@ -30,8 +294,13 @@ impl<'a> BreadcrumbBar<'a> {
         Self {
             selected_path,
             root_directory,
+            id_source: None,
             hide_file_extensions: false,
             allow_navigation: true,
+            path_validator: None,
+            path_completion: None,
+            history: None,
+            separator_style: SeparatorStyle::default(),
 
             file_filter: None,
             file_context_menu: None,
@@ -43,6 +312,13 @@ impl<'a> BreadcrumbBar<'a> {
         }
     }
 
+    /// Keys the inline editor state of the bar, needed when the bar moves around in
+    /// its `Ui` between frames. Defaults to the position of the bar in the `Ui`.
+    pub fn id_source(mut self, id_source: impl Hash) -> Self {
+        self.id_source = Some(Id::new(id_source));
+        self
+    }
+
     pub fn hide_file_extensions(mut self, hide_file_extensions: bool) -> Self {
         self.hide_file_extensions = hide_file_extensions;
         self
@@ -53,6 +329,49 @@ impl<'a> BreadcrumbBar<'a> {
         self
     }
 
+    /// Checks the paths typed into the inline editor, invalid paths are highlighted and
+    /// can't be committed. Paths outside of the root directory are always rejected.
+    pub fn validate(mut self, validator: impl Fn(&Path) -> bool + 'a) -> Self {
+        self.path_validator = Some(Box::new(validator));
+        self
+    }
+
+    /// Completes the paths typed into the inline editor. Called with the typed text once
+    /// typing pauses, the returned paths are listed below the editor. The Up and Down
+    /// keys move the highlight over the list, and Tab replaces the text with the
+    /// highlighted path.
+    ///
+    /// The paths are not required to exist locally, so virtual and remote filesystems
+    /// can be completed as well.
+    pub fn completion(mut self, completion: impl Fn(&Path) -> Vec<PathBuf> + 'a) -> Self {
+        self.path_completion = Some(Box::new(completion));
+        self
+    }
+
+    /// Adds back and forward buttons to the left of the bar, walking the paths visited
+    /// through the bar. Paths set by the application are recorded too.
+    pub fn history(mut self, history: &'a mut BreadcrumbBarState) -> Self {
+        self.history = Some(history);
+        self
+    }
+
+    pub fn separator(mut self, separator: BreadcrumbSeparator) -> Self {
+        self.separator_style.separator = separator;
+        self
+    }
+
+    /// Color of the separators, the text color of the style by default.
+    pub fn separator_color(mut self, separator_color: Color32) -> Self {
+        self.separator_style.color = Some(separator_color);
+        self
+    }
+
+    /// Font size of the separators, the size of the body text by default.
+    pub fn separator_size(mut self, separator_size: impl Into<f32>) -> Self {
+        self.separator_style.size = Some(separator_size.into());
+        self
+    }
+
     pub fn file_extensions(self, file_extensions: &'a [&'a str]) -> Self {
         self.file_filter(|path| {
             if let Some(file_extension) = path
@@ -116,38 +435,315 @@ impl<'a> BreadcrumbBar<'a> {
 
 // ----------------------------------------------------------------------------
 
-impl<'a> Widget for BreadcrumbBar<'a> {
-    fn ui(self, ui: &mut Ui) -> Response {
-        ui.horizontal(|ui| {
-            let path_cloned = self.selected_path.clone();
-            let components = path_cloned.components().collect_vec();
-
-            for (path_prefix_index, path_prefix) in (0..components.len())
-                .map(|n| components[..=n].iter())
-                .map(PathBuf::from_iter)
-                .enumerate()
-                .filter(|(_, path_prefix)| path_prefix.starts_with(self.root_directory))
+impl<'a> BreadcrumbBar<'a> {
+    /// Number of leading segments collapsed into the overflow button, greedily from the
+    /// left until the rest fits into the available width.
+    fn hidden_segment_count(&self, ui: &Ui, labels: &[String]) -> usize {
+        let font_id = TextStyle::Body.resolve(ui.style());
+        let item_spacing = ui.spacing().item_spacing.x;
+
+        let text_width = |text: &str, font_id: FontId| {
+            ui.painter()
+                .layout_no_wrap(text.to_owned(), font_id, Color32::PLACEHOLDER)
+                .size()
+                .x
+                + item_spacing
+        };
+
+        let separator_width = text_width(
+            self.separator_style.separator.symbol(),
+            self.separator_style.font_id(ui),
+        );
+        let overflow_width = text_width("\u{2026}", font_id.clone())
+            + 2.0 * ui.spacing().button_padding.x
+            + separator_width;
+
+        // Keeps some of the trailing area clickable for editing the path
+        let available_width = ui.available_width() - ui.spacing().interact_size.x;
+
+        let segment_widths = labels
+            .iter()
+            .map(|label| text_width(label, font_id.clone()) + separator_width)
+            .collect_vec();
+
+        let mut total_width = segment_widths.iter().sum::<f32>() - separator_width;
+        let mut hidden_count = 0;
+
+        while total_width > available_width && labels.len() - hidden_count > MIN_VISIBLE_SEGMENTS {
+            if hidden_count == 0 {
+                total_width += overflow_width;
+            }
+
+            total_width -= segment_widths[hidden_count];
+            hidden_count += 1;
+        }
+
+        hidden_count
+    }
+
+    fn is_valid_path(&self, path: &Path) -> bool {
+        path.starts_with(self.root_directory)
+            && self
+                .path_validator
+                .as_ref()
+                .is_none_or(|validator| validator(path))
+    }
+
+    /// Shows the inline path editor, returns `true` when a new path got committed.
+    fn edit_ui(&mut self, ui: &mut Ui, edit_id: Id, mut state: EditState) -> bool {
+        let text_id = edit_id.with("text");
+        let time = ui.input(|input| input.time);
+        let completing = !state.completions.is_empty();
+
+        // Taken before the text editor could move its cursor with them
+        let (key_up, key_down, key_tab) = if completing {
+            ui.input_mut(|input| {
+                (
+                    input.consume_key(Modifiers::NONE, Key::ArrowUp),
+                    input.consume_key(Modifiers::NONE, Key::ArrowDown),
+                    input.consume_key(Modifiers::NONE, Key::Tab),
+                )
+            })
+        } else {
+            (false, false, false)
+        };
+
+        // Tab applies the highlighted completion instead of moving the focus
+        let text_response = ui.add(
+            TextEdit::singleline(&mut state.text)
+                .id(text_id)
+                .lock_focus(completing)
+                .desired_width(f32::INFINITY),
+        );
+
+        if text_response.changed() {
+            state.last_change = time;
+            state.completed_text = None;
+            state.completions.clear();
+            state.highlighted = 0;
+        }
+
+        if let Some(path_completion) = &self.path_completion {
+            if state.completed_text.as_ref() != Some(&state.text) {
+                let remaining = COMPLETION_DELAY - (time - state.last_change);
+
+                if remaining <= 0.0 {
+                    state.completions = path_completion(Path::new(&state.text));
+                    state.completed_text = Some(state.text.clone());
+                    state.highlighted = 0;
+                } else {
+                    ui.ctx().request_repaint_after_secs(remaining as f32);
+                }
+            }
+        }
+
+        if key_up {
+            state.highlighted = state.highlighted.saturating_sub(1);
+        }
+
+        if key_down {
+            state.highlighted =
+                (state.highlighted + 1).min(state.completions.len().saturating_sub(1));
+        }
+
+        let mut applied_completion = key_tab
+            .then(|| state.completions.get(state.highlighted).cloned())
+            .flatten();
+
+        // Completions listed below the editor, clicking them keeps the editor open
+        let completions_rect = (!state.completions.is_empty()).then(|| {
+            Area::new(edit_id.with("completions"))
+                .order(Order::Foreground)
+                .fixed_pos(text_response.rect.left_bottom())
+                .show(ui.ctx(), |ui| {
+                    Frame::popup(ui.style()).show(ui, |ui| {
+                        ui.set_min_width(text_response.rect.width());
+
+                        ScrollArea::vertical()
+                            .max_height(ui.spacing().combo_height)
+                            .show(ui, |ui| {
+                                for (index, completion) in state.completions.iter().enumerate() {
+                                    let item_response = ui.selectable_label(
+                                        index == state.highlighted,
+                                        completion.display().to_string(),
+                                    );
+
+                                    if item_response.clicked() {
+                                        applied_completion = Some(completion.clone());
+                                    }
+
+                                    if index == state.highlighted && (key_up || key_down) {
+                                        item_response.scroll_to_me(None);
+                                    }
+                                }
+                            });
+                    });
+                })
+                .response
+                .rect
+        });
+
+        if let Some(completion) = applied_completion {
+            state.apply_completion(ui, text_id, &completion);
+
+            // Completing a directory lists its contents right away
+            state.last_change = f64::NEG_INFINITY;
+            state.request_focus = true;
+            ui.ctx().request_repaint();
+        }
+
+        let path = PathBuf::from(&state.text);
+        let valid = self.is_valid_path(&path);
+
+        if !valid {
+            ui.painter().rect_stroke(
+                text_response.rect,
+                ui.style().visuals.widgets.inactive.rounding,
+                Stroke::new(1.0, ui.style().visuals.error_fg_color),
+            );
+        }
+
+        if std::mem::take(&mut state.request_focus) {
+            text_response.request_focus();
+        }
+
+        if text_response.lost_focus() {
+            if ui.input(|input| input.key_pressed(Key::Enter)) {
+                if valid {
+                    ui.memory_mut(|memory| memory.data.remove::<EditState>(edit_id));
+                    *self.selected_path = path;
+                    return true;
+                }
+
+                // Invalid paths keep the editor open
+                text_response.request_focus();
+            } else if completions_rect.is_some_and(|completions_rect| {
+                ui.input(|input| input.pointer.interact_pos())
+                    .is_some_and(|pos| completions_rect.contains(pos))
+            }) {
+                text_response.request_focus();
+            } else {
+                // Escape or clicking elsewhere cancels the editing
+                ui.memory_mut(|memory| memory.data.remove::<EditState>(edit_id));
+                return false;
+            }
+        }
+
+        ui.memory_mut(|memory| memory.data.insert_temp(edit_id, state));
+        false
+    }
+
+    /// Shows the back and forward buttons, returns `true` when one of them got clicked.
+    fn history_ui(&mut self, ui: &mut Ui) -> bool {
+        let Some(history) = self.history.as_deref_mut() else {
+            return false;
+        };
+
+        history.visit(self.selected_path);
+
+        let back_response = ui
+            .add_enabled(
+                self.allow_navigation && history.can_go_back(),
+                Button::new("\u{23F4}").small(),
+            )
+            .on_hover_text("Back");
+
+        let forward_response = ui
+            .add_enabled(
+                self.allow_navigation && history.can_go_forward(),
+                Button::new("\u{23F5}").small(),
+            )
+            .on_hover_text("Forward");
+
+        let target_path = if back_response.clicked() {
+            history.go_back()
+        } else if forward_response.clicked() {
+            history.go_forward()
+        } else {
+            None
+        };
+
+        if let Some(target_path) = target_path {
+            *self.selected_path = target_path.to_owned();
+            true
+        } else {
+            false
+        }
+    }
+
+    pub fn show(mut self, ui: &mut Ui) -> BreadcrumbBarResponse {
+        let mut changed = false;
+        let mut hovered_segment = None;
+        let mut clicked_segment = None;
+
+        // Sibling bars would share the id of their horizontal child `Ui`
+        let edit_id = match self.id_source {
+            Some(id_source) => ui.make_persistent_id(id_source),
+            None => ui.next_auto_id(),
+        }
+        .with("edit");
+
+        let mut inner_response = ui.horizontal(|ui| {
+            if self.history_ui(ui) {
+                // Leaves the inline editor for the path walked to
+                ui.memory_mut(|memory| memory.data.remove::<EditState>(edit_id));
+                changed = true;
+            }
+
+            if let Some(state) = ui.memory(|memory| memory.data.get_temp::<EditState>(edit_id)) {
+                changed = self.edit_ui(ui, edit_id, state);
+                return;
+            }
+
+            let segments = path_segments(self.selected_path, self.root_directory);
+            let labels = segments
+                .iter()
+                .map(|path_prefix| segment_label(path_prefix))
+                .collect_vec();
+
+            let hidden_count = self.hidden_segment_count(ui, &labels);
+
+            if hidden_count > 0 {
+                let mut hidden_clicked = None;
+
+                ui.menu_button("\u{2026}", |ui| {
+                    for (path_prefix, label) in segments.iter().zip(&labels).take(hidden_count) {
+                        let item_response = ui.selectable_label(false, label);
+
+                        if item_response.hovered() {
+                            hovered_segment = Some(path_prefix.clone());
+                        }
+
+                        if item_response.clicked() {
+                            hidden_clicked = Some(path_prefix.clone());
+                            ui.close_menu();
+                        }
+                    }
+                });
+
+                self.separator_style.ui(ui);
+
+                if let Some(path_prefix) = hidden_clicked {
+                    if self.allow_navigation {
+                        *self.selected_path = path_prefix.clone();
+                        changed = true;
+                    }
+
+                    clicked_segment = Some(path_prefix);
+                }
+            }
+
+            for (segment_index, (path_prefix, label)) in
+                segments.iter().zip(labels).enumerate().skip(hidden_count)
             {
-                let component_label = {
-                    let component_symbol = path_prefix.symbol();
-                    let component_name = path_prefix
-                        .file_name()
-                        .map(OsStr::to_string_lossy)
-                        .unwrap_or_default();
-                    format!("{component_symbol} {component_name}")
-                };
-
-                let mut response = ui.add(
-                    Label::new(component_label)
-                        .selectable(false)
-                        .sense(Sense::click()),
-                );
+                let mut response =
+                    ui.add(Label::new(label).selectable(false).sense(Sense::click()));
 
                 if path_prefix.is_dir() {
                     if let Some((hover_ui_contents, hover_ui_enabled)) = &self.directory_hover_ui {
-                        if hover_ui_enabled(&path_prefix) {
+                        if hover_ui_enabled(path_prefix) {
                             response =
-                                response.on_hover_ui(|ui| hover_ui_contents(ui, &path_prefix));
+                                response.on_hover_ui(|ui| hover_ui_contents(ui, path_prefix));
                         }
                     }
 
@@ -155,10 +751,10 @@ impl<'a> Widget for BreadcrumbBar<'a> {
                         &self.directory_context_menu
                     {
                         if let Some(resp) = response.context_menu(|ui| {
-                            let context_menu_enabled = context_menu_enabled(&path_prefix);
+                            let context_menu_enabled = context_menu_enabled(path_prefix);
 
                             if context_menu_enabled {
-                                context_menu_contents(ui, &path_prefix);
+                                context_menu_contents(ui, path_prefix);
                             }
 
                             if self.allow_navigation {
@@ -179,18 +775,18 @@ impl<'a> Widget for BreadcrumbBar<'a> {
                     }
                 } else {
                     if let Some((hover_ui_contents, hover_ui_enabled)) = &self.file_hover_ui {
-                        if hover_ui_enabled(&path_prefix) {
+                        if hover_ui_enabled(path_prefix) {
                             response =
-                                response.on_hover_ui(|ui| hover_ui_contents(ui, &path_prefix));
+                                response.on_hover_ui(|ui| hover_ui_contents(ui, path_prefix));
                         }
                     }
 
                     if let Some((context_menu_contents, context_menu_enabled)) =
                         &self.file_context_menu
                     {
-                        if context_menu_enabled(&path_prefix) {
+                        if context_menu_enabled(path_prefix) {
                             if let Some(resp) =
-                                response.context_menu(|ui| context_menu_contents(ui, &path_prefix))
+                                response.context_menu(|ui| context_menu_contents(ui, path_prefix))
                             {
                                 response = resp.response;
                             }
@@ -198,18 +794,202 @@ impl<'a> Widget for BreadcrumbBar<'a> {
                     }
                 }
 
-                if response.clicked() && self.allow_navigation {
-                    *self.selected_path = path_prefix.clone();
-                    response.mark_changed();
+                if response.hovered() {
+                    hovered_segment = Some(path_prefix.clone());
+                }
+
+                if response.clicked() {
+                    if self.allow_navigation {
+                        *self.selected_path = path_prefix.clone();
+                        changed = true;
+                    }
+
+                    clicked_segment = Some(path_prefix.clone());
                 }
 
-                if path_prefix_index < components.len() - 1 {
-                    ui.add(Label::new("\u{23F5}"));
+                if segment_index < segments.len() - 1 {
+                    self.separator_style.ui(ui);
                 }
             }
+
+            // Clicking the empty area after the segments edits the path as text
+            let trailing_response = ui.allocate_response(
+                vec2(ui.available_width(), ui.spacing().interact_size.y),
+                Sense::click(),
+            );
+
+            if trailing_response.clicked() && self.allow_navigation {
+                let state = EditState::new(self.selected_path.display().to_string());
+
+                ui.memory_mut(|memory| memory.data.insert_temp(edit_id, state));
+                ui.ctx().request_repaint();
+            }
+        });
+
+        if changed {
+            if let Some(history) = self.history.as_deref_mut() {
+                history.visit(self.selected_path);
+            }
+
+            inner_response.response.mark_changed();
+        }
+
+        BreadcrumbBarResponse {
+            response: inner_response.response,
+            hovered_segment,
+            clicked_segment,
+        }
+    }
+}
+
+impl<'a> Widget for BreadcrumbBar<'a> {
+    fn ui(self, ui: &mut Ui) -> Response {
+        self.show(ui).response
+    }
+}
+
+// ----------------------------------------------------------------------------
+
+/// Read-only breadcrumb of a path, for log views and property panels. The segments are
+/// shown like the segments of the [`BreadcrumbBar`], but can't be clicked or edited.
+#[must_use = "You should put this widget in an ui with `ui.add(widget);`"]
+pub struct BreadcrumbDisplay<'a> {
+    path: &'a Path,
+    root_directory: &'a Path,
+    separator_style: SeparatorStyle,
+}
+
+impl<'a> BreadcrumbDisplay<'a> {
+    pub fn new(path: &'a Path) -> Self {
+        Self {
+            path,
+            root_directory: Path::new(""),
+            separator_style: SeparatorStyle::default(),
+        }
+    }
+
+    /// Hides the segments above the root directory, all segments are shown by default.
+    pub fn root_directory(mut self, root_directory: &'a Path) -> Self {
+        self.root_directory = root_directory;
+        self
+    }
+
+    pub fn separator(mut self, separator: BreadcrumbSeparator) -> Self {
+        self.separator_style.separator = separator;
+        self
+    }
+
+    /// Color of the separators, the text color of the style by default.
+    pub fn separator_color(mut self, separator_color: Color32) -> Self {
+        self.separator_style.color = Some(separator_color);
+        self
+    }
+
+    /// Font size of the separators, the size of the body text by default.
+    pub fn separator_size(mut self, separator_size: impl Into<f32>) -> Self {
+        self.separator_style.size = Some(separator_size.into());
+        self
+    }
+}
+
+impl<'a> Widget for BreadcrumbDisplay<'a> {
+    fn ui(self, ui: &mut Ui) -> Response {
+        ui.horizontal(|ui| {
+            let segments = path_segments(self.path, self.root_directory);
+
+            for (segment_index, path_prefix) in segments.iter().enumerate() {
+                if segment_index > 0 {
+                    self.separator_style.ui(ui);
+                }
+
+                ui.add(Label::new(segment_label(path_prefix)).selectable(false));
+            }
+        })
+        .response
+    }
+}
+
+// ----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use egui::{CentralPanel, Context, Event, Modifiers, PointerButton, Pos2, RawInput, Rect};
+    use epaint::Shape;
+
+    use super::*;
+
+    /// Runs a frame with two bars side by side, returning their rects and the texts
+    /// painted in the frame.
+    fn run_frame(
+        ctx: &Context,
+        paths: &mut [PathBuf; 2],
+        frame: usize,
+        events: Vec<Event>,
+    ) -> ([Rect; 2], Vec<String>) {
+        let input = RawInput {
+            screen_rect: Some(Rect::from_min_size(Pos2::ZERO, vec2(1200.0, 400.0))),
+            time: Some(frame as f64 / 60.0),
+            events,
+            ..Default::default()
+        };
+
+        let root_directory = Path::new("/");
+        let mut rects = [Rect::NOTHING; 2];
+
+        let output = ctx.run(input, |ctx| {
+            CentralPanel::default().show(ctx, |ui| {
+                ui.horizontal(|ui| {
+                    for (path, rect) in paths.iter_mut().zip(&mut rects) {
+                        ui.allocate_ui(vec2(500.0, 40.0), |ui| {
+                            *rect = ui.add(BreadcrumbBar::new(path, root_directory)).rect;
+                        });
+                    }
+                });
+            });
         });
 
-        // TODO: response
-        ui.scope(|_| {}).response
+        let texts = output
+            .shapes
+            .into_iter()
+            .filter_map(|clipped_shape| match clipped_shape.shape {
+                Shape::Text(text_shape) => Some(text_shape.galley.text().to_owned()),
+                _ => None,
+            })
+            .collect();
+        (rects, texts)
+    }
+
+    #[test]
+    fn side_by_side_bars_edit_separately() {
+        let ctx = Context::default();
+        let mut paths = [PathBuf::from("/alpha/beta"), PathBuf::from("/gamma/delta")];
+
+        // The editors show the whole path, the segments only show the names
+        let edited_paths = |texts: &[String]| {
+            texts
+                .iter()
+                .filter(|text| ["/alpha/beta", "/gamma/delta"].contains(&text.as_str()))
+                .cloned()
+                .collect_vec()
+        };
+
+        let (rects, texts) = run_frame(&ctx, &mut paths, 0, Vec::new());
+        assert!(edited_paths(&texts).is_empty());
+
+        // Clicking the trailing area of the first bar opens its inline editor
+        let click_pos = rects[0].right_center() - vec2(4.0, 0.0);
+        let button = |pressed| Event::PointerButton {
+            pos: click_pos,
+            button: PointerButton::Primary,
+            pressed,
+            modifiers: Modifiers::NONE,
+        };
+
+        run_frame(&ctx, &mut paths, 1, vec![Event::PointerMoved(click_pos)]);
+        run_frame(&ctx, &mut paths, 2, vec![button(true)]);
+        run_frame(&ctx, &mut paths, 3, vec![button(false)]);
+        let (_, texts) = run_frame(&ctx, &mut paths, 4, Vec::new());
+
+        assert_eq!(edited_paths(&texts), ["/alpha/beta"]);
     }
 }
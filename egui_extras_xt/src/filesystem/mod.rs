@@ -3,16 +3,21 @@ mod directory_cache;
 mod directory_tree_view;
 mod path_symbol;
 
-pub use breadcrumb_bar::BreadcrumbBar;
+pub use breadcrumb_bar::{
+    BreadcrumbBar, BreadcrumbBarResponse, BreadcrumbBarState, BreadcrumbDisplay,
+    BreadcrumbSeparator,
+};
 pub use directory_tree_view::DirectoryTreeViewWidget;
 
 // ----------------------------------------------------------------------------
 
 use egui::Ui;
-use std::path::Path;
+use std::path::{Path, PathBuf};
 
 pub type DirectoryFilter<'a> = Box<dyn Fn(&Path) -> bool + 'a>;
 
+pub type PathCompletion<'a> = Box<dyn Fn(&Path) -> Vec<PathBuf> + 'a>;
+
 pub type DirectoryContextMenu<'a> = (
     Box<dyn Fn(&mut Ui, &Path) + 'a>,
     Box<dyn Fn(&Path) -> bool + 'a>,
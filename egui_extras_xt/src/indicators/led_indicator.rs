@@ -0,0 +1,147 @@
+use egui::{self, Color32, Painter, Rect, Response, Sense, Shape, Stroke, Ui, Widget};
+use emath::Vec2;
+use strum::{Display, EnumIter};
+
+// ----------------------------------------------------------------------------
+
+#[non_exhaustive]
+#[derive(Clone, Copy, Debug, Display, EnumIter, Eq, PartialEq)]
+pub enum LedShape {
+    #[strum(to_string = "Circle")]
+    Circle,
+
+    #[strum(to_string = "Square")]
+    Square,
+
+    #[strum(to_string = "Diamond")]
+    Diamond,
+}
+
+impl LedShape {
+    pub(crate) fn paint(&self, painter: &Painter, rect: Rect, fill: Color32, stroke: Stroke) {
+        match *self {
+            LedShape::Circle => {
+                painter.circle(rect.center(), rect.width() / 2.0, fill, stroke);
+            }
+            LedShape::Square => {
+                painter.rect(rect, rect.width() / 6.0, fill, stroke);
+            }
+            LedShape::Diamond => {
+                painter.add(Shape::convex_polygon(
+                    vec![
+                        rect.center_top(),
+                        rect.right_center(),
+                        rect.center_bottom(),
+                        rect.left_center(),
+                    ],
+                    fill,
+                    stroke,
+                ));
+            }
+        }
+    }
+}
+
+// ----------------------------------------------------------------------------
+
+#[must_use = "You should put this widget in an ui with `ui.add(widget);`"]
+pub struct LedIndicator {
+    state: bool,
+    diameter: f32,
+    shape: LedShape,
+    on_color: Color32,
+    off_color: Color32,
+    blink_rate: f32,
+    show_highlight: bool,
+}
+
+impl LedIndicator {
+    pub fn new(state: bool) -> Self {
+        Self {
+            state,
+            diameter: 16.0,
+            shape: LedShape::Circle,
+            on_color: Color32::from_rgb(0x40, 0xE0, 0x40),
+            off_color: Color32::from_rgb(0x10, 0x30, 0x10),
+            blink_rate: 0.0,
+            show_highlight: true,
+        }
+    }
+
+    pub fn diameter(mut self, diameter: impl Into<f32>) -> Self {
+        self.diameter = diameter.into();
+        self
+    }
+
+    pub fn shape(mut self, shape: LedShape) -> Self {
+        self.shape = shape;
+        self
+    }
+
+    pub fn on_color(mut self, on_color: Color32) -> Self {
+        self.on_color = on_color;
+        self
+    }
+
+    pub fn off_color(mut self, off_color: Color32) -> Self {
+        self.off_color = off_color;
+        self
+    }
+
+    /// Blinks the LED with the given frequency in hertz, overriding its state.
+    /// Blinking always starts with the LED lit, zero disables blinking.
+    pub fn blink_rate(mut self, blink_rate: f32) -> Self {
+        self.blink_rate = blink_rate;
+        self
+    }
+
+    pub fn show_highlight(mut self, show_highlight: bool) -> Self {
+        self.show_highlight = show_highlight;
+        self
+    }
+}
+
+impl Widget for LedIndicator {
+    fn ui(self, ui: &mut Ui) -> Response {
+        let desired_size = Vec2::splat(self.diameter);
+
+        let (rect, response) = ui.allocate_exact_size(desired_size, Sense::hover());
+
+        let lit = if self.blink_rate > 0.0 {
+            let time = ui.input(|input| input.time);
+
+            // The start of the blinking is remembered, so the blink phase starts from the on state
+            let blink_start =
+                ui.memory_mut(|memory| *memory.data.get_temp_mut_or(response.id, time));
+            let blink_phase = ((time - blink_start) * self.blink_rate as f64).fract();
+
+            ui.ctx().request_repaint_after_secs(
+                ((0.5 - blink_phase).rem_euclid(0.5) / self.blink_rate as f64) as f32,
+            );
+
+            blink_phase < 0.5
+        } else {
+            ui.memory_mut(|memory| memory.data.remove::<f64>(response.id));
+            self.state
+        };
+
+        if ui.is_rect_visible(rect) {
+            let fill = if lit { self.on_color } else { self.off_color };
+            let stroke = Stroke::new(1.0, ui.style().visuals.window_stroke().color);
+
+            let radius = self.diameter / 2.0;
+
+            self.shape.paint(ui.painter(), rect, fill, stroke);
+
+            if self.show_highlight {
+                let highlight_center = rect.center() - Vec2::splat(radius * 0.3);
+                let highlight_color = Color32::from_white_alpha(if lit { 0xA0 } else { 0x40 });
+
+                ui.painter()
+                    .circle_filled(highlight_center, radius * 0.25, highlight_color);
+            }
+        }
+
+        response
+    }
+}
@@ -0,0 +1,5 @@
+mod led_indicator;
+mod progress_ring;
+
+pub use led_indicator::{LedIndicator, LedShape};
+pub use progress_ring::ProgressRing;
@@ -0,0 +1,206 @@
+use std::f32::consts::TAU;
+
+use egui::{self, Color32, Response, Sense, Stroke, Ui, Widget};
+use emath::{lerp, Vec2};
+
+use crate::common::{Orientation, WidgetShape, WidgetShapeError, Winding};
+
+// ----------------------------------------------------------------------------
+
+/// Number of differently colored pieces a full ring is split into when painting gradients.
+const GRADIENT_RESOLUTION: usize = 64;
+
+/// Length of the spinning arc in indeterminate mode, relative to the full ring.
+const INDETERMINATE_ARC_LENGTH: f32 = 0.25;
+
+/// Full turns per second of the spinning arc in indeterminate mode.
+const INDETERMINATE_SPEED: f64 = 1.0;
+
+type ColorGradient<'a> = Box<dyn 'a + Fn(f32) -> Color32>;
+
+// ----------------------------------------------------------------------------
+
+/// Ring shaped progress indicator for progress values in the `0.0..=1.0` range.
+#[must_use = "You should put this widget in an ui with `ui.add(widget);`"]
+pub struct ProgressRing<'a> {
+    progress: f32,
+    diameter: f32,
+    thickness: f32,
+    orientation: Orientation,
+    winding: Winding,
+    shape: WidgetShape,
+    shape_error: Option<WidgetShapeError>,
+    shape_resolution: Option<usize>,
+    gradient: Option<ColorGradient<'a>>,
+    indeterminate: bool,
+}
+
+impl<'a> ProgressRing<'a> {
+    pub fn new(progress: f32) -> Self {
+        Self {
+            progress,
+            diameter: 48.0,
+            thickness: 0.25,
+            orientation: Orientation::Top,
+            winding: Winding::Clockwise,
+            shape: WidgetShape::Circle,
+            shape_error: None,
+            shape_resolution: None,
+            gradient: None,
+            indeterminate: false,
+        }
+    }
+
+    pub fn diameter(mut self, diameter: impl Into<f32>) -> Self {
+        self.diameter = diameter.into();
+        self
+    }
+
+    /// Width of the ring relative to its radius.
+    pub fn thickness(mut self, thickness: impl Into<f32>) -> Self {
+        self.thickness = thickness.into();
+        self
+    }
+
+    /// Direction where the progress starts from.
+    pub fn orientation(mut self, orientation: Orientation) -> Self {
+        self.orientation = orientation;
+        self
+    }
+
+    pub fn winding(mut self, winding: Winding) -> Self {
+        self.winding = winding;
+        self
+    }
+
+    /// Invalid shapes are painted as a `\u{1F525}` error label in place of the progress ring, use
+    /// [`try_shape`](Self::try_shape) to handle them in code.
+    pub fn shape(mut self, shape: WidgetShape) -> Self {
+        self.shape_error = shape.validate().err();
+        self.shape = shape;
+        self
+    }
+
+    /// Sets the shape after checking it with [`WidgetShape::validate`].
+    pub fn try_shape(mut self, shape: WidgetShape) -> Result<Self, WidgetShapeError> {
+        shape.validate()?;
+        self.shape_error = None;
+        self.shape = shape;
+        Ok(self)
+    }
+
+    /// Vertices of the shape outline per full turn. By default derived from the size of
+    /// the widget, about one vertex per 2.5 points of the circumference.
+    pub fn shape_resolution(mut self, shape_resolution: Option<usize>) -> Self {
+        self.shape_resolution = shape_resolution;
+        self
+    }
+
+    /// Colors the filled arc by the position along the ring, in the `0.0..=1.0` range.
+    pub fn gradient(mut self, gradient: impl 'a + Fn(f32) -> Color32) -> Self {
+        self.gradient = Some(Box::new(gradient));
+        self
+    }
+
+    /// Ignores the progress value and spins an arc around the ring.
+    pub fn indeterminate(mut self, indeterminate: bool) -> Self {
+        self.indeterminate = indeterminate;
+        self
+    }
+}
+
+impl<'a> Widget for ProgressRing<'a> {
+    fn ui(self, ui: &mut Ui) -> Response {
+        if let Some(shape_error) = self.shape_error {
+            return ui.colored_label(
+                ui.style().visuals.error_fg_color,
+                format!("\u{1F525} Invalid progress ring shape: {shape_error}"),
+            );
+        }
+
+        let desired_size = Vec2::splat(self.diameter);
+
+        let (rect, response) = ui.allocate_exact_size(desired_size, Sense::hover());
+
+        let (arc_start, arc_end) = if self.indeterminate {
+            let time = ui.input(|input| input.time);
+            ui.ctx().request_repaint_after_secs(1.0 / 60.0);
+
+            let head = (time * INDETERMINATE_SPEED).fract() as f32;
+            (head, head + INDETERMINATE_ARC_LENGTH)
+        } else {
+            (0.0, self.progress.clamp(0.0, 1.0))
+        };
+
+        if ui.is_rect_visible(rect) {
+            let outer_radius = self.diameter / 2.0;
+            let inner_radius = outer_radius * (1.0 - self.thickness.clamp(0.0, 1.0));
+
+            let to_angle = |position: f32| {
+                self.orientation.angle_rad() + position * TAU * self.winding.to_float()
+            };
+
+            self.shape.paint_arc(
+                ui,
+                rect.center(),
+                inner_radius,
+                outer_radius,
+                to_angle(0.0),
+                to_angle(1.0),
+                ui.style().visuals.faint_bg_color,
+                ui.style().visuals.window_stroke(),
+                self.orientation.rot2(),
+                self.shape_resolution,
+            );
+
+            if let Some(gradient) = &self.gradient {
+                let piece_count =
+                    (((arc_end - arc_start) * GRADIENT_RESOLUTION as f32).ceil() as usize).max(1);
+
+                for piece_index in 0..piece_count {
+                    let piece_start =
+                        lerp(arc_start..=arc_end, piece_index as f32 / piece_count as f32);
+                    let piece_end = lerp(
+                        arc_start..=arc_end,
+                        (piece_index + 1) as f32 / piece_count as f32,
+                    );
+
+                    // Indeterminate arcs carry the whole gradient along, progress arcs reveal it
+                    let gradient_position = if self.indeterminate {
+                        (piece_index as f32 + 0.5) / piece_count as f32
+                    } else {
+                        (piece_start + piece_end) / 2.0
+                    };
+
+                    self.shape.paint_arc(
+                        ui,
+                        rect.center(),
+                        inner_radius,
+                        outer_radius,
+                        to_angle(piece_start),
+                        to_angle(piece_end),
+                        gradient(gradient_position),
+                        Stroke::NONE,
+                        self.orientation.rot2(),
+                        self.shape_resolution,
+                    );
+                }
+            } else {
+                self.shape.paint_arc(
+                    ui,
+                    rect.center(),
+                    inner_radius,
+                    outer_radius,
+                    to_angle(arc_start),
+                    to_angle(arc_end),
+                    ui.style().visuals.selection.bg_fill,
+                    Stroke::NONE,
+                    self.orientation.rot2(),
+                    self.shape_resolution,
+                );
+            }
+        }
+
+        response
+    }
+}
@@ -5,7 +5,9 @@ use emath::Vec2;
 use epaint::{Shape, Stroke};
 use strum::{Display, EnumIter};
 
-use crate::common::{snap_wrap_constrain_angle, Orientation, WidgetShape, Winding, WrapMode};
+use crate::common::{
+    snap_wrap_constrain_angle, Orientation, WidgetShape, WidgetShapeError, Winding, WrapMode,
+};
 
 // ----------------------------------------------------------------------------
 
@@ -100,6 +102,8 @@ pub struct AngleKnob<'a> {
     winding: Winding,
     wrap: WrapMode,
     shape: WidgetShape,
+    shape_error: Option<WidgetShapeError>,
+    shape_resolution: Option<usize>,
     min: Option<f32>,
     max: Option<f32>,
     snap: Option<f32>,
@@ -128,6 +132,8 @@ impl<'a> AngleKnob<'a> {
             winding: Winding::Clockwise,
             wrap: WrapMode::Unsigned,
             shape: WidgetShape::Circle,
+            shape_error: None,
+            shape_resolution: None,
             min: None,
             max: None,
             snap: None,
@@ -163,11 +169,29 @@ impl<'a> AngleKnob<'a> {
         self
     }
 
+    /// Invalid shapes are painted as a `\u{1F525}` error label in place of the knob, use
+    /// [`try_shape`](Self::try_shape) to handle them in code.
     pub fn shape(mut self, shape: WidgetShape) -> Self {
+        self.shape_error = shape.validate().err();
         self.shape = shape;
         self
     }
 
+    /// Sets the shape after checking it with [`WidgetShape::validate`].
+    pub fn try_shape(mut self, shape: WidgetShape) -> Result<Self, WidgetShapeError> {
+        shape.validate()?;
+        self.shape_error = None;
+        self.shape = shape;
+        Ok(self)
+    }
+
+    /// Vertices of the shape outline per full turn. By default derived from the size of
+    /// the widget, about one vertex per 2.5 points of the circumference.
+    pub fn shape_resolution(mut self, shape_resolution: Option<usize>) -> Self {
+        self.shape_resolution = shape_resolution;
+        self
+    }
+
     pub fn wrap(mut self, wrap: WrapMode) -> Self {
         self.wrap = wrap;
         self
@@ -211,6 +235,13 @@ impl<'a> AngleKnob<'a> {
 
 impl<'a> Widget for AngleKnob<'a> {
     fn ui(mut self, ui: &mut Ui) -> Response {
+        if let Some(shape_error) = self.shape_error {
+            return ui.colored_label(
+                ui.style().visuals.error_fg_color,
+                format!("\u{1F525} Invalid knob shape: {shape_error}"),
+            );
+        }
+
         let desired_size = Vec2::splat(self.diameter);
 
         let (rect, mut response) = ui.allocate_exact_size(
@@ -275,6 +306,7 @@ impl<'a> Widget for AngleKnob<'a> {
                 visuals.bg_fill,
                 visuals.fg_stroke,
                 self.orientation.rot2(),
+                self.shape_resolution,
             );
 
             {
@@ -355,3 +387,26 @@ impl<'a> Widget for AngleKnob<'a> {
         response
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn try_shape_reports_invalid_shapes() {
+        let mut value = 0.0;
+
+        assert_eq!(
+            AngleKnob::new(&mut value)
+                .try_shape(WidgetShape::Polygon(2))
+                .err(),
+            Some(WidgetShapeError::PolygonTooFewSides(2))
+        );
+
+        // A valid shape clears the error of an earlier invalid one
+        let knob = AngleKnob::new(&mut value)
+            .shape(WidgetShape::Squircle(0.0))
+            .try_shape(WidgetShape::Star(5, 0.5));
+        assert!(knob.is_ok_and(|knob| knob.shape_error.is_none()));
+    }
+}
@@ -4,7 +4,7 @@ use std::ops::RangeInclusive;
 use egui::{self, Response, Sense, Ui, Widget};
 use emath::{remap_clamp, Vec2};
 
-use crate::common::{Orientation, WidgetShape, Winding};
+use crate::common::{Orientation, WidgetShape, WidgetShapeError, Winding};
 
 // ----------------------------------------------------------------------------
 
@@ -34,6 +34,8 @@ pub struct AudioKnob<'a> {
     spread: f32,
     thickness: f32,
     shape: WidgetShape,
+    shape_error: Option<WidgetShapeError>,
+    shape_resolution: Option<usize>,
     animated: bool,
     snap: Option<f32>,
     shift_snap: Option<f32>,
@@ -61,6 +63,8 @@ impl<'a> AudioKnob<'a> {
             spread: 1.0,
             thickness: 0.66,
             shape: WidgetShape::Squircle(4.0),
+            shape_error: None,
+            shape_resolution: None,
             animated: true,
             snap: None,
             shift_snap: None,
@@ -107,11 +111,29 @@ impl<'a> AudioKnob<'a> {
         self
     }
 
+    /// Invalid shapes are painted as a `\u{1F525}` error label in place of the knob, use
+    /// [`try_shape`](Self::try_shape) to handle them in code.
     pub fn shape(mut self, shape: WidgetShape) -> Self {
+        self.shape_error = shape.validate().err();
         self.shape = shape;
         self
     }
 
+    /// Sets the shape after checking it with [`WidgetShape::validate`].
+    pub fn try_shape(mut self, shape: WidgetShape) -> Result<Self, WidgetShapeError> {
+        shape.validate()?;
+        self.shape_error = None;
+        self.shape = shape;
+        Ok(self)
+    }
+
+    /// Vertices of the shape outline per full turn. By default derived from the size of
+    /// the widget, about one vertex per 2.5 points of the circumference.
+    pub fn shape_resolution(mut self, shape_resolution: Option<usize>) -> Self {
+        self.shape_resolution = shape_resolution;
+        self
+    }
+
     pub fn animated(mut self, animated: bool) -> Self {
         self.animated = animated;
         self
@@ -130,6 +152,13 @@ impl<'a> AudioKnob<'a> {
 
 impl<'a> Widget for AudioKnob<'a> {
     fn ui(mut self, ui: &mut Ui) -> Response {
+        if let Some(shape_error) = self.shape_error {
+            return ui.colored_label(
+                ui.style().visuals.error_fg_color,
+                format!("\u{1F525} Invalid knob shape: {shape_error}"),
+            );
+        }
+
         let desired_size = Vec2::splat(self.diameter);
 
         let (rect, mut response) = ui.allocate_exact_size(
@@ -212,6 +241,7 @@ impl<'a> Widget for AudioKnob<'a> {
                 ui.style().visuals.faint_bg_color,
                 ui.style().visuals.window_stroke(),
                 self.orientation.rot2(),
+                self.shape_resolution,
             );
 
             self.shape.paint_arc(
@@ -224,6 +254,7 @@ impl<'a> Widget for AudioKnob<'a> {
                 visuals.bg_fill,
                 visuals.fg_stroke,
                 self.orientation.rot2(),
+                self.shape_resolution,
             );
         }
 
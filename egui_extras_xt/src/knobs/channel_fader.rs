@@ -0,0 +1,308 @@
+use egui::{
+    self, pos2, remap_clamp, Align2, FontId, Rect, Response, Sense, Stroke, TextStyle, Ui, Widget,
+};
+use emath::{vec2, Vec2};
+
+// ----------------------------------------------------------------------------
+
+/// Combined into one function (rather than two) to make it easier
+/// for the borrow checker.
+type GetSetValue<'a> = Box<dyn 'a + FnMut(Option<f32>) -> f32>;
+
+fn get(get_set_value: &mut GetSetValue<'_>) -> f32 {
+    (get_set_value)(None)
+}
+
+fn set(get_set_value: &mut GetSetValue<'_>, value: f32) {
+    (get_set_value)(Some(value));
+}
+
+// ----------------------------------------------------------------------------
+
+const DEFAULT_SCALE_MARKS: &[f32] = &[6.0, 0.0, -6.0, -12.0, -24.0];
+
+/// Levels covered by the travel of the fader, below the top of the fader.
+/// The bottom stop of the fader is always `-inf`.
+const TAPER_RANGE_DB: f32 = 60.0;
+
+fn format_db(db: f32) -> String {
+    if db == f32::NEG_INFINITY {
+        "-inf".to_owned()
+    } else if db > 0.0 {
+        format!("+{db}")
+    } else {
+        format!("{db}")
+    }
+}
+
+// ----------------------------------------------------------------------------
+
+/// Vertical mixer fader with a scale in dB.
+///
+/// The bound value is the position of the cap, linear in `0.0..=1.0` from the bottom
+/// to the top of the fader. The travel follows a dB taper: the level rises evenly by
+/// the dB from [`max_db`](Self::max_db) - 60 dB to [`max_db`](Self::max_db) at the top,
+/// the bottom stop is silence. Use [`ChannelFader::position_to_db`] to get the level
+/// of a position. Double-clicking resets the fader to unity (0 dB).
+#[must_use = "You should put this widget in an ui with `ui.add(widget);`"]
+pub struct ChannelFader<'a> {
+    get_set_value: GetSetValue<'a>,
+    max_db: f32,
+    scale_marks: &'a [f32],
+    channel_label: Option<String>,
+    show_value_tooltip: bool,
+    size: Vec2,
+    cap_size: Vec2,
+    interactive: bool,
+    animated: bool,
+}
+
+impl<'a> ChannelFader<'a> {
+    pub fn new(value: &'a mut f32) -> Self {
+        Self::from_get_set(move |v: Option<f32>| {
+            if let Some(v) = v {
+                *value = v;
+            }
+            *value
+        })
+    }
+
+    pub fn from_get_set(get_set_value: impl 'a + FnMut(Option<f32>) -> f32) -> Self {
+        Self {
+            get_set_value: Box::new(get_set_value),
+            max_db: 6.0,
+            scale_marks: DEFAULT_SCALE_MARKS,
+            channel_label: None,
+            show_value_tooltip: true,
+            size: vec2(64.0, 192.0),
+            cap_size: vec2(28.0, 12.0),
+            interactive: true,
+            animated: false,
+        }
+    }
+
+    /// Level at the given fader position on a fader topping out at `max_db`.
+    pub fn position_to_db(position: f32, max_db: f32) -> f32 {
+        if position <= 0.0 {
+            f32::NEG_INFINITY
+        } else {
+            max_db - (1.0 - position.min(1.0)) * TAPER_RANGE_DB
+        }
+    }
+
+    /// Fader position of the given level on a fader topping out at `max_db`.
+    /// Levels outside of the travel of the fader are clamped to its ends.
+    pub fn db_to_position(db: f32, max_db: f32) -> f32 {
+        ((db - max_db) / TAPER_RANGE_DB + 1.0).clamp(0.0, 1.0)
+    }
+
+    /// Level at the top of the fader.
+    pub fn max_db(mut self, max_db: f32) -> Self {
+        self.max_db = max_db;
+        self
+    }
+
+    /// Levels marked on the scale in dB, the `-inf` mark at the bottom is always shown.
+    pub fn scale_marks(mut self, scale_marks: &'a [f32]) -> Self {
+        self.scale_marks = scale_marks;
+        self
+    }
+
+    /// Text shown below the fader.
+    pub fn channel_label(mut self, channel_label: impl ToString) -> Self {
+        self.channel_label = Some(channel_label.to_string());
+        self
+    }
+
+    /// Shows the level in dB while hovering or dragging the fader.
+    pub fn show_value_tooltip(mut self, show_value_tooltip: bool) -> Self {
+        self.show_value_tooltip = show_value_tooltip;
+        self
+    }
+
+    /// Size of the fader, without the channel label.
+    pub fn size(mut self, size: impl Into<Vec2>) -> Self {
+        self.size = size.into();
+        self
+    }
+
+    pub fn cap_size(mut self, cap_size: impl Into<Vec2>) -> Self {
+        self.cap_size = cap_size.into();
+        self
+    }
+
+    pub fn interactive(mut self, interactive: bool) -> Self {
+        self.interactive = interactive;
+        self
+    }
+
+    pub fn animated(mut self, animated: bool) -> Self {
+        self.animated = animated;
+        self
+    }
+}
+
+impl<'a> Widget for ChannelFader<'a> {
+    fn ui(mut self, ui: &mut Ui) -> Response {
+        let font_id = TextStyle::Small.resolve(ui.style());
+
+        let label_height = if self.channel_label.is_some() {
+            ui.fonts(|fonts| fonts.row_height(&font_id)) + ui.spacing().item_spacing.y
+        } else {
+            0.0
+        };
+
+        let (rect, mut response) = ui.allocate_exact_size(
+            self.size + vec2(0.0, label_height),
+            if self.interactive {
+                Sense::click_and_drag()
+            } else {
+                Sense::hover()
+            },
+        );
+
+        let fader_rect = Rect::from_min_size(rect.min, self.size);
+
+        // Travel of the center of the cap, the track sits on the right half of the widget
+        let track_x = fader_rect.right() - self.cap_size.x / 2.0;
+        let track_y_range = (fader_rect.bottom() - self.cap_size.y / 2.0)
+            ..=(fader_rect.top() + self.cap_size.y / 2.0);
+
+        let old_position = get(&mut self.get_set_value);
+
+        if response.double_clicked() {
+            set(
+                &mut self.get_set_value,
+                Self::db_to_position(0.0, self.max_db),
+            );
+        } else if response.dragged() || response.clicked() {
+            if let Some(pointer_pos) = response.interact_pointer_pos() {
+                set(
+                    &mut self.get_set_value,
+                    remap_clamp(pointer_pos.y, track_y_range.clone(), 0.0..=1.0),
+                );
+            }
+        }
+
+        if get(&mut self.get_set_value) != old_position {
+            response.mark_changed();
+        }
+
+        if self.show_value_tooltip {
+            let db = Self::position_to_db(get(&mut self.get_set_value), self.max_db);
+            let db = (db * 10.0).round() / 10.0;
+            response = response.on_hover_text_at_pointer(format!("{} dB", format_db(db)));
+        }
+
+        if ui.is_rect_visible(rect) {
+            let visuals = *ui.style().interact(&response);
+            let text_color = ui.style().visuals.text_color();
+
+            let position = {
+                let position = get(&mut self.get_set_value);
+
+                if self.animated {
+                    ui.ctx().animate_value_with_time(response.id, position, 0.1)
+                } else {
+                    position
+                }
+            };
+
+            let position_to_y =
+                |position: f32| remap_clamp(position, 0.0..=1.0, track_y_range.clone());
+
+            // Slot
+            ui.painter().rect(
+                Rect::from_center_size(
+                    pos2(track_x, fader_rect.center().y),
+                    vec2(4.0, fader_rect.height() - self.cap_size.y / 2.0),
+                ),
+                2.0,
+                ui.style().visuals.extreme_bg_color,
+                ui.style().visuals.window_stroke(),
+            );
+
+            // Scale
+            let tick_right = track_x - self.cap_size.x / 2.0 - 2.0;
+            let tick_stroke = ui.style().visuals.noninteractive().fg_stroke;
+
+            let scale_marks = self
+                .scale_marks
+                .iter()
+                .copied()
+                .filter(|&db| (self.max_db - TAPER_RANGE_DB..=self.max_db).contains(&db))
+                .chain(std::iter::once(f32::NEG_INFINITY));
+
+            for db in scale_marks {
+                let y = position_to_y(Self::db_to_position(db, self.max_db));
+
+                ui.painter().line_segment(
+                    [pos2(tick_right - 4.0, y), pos2(tick_right, y)],
+                    tick_stroke,
+                );
+
+                ui.painter().text(
+                    pos2(tick_right - 6.0, y),
+                    Align2::RIGHT_CENTER,
+                    format_db(db),
+                    FontId::monospace(font_id.size),
+                    text_color,
+                );
+            }
+
+            // Cap
+            let cap_rect =
+                Rect::from_center_size(pos2(track_x, position_to_y(position)), self.cap_size);
+
+            ui.painter().rect(
+                cap_rect,
+                visuals.rounding,
+                visuals.bg_fill,
+                visuals.fg_stroke,
+            );
+
+            ui.painter().line_segment(
+                [cap_rect.left_center(), cap_rect.right_center()],
+                Stroke::new(2.0, visuals.fg_stroke.color),
+            );
+
+            if let Some(channel_label) = &self.channel_label {
+                ui.painter().text(
+                    pos2(track_x, rect.bottom()),
+                    Align2::CENTER_BOTTOM,
+                    channel_label,
+                    font_id,
+                    text_color,
+                );
+            }
+        }
+
+        response
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ChannelFader;
+
+    #[test]
+    fn travel_follows_a_db_taper() {
+        assert_eq!(ChannelFader::position_to_db(1.0, 6.0), 6.0);
+        assert_eq!(ChannelFader::position_to_db(0.9, 6.0).round(), 0.0);
+        assert_eq!(ChannelFader::position_to_db(0.5, 6.0).round(), -24.0);
+        assert_eq!(ChannelFader::position_to_db(0.0, 6.0), f32::NEG_INFINITY);
+    }
+
+    #[test]
+    fn positions_stay_in_the_unit_range() {
+        assert_eq!(ChannelFader::db_to_position(12.0, 6.0), 1.0);
+        assert_eq!(ChannelFader::db_to_position(-100.0, 6.0), 0.0);
+        assert_eq!(ChannelFader::db_to_position(f32::NEG_INFINITY, 6.0), 0.0);
+
+        for db in [6.0, 0.0, -6.0, -12.0, -24.0] {
+            let position = ChannelFader::db_to_position(db, 6.0);
+            assert!((0.0..=1.0).contains(&position));
+            assert!((ChannelFader::position_to_db(position, 6.0) - db).abs() < 1e-4);
+        }
+    }
+}
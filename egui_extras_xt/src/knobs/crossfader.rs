@@ -0,0 +1,215 @@
+use egui::{self, pos2, remap_clamp, Color32, Rect, Response, Sense, Stroke, Ui, Widget};
+use emath::{vec2, Vec2};
+
+// ----------------------------------------------------------------------------
+
+/// Combined into one function (rather than two) to make it easier
+/// for the borrow checker.
+type GetSetValue<'a> = Box<dyn 'a + FnMut(Option<f32>) -> f32>;
+
+fn get(get_set_value: &mut GetSetValue<'_>) -> f32 {
+    (get_set_value)(None)
+}
+
+fn set(get_set_value: &mut GetSetValue<'_>, value: f32) {
+    (get_set_value)(Some(value));
+}
+
+// ----------------------------------------------------------------------------
+
+/// Horizontal fader for values in the `-1.0..=1.0` range, blending between a left
+/// and a right source like the crossfaders of DJ mixers.
+///
+/// Values close to the center snap to zero, double-clicking resets the fader to the center.
+#[must_use = "You should put this widget in an ui with `ui.add(widget);`"]
+pub struct CrossFader<'a> {
+    get_set_value: GetSetValue<'a>,
+    detent_threshold: f32,
+    size: Vec2,
+    track_thickness: f32,
+    cap_width: f32,
+    left_color: Color32,
+    right_color: Color32,
+    interactive: bool,
+    animated: bool,
+}
+
+impl<'a> CrossFader<'a> {
+    pub fn new(value: &'a mut f32) -> Self {
+        Self::from_get_set(move |v: Option<f32>| {
+            if let Some(v) = v {
+                *value = v;
+            }
+            *value
+        })
+    }
+
+    pub fn from_get_set(get_set_value: impl 'a + FnMut(Option<f32>) -> f32) -> Self {
+        Self {
+            get_set_value: Box::new(get_set_value),
+            detent_threshold: 0.03,
+            size: vec2(192.0, 32.0),
+            track_thickness: 6.0,
+            cap_width: 16.0,
+            left_color: Color32::from_rgb(0x28, 0x8C, 0xE6),
+            right_color: Color32::from_rgb(0xE6, 0x8C, 0x28),
+            interactive: true,
+            animated: false,
+        }
+    }
+
+    /// Distance from the center within which the value snaps to zero.
+    pub fn detent_threshold(mut self, detent_threshold: f32) -> Self {
+        self.detent_threshold = detent_threshold;
+        self
+    }
+
+    pub fn size(mut self, size: impl Into<Vec2>) -> Self {
+        self.size = size.into();
+        self
+    }
+
+    pub fn track_thickness(mut self, track_thickness: impl Into<f32>) -> Self {
+        self.track_thickness = track_thickness.into();
+        self
+    }
+
+    pub fn cap_width(mut self, cap_width: impl Into<f32>) -> Self {
+        self.cap_width = cap_width.into();
+        self
+    }
+
+    pub fn left_color(mut self, left_color: Color32) -> Self {
+        self.left_color = left_color;
+        self
+    }
+
+    pub fn right_color(mut self, right_color: Color32) -> Self {
+        self.right_color = right_color;
+        self
+    }
+
+    pub fn interactive(mut self, interactive: bool) -> Self {
+        self.interactive = interactive;
+        self
+    }
+
+    pub fn animated(mut self, animated: bool) -> Self {
+        self.animated = animated;
+        self
+    }
+}
+
+impl<'a> Widget for CrossFader<'a> {
+    fn ui(mut self, ui: &mut Ui) -> Response {
+        let (rect, mut response) = ui.allocate_exact_size(
+            self.size,
+            if self.interactive {
+                Sense::click_and_drag()
+            } else {
+                Sense::hover()
+            },
+        );
+
+        // Travel of the center of the cap
+        let track_x_range =
+            (rect.left() + self.cap_width / 2.0)..=(rect.right() - self.cap_width / 2.0);
+
+        let old_value = get(&mut self.get_set_value);
+
+        if response.double_clicked() {
+            set(&mut self.get_set_value, 0.0);
+        } else if response.dragged() || response.clicked() {
+            if let Some(pointer_pos) = response.interact_pointer_pos() {
+                let value = remap_clamp(pointer_pos.x, track_x_range.clone(), -1.0..=1.0);
+
+                set(
+                    &mut self.get_set_value,
+                    if value.abs() < self.detent_threshold {
+                        0.0
+                    } else {
+                        value
+                    },
+                );
+            }
+        }
+
+        if get(&mut self.get_set_value) != old_value {
+            response.mark_changed();
+        }
+
+        if ui.is_rect_visible(rect) {
+            let visuals = *ui.style().interact(&response);
+
+            let value = {
+                let value = get(&mut self.get_set_value);
+
+                if self.animated {
+                    ui.ctx().animate_value_with_time(response.id, value, 0.1)
+                } else {
+                    value
+                }
+            };
+
+            let center_x = rect.center().x;
+            let value_x = remap_clamp(value, -1.0..=1.0, track_x_range.clone());
+
+            let track_rect = |x_from: f32, x_to: f32| {
+                Rect::from_x_y_ranges(
+                    x_from.min(x_to)..=x_from.max(x_to),
+                    (rect.center().y - self.track_thickness / 2.0)
+                        ..=(rect.center().y + self.track_thickness / 2.0),
+                )
+            };
+
+            // Dimmed halves, with the part between the center and the cap lit
+            ui.painter().rect_filled(
+                track_rect(*track_x_range.start(), center_x),
+                0.0,
+                self.left_color.gamma_multiply(0.25),
+            );
+            ui.painter().rect_filled(
+                track_rect(center_x, *track_x_range.end()),
+                0.0,
+                self.right_color.gamma_multiply(0.25),
+            );
+            ui.painter().rect_filled(
+                track_rect(center_x, value_x),
+                0.0,
+                if value < 0.0 {
+                    self.left_color
+                } else {
+                    self.right_color
+                },
+            );
+
+            ui.painter().line_segment(
+                [pos2(center_x, rect.top()), pos2(center_x, rect.bottom())],
+                ui.style().visuals.noninteractive().fg_stroke,
+            );
+
+            let cap_rect = Rect::from_center_size(
+                pos2(value_x, rect.center().y),
+                vec2(self.cap_width, rect.height()),
+            );
+
+            ui.painter().rect(
+                cap_rect,
+                visuals.rounding,
+                visuals.bg_fill,
+                visuals.fg_stroke,
+            );
+
+            // Grip line
+            ui.painter().line_segment(
+                [
+                    pos2(value_x, cap_rect.top() + cap_rect.height() * 0.2),
+                    pos2(value_x, cap_rect.bottom() - cap_rect.height() * 0.2),
+                ],
+                Stroke::new(2.0, visuals.fg_stroke.color),
+            );
+        }
+
+        response
+    }
+}
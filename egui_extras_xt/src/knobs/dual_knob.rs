@@ -0,0 +1,334 @@
+use std::f32::consts::TAU;
+use std::ops::RangeInclusive;
+
+use egui::{self, Pos2, Response, Sense, Ui, Widget};
+use emath::{remap_clamp, Vec2};
+
+use crate::common::{Orientation, WidgetShape, WidgetShapeError, Winding};
+
+// ----------------------------------------------------------------------------
+
+/// Combined into one function (rather than two) to make it easier
+/// for the borrow checker.
+type GetSetValue<'a> = Box<dyn 'a + FnMut(Option<f32>) -> f32>;
+
+fn get(get_set_value: &mut GetSetValue<'_>) -> f32 {
+    (get_set_value)(None)
+}
+
+fn set(get_set_value: &mut GetSetValue<'_>, value: f32) {
+    (get_set_value)(Some(value));
+}
+
+// ----------------------------------------------------------------------------
+
+/// Radius splitting the inner and the outer rings, relative to the knob radius.
+const SPLIT_RADIUS: f32 = 0.5;
+
+#[derive(Clone, Copy, PartialEq)]
+enum Ring {
+    Inner,
+    Outer,
+}
+
+/// Two concentric [`AudioKnob`](crate::knobs::AudioKnob)-style rings bound to separate values.
+///
+/// Dragging inside the middle of the knob adjusts the inner value, dragging near the
+/// edge adjusts the outer value.
+#[must_use = "You should put this widget in an ui with `ui.add(widget);`"]
+pub struct DualKnob<'a> {
+    get_set_inner_value: GetSetValue<'a>,
+    get_set_outer_value: GetSetValue<'a>,
+    inner_range: RangeInclusive<f32>,
+    outer_range: RangeInclusive<f32>,
+    inner_label: Option<String>,
+    outer_label: Option<String>,
+    interactive: bool,
+    diameter: f32,
+    drag_length: f32,
+    winding: Winding,
+    orientation: Orientation,
+    spread: f32,
+    shape: WidgetShape,
+    shape_error: Option<WidgetShapeError>,
+    shape_resolution: Option<usize>,
+    animated: bool,
+}
+
+impl<'a> DualKnob<'a> {
+    pub fn new(inner_value: &'a mut f32, outer_value: &'a mut f32) -> Self {
+        Self::from_get_set(
+            move |v: Option<f32>| {
+                if let Some(v) = v {
+                    *inner_value = v;
+                }
+                *inner_value
+            },
+            move |v: Option<f32>| {
+                if let Some(v) = v {
+                    *outer_value = v;
+                }
+                *outer_value
+            },
+        )
+    }
+
+    pub fn from_get_set(
+        get_set_inner_value: impl 'a + FnMut(Option<f32>) -> f32,
+        get_set_outer_value: impl 'a + FnMut(Option<f32>) -> f32,
+    ) -> Self {
+        Self {
+            get_set_inner_value: Box::new(get_set_inner_value),
+            get_set_outer_value: Box::new(get_set_outer_value),
+            inner_range: 0.0..=1.0,
+            outer_range: 0.0..=1.0,
+            inner_label: None,
+            outer_label: None,
+            interactive: true,
+            diameter: 48.0,
+            drag_length: 1.0,
+            orientation: Orientation::Top,
+            winding: Winding::Clockwise,
+            spread: 1.0,
+            shape: WidgetShape::Squircle(4.0),
+            shape_error: None,
+            shape_resolution: None,
+            animated: true,
+        }
+    }
+
+    pub fn inner_range(mut self, inner_range: RangeInclusive<f32>) -> Self {
+        self.inner_range = inner_range;
+        self
+    }
+
+    pub fn outer_range(mut self, outer_range: RangeInclusive<f32>) -> Self {
+        self.outer_range = outer_range;
+        self
+    }
+
+    /// Name of the inner value, shown when hovering the inner ring.
+    pub fn inner_label(mut self, inner_label: impl Into<String>) -> Self {
+        self.inner_label = Some(inner_label.into());
+        self
+    }
+
+    /// Name of the outer value, shown when hovering the outer ring.
+    pub fn outer_label(mut self, outer_label: impl Into<String>) -> Self {
+        self.outer_label = Some(outer_label.into());
+        self
+    }
+
+    pub fn interactive(mut self, interactive: bool) -> Self {
+        self.interactive = interactive;
+        self
+    }
+
+    pub fn diameter(mut self, diameter: impl Into<f32>) -> Self {
+        self.diameter = diameter.into();
+        self
+    }
+
+    pub fn drag_length(mut self, drag_length: impl Into<f32>) -> Self {
+        self.drag_length = drag_length.into();
+        self
+    }
+
+    pub fn winding(mut self, winding: Winding) -> Self {
+        self.winding = winding;
+        self
+    }
+
+    pub fn orientation(mut self, orientation: Orientation) -> Self {
+        self.orientation = orientation;
+        self
+    }
+
+    pub fn spread(mut self, spread: impl Into<f32>) -> Self {
+        self.spread = spread.into();
+        self
+    }
+
+    /// Invalid shapes are painted as a `\u{1F525}` error label in place of the knob, use
+    /// [`try_shape`](Self::try_shape) to handle them in code.
+    pub fn shape(mut self, shape: WidgetShape) -> Self {
+        self.shape_error = shape.validate().err();
+        self.shape = shape;
+        self
+    }
+
+    /// Sets the shape after checking it with [`WidgetShape::validate`].
+    pub fn try_shape(mut self, shape: WidgetShape) -> Result<Self, WidgetShapeError> {
+        shape.validate()?;
+        self.shape_error = None;
+        self.shape = shape;
+        Ok(self)
+    }
+
+    /// Vertices of the shape outline per full turn. By default derived from the size of
+    /// the widget, about one vertex per 2.5 points of the circumference.
+    pub fn shape_resolution(mut self, shape_resolution: Option<usize>) -> Self {
+        self.shape_resolution = shape_resolution;
+        self
+    }
+
+    pub fn animated(mut self, animated: bool) -> Self {
+        self.animated = animated;
+        self
+    }
+
+    /// The ring under the given position, using the knob shape as the distance metric.
+    fn ring_at(&self, center: Pos2, pos: Pos2) -> Ring {
+        let offset = pos - center;
+        let shape_radius = (self.diameter / 2.0)
+            * self
+                .shape
+                .eval(offset.angle() - self.orientation.angle_rad());
+
+        if offset.length() < shape_radius * SPLIT_RADIUS {
+            Ring::Inner
+        } else {
+            Ring::Outer
+        }
+    }
+}
+
+impl<'a> Widget for DualKnob<'a> {
+    fn ui(mut self, ui: &mut Ui) -> Response {
+        if let Some(shape_error) = self.shape_error {
+            return ui.colored_label(
+                ui.style().visuals.error_fg_color,
+                format!("\u{1F525} Invalid knob shape: {shape_error}"),
+            );
+        }
+
+        let desired_size = Vec2::splat(self.diameter);
+
+        let (rect, mut response) = ui.allocate_exact_size(
+            desired_size,
+            if self.interactive {
+                Sense::click_and_drag()
+            } else {
+                Sense::hover()
+            },
+        );
+
+        // The dragged ring is decided when the drag starts
+        if response.drag_started() {
+            if let Some(pointer_pos) = response.interact_pointer_pos() {
+                let ring = self.ring_at(rect.center(), pointer_pos);
+                ui.memory_mut(|memory| memory.data.insert_temp(response.id, ring));
+            }
+        }
+
+        if response.dragged() {
+            if let Some(ring) = ui.memory(|memory| memory.data.get_temp::<Ring>(response.id)) {
+                let (get_set_value, range) = match ring {
+                    Ring::Inner => (&mut self.get_set_inner_value, self.inner_range.clone()),
+                    Ring::Outer => (&mut self.get_set_outer_value, self.outer_range.clone()),
+                };
+
+                let drag_delta = self.orientation.rot2().inverse() * response.drag_delta();
+                let delta = drag_delta.x + drag_delta.y * self.winding.to_float();
+
+                let new_value = get(get_set_value)
+                    + delta * (range.end() - range.start()) / (self.diameter * self.drag_length);
+
+                set(get_set_value, new_value.clamp(*range.start(), *range.end()));
+                response.mark_changed();
+            }
+        }
+
+        if response.drag_stopped() {
+            ui.memory_mut(|memory| memory.data.remove::<Ring>(response.id));
+        }
+
+        let inner_value = get(&mut self.get_set_inner_value);
+        let outer_value = get(&mut self.get_set_outer_value);
+
+        if ui.is_rect_visible(rect) {
+            let visuals = *ui.style().interact(&response);
+
+            let center_angle = self.orientation.angle_rad();
+            let spread_angle = (TAU / 2.0) * self.spread.clamp(0.0, 1.0);
+
+            let (min_angle, max_angle) = (
+                center_angle - spread_angle * self.winding.to_float(),
+                center_angle + spread_angle * self.winding.to_float(),
+            );
+
+            let radius = self.diameter / 2.0;
+
+            let mut paint_ring = |ring: Ring,
+                                  value: f32,
+                                  range: RangeInclusive<f32>,
+                                  inner_radius: f32,
+                                  outer_radius: f32| {
+                let value = if self.animated && !response.dragged() {
+                    ui.ctx().animate_value_with_time(
+                        response.id.with(ring == Ring::Inner),
+                        value,
+                        ui.style().animation_time,
+                    )
+                } else {
+                    value
+                };
+
+                self.shape.paint_arc(
+                    ui,
+                    rect.center(),
+                    inner_radius,
+                    outer_radius,
+                    min_angle,
+                    max_angle,
+                    ui.style().visuals.faint_bg_color,
+                    ui.style().visuals.window_stroke(),
+                    self.orientation.rot2(),
+                    self.shape_resolution,
+                );
+
+                self.shape.paint_arc(
+                    ui,
+                    rect.center(),
+                    inner_radius,
+                    outer_radius,
+                    remap_clamp(*range.start(), range.clone(), min_angle..=max_angle),
+                    remap_clamp(value, range, min_angle..=max_angle),
+                    visuals.bg_fill,
+                    visuals.fg_stroke,
+                    self.orientation.rot2(),
+                    self.shape_resolution,
+                );
+            };
+
+            paint_ring(
+                Ring::Outer,
+                outer_value,
+                self.outer_range.clone(),
+                radius * (SPLIT_RADIUS + 0.1),
+                radius,
+            );
+
+            paint_ring(
+                Ring::Inner,
+                inner_value,
+                self.inner_range.clone(),
+                0.0,
+                radius * (SPLIT_RADIUS - 0.05),
+            );
+        }
+
+        if let Some(hover_pos) = response.hover_pos() {
+            let (label, value) = match self.ring_at(rect.center(), hover_pos) {
+                Ring::Inner => (&self.inner_label, inner_value),
+                Ring::Outer => (&self.outer_label, outer_value),
+            };
+
+            if let Some(label) = label {
+                response = response.on_hover_text(format!("{label}: {value:.2}"));
+            }
+        }
+
+        response
+    }
+}
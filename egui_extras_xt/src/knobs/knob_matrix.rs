@@ -0,0 +1,233 @@
+use std::ops::RangeInclusive;
+
+use egui::{self, Grid, Label, Response, RichText, Sense, Ui, Widget};
+
+use crate::common::{Orientation, WidgetShape, Winding};
+use crate::knobs::AudioKnob;
+
+// ----------------------------------------------------------------------------
+
+/// Response of [`KnobMatrix::show`].
+pub struct KnobMatrixResponse {
+    pub response: Response,
+
+    /// Row and column of the cell changed by the user in this frame. In gang mode the
+    /// other cells change along with it.
+    pub changed_cell: Option<(usize, usize)>,
+}
+
+// ----------------------------------------------------------------------------
+
+/// Grid of [`AudioKnob`]s sharing the same configuration, one knob per value.
+///
+/// Dragging a knob while holding Ctrl changes all knobs by the same amount (gang mode).
+/// Clicking the value label below a knob resets it to the default value.
+#[must_use = "You should put this widget in an ui with `ui.add(widget);`"]
+pub struct KnobMatrix<'a> {
+    values: &'a mut Vec<Vec<f32>>,
+    range: RangeInclusive<f32>,
+    default_value: Option<f32>,
+    row_labels: &'a [&'a str],
+    column_labels: &'a [&'a str],
+    show_value_labels: bool,
+    diameter: f32,
+    drag_length: f32,
+    winding: Winding,
+    orientation: Orientation,
+    spread: f32,
+    thickness: f32,
+    shape: WidgetShape,
+    shape_resolution: Option<usize>,
+}
+
+impl<'a> KnobMatrix<'a> {
+    pub fn new(values: &'a mut Vec<Vec<f32>>) -> Self {
+        Self {
+            values,
+            range: 0.0..=1.0,
+            default_value: None,
+            row_labels: &[],
+            column_labels: &[],
+            show_value_labels: true,
+            diameter: 32.0,
+            drag_length: 1.0,
+            winding: Winding::Clockwise,
+            orientation: Orientation::Top,
+            spread: 1.0,
+            thickness: 0.66,
+            shape: WidgetShape::Squircle(4.0),
+            shape_resolution: None,
+        }
+    }
+
+    pub fn range(mut self, range: RangeInclusive<f32>) -> Self {
+        self.range = range;
+        self
+    }
+
+    /// Value the cells reset to, the start of the range by default.
+    pub fn default_value(mut self, default_value: f32) -> Self {
+        self.default_value = Some(default_value);
+        self
+    }
+
+    pub fn row_labels(mut self, row_labels: &'a [&'a str]) -> Self {
+        self.row_labels = row_labels;
+        self
+    }
+
+    pub fn column_labels(mut self, column_labels: &'a [&'a str]) -> Self {
+        self.column_labels = column_labels;
+        self
+    }
+
+    /// Shows the value of each knob below it.
+    pub fn show_value_labels(mut self, show_value_labels: bool) -> Self {
+        self.show_value_labels = show_value_labels;
+        self
+    }
+
+    pub fn diameter(mut self, diameter: impl Into<f32>) -> Self {
+        self.diameter = diameter.into();
+        self
+    }
+
+    pub fn drag_length(mut self, drag_length: impl Into<f32>) -> Self {
+        self.drag_length = drag_length.into();
+        self
+    }
+
+    pub fn winding(mut self, winding: Winding) -> Self {
+        self.winding = winding;
+        self
+    }
+
+    pub fn orientation(mut self, orientation: Orientation) -> Self {
+        self.orientation = orientation;
+        self
+    }
+
+    pub fn spread(mut self, spread: impl Into<f32>) -> Self {
+        self.spread = spread.into();
+        self
+    }
+
+    pub fn thickness(mut self, thickness: impl Into<f32>) -> Self {
+        self.thickness = thickness.into();
+        self
+    }
+
+    pub fn shape(mut self, shape: WidgetShape) -> Self {
+        self.shape = shape;
+        self
+    }
+
+    /// Vertices of the shape outline per full turn. By default derived from the size of
+    /// the widget, about one vertex per 2.5 points of the circumference.
+    pub fn shape_resolution(mut self, shape_resolution: Option<usize>) -> Self {
+        self.shape_resolution = shape_resolution;
+        self
+    }
+
+    pub fn show(self, ui: &mut Ui) -> KnobMatrixResponse {
+        let default_value = self.default_value.unwrap_or(*self.range.start());
+        let column_count = self.values.iter().map(Vec::len).max().unwrap_or(0);
+
+        let mut changed_cell = None;
+        let mut gang_delta = None;
+
+        let inner_response = Grid::new(ui.next_auto_id())
+            .num_columns(column_count + usize::from(!self.row_labels.is_empty()))
+            .show(ui, |ui| {
+                if !self.column_labels.is_empty() {
+                    if !self.row_labels.is_empty() {
+                        ui.label("");
+                    }
+                    for column_label in self.column_labels.iter().take(column_count) {
+                        ui.vertical_centered(|ui| ui.label(*column_label));
+                    }
+                    ui.end_row();
+                }
+
+                for (row, row_values) in self.values.iter_mut().enumerate() {
+                    if !self.row_labels.is_empty() {
+                        ui.label(self.row_labels.get(row).copied().unwrap_or_default());
+                    }
+
+                    for (column, value) in row_values.iter_mut().enumerate() {
+                        ui.vertical_centered(|ui| {
+                            let old_value = *value;
+
+                            let knob_response = ui.add(
+                                AudioKnob::new(value)
+                                    .range(self.range.clone())
+                                    .diameter(self.diameter)
+                                    .drag_length(self.drag_length)
+                                    .winding(self.winding)
+                                    .orientation(self.orientation)
+                                    .spread(self.spread)
+                                    .thickness(self.thickness)
+                                    .shape(self.shape.clone())
+                                    .shape_resolution(self.shape_resolution),
+                            );
+
+                            if knob_response.changed() {
+                                changed_cell = Some((row, column));
+
+                                if ui.input(|input| input.modifiers.command) {
+                                    gang_delta = Some(*value - old_value);
+                                }
+                            }
+
+                            if self.show_value_labels {
+                                let label_response = ui
+                                    .add(
+                                        Label::new(RichText::new(format!("{value:.2}")).small())
+                                            .sense(Sense::click()),
+                                    )
+                                    .on_hover_text("Click to reset");
+
+                                if label_response.clicked() {
+                                    *value = default_value;
+                                    changed_cell = Some((row, column));
+                                }
+                            }
+                        });
+                    }
+
+                    ui.end_row();
+                }
+            });
+
+        if let (Some(delta), Some(changed_cell)) = (gang_delta, changed_cell) {
+            let (min, max) = (
+                self.range.start().min(*self.range.end()),
+                self.range.start().max(*self.range.end()),
+            );
+
+            for (row, row_values) in self.values.iter_mut().enumerate() {
+                for (column, value) in row_values.iter_mut().enumerate() {
+                    if (row, column) != changed_cell {
+                        *value = (*value + delta).clamp(min, max);
+                    }
+                }
+            }
+        }
+
+        let mut response = inner_response.response;
+        if changed_cell.is_some() {
+            response.mark_changed();
+        }
+
+        KnobMatrixResponse {
+            response,
+            changed_cell,
+        }
+    }
+}
+
+impl<'a> Widget for KnobMatrix<'a> {
+    fn ui(self, ui: &mut Ui) -> Response {
+        self.show(ui).response
+    }
+}
@@ -1,7 +1,19 @@
 mod angle_knob;
 mod audio_knob;
+mod channel_fader;
+mod crossfader;
+mod dual_knob;
+mod knob_matrix;
+mod rotary_switch;
 mod thumbstick_widget;
+mod xy_pad;
 
 pub use angle_knob::{AngleKnob, AngleKnobPreset};
 pub use audio_knob::AudioKnob;
+pub use channel_fader::ChannelFader;
+pub use crossfader::CrossFader;
+pub use dual_knob::DualKnob;
+pub use knob_matrix::{KnobMatrix, KnobMatrixResponse};
+pub use rotary_switch::RotarySwitch;
 pub use thumbstick_widget::{ThumbstickDeadZone, ThumbstickSnap, ThumbstickWidget};
+pub use xy_pad::XyPad;
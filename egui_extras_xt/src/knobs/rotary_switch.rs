@@ -0,0 +1,219 @@
+use std::f32::consts::TAU;
+
+use egui::{self, Align2, FontFamily, FontId, Response, Sense, Stroke, Ui, Widget};
+use emath::Vec2;
+
+use crate::common::{angle_delta, Orientation};
+
+// ----------------------------------------------------------------------------
+
+/// Duration of the highlight flash after switching to a new position, in seconds.
+const CLICK_FEEDBACK_DURATION: f64 = 0.15;
+
+// ----------------------------------------------------------------------------
+
+/// Knob with discrete labeled positions, like the mode selectors of measuring instruments.
+///
+/// Moving across multiple positions steps through them one frame at a time, so
+/// [`Response::changed`] is reported for each crossed position.
+#[must_use = "You should put this widget in an ui with `ui.add(widget);`"]
+pub struct RotarySwitch<'a, T: Clone + PartialEq> {
+    value: &'a mut T,
+    positions: Vec<(T, &'a str)>,
+    interactive: bool,
+    diameter: f32,
+    orientation: Orientation,
+    sweep: f32,
+}
+
+impl<'a, T: Clone + PartialEq> RotarySwitch<'a, T> {
+    pub fn new(value: &'a mut T, positions: Vec<(T, &'a str)>) -> Self {
+        Self {
+            value,
+            positions,
+            interactive: true,
+            diameter: 96.0,
+            orientation: Orientation::Top,
+            sweep: TAU * 0.75,
+        }
+    }
+
+    pub fn interactive(mut self, interactive: bool) -> Self {
+        self.interactive = interactive;
+        self
+    }
+
+    pub fn diameter(mut self, diameter: impl Into<f32>) -> Self {
+        self.diameter = diameter.into();
+        self
+    }
+
+    /// Direction of the middle of the position range.
+    pub fn orientation(mut self, orientation: Orientation) -> Self {
+        self.orientation = orientation;
+        self
+    }
+
+    /// Angle between the first and the last positions, clockwise.
+    pub fn sweep(mut self, sweep: impl Into<f32>) -> Self {
+        self.sweep = sweep.into();
+        self
+    }
+
+    fn position_angle(&self, index: usize) -> f32 {
+        let t = if self.positions.len() > 1 {
+            index as f32 / (self.positions.len() - 1) as f32
+        } else {
+            0.5
+        };
+
+        self.orientation.angle_rad() + self.sweep * (t - 0.5)
+    }
+}
+
+impl<'a, T: Clone + PartialEq> Widget for RotarySwitch<'a, T> {
+    fn ui(self, ui: &mut Ui) -> Response {
+        let desired_size = Vec2::splat(self.diameter);
+
+        let (rect, mut response) = ui.allocate_exact_size(
+            desired_size,
+            if self.interactive {
+                Sense::click_and_drag()
+            } else {
+                Sense::hover()
+            },
+        );
+
+        let time = ui.input(|input| input.time);
+        let current_index = self
+            .positions
+            .iter()
+            .position(|(position, _)| position == self.value);
+
+        let target_id = response.id.with("target");
+
+        if (response.clicked() || response.dragged()) && !self.positions.is_empty() {
+            let pointer_angle = (response.interact_pointer_pos().unwrap() - rect.center()).angle();
+
+            let target_index = (0..self.positions.len())
+                .min_by(|&a, &b| {
+                    angle_delta(pointer_angle, self.position_angle(a))
+                        .abs()
+                        .total_cmp(&angle_delta(pointer_angle, self.position_angle(b)).abs())
+                })
+                .unwrap();
+
+            ui.memory_mut(|memory| memory.data.insert_temp(target_id, target_index));
+        }
+
+        // Step one position per frame towards the target, so every crossed position
+        // gets reported, even after the pointer got released.
+        if let Some(target_index) = ui
+            .memory(|memory| memory.data.get_temp::<usize>(target_id))
+            .filter(|&target_index| target_index < self.positions.len())
+        {
+            let next_index = match current_index {
+                Some(current_index) if current_index < target_index => current_index + 1,
+                Some(current_index) if current_index > target_index => current_index - 1,
+                Some(current_index) => current_index,
+                None => target_index,
+            };
+
+            if Some(next_index) != current_index {
+                *self.value = self.positions[next_index].0.clone();
+                response.mark_changed();
+
+                ui.memory_mut(|memory| memory.data.insert_temp(response.id, time));
+            }
+
+            if next_index == target_index {
+                ui.memory_mut(|memory| memory.data.remove::<usize>(target_id));
+            } else {
+                ui.ctx().request_repaint();
+            }
+        }
+
+        if ui.is_rect_visible(rect) {
+            let visuals = *ui.style().interact(&response);
+
+            let radius = self.diameter / 2.0;
+            let knob_radius = radius * 0.55;
+            let label_radius = radius * 0.85;
+            let font_id = FontId::new(radius * 0.2, FontFamily::Proportional);
+
+            let current_index = self
+                .positions
+                .iter()
+                .position(|(position, _)| position == self.value);
+
+            for (index, (_, label)) in self.positions.iter().enumerate() {
+                let direction = Vec2::angled(self.position_angle(index));
+                let selected = Some(index) == current_index;
+
+                let text_color = if selected {
+                    visuals.text_color()
+                } else {
+                    ui.style().visuals.weak_text_color()
+                };
+
+                ui.painter().line_segment(
+                    [
+                        rect.center() + direction * (knob_radius + radius * 0.05),
+                        rect.center() + direction * (knob_radius + radius * 0.15),
+                    ],
+                    Stroke::new(1.0, text_color),
+                );
+
+                ui.painter().text(
+                    rect.center() + direction * label_radius,
+                    Align2::CENTER_CENTER,
+                    label,
+                    font_id.clone(),
+                    text_color,
+                );
+            }
+
+            // Brief flash after each position change
+            let click_feedback = {
+                let last_click = ui
+                    .memory(|memory| memory.data.get_temp::<f64>(response.id))
+                    .unwrap_or(f64::NEG_INFINITY);
+                let elapsed = time - last_click;
+
+                if elapsed < CLICK_FEEDBACK_DURATION {
+                    ui.ctx().request_repaint();
+                    1.0 - (elapsed / CLICK_FEEDBACK_DURATION) as f32
+                } else {
+                    0.0
+                }
+            };
+
+            ui.painter().circle(
+                rect.center(),
+                knob_radius,
+                visuals.bg_fill,
+                Stroke::new(
+                    visuals.fg_stroke.width * (1.0 + click_feedback),
+                    visuals
+                        .fg_stroke
+                        .color
+                        .lerp_to_gamma(ui.style().visuals.selection.stroke.color, click_feedback),
+                ),
+            );
+
+            if let Some(current_index) = current_index {
+                let direction = Vec2::angled(self.position_angle(current_index));
+
+                ui.painter().line_segment(
+                    [
+                        rect.center() + direction * (knob_radius * 0.3),
+                        rect.center() + direction * (knob_radius * 0.9),
+                    ],
+                    visuals.fg_stroke,
+                );
+            }
+        }
+
+        response
+    }
+}
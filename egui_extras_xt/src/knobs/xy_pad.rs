@@ -0,0 +1,324 @@
+use std::ops::RangeInclusive;
+
+use egui::{self, pos2, remap, remap_clamp, Response, Sense, Stroke, Ui, Widget};
+use emath::Vec2;
+
+use crate::common::WrapMode;
+
+// ----------------------------------------------------------------------------
+
+/// Combined into one function (rather than two) to make it easier
+/// for the borrow checker.
+type GetSetValue<'a> = Box<dyn 'a + FnMut(Option<(f32, f32)>) -> (f32, f32)>;
+
+fn get(get_set_value: &mut GetSetValue<'_>) -> (f32, f32) {
+    (get_set_value)(None)
+}
+
+fn set(get_set_value: &mut GetSetValue<'_>, value: (f32, f32)) {
+    (get_set_value)(Some(value));
+}
+
+// ----------------------------------------------------------------------------
+
+fn constrain_axis(
+    value: f32,
+    range: &RangeInclusive<f32>,
+    wrap: WrapMode,
+    grid: Option<f32>,
+    snap_to_grid: bool,
+) -> f32 {
+    let (min, max) = (
+        range.start().min(*range.end()),
+        range.start().max(*range.end()),
+    );
+
+    let value = match grid {
+        Some(grid) if snap_to_grid && grid > 0.0 => {
+            range.start() + ((value - range.start()) / grid).round() * grid
+        }
+        _ => value,
+    };
+
+    wrap.wrap(value, min, max)
+}
+
+// ----------------------------------------------------------------------------
+
+/// Two-axis pad, setting both values at once by clicking or dragging in it.
+///
+/// The start of the vertical range is at the bottom of the pad.
+/// Double-clicking the pad resets both values to zero.
+#[must_use = "You should put this widget in an ui with `ui.add(widget);`"]
+pub struct XyPad<'a> {
+    get_set_value: GetSetValue<'a>,
+    range_x: RangeInclusive<f32>,
+    range_y: RangeInclusive<f32>,
+    wrap_x: WrapMode,
+    wrap_y: WrapMode,
+    grid_x: Option<f32>,
+    grid_y: Option<f32>,
+    show_grid: bool,
+    snap_to_grid: bool,
+    interactive: bool,
+    size: Vec2,
+    cursor_radius: f32,
+    animated: bool,
+}
+
+impl<'a> XyPad<'a> {
+    pub fn new(x: &'a mut f32, y: &'a mut f32) -> Self {
+        Self::from_get_set(move |v: Option<(f32, f32)>| {
+            if let Some((new_x, new_y)) = v {
+                *x = new_x;
+                *y = new_y;
+            }
+            (*x, *y)
+        })
+    }
+
+    pub fn from_get_set(get_set_value: impl 'a + FnMut(Option<(f32, f32)>) -> (f32, f32)) -> Self {
+        Self {
+            get_set_value: Box::new(get_set_value),
+            range_x: -1.0..=1.0,
+            range_y: -1.0..=1.0,
+            wrap_x: WrapMode::Clamped,
+            wrap_y: WrapMode::Clamped,
+            grid_x: None,
+            grid_y: None,
+            show_grid: true,
+            snap_to_grid: false,
+            interactive: true,
+            size: Vec2::splat(128.0),
+            cursor_radius: 6.0,
+            animated: false,
+        }
+    }
+
+    pub fn interactive(mut self, interactive: bool) -> Self {
+        self.interactive = interactive;
+        self
+    }
+
+    pub fn size(mut self, size: impl Into<Vec2>) -> Self {
+        self.size = size.into();
+        self
+    }
+
+    pub fn cursor_radius(mut self, cursor_radius: impl Into<f32>) -> Self {
+        self.cursor_radius = cursor_radius.into();
+        self
+    }
+
+    pub fn animated(mut self, animated: bool) -> Self {
+        self.animated = animated;
+        self
+    }
+
+    pub fn range(mut self, range: RangeInclusive<f32>) -> Self {
+        self.range_x = range.clone();
+        self.range_y = range;
+        self
+    }
+
+    pub fn range_x(mut self, range_x: RangeInclusive<f32>) -> Self {
+        self.range_x = range_x;
+        self
+    }
+
+    pub fn range_y(mut self, range_y: RangeInclusive<f32>) -> Self {
+        self.range_y = range_y;
+        self
+    }
+
+    /// Controls what happens to the values when dragging past the edges of the pad.
+    pub fn wrap(mut self, wrap: WrapMode) -> Self {
+        self.wrap_x = wrap;
+        self.wrap_y = wrap;
+        self
+    }
+
+    pub fn wrap_x(mut self, wrap_x: WrapMode) -> Self {
+        self.wrap_x = wrap_x;
+        self
+    }
+
+    pub fn wrap_y(mut self, wrap_y: WrapMode) -> Self {
+        self.wrap_y = wrap_y;
+        self
+    }
+
+    /// Grid spacing in value units, measured from the start of the ranges.
+    pub fn grid(mut self, grid: Option<f32>) -> Self {
+        self.grid_x = grid;
+        self.grid_y = grid;
+        self
+    }
+
+    pub fn grid_x(mut self, grid_x: Option<f32>) -> Self {
+        self.grid_x = grid_x;
+        self
+    }
+
+    pub fn grid_y(mut self, grid_y: Option<f32>) -> Self {
+        self.grid_y = grid_y;
+        self
+    }
+
+    pub fn show_grid(mut self, show_grid: bool) -> Self {
+        self.show_grid = show_grid;
+        self
+    }
+
+    pub fn snap_to_grid(mut self, snap_to_grid: bool) -> Self {
+        self.snap_to_grid = snap_to_grid;
+        self
+    }
+}
+
+impl<'a> Widget for XyPad<'a> {
+    fn ui(mut self, ui: &mut Ui) -> Response {
+        let (rect, mut response) = ui.allocate_exact_size(
+            self.size,
+            if self.interactive {
+                Sense::click_and_drag()
+            } else {
+                Sense::hover()
+            },
+        );
+
+        let range_x = self.range_x.clone();
+        let range_y = *self.range_y.end()..=*self.range_y.start();
+
+        if response.double_clicked() {
+            let x = constrain_axis(0.0, &self.range_x, self.wrap_x, None, false);
+            let y = constrain_axis(0.0, &self.range_y, self.wrap_y, None, false);
+
+            set(&mut self.get_set_value, (x, y));
+            response.mark_changed();
+        } else if response.dragged() || response.clicked() {
+            if let Some(pointer_pos) = response.interact_pointer_pos() {
+                let x = constrain_axis(
+                    remap(pointer_pos.x, rect.x_range(), range_x.clone()),
+                    &self.range_x,
+                    self.wrap_x,
+                    self.grid_x,
+                    self.snap_to_grid,
+                );
+                let y = constrain_axis(
+                    remap(pointer_pos.y, rect.y_range(), range_y.clone()),
+                    &self.range_y,
+                    self.wrap_y,
+                    self.grid_y,
+                    self.snap_to_grid,
+                );
+
+                set(&mut self.get_set_value, (x, y));
+                response.mark_changed();
+            }
+        }
+
+        if ui.is_rect_visible(rect) {
+            let visuals = *ui.style().interact(&response);
+
+            ui.painter().rect(
+                rect,
+                visuals.rounding,
+                ui.style().visuals.extreme_bg_color,
+                ui.style().visuals.window_stroke(),
+            );
+
+            let grid_stroke = Stroke::new(1.0, ui.style().visuals.faint_bg_color);
+
+            if self.show_grid {
+                let paint_grid_lines = |grid: Option<f32>,
+                                        range: &RangeInclusive<f32>,
+                                        screen_range: RangeInclusive<f32>,
+                                        vertical: bool| {
+                    let Some(grid) = grid.filter(|&grid| grid > 0.0) else {
+                        return;
+                    };
+
+                    let (min, max) = (
+                        range.start().min(*range.end()),
+                        range.start().max(*range.end()),
+                    );
+                    let line_count = ((max - min) / grid).floor() as usize;
+
+                    for line_index in 0..=line_count {
+                        let screen = remap(
+                            range.start()
+                                + line_index as f32 * grid * (range.end() - range.start()).signum(),
+                            range.clone(),
+                            screen_range.clone(),
+                        );
+
+                        let points = if vertical {
+                            [pos2(screen, rect.top()), pos2(screen, rect.bottom())]
+                        } else {
+                            [pos2(rect.left(), screen), pos2(rect.right(), screen)]
+                        };
+
+                        ui.painter().line_segment(points, grid_stroke);
+                    }
+                };
+
+                paint_grid_lines(self.grid_x, &self.range_x, rect.left()..=rect.right(), true);
+                paint_grid_lines(
+                    self.grid_y,
+                    &self.range_y,
+                    rect.bottom()..=rect.top(),
+                    false,
+                );
+            }
+
+            let (x, y) = {
+                let (x, y) = get(&mut self.get_set_value);
+
+                if self.animated {
+                    (
+                        ui.ctx()
+                            .animate_value_with_time(response.id.with("x"), x, 0.1),
+                        ui.ctx()
+                            .animate_value_with_time(response.id.with("y"), y, 0.1),
+                    )
+                } else {
+                    (x, y)
+                }
+            };
+
+            let cursor_pos = pos2(
+                remap_clamp(x, range_x, rect.x_range()),
+                remap_clamp(y, range_y, rect.y_range()),
+            );
+
+            let mut child_ui = ui.child_ui(rect, *ui.layout(), None);
+            child_ui.set_clip_rect(child_ui.clip_rect().intersect(rect));
+
+            child_ui.painter().line_segment(
+                [
+                    pos2(rect.left(), cursor_pos.y),
+                    pos2(rect.right(), cursor_pos.y),
+                ],
+                ui.style().visuals.window_stroke(),
+            );
+
+            child_ui.painter().line_segment(
+                [
+                    pos2(cursor_pos.x, rect.top()),
+                    pos2(cursor_pos.x, rect.bottom()),
+                ],
+                ui.style().visuals.window_stroke(),
+            );
+
+            child_ui.painter().circle(
+                cursor_pos,
+                self.cursor_radius,
+                visuals.bg_fill,
+                visuals.fg_stroke,
+            );
+        }
+
+        response
+    }
+}
@@ -14,8 +14,17 @@ pub mod displays;
 #[cfg(feature = "filesystem")]
 pub mod filesystem;
 
+#[cfg(feature = "indicators")]
+pub mod indicators;
+
 #[cfg(feature = "knobs")]
 pub mod knobs;
 
+#[cfg(feature = "meters")]
+pub mod meters;
+
+#[cfg(feature = "music")]
+pub mod music;
+
 #[cfg(feature = "ui")]
 pub mod ui;
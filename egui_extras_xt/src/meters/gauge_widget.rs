@@ -0,0 +1,285 @@
+use std::f32::consts::TAU;
+use std::ops::RangeInclusive;
+
+use egui::{self, Align2, Color32, FontFamily, FontId, Rect, Response, Sense, Stroke, Ui, Widget};
+use emath::{format_with_decimals_in_range, lerp, remap_clamp, Pos2, Vec2};
+
+use crate::common::{Orientation, WidgetShape, WidgetShapeError};
+
+// ----------------------------------------------------------------------------
+
+/// Read-only dial instrument with a needle, like the speedometers of cars.
+#[must_use = "You should put this widget in an ui with `ui.add(widget);`"]
+pub struct GaugeWidget<'a> {
+    value: f32,
+    range: RangeInclusive<f32>,
+    diameter: f32,
+    orientation: Orientation,
+    sweep: f32,
+    shape: WidgetShape,
+    shape_error: Option<WidgetShapeError>,
+    shape_resolution: Option<usize>,
+    major_ticks: usize,
+    minor_ticks: usize,
+    show_tick_labels: bool,
+    color_zones: &'a [(f32, f32, Color32)],
+    label: Option<String>,
+    animated: bool,
+}
+
+impl<'a> GaugeWidget<'a> {
+    pub fn new(value: f32) -> Self {
+        Self {
+            value,
+            range: 0.0..=100.0,
+            diameter: 160.0,
+            orientation: Orientation::Top,
+            sweep: TAU / 2.0,
+            shape: WidgetShape::Circle,
+            shape_error: None,
+            shape_resolution: None,
+            major_ticks: 10,
+            minor_ticks: 5,
+            show_tick_labels: true,
+            color_zones: &[],
+            label: None,
+            animated: false,
+        }
+    }
+
+    pub fn range(mut self, range: RangeInclusive<f32>) -> Self {
+        self.range = range;
+        self
+    }
+
+    pub fn diameter(mut self, diameter: impl Into<f32>) -> Self {
+        self.diameter = diameter.into();
+        self
+    }
+
+    /// Direction of the middle of the dial.
+    pub fn orientation(mut self, orientation: Orientation) -> Self {
+        self.orientation = orientation;
+        self
+    }
+
+    /// Angle between the ends of the scale, clockwise.
+    pub fn sweep(mut self, sweep: impl Into<f32>) -> Self {
+        self.sweep = sweep.into();
+        self
+    }
+
+    /// Invalid shapes are painted as a `\u{1F525}` error label in place of the gauge, use
+    /// [`try_shape`](Self::try_shape) to handle them in code.
+    pub fn shape(mut self, shape: WidgetShape) -> Self {
+        self.shape_error = shape.validate().err();
+        self.shape = shape;
+        self
+    }
+
+    /// Sets the shape after checking it with [`WidgetShape::validate`].
+    pub fn try_shape(mut self, shape: WidgetShape) -> Result<Self, WidgetShapeError> {
+        shape.validate()?;
+        self.shape_error = None;
+        self.shape = shape;
+        Ok(self)
+    }
+
+    /// Vertices of the shape outline per full turn. By default derived from the size of
+    /// the widget, about one vertex per 2.5 points of the circumference.
+    pub fn shape_resolution(mut self, shape_resolution: Option<usize>) -> Self {
+        self.shape_resolution = shape_resolution;
+        self
+    }
+
+    /// Number of labeled intervals the scale is divided into.
+    pub fn major_ticks(mut self, major_ticks: usize) -> Self {
+        self.major_ticks = major_ticks;
+        self
+    }
+
+    /// Number of intervals between neighboring major ticks.
+    pub fn minor_ticks(mut self, minor_ticks: usize) -> Self {
+        self.minor_ticks = minor_ticks;
+        self
+    }
+
+    pub fn show_tick_labels(mut self, show_tick_labels: bool) -> Self {
+        self.show_tick_labels = show_tick_labels;
+        self
+    }
+
+    /// Colored bands along the scale, given as `(from, to, color)` value ranges.
+    pub fn color_zones(mut self, color_zones: &'a [(f32, f32, Color32)]) -> Self {
+        self.color_zones = color_zones;
+        self
+    }
+
+    /// Text shown in the middle of the dial, usually the unit of the value.
+    pub fn label(mut self, label: impl Into<String>) -> Self {
+        self.label = Some(label.into());
+        self
+    }
+
+    pub fn animated(mut self, animated: bool) -> Self {
+        self.animated = animated;
+        self
+    }
+
+    fn value_to_angle(&self, value: f32) -> f32 {
+        let t = remap_clamp(value, self.range.clone(), 0.0..=1.0);
+        self.orientation.angle_rad() + self.sweep * (t - 0.5)
+    }
+
+    /// Offset of the dial outline from the center of the dial.
+    fn outline_offset(&self, angle: f32, radius: f32) -> Vec2 {
+        Vec2::angled(angle) * radius * self.shape.eval(angle - self.orientation.angle_rad())
+    }
+
+    /// Bounding box of the dial relative to its center.
+    fn bounds(&self, radius: f32) -> Rect {
+        const RESOLUTION: usize = 32;
+
+        let hub_radius = radius * 0.08;
+
+        Rect::from_points(
+            &(0..=RESOLUTION)
+                .map(|i| {
+                    let angle = self.orientation.angle_rad()
+                        + self.sweep * (i as f32 / RESOLUTION as f32 - 0.5);
+                    Pos2::ZERO + self.outline_offset(angle, radius)
+                })
+                .collect::<Vec<_>>(),
+        )
+        .union(Rect::from_center_size(
+            Pos2::ZERO,
+            Vec2::splat(hub_radius * 2.0),
+        ))
+    }
+}
+
+impl<'a> Widget for GaugeWidget<'a> {
+    fn ui(self, ui: &mut Ui) -> Response {
+        if let Some(shape_error) = self.shape_error {
+            return ui.colored_label(
+                ui.style().visuals.error_fg_color,
+                format!("\u{1F525} Invalid gauge shape: {shape_error}"),
+            );
+        }
+
+        let radius = self.diameter / 2.0;
+        let bounds = self.bounds(radius);
+
+        let (rect, response) = ui.allocate_exact_size(bounds.size(), Sense::hover());
+
+        if ui.is_rect_visible(rect) {
+            let center = rect.min - bounds.min.to_vec2();
+            let (start_angle, end_angle) = (
+                self.value_to_angle(*self.range.start()),
+                self.value_to_angle(*self.range.end()),
+            );
+
+            let visuals = ui.style().visuals.clone();
+            let text_color = visuals.text_color();
+
+            // Dial face
+            self.shape.paint_arc(
+                ui,
+                center,
+                0.0,
+                radius,
+                start_angle,
+                end_angle,
+                visuals.extreme_bg_color,
+                visuals.window_stroke(),
+                self.orientation.rot2(),
+                self.shape_resolution,
+            );
+
+            for &(from, to, color) in self.color_zones {
+                self.shape.paint_arc(
+                    ui,
+                    center,
+                    radius * 0.86,
+                    radius * 0.96,
+                    self.value_to_angle(from),
+                    self.value_to_angle(to),
+                    color,
+                    Stroke::NONE,
+                    self.orientation.rot2(),
+                    self.shape_resolution,
+                );
+            }
+
+            let major_ticks = self.major_ticks.max(1);
+            let minor_ticks = self.minor_ticks.max(1);
+            let tick_count = major_ticks * minor_ticks;
+            let font_id = FontId::new(radius * 0.11, FontFamily::Proportional);
+
+            for tick_index in 0..=tick_count {
+                let tick_value = lerp(self.range.clone(), tick_index as f32 / tick_count as f32);
+                let tick_angle = self.value_to_angle(tick_value);
+                let major = tick_index % minor_ticks == 0;
+
+                let (inner_radius, stroke) = if major {
+                    (radius * 0.76, Stroke::new(2.0, text_color))
+                } else {
+                    (radius * 0.86, Stroke::new(1.0, text_color))
+                };
+
+                ui.painter().line_segment(
+                    [
+                        center + self.outline_offset(tick_angle, inner_radius),
+                        center + self.outline_offset(tick_angle, radius * 0.96),
+                    ],
+                    stroke,
+                );
+
+                if major && self.show_tick_labels {
+                    ui.painter().text(
+                        center + self.outline_offset(tick_angle, radius * 0.62),
+                        Align2::CENTER_CENTER,
+                        format_with_decimals_in_range(tick_value as f64, 0..=2),
+                        font_id.clone(),
+                        text_color,
+                    );
+                }
+            }
+
+            if let Some(label) = &self.label {
+                ui.painter().text(
+                    center + Vec2::angled(self.orientation.angle_rad()) * radius * 0.35,
+                    Align2::CENTER_CENTER,
+                    label,
+                    font_id,
+                    visuals.weak_text_color(),
+                );
+            }
+
+            let value = if self.animated {
+                ui.ctx()
+                    .animate_value_with_time(response.id, self.value, ui.style().animation_time)
+            } else {
+                self.value
+            };
+
+            // Needle
+            ui.painter().line_segment(
+                [
+                    center,
+                    center + self.outline_offset(self.value_to_angle(value), radius * 0.84),
+                ],
+                Stroke::new(2.0, visuals.error_fg_color),
+            );
+
+            ui.painter().circle(
+                center,
+                radius * 0.08,
+                visuals.widgets.inactive.bg_fill,
+                Stroke::new(1.0, text_color),
+            );
+        }
+
+        response
+    }
+}
@@ -0,0 +1,7 @@
+mod gauge_widget;
+mod spectrum_analyzer;
+mod vu_meter;
+
+pub use gauge_widget::GaugeWidget;
+pub use spectrum_analyzer::SpectrumAnalyzer;
+pub use vu_meter::VuMeter;
@@ -0,0 +1,245 @@
+use std::ops::RangeInclusive;
+use std::time::Duration;
+
+use egui::epaint::{Hsva, Mesh};
+use egui::{self, vec2, Color32, Rect, Response, Sense, Shape, Stroke, Ui, Widget};
+use emath::{lerp, remap, remap_clamp};
+
+// ----------------------------------------------------------------------------
+
+/// Height of the peak hold markers, in points.
+const PEAK_MARKER_HEIGHT: f32 = 2.0;
+
+type ColorGradient<'a> = Box<dyn 'a + Fn(f32) -> Color32>;
+
+#[derive(Clone, Copy)]
+struct BandPeak {
+    peak: f32,
+    hold_until: f64,
+}
+
+#[derive(Clone, Default)]
+struct PeakState {
+    bands: Vec<BandPeak>,
+    last_time: f64,
+}
+
+// ----------------------------------------------------------------------------
+
+/// Bar graph of frequency band magnitudes in dBFS, one bar per band, ordered from
+/// the lowest frequency to the highest.
+#[must_use = "You should put this widget in an ui with `ui.add(widget);`"]
+pub struct SpectrumAnalyzer<'a> {
+    magnitudes: &'a [f32],
+    range: RangeInclusive<f32>,
+    log_frequency_axis: bool,
+    width: f32,
+    height: f32,
+    bar_gap: f32,
+    gradient: Option<ColorGradient<'a>>,
+    peak_hold: bool,
+    peak_hold_duration: Duration,
+    peak_hold_decay_rate: f32,
+}
+
+impl<'a> SpectrumAnalyzer<'a> {
+    pub fn new(magnitudes: &'a [f32]) -> Self {
+        Self {
+            magnitudes,
+            range: -60.0..=0.0,
+            log_frequency_axis: false,
+            width: 256.0,
+            height: 96.0,
+            bar_gap: 1.0,
+            gradient: None,
+            peak_hold: false,
+            peak_hold_duration: Duration::from_millis(1000),
+            peak_hold_decay_rate: 30.0,
+        }
+    }
+
+    pub fn range(mut self, range: RangeInclusive<f32>) -> Self {
+        self.range = range;
+        self
+    }
+
+    /// Widens the bars of the low frequency bands, assuming the bands are linearly spaced
+    /// (like the bins of an FFT), so each octave takes up the same width.
+    pub fn log_frequency_axis(mut self, log_frequency_axis: bool) -> Self {
+        self.log_frequency_axis = log_frequency_axis;
+        self
+    }
+
+    pub fn width(mut self, width: impl Into<f32>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    pub fn height(mut self, height: impl Into<f32>) -> Self {
+        self.height = height.into();
+        self
+    }
+
+    pub fn bar_gap(mut self, bar_gap: impl Into<f32>) -> Self {
+        self.bar_gap = bar_gap.into();
+        self
+    }
+
+    /// Colors the bars by their position along the frequency axis, in the `0.0..=1.0` range.
+    /// Defaults to a green to red gradient.
+    pub fn gradient(mut self, gradient: impl 'a + Fn(f32) -> Color32) -> Self {
+        self.gradient = Some(Box::new(gradient));
+        self
+    }
+
+    pub fn peak_hold(mut self, peak_hold: bool) -> Self {
+        self.peak_hold = peak_hold;
+        self
+    }
+
+    /// Time the peak markers stay at the highest level before falling back.
+    pub fn peak_hold_duration(mut self, peak_hold_duration: Duration) -> Self {
+        self.peak_hold_duration = peak_hold_duration;
+        self
+    }
+
+    /// Falling speed of the peak markers after the hold time, in dB per second.
+    pub fn peak_hold_decay_rate(mut self, peak_hold_decay_rate: f32) -> Self {
+        self.peak_hold_decay_rate = peak_hold_decay_rate;
+        self
+    }
+
+    fn bar_color(&self, position: f32) -> Color32 {
+        if let Some(gradient) = &self.gradient {
+            gradient(position)
+        } else {
+            Color32::from(Hsva::new(lerp(0.4..=0.0, position), 0.75, 0.9, 1.0))
+        }
+    }
+
+    /// Updates the peak hold state stored in the memory of the ui.
+    fn update_peaks(&self, ui: &Ui, response: &Response) -> Vec<f32> {
+        let time = ui.input(|input| input.time);
+        let hold_duration = self.peak_hold_duration.as_secs_f64();
+
+        let (peaks, next_repaint) = ui.memory_mut(|memory| {
+            let state = memory
+                .data
+                .get_temp_mut_or_default::<PeakState>(response.id);
+
+            if state.bands.len() != self.magnitudes.len() {
+                state.bands = self
+                    .magnitudes
+                    .iter()
+                    .map(|&magnitude| BandPeak {
+                        peak: magnitude,
+                        hold_until: time + hold_duration,
+                    })
+                    .collect();
+                state.last_time = time;
+            }
+
+            let mut next_repaint: Option<f64> = None;
+
+            for (band, &magnitude) in state.bands.iter_mut().zip(self.magnitudes) {
+                if magnitude >= band.peak {
+                    band.peak = magnitude;
+                    band.hold_until = time + hold_duration;
+                } else if time > band.hold_until {
+                    let decay_time = time - state.last_time.max(band.hold_until);
+                    band.peak =
+                        (band.peak - self.peak_hold_decay_rate * decay_time as f32).max(magnitude);
+                }
+
+                if band.peak > magnitude {
+                    let delay = (band.hold_until - time).max(0.0);
+                    next_repaint = Some(next_repaint.map_or(delay, |next| next.min(delay)));
+                }
+            }
+            state.last_time = time;
+
+            (
+                state.bands.iter().map(|band| band.peak).collect(),
+                next_repaint,
+            )
+        });
+
+        if let Some(delay) = next_repaint {
+            ui.ctx().request_repaint_after_secs(delay as f32);
+        }
+
+        peaks
+    }
+}
+
+impl<'a> Widget for SpectrumAnalyzer<'a> {
+    fn ui(self, ui: &mut Ui) -> Response {
+        let desired_size = vec2(self.width, self.height);
+        let (rect, response) = ui.allocate_exact_size(desired_size, Sense::hover());
+
+        let peaks = if self.peak_hold {
+            Some(self.update_peaks(ui, &response))
+        } else {
+            ui.memory_mut(|memory| memory.data.remove::<PeakState>(response.id));
+            None
+        };
+
+        if ui.is_rect_visible(rect) {
+            ui.painter().rect(
+                rect,
+                ui.style().visuals.noninteractive().rounding,
+                ui.style().visuals.extreme_bg_color,
+                Stroke::NONE,
+            );
+
+            let band_count = self.magnitudes.len();
+
+            // Left edge of the band in the `0.0..=1.0` range, the band count gives the right edge
+            let band_edge = |band_index: usize| {
+                if self.log_frequency_axis {
+                    (band_index as f32).ln_1p() / (band_count as f32).ln_1p()
+                } else {
+                    band_index as f32 / band_count as f32
+                }
+            };
+
+            let bar_x_range = |band_index: usize| {
+                let left = remap(band_edge(band_index), 0.0..=1.0, rect.x_range());
+                let right = remap(band_edge(band_index + 1), 0.0..=1.0, rect.x_range());
+                (left + self.bar_gap / 2.0)..=(right - self.bar_gap / 2.0).max(left + 1.0)
+            };
+
+            let level_to_y =
+                |level: f32| remap_clamp(level, self.range.clone(), rect.bottom_up_range());
+
+            let mut mesh = Mesh::default();
+
+            for (band_index, &magnitude) in self.magnitudes.iter().enumerate() {
+                let x_range = bar_x_range(band_index);
+                let color = self.bar_color((band_index as f32 + 0.5) / band_count as f32);
+
+                mesh.add_colored_rect(
+                    Rect::from_x_y_ranges(x_range.clone(), level_to_y(magnitude)..=rect.bottom()),
+                    color,
+                );
+
+                if let Some(peak) = peaks.as_ref().and_then(|peaks| peaks.get(band_index)) {
+                    if *peak > *self.range.start() {
+                        let peak_y = level_to_y(*peak);
+                        mesh.add_colored_rect(
+                            Rect::from_x_y_ranges(
+                                x_range,
+                                peak_y..=(peak_y + PEAK_MARKER_HEIGHT).min(rect.bottom()),
+                            ),
+                            color,
+                        );
+                    }
+                }
+            }
+
+            ui.painter().add(Shape::mesh(mesh));
+        }
+
+        response
+    }
+}
@@ -0,0 +1,255 @@
+use std::ops::RangeInclusive;
+use std::time::Duration;
+
+use egui::{self, lerp, vec2, Color32, Rect, Response, Sense, Stroke, Ui, Widget};
+use emath::{remap_clamp, Vec2};
+
+use crate::common::Orientation;
+
+// ----------------------------------------------------------------------------
+
+const DEFAULT_COLOR_ZONES: &[(f32, f32, Color32)] = &[
+    (
+        f32::NEG_INFINITY,
+        -18.0,
+        Color32::from_rgb(0x3C, 0xC8, 0x3C),
+    ),
+    (-18.0, -6.0, Color32::from_rgb(0xE6, 0xC8, 0x28)),
+    (-6.0, f32::INFINITY, Color32::from_rgb(0xE6, 0x3C, 0x28)),
+];
+
+#[derive(Clone, Copy)]
+struct PeakState {
+    peak: f32,
+    hold_until: f64,
+    last_time: f64,
+}
+
+// ----------------------------------------------------------------------------
+
+/// Segmented level meter for values in dBFS, with a peak hold indicator.
+///
+/// The meter grows in the direction of its [`Orientation`], custom orientations
+/// use the closest of the four sides.
+#[must_use = "You should put this widget in an ui with `ui.add(widget);`"]
+pub struct VuMeter<'a> {
+    value: f32,
+    range: RangeInclusive<f32>,
+    orientation: Orientation,
+    color_zones: &'a [(f32, f32, Color32)],
+    segment_count: usize,
+    segment_gap: f32,
+    length: f32,
+    thickness: f32,
+    show_peak: bool,
+    peak_hold_duration: Duration,
+    peak_hold_decay_rate: f32,
+}
+
+impl<'a> VuMeter<'a> {
+    pub fn new(value: f32) -> Self {
+        Self {
+            value,
+            range: -60.0..=0.0,
+            orientation: Orientation::Top,
+            color_zones: DEFAULT_COLOR_ZONES,
+            segment_count: 24,
+            segment_gap: 2.0,
+            length: 160.0,
+            thickness: 12.0,
+            show_peak: true,
+            peak_hold_duration: Duration::from_millis(1500),
+            peak_hold_decay_rate: 20.0,
+        }
+    }
+
+    pub fn range(mut self, range: RangeInclusive<f32>) -> Self {
+        self.range = range;
+        self
+    }
+
+    pub fn orientation(mut self, orientation: Orientation) -> Self {
+        self.orientation = orientation;
+        self
+    }
+
+    /// Colors of the segments, given as `(from, to, color)` level ranges in dBFS.
+    /// The first matching zone wins, segments outside all zones use the text color.
+    pub fn color_zones(mut self, color_zones: &'a [(f32, f32, Color32)]) -> Self {
+        self.color_zones = color_zones;
+        self
+    }
+
+    pub fn segment_count(mut self, segment_count: usize) -> Self {
+        self.segment_count = segment_count;
+        self
+    }
+
+    pub fn segment_gap(mut self, segment_gap: impl Into<f32>) -> Self {
+        self.segment_gap = segment_gap.into();
+        self
+    }
+
+    pub fn length(mut self, length: impl Into<f32>) -> Self {
+        self.length = length.into();
+        self
+    }
+
+    pub fn thickness(mut self, thickness: impl Into<f32>) -> Self {
+        self.thickness = thickness.into();
+        self
+    }
+
+    pub fn show_peak(mut self, show_peak: bool) -> Self {
+        self.show_peak = show_peak;
+        self
+    }
+
+    /// Time the peak indicator stays at the highest level before falling back.
+    pub fn peak_hold_duration(mut self, peak_hold_duration: Duration) -> Self {
+        self.peak_hold_duration = peak_hold_duration;
+        self
+    }
+
+    /// Falling speed of the peak indicator after the hold time, in dB per second.
+    pub fn peak_hold_decay_rate(mut self, peak_hold_decay_rate: f32) -> Self {
+        self.peak_hold_decay_rate = peak_hold_decay_rate;
+        self
+    }
+
+    fn zone_color(&self, ui: &Ui, level: f32) -> Color32 {
+        self.color_zones
+            .iter()
+            .find(|(from, to, _)| (*from..=*to).contains(&level))
+            .map(|&(_, _, color)| color)
+            .unwrap_or(ui.style().visuals.text_color())
+    }
+
+    /// Updates the peak hold state stored in the memory of the ui.
+    fn update_peak(&self, ui: &Ui, response: &Response) -> f32 {
+        let time = ui.input(|input| input.time);
+        let hold_duration = self.peak_hold_duration.as_secs_f64();
+
+        let mut state = ui
+            .memory(|memory| memory.data.get_temp::<PeakState>(response.id))
+            .unwrap_or(PeakState {
+                peak: self.value,
+                hold_until: time + hold_duration,
+                last_time: time,
+            });
+
+        if self.value >= state.peak {
+            state.peak = self.value;
+            state.hold_until = time + hold_duration;
+        } else if time > state.hold_until {
+            let decay_time = time - state.last_time.max(state.hold_until);
+            state.peak =
+                (state.peak - self.peak_hold_decay_rate * decay_time as f32).max(self.value);
+        }
+        state.last_time = time;
+
+        if state.peak > self.value {
+            if time < state.hold_until {
+                ui.ctx()
+                    .request_repaint_after_secs((state.hold_until - time) as f32);
+            } else {
+                ui.ctx().request_repaint();
+            }
+        }
+
+        ui.memory_mut(|memory| memory.data.insert_temp(response.id, state));
+        state.peak
+    }
+}
+
+impl<'a> Widget for VuMeter<'a> {
+    fn ui(self, ui: &mut Ui) -> Response {
+        // Direction of growth, snapped to the closest axis
+        let direction = {
+            let direction = Vec2::angled(self.orientation.angle_rad());
+            if direction.x.abs() >= direction.y.abs() {
+                vec2(direction.x.signum(), 0.0)
+            } else {
+                vec2(0.0, direction.y.signum())
+            }
+        };
+
+        let desired_size = if direction.x != 0.0 {
+            vec2(self.length, self.thickness)
+        } else {
+            vec2(self.thickness, self.length)
+        };
+
+        let (rect, response) = ui.allocate_exact_size(desired_size, Sense::hover());
+
+        let peak = self.update_peak(ui, &response);
+
+        if ui.is_rect_visible(rect) {
+            ui.painter().rect(
+                rect,
+                ui.style().visuals.noninteractive().rounding,
+                ui.style().visuals.extreme_bg_color,
+                Stroke::NONE,
+            );
+
+            let segment_count = self.segment_count.max(1);
+            let segment_length =
+                ((self.length - self.segment_gap) / segment_count as f32) - self.segment_gap;
+
+            let segment_rect = |segment_index: usize| {
+                let offset = self.segment_gap
+                    + (segment_length + self.segment_gap) * segment_index as f32
+                    + segment_length / 2.0;
+
+                let center = rect.center() + direction * (offset - self.length / 2.0);
+                let size = if direction.x != 0.0 {
+                    vec2(segment_length, self.thickness - 2.0 * self.segment_gap)
+                } else {
+                    vec2(self.thickness - 2.0 * self.segment_gap, segment_length)
+                };
+
+                Rect::from_center_size(center, size)
+            };
+
+            let level_to_segments =
+                |level: f32| remap_clamp(level, self.range.clone(), 0.0..=segment_count as f32);
+
+            let lit_segments = level_to_segments(self.value).round() as usize;
+
+            for segment_index in 0..segment_count {
+                let segment_level = lerp(
+                    self.range.clone(),
+                    (segment_index as f32 + 0.5) / segment_count as f32,
+                );
+                let color = self.zone_color(ui, segment_level);
+
+                ui.painter().rect_filled(
+                    segment_rect(segment_index),
+                    0.0,
+                    if segment_index < lit_segments {
+                        color
+                    } else {
+                        color.gamma_multiply(0.15)
+                    },
+                );
+            }
+
+            if self.show_peak && peak > *self.range.start() {
+                let peak_segment =
+                    (level_to_segments(peak).round() as usize).clamp(1, segment_count) - 1;
+                let segment_level = lerp(
+                    self.range.clone(),
+                    (peak_segment as f32 + 0.5) / segment_count as f32,
+                );
+
+                ui.painter().rect_filled(
+                    segment_rect(peak_segment),
+                    0.0,
+                    self.zone_color(ui, segment_level),
+                );
+            }
+        }
+
+        response
+    }
+}
@@ -0,0 +1,274 @@
+use std::ops::RangeInclusive;
+
+use egui::{self, vec2, Color32, Pos2, Rect, Response, Sense, Shape, Stroke, Ui, Widget};
+use emath::{lerp, remap_clamp, Vec2};
+use strum::{Display, EnumIter};
+
+// ----------------------------------------------------------------------------
+
+#[non_exhaustive]
+#[derive(Clone, Copy, Debug, Display, EnumIter, Eq, PartialEq)]
+pub enum AdsrTimeScale {
+    #[strum(to_string = "Linear")]
+    Linear,
+
+    #[strum(to_string = "Logarithmic")]
+    Logarithmic,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum Stage {
+    Attack,
+    Decay,
+    Sustain,
+    Release,
+}
+
+impl Stage {
+    const ALL: [Stage; 4] = [Stage::Attack, Stage::Decay, Stage::Sustain, Stage::Release];
+
+    fn name(self) -> &'static str {
+        match self {
+            Stage::Attack => "Attack",
+            Stage::Decay => "Decay",
+            Stage::Sustain => "Sustain",
+            Stage::Release => "Release",
+        }
+    }
+}
+
+// ----------------------------------------------------------------------------
+
+/// Attack-decay-sustain-release envelope editor with draggable breakpoints.
+///
+/// The attack, decay and release values are times in seconds, the sustain value is
+/// a level in the `0.0..=1.0` range.
+#[must_use = "You should put this widget in an ui with `ui.add(widget);`"]
+pub struct AdsrWidget<'a> {
+    attack: &'a mut f32,
+    decay: &'a mut f32,
+    sustain: &'a mut f32,
+    release: &'a mut f32,
+    size: Vec2,
+    time_range: RangeInclusive<f32>,
+    time_scale: AdsrTimeScale,
+    stage_colors: [Color32; 4],
+    interactive: bool,
+}
+
+impl<'a> AdsrWidget<'a> {
+    pub fn new(
+        attack: &'a mut f32,
+        decay: &'a mut f32,
+        sustain: &'a mut f32,
+        release: &'a mut f32,
+    ) -> Self {
+        Self {
+            attack,
+            decay,
+            sustain,
+            release,
+            size: vec2(320.0, 120.0),
+            time_range: 0.001..=2.0,
+            time_scale: AdsrTimeScale::Linear,
+            stage_colors: [
+                Color32::from_rgb(0x3C, 0xC8, 0x3C),
+                Color32::from_rgb(0xE6, 0xC8, 0x28),
+                Color32::from_rgb(0x3C, 0x8C, 0xE6),
+                Color32::from_rgb(0xE6, 0x3C, 0x28),
+            ],
+            interactive: true,
+        }
+    }
+
+    pub fn size(mut self, size: impl Into<Vec2>) -> Self {
+        self.size = size.into();
+        self
+    }
+
+    /// Shortest and longest times of the attack, decay and release stages in seconds.
+    /// The shortest time is only used by the logarithmic time scale.
+    pub fn time_range(mut self, time_range: RangeInclusive<f32>) -> Self {
+        self.time_range = time_range;
+        self
+    }
+
+    pub fn time_scale(mut self, time_scale: AdsrTimeScale) -> Self {
+        self.time_scale = time_scale;
+        self
+    }
+
+    /// Colors of the attack, decay, sustain and release stages.
+    pub fn stage_colors(mut self, stage_colors: [Color32; 4]) -> Self {
+        self.stage_colors = stage_colors;
+        self
+    }
+
+    pub fn interactive(mut self, interactive: bool) -> Self {
+        self.interactive = interactive;
+        self
+    }
+
+    /// Maps stage times to the `0.0..=1.0` range.
+    fn time_to_unit(&self, time: f32) -> f32 {
+        let (min_time, max_time) = (*self.time_range.start(), *self.time_range.end());
+
+        match self.time_scale {
+            AdsrTimeScale::Linear => remap_clamp(time, 0.0..=max_time, 0.0..=1.0),
+            AdsrTimeScale::Logarithmic => remap_clamp(
+                time.max(min_time).ln(),
+                min_time.ln()..=max_time.ln(),
+                0.0..=1.0,
+            ),
+        }
+    }
+
+    fn unit_to_time(&self, unit: f32) -> f32 {
+        let (min_time, max_time) = (*self.time_range.start(), *self.time_range.end());
+        let unit = unit.clamp(0.0, 1.0);
+
+        match self.time_scale {
+            AdsrTimeScale::Linear => lerp(0.0..=max_time, unit),
+            AdsrTimeScale::Logarithmic => lerp(min_time.ln()..=max_time.ln(), unit).exp(),
+        }
+    }
+
+    fn stage_value(&self, stage: Stage) -> f32 {
+        match stage {
+            Stage::Attack => *self.attack,
+            Stage::Decay => *self.decay,
+            Stage::Sustain => *self.sustain,
+            Stage::Release => *self.release,
+        }
+    }
+}
+
+impl<'a> Widget for AdsrWidget<'a> {
+    fn ui(self, ui: &mut Ui) -> Response {
+        let (rect, mut response) = ui.allocate_exact_size(self.size, Sense::hover());
+
+        // The attack, decay and release stages span up to one column each,
+        // the sustain stage is shown with a fixed width of one column.
+        let column_width = rect.width() / 4.0;
+        let plot_rect = rect.shrink(4.0);
+
+        let level_to_y = |level: f32| lerp(plot_rect.bottom()..=plot_rect.top(), level);
+        let y_to_level = |y: f32| remap_clamp(y, plot_rect.bottom()..=plot_rect.top(), 0.0..=1.0);
+
+        let breakpoints = |widget: &Self| {
+            let attack_x = plot_rect.left() + widget.time_to_unit(*widget.attack) * column_width;
+            let decay_x = attack_x + widget.time_to_unit(*widget.decay) * column_width;
+            let sustain_x = decay_x + column_width;
+            let release_x = sustain_x + widget.time_to_unit(*widget.release) * column_width;
+
+            [
+                Pos2::new(plot_rect.left(), level_to_y(0.0)),
+                Pos2::new(attack_x, level_to_y(1.0)),
+                Pos2::new(decay_x, level_to_y(*widget.sustain)),
+                Pos2::new(sustain_x, level_to_y(*widget.sustain)),
+                Pos2::new(release_x, level_to_y(0.0)),
+            ]
+        };
+
+        if self.interactive {
+            let handle_radius = 6.0;
+
+            // Breakpoints ending the attack, decay, sustain and release stages
+            for (stage_index, stage) in Stage::ALL.into_iter().enumerate() {
+                let points = breakpoints(&self);
+                let handle_pos = points[stage_index + 1];
+
+                let handle_response = ui.interact(
+                    Rect::from_center_size(handle_pos, Vec2::splat(handle_radius * 2.0)),
+                    response.id.with(stage_index),
+                    Sense::drag(),
+                );
+
+                if handle_response.dragged() {
+                    if let Some(pointer_pos) = handle_response.interact_pointer_pos() {
+                        let stage_start_x = points[stage_index].x;
+                        let time =
+                            self.unit_to_time((pointer_pos.x - stage_start_x) / column_width);
+
+                        match stage {
+                            Stage::Attack => *self.attack = time,
+                            Stage::Decay => {
+                                *self.decay = time;
+                                *self.sustain = y_to_level(pointer_pos.y);
+                            }
+                            Stage::Sustain => *self.sustain = y_to_level(pointer_pos.y),
+                            Stage::Release => *self.release = time,
+                        }
+
+                        response.mark_changed();
+                    }
+                }
+
+                response = response.union(handle_response);
+            }
+        }
+
+        let points = breakpoints(&self);
+
+        if ui.is_rect_visible(rect) {
+            let visuals = ui.style().visuals.clone();
+
+            ui.painter().rect(
+                rect,
+                visuals.noninteractive().rounding,
+                visuals.extreme_bg_color,
+                visuals.window_stroke(),
+            );
+
+            for (stage_index, color) in self.stage_colors.into_iter().enumerate() {
+                let (start, end) = (points[stage_index], points[stage_index + 1]);
+
+                ui.painter().add(Shape::convex_polygon(
+                    vec![
+                        start,
+                        end,
+                        Pos2::new(end.x, plot_rect.bottom()),
+                        Pos2::new(start.x, plot_rect.bottom()),
+                    ],
+                    color.gamma_multiply(0.25),
+                    Stroke::NONE,
+                ));
+
+                ui.painter()
+                    .line_segment([start, end], Stroke::new(2.0, color));
+            }
+
+            for (stage_index, &point) in points.iter().enumerate().skip(1) {
+                let hovered = ui.ctx().is_being_dragged(response.id.with(stage_index - 1))
+                    || response
+                        .hover_pos()
+                        .is_some_and(|hover_pos| hover_pos.distance(point) < 6.0);
+
+                ui.painter().circle(
+                    point,
+                    if hovered { 5.0 } else { 4.0 },
+                    self.stage_colors[stage_index - 1],
+                    Stroke::new(1.0, visuals.text_color()),
+                );
+            }
+        }
+
+        if let Some(hover_pos) = response.hover_pos() {
+            if let Some(stage_index) =
+                (0..Stage::ALL.len()).find(|&stage_index| hover_pos.x <= points[stage_index + 1].x)
+            {
+                let stage = Stage::ALL[stage_index];
+                let value = self.stage_value(stage);
+
+                let text = match stage {
+                    Stage::Sustain => format!("{}: {:.0}%", stage.name(), value * 100.0),
+                    _ => format!("{}: {:.3} s", stage.name(), value),
+                };
+
+                response = response.on_hover_text_at_pointer(text);
+            }
+        }
+
+        response
+    }
+}
@@ -0,0 +1,205 @@
+use egui::{self, vec2, Color32, Rect, Response, Sense, Stroke, Ui, Widget};
+use emath::Vec2;
+
+// ----------------------------------------------------------------------------
+
+type CellColor<'a> = Box<dyn 'a + Fn(usize, usize) -> Color32>;
+
+/// Cell state applied to the cells swept over during a multi-select drag.
+#[derive(Clone, Copy)]
+struct PaintState {
+    state: bool,
+    last_cell: (usize, usize),
+}
+
+// ----------------------------------------------------------------------------
+
+/// Response of [`ButtonMatrix::show`].
+pub struct ButtonMatrixResponse {
+    pub response: Response,
+
+    /// Row and column of the last cell toggled in this frame.
+    pub last_toggled: Option<(usize, usize)>,
+}
+
+// ----------------------------------------------------------------------------
+
+/// Grid of toggle pads, like the pad grids of drum machines and grid controllers.
+#[must_use = "You should put this widget in an ui with `ui.add(widget);`"]
+pub struct ButtonMatrix<'a> {
+    states: &'a mut Vec<Vec<bool>>,
+    cell_size: Vec2,
+    cell_gap: f32,
+    active_color: Color32,
+    color_fn: Option<CellColor<'a>>,
+    multi_select: bool,
+}
+
+impl<'a> ButtonMatrix<'a> {
+    pub fn new(states: &'a mut Vec<Vec<bool>>) -> Self {
+        Self {
+            states,
+            cell_size: Vec2::splat(32.0),
+            cell_gap: 4.0,
+            active_color: Color32::from_rgb(0xE6, 0x8C, 0x28),
+            color_fn: None,
+            multi_select: true,
+        }
+    }
+
+    pub fn cell_size(mut self, cell_size: impl Into<Vec2>) -> Self {
+        self.cell_size = cell_size.into();
+        self
+    }
+
+    pub fn cell_gap(mut self, cell_gap: impl Into<f32>) -> Self {
+        self.cell_gap = cell_gap.into();
+        self
+    }
+
+    pub fn active_color(mut self, active_color: Color32) -> Self {
+        self.active_color = active_color;
+        self
+    }
+
+    /// Active color of each cell by its row and column, overriding the
+    /// [`active_color`](Self::active_color).
+    pub fn color_fn(mut self, color_fn: impl 'a + Fn(usize, usize) -> Color32) -> Self {
+        self.color_fn = Some(Box::new(color_fn));
+        self
+    }
+
+    /// Dragging over the cells while holding Shift sets all of them to the new state
+    /// of the first cell.
+    pub fn multi_select(mut self, multi_select: bool) -> Self {
+        self.multi_select = multi_select;
+        self
+    }
+
+    pub fn show(self, ui: &mut Ui) -> ButtonMatrixResponse {
+        let row_count = self.states.len();
+        let column_count = self.states.iter().map(Vec::len).max().unwrap_or(0);
+
+        let pitch = self.cell_size + Vec2::splat(self.cell_gap);
+
+        let desired_size = (vec2(column_count as f32, row_count as f32) * pitch
+            - Vec2::splat(self.cell_gap))
+        .max(Vec2::ZERO);
+
+        let (rect, mut response) = ui.allocate_exact_size(desired_size, Sense::click_and_drag());
+
+        let cell_rect = |row: usize, column: usize| {
+            Rect::from_min_size(
+                rect.min + vec2(column as f32, row as f32) * pitch,
+                self.cell_size,
+            )
+        };
+
+        let cell_at = |states: &Vec<Vec<bool>>, pos| {
+            (0..row_count)
+                .flat_map(|row| (0..states[row].len()).map(move |column| (row, column)))
+                .find(|&(row, column)| cell_rect(row, column).contains(pos))
+        };
+
+        let mut last_toggled = None;
+
+        let paint_id = response.id.with("paint");
+        let shift = ui.input(|input| input.modifiers.shift);
+
+        if response.drag_started() && self.multi_select && shift {
+            if let Some((row, column)) = response
+                .interact_pointer_pos()
+                .and_then(|pos| cell_at(self.states, pos))
+            {
+                let state = &mut self.states[row][column];
+                *state = !*state;
+                last_toggled = Some((row, column));
+
+                let paint_state = PaintState {
+                    state: *state,
+                    last_cell: (row, column),
+                };
+                ui.memory_mut(|memory| memory.data.insert_temp(paint_id, paint_state));
+            }
+        } else if response.dragged() {
+            let paint_state = ui.memory(|memory| memory.data.get_temp::<PaintState>(paint_id));
+
+            if let Some(mut paint_state) = paint_state {
+                if let Some((row, column)) = response
+                    .interact_pointer_pos()
+                    .and_then(|pos| cell_at(self.states, pos))
+                    .filter(|&cell| cell != paint_state.last_cell)
+                {
+                    if self.states[row][column] != paint_state.state {
+                        self.states[row][column] = paint_state.state;
+                        last_toggled = Some((row, column));
+                    }
+
+                    paint_state.last_cell = (row, column);
+                    ui.memory_mut(|memory| memory.data.insert_temp(paint_id, paint_state));
+                }
+            }
+        } else if response.clicked() {
+            if let Some((row, column)) = response
+                .interact_pointer_pos()
+                .and_then(|pos| cell_at(self.states, pos))
+            {
+                let state = &mut self.states[row][column];
+                *state = !*state;
+                last_toggled = Some((row, column));
+            }
+        }
+
+        if response.drag_stopped() {
+            ui.memory_mut(|memory| memory.data.remove::<PaintState>(paint_id));
+        }
+
+        if last_toggled.is_some() {
+            response.mark_changed();
+        }
+
+        if ui.is_rect_visible(rect) {
+            let visuals = ui.style().visuals.clone();
+            let hover_pos = response.hover_pos();
+
+            for (row, row_states) in self.states.iter().enumerate() {
+                for (column, &state) in row_states.iter().enumerate() {
+                    let cell_rect = cell_rect(row, column);
+
+                    let active_color = match &self.color_fn {
+                        Some(color_fn) => color_fn(row, column),
+                        None => self.active_color,
+                    };
+
+                    let hovered = hover_pos.is_some_and(|pos| cell_rect.contains(pos));
+
+                    ui.painter().rect(
+                        cell_rect,
+                        visuals.widgets.inactive.rounding,
+                        if state {
+                            active_color
+                        } else {
+                            active_color.gamma_multiply(0.15)
+                        },
+                        if hovered {
+                            visuals.widgets.hovered.fg_stroke
+                        } else {
+                            Stroke::new(1.0, active_color.gamma_multiply(0.5))
+                        },
+                    );
+                }
+            }
+        }
+
+        ButtonMatrixResponse {
+            response,
+            last_toggled,
+        }
+    }
+}
+
+impl<'a> Widget for ButtonMatrix<'a> {
+    fn ui(self, ui: &mut Ui) -> Response {
+        self.show(ui).response
+    }
+}
@@ -0,0 +1,11 @@
+mod adsr_widget;
+mod button_matrix;
+mod patchbay_widget;
+mod piano_keyboard;
+mod step_sequencer;
+
+pub use adsr_widget::{AdsrTimeScale, AdsrWidget};
+pub use button_matrix::{ButtonMatrix, ButtonMatrixResponse};
+pub use patchbay_widget::PatchbayWidget;
+pub use piano_keyboard::PianoKeyboard;
+pub use step_sequencer::StepSequencer;
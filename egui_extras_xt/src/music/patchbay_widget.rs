@@ -0,0 +1,226 @@
+use std::f32::consts::TAU;
+
+use egui::epaint::TextShape;
+use egui::{
+    self, pos2, vec2, Align2, Color32, Pos2, Rect, Response, Sense, Stroke, TextStyle, Ui, Widget,
+};
+
+// ----------------------------------------------------------------------------
+
+/// Routing matrix connecting sources (rows) to destinations (columns), like the
+/// patch bays of studios and the port routing views of MIDI tools.
+#[must_use = "You should put this widget in an ui with `ui.add(widget);`"]
+pub struct PatchbayWidget<'a> {
+    connections: &'a mut Vec<Vec<bool>>,
+    row_labels: &'a [&'a str],
+    column_labels: &'a [&'a str],
+    exclusive_columns: bool,
+    junction_spacing: f32,
+    junction_radius: f32,
+    connected_color: Color32,
+}
+
+impl<'a> PatchbayWidget<'a> {
+    pub fn new(connections: &'a mut Vec<Vec<bool>>) -> Self {
+        Self {
+            connections,
+            row_labels: &[],
+            column_labels: &[],
+            exclusive_columns: false,
+            junction_spacing: 24.0,
+            junction_radius: 6.0,
+            connected_color: Color32::from_rgb(0xE6, 0x8C, 0x28),
+        }
+    }
+
+    /// Row headers.
+    pub fn row_labels(mut self, row_labels: &'a [&'a str]) -> Self {
+        self.row_labels = row_labels;
+        self
+    }
+
+    /// Column headers, drawn vertically.
+    pub fn column_labels(mut self, column_labels: &'a [&'a str]) -> Self {
+        self.column_labels = column_labels;
+        self
+    }
+
+    /// Allows only one connection per column (destination), connecting a junction
+    /// disconnects the other junctions of its column.
+    pub fn exclusive_columns(mut self, exclusive_columns: bool) -> Self {
+        self.exclusive_columns = exclusive_columns;
+        self
+    }
+
+    /// Distance between the centers of neighboring junctions.
+    pub fn junction_spacing(mut self, junction_spacing: impl Into<f32>) -> Self {
+        self.junction_spacing = junction_spacing.into();
+        self
+    }
+
+    pub fn junction_radius(mut self, junction_radius: impl Into<f32>) -> Self {
+        self.junction_radius = junction_radius.into();
+        self
+    }
+
+    pub fn connected_color(mut self, connected_color: Color32) -> Self {
+        self.connected_color = connected_color;
+        self
+    }
+}
+
+impl<'a> Widget for PatchbayWidget<'a> {
+    fn ui(self, ui: &mut Ui) -> Response {
+        let row_count = self.connections.len();
+        let column_count = self.connections.iter().map(Vec::len).max().unwrap_or(0);
+
+        let font_id = TextStyle::Small.resolve(ui.style());
+        let text_color = ui.style().visuals.text_color();
+        let label_spacing = ui.spacing().item_spacing.x;
+
+        let layout_labels = |labels: &[&str]| {
+            labels
+                .iter()
+                .map(|label| {
+                    ui.painter()
+                        .layout_no_wrap(label.to_string(), font_id.clone(), text_color)
+                })
+                .collect::<Vec<_>>()
+        };
+
+        let row_galleys = layout_labels(self.row_labels);
+        let column_galleys = layout_labels(self.column_labels);
+
+        // Header sizes, the destination labels are rotated by a quarter turn
+        let header_width = row_galleys
+            .iter()
+            .map(|galley| galley.size().x + label_spacing)
+            .fold(0.0, f32::max);
+        let header_height = column_galleys
+            .iter()
+            .map(|galley| galley.size().x + label_spacing)
+            .fold(0.0, f32::max);
+
+        let desired_size = vec2(
+            header_width + self.junction_spacing * column_count as f32,
+            header_height + self.junction_spacing * row_count as f32,
+        );
+
+        let (rect, mut response) = ui.allocate_exact_size(desired_size, Sense::click());
+
+        let grid_rect = Rect::from_min_max(rect.min + vec2(header_width, header_height), rect.max);
+
+        let junction_center = |row: usize, column: usize| {
+            grid_rect.min + vec2(column as f32 + 0.5, row as f32 + 0.5) * self.junction_spacing
+        };
+
+        let junction_at = |connections: &Vec<Vec<bool>>, pos: Pos2| {
+            let cell = (pos - grid_rect.min) / self.junction_spacing;
+            (cell.x >= 0.0 && cell.y >= 0.0)
+                .then_some((cell.y as usize, cell.x as usize))
+                .filter(|&(row, column)| row < row_count && column < connections[row].len())
+        };
+
+        if response.clicked() {
+            if let Some((row, column)) = response
+                .interact_pointer_pos()
+                .and_then(|pos| junction_at(self.connections, pos))
+            {
+                let connected = !self.connections[row][column];
+
+                if connected && self.exclusive_columns {
+                    for row_connections in self.connections.iter_mut() {
+                        if let Some(connection) = row_connections.get_mut(column) {
+                            *connection = false;
+                        }
+                    }
+                }
+
+                self.connections[row][column] = connected;
+                response.mark_changed();
+            }
+        }
+
+        let hovered_junction = response
+            .hover_pos()
+            .and_then(|pos| junction_at(self.connections, pos));
+
+        if let Some((row, column)) = hovered_junction {
+            let source = self.row_labels.get(row).copied();
+            let destination = self.column_labels.get(column).copied();
+
+            if let (Some(source), Some(destination)) = (source, destination) {
+                response =
+                    response.on_hover_text_at_pointer(format!("{source} \u{2192} {destination}"));
+            }
+        }
+
+        if ui.is_rect_visible(rect) {
+            let visuals = ui.style().visuals.clone();
+
+            // Crosshair of the hovered junction
+            if let Some((row, column)) = hovered_junction {
+                let center = junction_center(row, column);
+                let highlight = visuals.widgets.hovered.weak_bg_fill.gamma_multiply(0.5);
+
+                ui.painter().rect_filled(
+                    Rect::from_x_y_ranges(
+                        rect.x_range(),
+                        (center.y - self.junction_spacing / 2.0)
+                            ..=(center.y + self.junction_spacing / 2.0),
+                    ),
+                    0.0,
+                    highlight,
+                );
+                ui.painter().rect_filled(
+                    Rect::from_x_y_ranges(
+                        (center.x - self.junction_spacing / 2.0)
+                            ..=(center.x + self.junction_spacing / 2.0),
+                        rect.y_range(),
+                    ),
+                    0.0,
+                    highlight,
+                );
+            }
+
+            for (row, galley) in row_galleys.into_iter().enumerate().take(row_count) {
+                let pos = Align2::LEFT_CENTER
+                    .anchor_size(pos2(rect.left(), junction_center(row, 0).y), galley.size())
+                    .min;
+                ui.painter().galley(pos, galley, text_color);
+            }
+
+            for (column, galley) in column_galleys.into_iter().enumerate().take(column_count) {
+                // Rotated counter-clockwise, reading from bottom to top
+                let pos = pos2(
+                    junction_center(0, column).x - galley.size().y / 2.0,
+                    grid_rect.top() - label_spacing,
+                );
+                ui.painter()
+                    .add(TextShape::new(pos, galley, text_color).with_angle(-TAU / 4.0));
+            }
+
+            let line_stroke = Stroke::new(1.0, visuals.widgets.noninteractive.bg_stroke.color);
+
+            for (row, row_connections) in self.connections.iter().enumerate() {
+                for (column, &connected) in row_connections.iter().enumerate() {
+                    let center = junction_center(row, column);
+
+                    if connected {
+                        ui.painter().circle(
+                            center,
+                            self.junction_radius,
+                            self.connected_color,
+                            Stroke::new(1.0, self.connected_color),
+                        );
+                    } else {
+                        ui.painter()
+                            .circle_stroke(center, self.junction_radius, line_stroke);
+                    }
+                }
+            }
+        }
+
+        response
+    }
+}
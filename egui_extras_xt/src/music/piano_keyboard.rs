@@ -0,0 +1,217 @@
+use std::ops::RangeInclusive;
+
+use egui::{self, vec2, Align2, Color32, FontId, Pos2, Rect, Response, Sense, Stroke, Ui, Widget};
+use emath::Vec2;
+
+// ----------------------------------------------------------------------------
+
+const NOTE_NAMES: [&str; 12] = [
+    "C", "C#", "D", "D#", "E", "F", "F#", "G", "G#", "A", "A#", "B",
+];
+
+fn is_black_key(note: u8) -> bool {
+    matches!(note % 12, 1 | 3 | 6 | 8 | 10)
+}
+
+/// Name of the MIDI note in scientific pitch notation, middle C (60) is `C4`.
+fn note_name(note: u8) -> String {
+    format!(
+        "{}{}",
+        NOTE_NAMES[(note % 12) as usize],
+        (note / 12) as i32 - 1
+    )
+}
+
+// ----------------------------------------------------------------------------
+
+/// Piano keyboard for picking MIDI notes.
+///
+/// Ranges starting or ending on black keys are extended to the neighboring white keys.
+#[must_use = "You should put this widget in an ui with `ui.add(widget);`"]
+pub struct PianoKeyboard<'a> {
+    note_range: RangeInclusive<u8>,
+    active_notes: &'a [u8],
+    on_note_click: Option<Box<dyn 'a + FnMut(u8)>>,
+    white_key_size: Vec2,
+    white_key_color: Color32,
+    black_key_color: Color32,
+    active_color: Color32,
+    show_note_names: bool,
+}
+
+impl<'a> PianoKeyboard<'a> {
+    pub fn new(note_range: RangeInclusive<u8>) -> Self {
+        Self {
+            note_range,
+            active_notes: &[],
+            on_note_click: None,
+            white_key_size: vec2(20.0, 96.0),
+            white_key_color: Color32::from_gray(0xF0),
+            black_key_color: Color32::from_gray(0x20),
+            active_color: Color32::from_rgb(0x3C, 0x8C, 0xE6),
+            show_note_names: false,
+        }
+    }
+
+    /// Highlighted notes.
+    pub fn active_notes(mut self, active_notes: &'a [u8]) -> Self {
+        self.active_notes = active_notes;
+        self
+    }
+
+    /// Called with the note of the clicked key.
+    pub fn on_note_click(mut self, on_note_click: impl 'a + FnMut(u8)) -> Self {
+        self.on_note_click = Some(Box::new(on_note_click));
+        self
+    }
+
+    /// Size of the white keys, the black keys are sized relative to them.
+    pub fn white_key_size(mut self, white_key_size: impl Into<Vec2>) -> Self {
+        self.white_key_size = white_key_size.into();
+        self
+    }
+
+    pub fn white_key_color(mut self, white_key_color: Color32) -> Self {
+        self.white_key_color = white_key_color;
+        self
+    }
+
+    pub fn black_key_color(mut self, black_key_color: Color32) -> Self {
+        self.black_key_color = black_key_color;
+        self
+    }
+
+    pub fn active_color(mut self, active_color: Color32) -> Self {
+        self.active_color = active_color;
+        self
+    }
+
+    /// Shows the note names on the white keys.
+    pub fn show_note_names(mut self, show_note_names: bool) -> Self {
+        self.show_note_names = show_note_names;
+        self
+    }
+
+    /// Notes of the keyboard, extended to start and end with white keys.
+    fn notes(&self) -> RangeInclusive<u8> {
+        let start = *self.note_range.start();
+        let end = *self.note_range.end();
+
+        let start = if is_black_key(start) {
+            start - 1
+        } else {
+            start
+        };
+        let end = if is_black_key(end) { end + 1 } else { end };
+
+        start..=end
+    }
+
+    /// Rectangle of the key relative to the top left corner of the keyboard.
+    fn key_rect(&self, note: u8) -> Rect {
+        let white_keys_before = (*self.notes().start()..note)
+            .filter(|&note| !is_black_key(note))
+            .count() as f32;
+
+        if is_black_key(note) {
+            let black_key_size = self.white_key_size * vec2(0.6, 0.62);
+
+            Rect::from_min_size(
+                Pos2::new(
+                    white_keys_before * self.white_key_size.x - black_key_size.x / 2.0,
+                    0.0,
+                ),
+                black_key_size,
+            )
+        } else {
+            Rect::from_min_size(
+                Pos2::new(white_keys_before * self.white_key_size.x, 0.0),
+                self.white_key_size,
+            )
+        }
+    }
+}
+
+impl<'a> Widget for PianoKeyboard<'a> {
+    fn ui(mut self, ui: &mut Ui) -> Response {
+        let white_key_count = self.notes().filter(|&note| !is_black_key(note)).count();
+
+        let desired_size = vec2(
+            self.white_key_size.x * white_key_count as f32,
+            self.white_key_size.y,
+        );
+
+        let (rect, response) = ui.allocate_exact_size(desired_size, Sense::click());
+
+        let key_rect = |note: u8| self.key_rect(note).translate(rect.min.to_vec2());
+
+        // Black keys cover the white keys, so they get hit tested first
+        let note_at = |pos: Pos2| {
+            self.notes()
+                .filter(|&note| is_black_key(note))
+                .chain(self.notes().filter(|&note| !is_black_key(note)))
+                .find(|&note| key_rect(note).contains(pos))
+        };
+
+        let pressed_note = response
+            .interact_pointer_pos()
+            .filter(|_| response.is_pointer_button_down_on())
+            .and_then(note_at);
+
+        let clicked_note = response
+            .interact_pointer_pos()
+            .filter(|_| response.clicked())
+            .and_then(note_at);
+
+        if ui.is_rect_visible(rect) {
+            let stroke = Stroke::new(1.0, ui.style().visuals.window_stroke().color);
+            let rounding = 2.0;
+
+            let key_color = |note: u8, base_color: Color32| {
+                if Some(note) == pressed_note {
+                    self.active_color.gamma_multiply(0.7)
+                } else if self.active_notes.contains(&note) {
+                    self.active_color
+                } else {
+                    base_color
+                }
+            };
+
+            for note in self.notes().filter(|&note| !is_black_key(note)) {
+                let key_rect = key_rect(note);
+
+                ui.painter().rect(
+                    key_rect,
+                    rounding,
+                    key_color(note, self.white_key_color),
+                    stroke,
+                );
+
+                if self.show_note_names {
+                    ui.painter().text(
+                        key_rect.center_bottom() - vec2(0.0, 4.0),
+                        Align2::CENTER_BOTTOM,
+                        note_name(note),
+                        FontId::proportional((self.white_key_size.x * 0.45).max(6.0)),
+                        self.black_key_color,
+                    );
+                }
+            }
+
+            for note in self.notes().filter(|&note| is_black_key(note)) {
+                ui.painter().rect(
+                    key_rect(note),
+                    rounding,
+                    key_color(note, self.black_key_color),
+                    stroke,
+                );
+            }
+        }
+
+        if let (Some(note), Some(on_note_click)) = (clicked_note, &mut self.on_note_click) {
+            on_note_click(note);
+        }
+
+        response
+    }
+}
@@ -0,0 +1,222 @@
+use egui::{
+    self, vec2, Align2, Color32, Pos2, Rect, Response, Sense, Stroke, TextStyle, Ui, Widget,
+};
+use emath::Vec2;
+
+type ToggleCallback<'a> = Box<dyn 'a + FnMut(usize, usize, bool)>;
+
+// ----------------------------------------------------------------------------
+
+/// Grid of toggleable steps, one row per instrument and one column per step.
+#[must_use = "You should put this widget in an ui with `ui.add(widget);`"]
+pub struct StepSequencer<'a> {
+    steps: &'a mut Vec<Vec<bool>>,
+    current_step: Option<usize>,
+    on_toggle: Option<ToggleCallback<'a>>,
+    row_labels: &'a [&'a str],
+    show_step_numbers: bool,
+    cell_size: Vec2,
+    cell_gap: f32,
+    grid_color: Option<Color32>,
+    active_color: Color32,
+    current_step_color: Color32,
+}
+
+impl<'a> StepSequencer<'a> {
+    pub fn new(steps: &'a mut Vec<Vec<bool>>) -> Self {
+        Self {
+            steps,
+            current_step: None,
+            on_toggle: None,
+            row_labels: &[],
+            show_step_numbers: true,
+            cell_size: Vec2::splat(20.0),
+            cell_gap: 2.0,
+            grid_color: None,
+            active_color: Color32::from_rgb(0xE6, 0x8C, 0x28),
+            current_step_color: Color32::from_white_alpha(0x30),
+        }
+    }
+
+    /// Column highlighted as the playhead.
+    pub fn current_step(mut self, current_step: Option<usize>) -> Self {
+        self.current_step = current_step;
+        self
+    }
+
+    /// Called with the row, the column and the new state of the toggled cell.
+    pub fn on_toggle(mut self, on_toggle: impl 'a + FnMut(usize, usize, bool)) -> Self {
+        self.on_toggle = Some(Box::new(on_toggle));
+        self
+    }
+
+    /// Labels in front of the rows, usually the names of the instruments.
+    pub fn row_labels(mut self, row_labels: &'a [&'a str]) -> Self {
+        self.row_labels = row_labels;
+        self
+    }
+
+    /// Shows the 1-based step numbers above the columns.
+    pub fn show_step_numbers(mut self, show_step_numbers: bool) -> Self {
+        self.show_step_numbers = show_step_numbers;
+        self
+    }
+
+    pub fn cell_size(mut self, cell_size: impl Into<Vec2>) -> Self {
+        self.cell_size = cell_size.into();
+        self
+    }
+
+    pub fn cell_gap(mut self, cell_gap: impl Into<f32>) -> Self {
+        self.cell_gap = cell_gap.into();
+        self
+    }
+
+    /// Color of the inactive cells, the faint background color of the ui by default.
+    pub fn grid_color(mut self, grid_color: Color32) -> Self {
+        self.grid_color = Some(grid_color);
+        self
+    }
+
+    pub fn active_color(mut self, active_color: Color32) -> Self {
+        self.active_color = active_color;
+        self
+    }
+
+    pub fn current_step_color(mut self, current_step_color: Color32) -> Self {
+        self.current_step_color = current_step_color;
+        self
+    }
+}
+
+impl<'a> Widget for StepSequencer<'a> {
+    fn ui(mut self, ui: &mut Ui) -> Response {
+        let row_count = self.steps.len();
+        let column_count = self.steps.iter().map(Vec::len).max().unwrap_or(0);
+
+        let font_id = TextStyle::Small.resolve(ui.style());
+        let text_color = ui.style().visuals.text_color();
+
+        let label_width = self
+            .row_labels
+            .iter()
+            .map(|label| {
+                ui.painter()
+                    .layout_no_wrap(label.to_string(), font_id.clone(), text_color)
+                    .size()
+                    .x
+            })
+            .reduce(f32::max)
+            .map_or(0.0, |width| width + ui.spacing().item_spacing.x);
+
+        let header_height = if self.show_step_numbers {
+            ui.fonts(|fonts| fonts.row_height(&font_id)) + self.cell_gap
+        } else {
+            0.0
+        };
+
+        let pitch = self.cell_size + Vec2::splat(self.cell_gap);
+
+        let desired_size = vec2(
+            label_width + pitch.x * column_count as f32 - self.cell_gap,
+            header_height + pitch.y * row_count as f32 - self.cell_gap,
+        )
+        .max(Vec2::ZERO);
+
+        let (rect, mut response) = ui.allocate_exact_size(desired_size, Sense::click());
+
+        let grid_origin = rect.min + vec2(label_width, header_height);
+
+        let cell_rect = |row: usize, column: usize| {
+            Rect::from_min_size(
+                grid_origin + vec2(column as f32, row as f32) * pitch,
+                self.cell_size,
+            )
+        };
+
+        if response.clicked() {
+            if let Some(pointer_pos) = response.interact_pointer_pos() {
+                let cell = (0..row_count)
+                    .flat_map(|row| (0..self.steps[row].len()).map(move |column| (row, column)))
+                    .find(|&(row, column)| cell_rect(row, column).contains(pointer_pos));
+
+                if let Some((row, column)) = cell {
+                    let state = &mut self.steps[row][column];
+                    *state = !*state;
+
+                    if let Some(on_toggle) = &mut self.on_toggle {
+                        on_toggle(row, column, *state);
+                    }
+
+                    response.mark_changed();
+                }
+            }
+        }
+
+        if ui.is_rect_visible(rect) {
+            let grid_color = self.grid_color.unwrap_or(ui.style().visuals.faint_bg_color);
+            let rounding = ui.style().visuals.widgets.inactive.rounding;
+
+            if let Some(current_step) = self.current_step.filter(|&step| step < column_count) {
+                let column_rect = Rect::from_min_size(
+                    Pos2::new(
+                        grid_origin.x + current_step as f32 * pitch.x - self.cell_gap / 2.0,
+                        rect.top(),
+                    ),
+                    vec2(pitch.x, rect.height()),
+                );
+
+                ui.painter()
+                    .rect_filled(column_rect, rounding, self.current_step_color);
+            }
+
+            if self.show_step_numbers {
+                for column in 0..column_count {
+                    ui.painter().text(
+                        Pos2::new(cell_rect(0, column).center().x, rect.top()),
+                        Align2::CENTER_TOP,
+                        (column + 1).to_string(),
+                        font_id.clone(),
+                        if Some(column) == self.current_step {
+                            text_color
+                        } else {
+                            ui.style().visuals.weak_text_color()
+                        },
+                    );
+                }
+            }
+
+            for (row, label) in self.row_labels.iter().enumerate().take(row_count) {
+                ui.painter().text(
+                    Pos2::new(rect.left(), cell_rect(row, 0).center().y),
+                    Align2::LEFT_CENTER,
+                    label,
+                    font_id.clone(),
+                    text_color,
+                );
+            }
+
+            let hover_pos = response.hover_pos();
+
+            for (row, row_steps) in self.steps.iter().enumerate() {
+                for (column, &state) in row_steps.iter().enumerate() {
+                    let cell_rect = cell_rect(row, column);
+                    let hovered = hover_pos.is_some_and(|pos| cell_rect.contains(pos));
+
+                    ui.painter().rect(
+                        cell_rect,
+                        rounding,
+                        if state { self.active_color } else { grid_color },
+                        if hovered {
+                            ui.style().visuals.widgets.hovered.fg_stroke
+                        } else {
+                            Stroke::NONE
+                        },
+                    );
+                }
+            }
+        }
+
+        response
+    }
+}
@@ -0,0 +1,275 @@
+use std::fmt::Display;
+use std::hash::Hash;
+
+use egui::{Id, Response, Sense, Ui, Widget};
+
+// ----------------------------------------------------------------------------
+
+/// Row being dragged, lists only accept the rows of their own.
+struct DragPayload {
+    list_id: Id,
+    index: usize,
+}
+
+// ----------------------------------------------------------------------------
+
+/// List of items reordered by dragging their rows at the handle on the left, like the
+/// effect chains of audio workstations. Reordering and removing items are reported by
+/// [`Response::changed`].
+#[must_use = "You should put this widget in an ui with `ui.add(widget);`"]
+pub struct DragAndDropList<'a, T: Display> {
+    items: &'a mut Vec<T>,
+    id_source: Option<Id>,
+    removable: bool,
+}
+
+impl<'a, T: Display> DragAndDropList<'a, T> {
+    pub fn new(items: &'a mut Vec<T>) -> Self {
+        Self {
+            items,
+            id_source: None,
+            removable: false,
+        }
+    }
+
+    /// Keeps the drags of this list apart from the other lists of the same `Ui`.
+    /// Without it the list is keyed on its position in the `Ui`, which changes when
+    /// widgets are added in front of it.
+    pub fn id_source(mut self, id_source: impl Hash) -> Self {
+        self.id_source = Some(Id::new(id_source));
+        self
+    }
+
+    /// Adds a remove button to each row.
+    pub fn removable(mut self, removable: bool) -> Self {
+        self.removable = removable;
+        self
+    }
+}
+
+impl<'a, T: Display> Widget for DragAndDropList<'a, T> {
+    fn ui(self, ui: &mut Ui) -> Response {
+        let mut removed_index = None;
+
+        // Sibling child `Ui`s share their ids, so `ui.id()` can't tell lists apart
+        let list_id = match self.id_source {
+            Some(id_source) => ui.make_persistent_id(id_source),
+            None => ui.next_auto_id(),
+        };
+
+        let inner_response = ui.vertical(|ui| {
+            let row_rects = self
+                .items
+                .iter()
+                .enumerate()
+                .map(|(index, item)| {
+                    ui.horizontal(|ui| {
+                        ui.dnd_drag_source(
+                            list_id.with(("handle", index)),
+                            DragPayload { list_id, index },
+                            |ui| ui.label("\u{2630}"),
+                        );
+
+                        ui.label(item.to_string());
+
+                        if self.removable
+                            && ui
+                                .small_button("\u{00D7}")
+                                .on_hover_text("Remove")
+                                .clicked()
+                        {
+                            removed_index = Some(index);
+                        }
+                    })
+                    .response
+                    .rect
+                })
+                .collect::<Vec<_>>();
+
+            row_rects
+        });
+
+        // The response of the child `Ui` has a shared id as well, it would be hit-tested
+        // with the rect of the last sibling list
+        let row_rects = inner_response.inner;
+        let mut response = ui.interact(inner_response.response.rect, list_id, Sense::hover());
+
+        let payload = response
+            .dnd_hover_payload::<DragPayload>()
+            .filter(|payload| payload.list_id == list_id);
+
+        if let (Some(payload), Some(pointer_pos)) = (payload, ui.ctx().pointer_interact_pos()) {
+            // Slot in front of the first row below the pointer
+            let insert_index = row_rects
+                .iter()
+                .position(|row_rect| pointer_pos.y < row_rect.center().y)
+                .unwrap_or(row_rects.len());
+
+            let half_spacing = ui.spacing().item_spacing.y / 2.0;
+            let indicator_y = match row_rects.get(insert_index) {
+                Some(row_rect) => row_rect.top() - half_spacing,
+                None => response.rect.bottom() + half_spacing,
+            };
+
+            ui.painter().hline(
+                response.rect.x_range(),
+                indicator_y,
+                ui.style().visuals.selection.stroke,
+            );
+
+            if response.dnd_release_payload::<DragPayload>().is_some() {
+                // Removing the dragged item shifts the slots below it
+                let target_index = if insert_index > payload.index {
+                    insert_index - 1
+                } else {
+                    insert_index
+                };
+
+                if target_index != payload.index {
+                    let item = self.items.remove(payload.index);
+                    self.items.insert(target_index, item);
+                    response.mark_changed();
+                }
+            }
+        }
+
+        if let Some(index) = removed_index {
+            self.items.remove(index);
+            response.mark_changed();
+        }
+
+        response
+    }
+}
+
+// ----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use egui::{CentralPanel, Context, Event, Modifiers, PointerButton, Pos2, RawInput, Rect};
+    use emath::vec2;
+
+    use super::*;
+
+    /// Runs a frame with two lists side by side, returning the rects of their rows.
+    fn run_frame(
+        ctx: &Context,
+        left: &mut Vec<&'static str>,
+        right: &mut Vec<&'static str>,
+        frame: usize,
+        events: Vec<Event>,
+    ) -> (Vec<Rect>, Vec<Rect>) {
+        let input = RawInput {
+            screen_rect: Some(Rect::from_min_size(Pos2::ZERO, vec2(800.0, 600.0))),
+            time: Some(frame as f64 / 60.0),
+            events,
+            ..Default::default()
+        };
+
+        let mut rects = (Vec::new(), Vec::new());
+        let _ = ctx.run(input, |ctx| {
+            CentralPanel::default().show(ctx, |ui| {
+                ui.horizontal(|ui| {
+                    let row_rects = |response: Response, count: usize| {
+                        let row_height = response.rect.height() / count as f32;
+                        (0..count)
+                            .map(|index| {
+                                Rect::from_min_size(
+                                    response.rect.min + vec2(0.0, row_height * index as f32),
+                                    vec2(response.rect.width(), row_height),
+                                )
+                            })
+                            .collect()
+                    };
+
+                    let count = left.len();
+                    rects.0 = row_rects(ui.add(DragAndDropList::new(left)), count);
+                    let count = right.len();
+                    rects.1 = row_rects(ui.add(DragAndDropList::new(right)), count);
+                });
+            });
+        });
+        rects
+    }
+
+    fn drag(
+        left: &mut Vec<&'static str>,
+        right: &mut Vec<&'static str>,
+        from: impl Fn(&(Vec<Rect>, Vec<Rect>)) -> Pos2,
+        to: impl Fn(&(Vec<Rect>, Vec<Rect>)) -> Pos2,
+    ) {
+        let ctx = Context::default();
+        let rects = run_frame(&ctx, left, right, 0, Vec::new());
+        let (start, end) = (from(&rects), to(&rects));
+
+        let button = |pos, pressed| Event::PointerButton {
+            pos,
+            button: PointerButton::Primary,
+            pressed,
+            modifiers: Modifiers::NONE,
+        };
+
+        let mut frame = 1;
+        let mut run = |events| {
+            run_frame(&ctx, left, right, frame, events);
+            frame += 1;
+        };
+
+        run(vec![Event::PointerMoved(start)]);
+        run(vec![button(start, true)]);
+        for step in 1..=10 {
+            run(vec![Event::PointerMoved(
+                start.lerp(end, step as f32 / 10.0),
+            )]);
+        }
+        run(vec![button(end, false)]);
+        run(Vec::new());
+    }
+
+    /// Handles sit at the left edge of the rows.
+    fn handle(row_rect: Rect) -> Pos2 {
+        row_rect.left_center() + vec2(4.0, 0.0)
+    }
+
+    #[test]
+    fn reorders_within_a_list() {
+        let mut left = vec!["a", "b", "c", "d", "e"];
+        let mut right = vec!["x", "y"];
+
+        drag(
+            &mut left,
+            &mut right,
+            |(left, _)| handle(left[4]),
+            |(left, _)| left[0].center_top() + vec2(0.0, 1.0),
+        );
+
+        assert_eq!(left, ["e", "a", "b", "c", "d"]);
+        assert_eq!(right, ["x", "y"]);
+    }
+
+    #[test]
+    fn side_by_side_lists_ignore_rows_of_each_other() {
+        let mut left = vec!["a", "b", "c", "d", "e"];
+        let mut right = vec!["x", "y"];
+
+        drag(
+            &mut left,
+            &mut right,
+            |(left, _)| handle(left[4]),
+            |(_, right)| right[0].center_top() + vec2(0.0, 1.0),
+        );
+
+        assert_eq!(left, ["a", "b", "c", "d", "e"]);
+        assert_eq!(right, ["x", "y"]);
+
+        drag(
+            &mut left,
+            &mut right,
+            |(_, right)| handle(right[1]),
+            |(left, _)| left[0].center_top() + vec2(0.0, 1.0),
+        );
+
+        assert_eq!(left, ["a", "b", "c", "d", "e"]);
+        assert_eq!(right, ["x", "y"]);
+    }
+}
@@ -1,8 +1,13 @@
 pub mod about_window;
+pub mod drag_list;
 pub mod drag_rangeinclusive;
 pub mod hyperlink_with_icon;
+pub mod numeric_stepper;
 pub mod optional_value_widget;
 pub mod rotated_label;
+pub mod searchable_combo;
 pub mod standard_buttons;
+pub mod tab_bar;
+pub mod toggle_switch;
 pub mod widgets_from_iter;
 pub mod widgets_from_slice;
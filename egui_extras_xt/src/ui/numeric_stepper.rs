@@ -0,0 +1,164 @@
+use std::time::Duration;
+
+use egui::emath::{format_with_decimals_in_range, Numeric};
+use egui::{Button, Response, Ui, Widget};
+
+// ----------------------------------------------------------------------------
+
+#[derive(Clone, Copy)]
+struct RepeatState {
+    next_repeat: f64,
+    interval: f64,
+}
+
+// ----------------------------------------------------------------------------
+
+/// Value with decrement and increment buttons, repeating while the buttons are held down.
+#[must_use = "You should put this widget in an ui with `ui.add(widget);`"]
+pub struct NumericStepper<'a, T: Numeric> {
+    value: &'a mut T,
+    step: T,
+    min: T,
+    max: T,
+    format: Option<Box<dyn 'a + Fn(T) -> String>>,
+    repeat_delay: Duration,
+    repeat_rate: Duration,
+    repeat_acceleration: f32,
+}
+
+impl<'a, T: Numeric> NumericStepper<'a, T> {
+    pub fn new(value: &'a mut T) -> Self {
+        Self {
+            value,
+            step: T::from_f64(1.0),
+            min: T::MIN,
+            max: T::MAX,
+            format: None,
+            repeat_delay: Duration::from_millis(500),
+            repeat_rate: Duration::from_millis(100),
+            repeat_acceleration: 1.0,
+        }
+    }
+
+    pub fn step(mut self, step: T) -> Self {
+        self.step = step;
+        self
+    }
+
+    pub fn min(mut self, min: T) -> Self {
+        self.min = min;
+        self
+    }
+
+    pub fn max(mut self, max: T) -> Self {
+        self.max = max;
+        self
+    }
+
+    pub fn format(mut self, format: impl 'a + Fn(T) -> String) -> Self {
+        self.format = Some(Box::new(format));
+        self
+    }
+
+    /// Time a button has to be held down before the value starts repeating.
+    pub fn repeat_delay(mut self, repeat_delay: Duration) -> Self {
+        self.repeat_delay = repeat_delay;
+        self
+    }
+
+    /// Time between the repeated steps.
+    pub fn repeat_rate(mut self, repeat_rate: Duration) -> Self {
+        self.repeat_rate = repeat_rate;
+        self
+    }
+
+    /// Divides the time between the repeated steps by the given factor after each step,
+    /// speeding up the longer the button is held. `1.0` repeats at a constant rate.
+    pub fn repeat_acceleration(mut self, repeat_acceleration: f32) -> Self {
+        self.repeat_acceleration = repeat_acceleration;
+        self
+    }
+
+    /// Returns `true` when the button should step the value in this frame.
+    fn repeat_button(&self, ui: &Ui, response: &Response) -> bool {
+        let time = ui.input(|input| input.time);
+
+        if !response.is_pointer_button_down_on() {
+            ui.memory_mut(|memory| memory.data.remove::<RepeatState>(response.id));
+            return false;
+        }
+
+        let state = ui.memory(|memory| memory.data.get_temp::<RepeatState>(response.id));
+
+        let (fire, state) = match state {
+            // Freshly pressed button
+            None => (
+                true,
+                RepeatState {
+                    next_repeat: time + self.repeat_delay.as_secs_f64(),
+                    interval: self.repeat_rate.as_secs_f64(),
+                },
+            ),
+            Some(state) if time >= state.next_repeat => (
+                true,
+                RepeatState {
+                    next_repeat: state.next_repeat + state.interval,
+                    interval: state.interval / self.repeat_acceleration.max(1.0) as f64,
+                },
+            ),
+            Some(state) => (false, state),
+        };
+
+        ui.memory_mut(|memory| memory.data.insert_temp(response.id, state));
+        ui.ctx()
+            .request_repaint_after_secs((state.next_repeat - time).max(0.0) as f32);
+
+        fire
+    }
+
+    fn format_value(&self, value: T) -> String {
+        match self.format {
+            Some(ref format) => format(value),
+            None if T::INTEGRAL => format_with_decimals_in_range(value.to_f64(), 0..=0),
+            None => format_with_decimals_in_range(value.to_f64(), 0..=6),
+        }
+    }
+}
+
+impl<'a, T: Numeric> Widget for NumericStepper<'a, T> {
+    fn ui(self, ui: &mut Ui) -> Response {
+        let mut inner_response = ui.horizontal(|ui| {
+            let decrement_response = ui.add(Button::new("\u{2796}"));
+            ui.label(self.format_value(*self.value));
+            let increment_response = ui.add(Button::new("\u{2795}"));
+
+            let direction = if self.repeat_button(ui, &decrement_response) {
+                -1.0
+            } else if self.repeat_button(ui, &increment_response) {
+                1.0
+            } else {
+                0.0
+            };
+
+            (direction, decrement_response | increment_response)
+        });
+
+        let (direction, buttons_response) = inner_response.inner;
+
+        if direction != 0.0 {
+            let old_value = self.value.to_f64();
+            let new_value = (old_value + direction * self.step.to_f64())
+                .clamp(self.min.to_f64(), self.max.to_f64());
+
+            if new_value != old_value {
+                *self.value = T::from_f64(new_value);
+                inner_response.response.mark_changed();
+
+                // The label got painted with the previous value
+                ui.ctx().request_repaint();
+            }
+        }
+
+        inner_response.response | buttons_response
+    }
+}
@@ -1,4 +1,9 @@
-use egui::{Response, Ui};
+use egui::epaint::{shape_transform::adjust_colors, ClippedShape};
+use egui::layers::ShapeIdx;
+use egui::{ecolor::tint_color_towards, Color32, Id, KeyboardShortcut, Response, Sense, Ui};
+
+/// Duration of the fade between the enabled and disabled looks, in seconds.
+pub const DEFAULT_FADE_DURATION: f32 = 0.15;
 
 pub trait OptionalValueWidget<T> {
     fn optional_value_widget(
@@ -6,6 +11,38 @@ pub trait OptionalValueWidget<T> {
         value: &mut Option<T>,
         add_contents: impl FnOnce(&mut Self, &mut T) -> Response,
     ) -> Response;
+
+    /// Like [`OptionalValueWidget::optional_value_widget`], with the checkbox labeled by
+    /// the text, such as "Enable compression".
+    fn optional_value_widget_labeled(
+        &mut self,
+        value: &mut Option<T>,
+        enable_label: &str,
+        add_contents: impl FnOnce(&mut Self, &mut T) -> Response,
+    ) -> Response;
+
+    /// Like [`OptionalValueWidget::optional_value_widget`], fading the inner widget over
+    /// the given duration in seconds instead of [`DEFAULT_FADE_DURATION`] when toggled.
+    /// Zero duration switches the look instantly.
+    fn optional_value_widget_with_fade(
+        &mut self,
+        value: &mut Option<T>,
+        fade_duration: f32,
+        add_contents: impl FnOnce(&mut Self, &mut T) -> Response,
+    ) -> Response;
+
+    /// Like [`OptionalValueWidget::optional_value_widget`], also toggled by pressing the
+    /// shortcut while the widget is visible. Disabling the value remembers it in
+    /// [`egui::Memory`], and enabling it again restores the remembered value instead of
+    /// the default one.
+    fn optional_value_widget_with_shortcut(
+        &mut self,
+        value: &mut Option<T>,
+        shortcut: KeyboardShortcut,
+        add_contents: impl FnOnce(&mut Self, &mut T) -> Response,
+    ) -> Response
+    where
+        T: Clone + Send + Sync + 'static;
 }
 
 impl<T: Default> OptionalValueWidget<T> for Ui {
@@ -14,31 +51,341 @@ impl<T: Default> OptionalValueWidget<T> for Ui {
         value: &mut Option<T>,
         add_contents: impl FnOnce(&mut Self, &mut T) -> Response,
     ) -> Response {
-        self.group(|ui| {
-            ui.horizontal(|ui| {
-                let mut checkbox_state = value.is_some();
-                let mut response = ui.checkbox(&mut checkbox_state, "");
-
-                match (value.is_some(), checkbox_state) {
-                    (false, true) => *value = Some(T::default()),
-                    (true, false) => *value = None,
-                    _ => {}
-                };
-
-                match value {
-                    Some(ref mut value) => {
-                        response = response.union(add_contents(ui, value));
-                    }
-                    None => {
-                        let mut dummy_value = T::default();
-                        ui.add_enabled_ui(false, |ui| add_contents(ui, &mut dummy_value));
-                    }
+        optional_value_widget_impl(
+            self,
+            value,
+            "",
+            None,
+            DEFAULT_FADE_DURATION,
+            |_, _, value, enabled| *value = enabled.then(T::default),
+            add_contents,
+        )
+    }
+
+    fn optional_value_widget_labeled(
+        &mut self,
+        value: &mut Option<T>,
+        enable_label: &str,
+        add_contents: impl FnOnce(&mut Self, &mut T) -> Response,
+    ) -> Response {
+        optional_value_widget_impl(
+            self,
+            value,
+            enable_label,
+            None,
+            DEFAULT_FADE_DURATION,
+            |_, _, value, enabled| *value = enabled.then(T::default),
+            add_contents,
+        )
+    }
+
+    fn optional_value_widget_with_fade(
+        &mut self,
+        value: &mut Option<T>,
+        fade_duration: f32,
+        add_contents: impl FnOnce(&mut Self, &mut T) -> Response,
+    ) -> Response {
+        optional_value_widget_impl(
+            self,
+            value,
+            "",
+            None,
+            fade_duration,
+            |_, _, value, enabled| *value = enabled.then(T::default),
+            add_contents,
+        )
+    }
+
+    fn optional_value_widget_with_shortcut(
+        &mut self,
+        value: &mut Option<T>,
+        shortcut: KeyboardShortcut,
+        add_contents: impl FnOnce(&mut Self, &mut T) -> Response,
+    ) -> Response
+    where
+        T: Clone + Send + Sync + 'static,
+    {
+        optional_value_widget_impl(
+            self,
+            value,
+            "",
+            Some(&shortcut),
+            DEFAULT_FADE_DURATION,
+            toggle_remembered,
+            add_contents,
+        )
+    }
+}
+
+// ----------------------------------------------------------------------------
+
+fn target_gray_out(enabled: bool) -> f32 {
+    if enabled {
+        0.0
+    } else {
+        1.0
+    }
+}
+
+/// Progress of the fade between the looks, kept in [`egui::Memory`].
+#[derive(Clone, Copy)]
+struct FadeState {
+    enabled: bool,
+    start_gray_out: f32,
+    start_time: f64,
+}
+
+impl FadeState {
+    /// Gray out moving towards the target at a constant rate, crossing the whole range in
+    /// the fade duration.
+    fn gray_out(&self, time: f64, fade_duration: f32) -> f32 {
+        let step = (time - self.start_time) as f32 / fade_duration;
+        let target = target_gray_out(self.enabled);
+
+        if self.start_gray_out < target {
+            (self.start_gray_out + step).min(target)
+        } else {
+            (self.start_gray_out - step).max(target)
+        }
+    }
+}
+
+/// How far the inner widget is grayed out towards the disabled look, from `0.0` for the
+/// enabled look to `1.0` for the disabled one. The fade restarts when the value got
+/// toggled, values shown for the first time don't fade.
+fn fade_gray_out(ui: &Ui, id: Id, enabled: bool, fade_duration: f32) -> f32 {
+    if fade_duration <= 0.0 {
+        return target_gray_out(enabled);
+    }
+
+    let time = ui.input(|input| input.time);
+
+    let state = match ui.memory(|memory| memory.data.get_temp::<FadeState>(id)) {
+        Some(state) if state.enabled == enabled => state,
+        previous_state => {
+            let state = FadeState {
+                enabled,
+                start_gray_out: previous_state.map_or(target_gray_out(enabled), |state| {
+                    state.gray_out(time, fade_duration)
+                }),
+                start_time: time,
+            };
+
+            ui.memory_mut(|memory| memory.data.insert_temp(id, state));
+            state
+        }
+    };
+
+    let gray_out = state.gray_out(time, fade_duration);
+
+    if gray_out != target_gray_out(enabled) {
+        ui.ctx()
+            .request_repaint_after_secs(ui.input(|input| input.predicted_dt));
+    }
+
+    gray_out
+}
+
+/// Moves the colors of the shapes painted since `first_shape` towards the way egui grays
+/// out disabled widgets, by the given fraction.
+fn gray_out_shapes(ui: &Ui, first_shape: usize, gray_out: f32) {
+    let fade_to_color = ui.visuals().fade_out_to_color();
+
+    ui.ctx().graphics_mut(|graphics| {
+        let paint_list = graphics.entry(ui.layer_id());
+        let shapes = paint_list
+            .all_entries()
+            .skip(first_shape)
+            .cloned()
+            .collect::<Vec<_>>();
+
+        for (
+            index,
+            ClippedShape {
+                clip_rect,
+                mut shape,
+            },
+        ) in shapes.into_iter().enumerate()
+        {
+            adjust_colors(&mut shape, move |color| {
+                if *color != Color32::PLACEHOLDER {
+                    *color =
+                        color.lerp_to_gamma(tint_color_towards(*color, fade_to_color), gray_out);
                 }
+            });
+            paint_list.set(ShapeIdx(first_shape + index), clip_rect, shape);
+        }
+    });
+}
 
-                response
-            })
+/// Toggles the value, remembering the disabled values under the id.
+fn toggle_remembered<T: Clone + Default + Send + Sync + 'static>(
+    ui: &mut Ui,
+    id: Id,
+    value: &mut Option<T>,
+    enabled: bool,
+) {
+    if enabled {
+        let remembered_value = ui.memory(|memory| memory.data.get_temp::<T>(id));
+        *value = Some(remembered_value.unwrap_or_default());
+    } else if let Some(old_value) = value.take() {
+        ui.memory_mut(|memory| memory.data.insert_temp(id, old_value));
+    }
+}
+
+fn optional_value_widget_impl<T: Default>(
+    ui: &mut Ui,
+    value: &mut Option<T>,
+    enable_label: &str,
+    shortcut: Option<&KeyboardShortcut>,
+    fade_duration: f32,
+    toggle: impl FnOnce(&mut Ui, Id, &mut Option<T>, bool),
+    add_contents: impl FnOnce(&mut Ui, &mut T) -> Response,
+) -> Response {
+    ui.group(|ui| {
+        ui.horizontal(|ui| {
+            let mut checkbox_state = value.is_some();
+            let mut response = ui.checkbox(&mut checkbox_state, enable_label);
+
+            if let Some(shortcut) = shortcut {
+                response = response.on_hover_text(ui.ctx().format_shortcut(shortcut));
+
+                if ui.is_rect_visible(response.rect)
+                    && ui.input_mut(|input| input.consume_shortcut(shortcut))
+                {
+                    checkbox_state = !checkbox_state;
+                    response.mark_changed();
+                }
+            }
+
+            if value.is_some() != checkbox_state {
+                toggle(
+                    ui,
+                    response.id.with("remembered_value"),
+                    value,
+                    checkbox_state,
+                );
+            }
+
+            let gray_out =
+                fade_gray_out(ui, response.id.with("fade"), value.is_some(), fade_duration);
+
+            let first_shape = ui.ctx().graphics(|graphics| {
+                graphics
+                    .get(ui.layer_id())
+                    .map_or(0, |paint_list| paint_list.all_entries().len())
+            });
+
+            match value {
+                Some(ref mut value) => {
+                    response = response.union(add_contents(ui, value));
+                }
+                None if gray_out >= 1.0 => {
+                    let mut dummy_value = T::default();
+                    ui.add_enabled_ui(false, |ui| add_contents(ui, &mut dummy_value));
+                }
+                None => {
+                    // Disabled widgets look like inactive ones grayed out, so the fade
+                    // starts from the enabled look, covered against interaction
+                    let mut dummy_value = T::default();
+                    let dummy_response = add_contents(ui, &mut dummy_value);
+                    ui.interact(
+                        dummy_response.rect,
+                        response.id.with("fade_cover"),
+                        Sense::click_and_drag(),
+                    );
+                }
+            }
+
+            // The settled disabled look is painted by egui itself
+            let settled_disabled = value.is_none() && gray_out >= 1.0;
+            if gray_out > 0.0 && !settled_disabled {
+                gray_out_shapes(ui, first_shape, gray_out);
+            }
+
+            response
         })
-        .inner
-        .inner
+    })
+    .inner
+    .inner
+}
+
+// ----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use egui::epaint::ClippedShape;
+    use egui::{CentralPanel, Context, RawInput, Slider};
+
+    use super::*;
+
+    fn slider(ui: &mut Ui, value: &mut f32) -> Response {
+        ui.add(Slider::new(value, 0.0..=1.0))
+    }
+
+    fn run_frame(
+        ctx: &Context,
+        time: f64,
+        add_contents: impl FnOnce(&mut Ui),
+    ) -> Vec<ClippedShape> {
+        let raw_input = RawInput {
+            time: Some(time),
+            ..RawInput::default()
+        };
+
+        ctx.run(raw_input, |ctx| {
+            CentralPanel::default().show(ctx, add_contents);
+        })
+        .shapes
+    }
+
+    /// Shapes of the widget with the value shown for the first time, without fading.
+    fn settled_look(enabled: bool) -> Vec<ClippedShape> {
+        let mut value = enabled.then_some(0.5);
+        run_frame(&Context::default(), 0.0, |ui| {
+            ui.optional_value_widget(&mut value, slider);
+        })
+    }
+
+    /// The disabled look by egui, without any fading on top.
+    fn egui_disabled_look() -> Vec<ClippedShape> {
+        run_frame(&Context::default(), 0.0, |ui| {
+            ui.group(|ui| {
+                ui.horizontal(|ui| {
+                    ui.checkbox(&mut false, "");
+                    ui.add_enabled_ui(false, |ui| slider(ui, &mut 0.0));
+                })
+            });
+        })
+    }
+
+    #[test]
+    fn settled_disabled_look_is_the_egui_one() {
+        assert_eq!(settled_look(false), egui_disabled_look());
+    }
+
+    #[test]
+    fn fades_between_the_settled_looks() {
+        let ctx = Context::default();
+        let mut value = Some(0.5);
+        let frame = |time: f64, value: &mut Option<f32>| {
+            run_frame(&ctx, time, |ui| {
+                ui.optional_value_widget(value, slider);
+            })
+        };
+
+        assert_eq!(frame(0.0, &mut value), settled_look(true));
+
+        value = None;
+        frame(1.0, &mut value);
+        let halfway_look = frame(1.0 + DEFAULT_FADE_DURATION as f64 / 2.0, &mut value);
+        assert_ne!(halfway_look, settled_look(false));
+
+        assert_eq!(frame(2.0, &mut value), settled_look(false));
+
+        value = Some(0.0);
+        assert_ne!(frame(3.0, &mut value), settled_look(true));
+        value = Some(0.5);
+        assert_eq!(frame(4.0, &mut value), settled_look(true));
     }
 }
@@ -0,0 +1,238 @@
+use std::fmt::Display;
+
+use egui::popup::{popup_below_widget, PopupCloseBehavior};
+use egui::{
+    pos2, vec2, Align2, Key, Modifiers, Response, ScrollArea, Sense, Shape, Stroke, TextEdit,
+    TextStyle, Ui, Widget,
+};
+
+// ----------------------------------------------------------------------------
+
+/// Search query and keyboard selection of an open popup.
+#[derive(Clone, Default)]
+struct SearchState {
+    query: String,
+    highlighted: usize,
+}
+
+// ----------------------------------------------------------------------------
+
+/// Combo box with a search field at the top of its popup, filtering the items by
+/// their `Display` string, ignoring the case.
+///
+/// The search field gets focused when the popup opens. The Up and Down keys move the
+/// highlight over the matching items, Enter selects the highlighted item and Escape
+/// closes the popup.
+#[must_use = "You should put this widget in an ui with `ui.add(widget);`"]
+pub struct SearchableCombobox<'a, T: Clone + Display + PartialEq> {
+    current: &'a mut T,
+    items: &'a [T],
+    width: Option<f32>,
+    height: Option<f32>,
+    hint_text: String,
+}
+
+impl<'a, T: Clone + Display + PartialEq> SearchableCombobox<'a, T> {
+    pub fn new(current: &'a mut T, items: &'a [T]) -> Self {
+        Self {
+            current,
+            items,
+            width: None,
+            height: None,
+            hint_text: "Search".to_owned(),
+        }
+    }
+
+    /// Width of the combo box, fits the current item by default.
+    pub fn width(mut self, width: impl Into<f32>) -> Self {
+        self.width = Some(width.into());
+        self
+    }
+
+    /// Maximum height of the item list, scrolling beyond it.
+    pub fn height(mut self, height: impl Into<f32>) -> Self {
+        self.height = Some(height.into());
+        self
+    }
+
+    /// Text shown in the empty search field.
+    pub fn hint_text(mut self, hint_text: impl ToString) -> Self {
+        self.hint_text = hint_text.to_string();
+        self
+    }
+}
+
+impl<'a, T: Clone + Display + PartialEq> Widget for SearchableCombobox<'a, T> {
+    fn ui(self, ui: &mut Ui) -> Response {
+        let padding = ui.spacing().button_padding;
+        let icon_width = ui.spacing().icon_width;
+
+        let galley = ui.painter().layout_no_wrap(
+            self.current.to_string(),
+            TextStyle::Button.resolve(ui.style()),
+            ui.style().visuals.text_color(),
+        );
+
+        let width = self.width.unwrap_or_else(|| {
+            (galley.size().x + ui.spacing().icon_spacing + icon_width + 2.0 * padding.x)
+                .max(ui.spacing().combo_width)
+        });
+
+        let (rect, mut response) =
+            ui.allocate_exact_size(vec2(width, ui.spacing().interact_size.y), Sense::click());
+
+        let popup_id = response.id.with("popup");
+        let search_id = response.id.with("search");
+
+        if response.clicked() {
+            ui.memory_mut(|memory| memory.toggle_popup(popup_id));
+        }
+
+        let popup_open = ui.memory(|memory| memory.is_popup_open(popup_id));
+        let popup_opened = response.clicked() && popup_open;
+
+        if ui.is_rect_visible(rect) {
+            let visuals = if popup_open {
+                &ui.style().visuals.widgets.open
+            } else {
+                ui.style().interact(&response)
+            };
+
+            ui.painter().rect(
+                rect.expand(visuals.expansion),
+                visuals.rounding,
+                visuals.weak_bg_fill,
+                visuals.bg_stroke,
+            );
+
+            let content_rect = rect.shrink2(padding);
+
+            ui.painter().with_clip_rect(content_rect).galley(
+                Align2::LEFT_CENTER
+                    .align_size_within_rect(galley.size(), content_rect)
+                    .min,
+                galley,
+                visuals.text_color(),
+            );
+
+            // Down arrow, like the icon of the combo boxes of egui
+            let icon_rect = Align2::RIGHT_CENTER
+                .align_size_within_rect(vec2(icon_width, icon_width), content_rect)
+                .shrink(icon_width * 0.2);
+
+            ui.painter().add(Shape::convex_polygon(
+                vec![
+                    icon_rect.left_top(),
+                    icon_rect.right_top(),
+                    pos2(icon_rect.center().x, icon_rect.bottom()),
+                ],
+                visuals.fg_stroke.color,
+                Stroke::NONE,
+            ));
+        }
+
+        let mut selected_index = None;
+
+        popup_below_widget(
+            ui,
+            popup_id,
+            &response,
+            PopupCloseBehavior::CloseOnClickOutside,
+            |ui| {
+                let mut state = if popup_opened {
+                    SearchState::default()
+                } else {
+                    ui.memory(|memory| memory.data.get_temp::<SearchState>(search_id))
+                        .unwrap_or_default()
+                };
+
+                // Taken before the search field could move its cursor with them
+                let (key_up, key_down, key_enter) = ui.input_mut(|input| {
+                    (
+                        input.consume_key(Modifiers::NONE, Key::ArrowUp),
+                        input.consume_key(Modifiers::NONE, Key::ArrowDown),
+                        input.consume_key(Modifiers::NONE, Key::Enter),
+                    )
+                });
+
+                let search_response = ui.add(
+                    TextEdit::singleline(&mut state.query)
+                        .hint_text(self.hint_text.as_str())
+                        .desired_width(f32::INFINITY),
+                );
+
+                if popup_opened {
+                    search_response.request_focus();
+                }
+
+                let query = state.query.to_lowercase();
+                let matches = self
+                    .items
+                    .iter()
+                    .enumerate()
+                    .filter(|(_, item)| item.to_string().to_lowercase().contains(&query))
+                    .collect::<Vec<_>>();
+
+                if popup_opened {
+                    state.highlighted = matches
+                        .iter()
+                        .position(|(_, item)| **item == *self.current)
+                        .unwrap_or(0);
+                } else if search_response.changed() {
+                    state.highlighted = 0;
+                }
+
+                if key_up {
+                    state.highlighted = state.highlighted.saturating_sub(1);
+                }
+
+                if key_down {
+                    state.highlighted += 1;
+                }
+
+                state.highlighted = state.highlighted.min(matches.len().saturating_sub(1));
+
+                if key_enter {
+                    selected_index = matches.get(state.highlighted).map(|&(index, _)| index);
+                }
+
+                ui.separator();
+
+                ScrollArea::vertical()
+                    .max_height(self.height.unwrap_or_else(|| ui.spacing().combo_height))
+                    .show(ui, |ui| {
+                        if matches.is_empty() {
+                            ui.weak("No matches");
+                        }
+
+                        for (position, &(index, item)) in matches.iter().enumerate() {
+                            let item_response = ui
+                                .selectable_label(position == state.highlighted, item.to_string());
+
+                            if item_response.clicked() {
+                                selected_index = Some(index);
+                            }
+
+                            if position == state.highlighted && (key_up || key_down || popup_opened)
+                            {
+                                item_response.scroll_to_me(None);
+                            }
+                        }
+                    });
+
+                ui.memory_mut(|memory| memory.data.insert_temp(search_id, state));
+            },
+        );
+
+        if let Some(index) = selected_index {
+            ui.memory_mut(|memory| memory.close_popup());
+
+            if self.items[index] != *self.current {
+                *self.current = self.items[index].clone();
+                response.mark_changed();
+            }
+        }
+
+        response
+    }
+}
@@ -0,0 +1,285 @@
+use std::fmt::Display;
+
+use egui::{
+    pos2, vec2, Color32, Rect, Response, Rounding, Sense, Shape, Stroke, TextStyle, Ui, Vec2,
+    Widget,
+};
+
+// ----------------------------------------------------------------------------
+
+type CloseCallback<'a, T> = Box<dyn 'a + FnMut(&T)>;
+
+// ----------------------------------------------------------------------------
+
+/// Row of tabs selecting one of the items. Tabs not fitting into the available width
+/// can be scrolled into view with the arrow buttons or the mouse wheel.
+#[must_use = "You should put this widget in an ui with `ui.add(widget);`"]
+pub struct TabBar<'a, T: Clone + PartialEq + Display> {
+    current: &'a mut T,
+    items: &'a [T],
+    closeable: bool,
+    on_close: Option<CloseCallback<'a, T>>,
+}
+
+impl<'a, T: Clone + PartialEq + Display> TabBar<'a, T> {
+    pub fn new(current: &'a mut T, items: &'a [T]) -> Self {
+        Self {
+            current,
+            items,
+            closeable: false,
+            on_close: None,
+        }
+    }
+
+    /// Adds a close button to each tab.
+    pub fn closeable(mut self, closeable: bool) -> Self {
+        self.closeable = closeable;
+        self
+    }
+
+    /// Called with the item of the tab whose close button got clicked. Removing the item
+    /// is up to the caller.
+    pub fn on_close(mut self, on_close: impl 'a + FnMut(&T)) -> Self {
+        self.on_close = Some(Box::new(on_close));
+        self
+    }
+}
+
+impl<'a, T: Clone + PartialEq + Display> Widget for TabBar<'a, T> {
+    fn ui(mut self, ui: &mut Ui) -> Response {
+        let height = ui.spacing().interact_size.y;
+        let padding = ui.spacing().button_padding.x;
+        let close_size = height * 0.5;
+        let font_id = TextStyle::Button.resolve(ui.style());
+
+        let galleys = self
+            .items
+            .iter()
+            .map(|item| {
+                ui.painter()
+                    .layout_no_wrap(item.to_string(), font_id.clone(), Color32::PLACEHOLDER)
+            })
+            .collect::<Vec<_>>();
+
+        let tab_widths = galleys
+            .iter()
+            .map(|galley| {
+                let close_width = if self.closeable {
+                    close_size + padding
+                } else {
+                    0.0
+                };
+
+                galley.size().x + 2.0 * padding + close_width
+            })
+            .collect::<Vec<_>>();
+
+        let total_width: f32 = tab_widths.iter().sum();
+
+        let (rect, mut response) =
+            ui.allocate_exact_size(vec2(ui.available_width(), height), Sense::hover());
+
+        // Scroll arrows are only shown when the tabs do not fit
+        let overflow = total_width > rect.width();
+
+        let tabs_rect = if overflow {
+            rect.shrink2(vec2(height, 0.0))
+        } else {
+            rect
+        };
+
+        let scroll_id = response.id.with("scroll");
+        let old_scroll = ui
+            .memory(|memory| memory.data.get_temp::<f32>(scroll_id))
+            .unwrap_or(0.0);
+        let mut scroll = old_scroll;
+
+        if overflow {
+            let scroll_step = tabs_rect.width() / 2.0;
+
+            let arrows = [
+                (Rect::from_min_size(rect.min, Vec2::splat(height)), -1.0),
+                (
+                    Rect::from_min_size(
+                        pos2(rect.right() - height, rect.top()),
+                        Vec2::splat(height),
+                    ),
+                    1.0,
+                ),
+            ];
+
+            for (arrow_rect, direction) in arrows {
+                let arrow_response = ui.interact(
+                    arrow_rect,
+                    response.id.with(("arrow", direction as i32)),
+                    Sense::click(),
+                );
+
+                if arrow_response.clicked() {
+                    scroll += direction * scroll_step;
+                }
+
+                if ui.is_rect_visible(arrow_rect) {
+                    let visuals = ui.style().interact(&arrow_response);
+                    let center = arrow_rect.center();
+                    let size = arrow_rect.height() / 4.0;
+
+                    ui.painter().add(Shape::convex_polygon(
+                        vec![
+                            center + vec2(direction * size, 0.0),
+                            center + vec2(-direction * size, -size),
+                            center + vec2(-direction * size, size),
+                        ],
+                        visuals.fg_stroke.color,
+                        Stroke::NONE,
+                    ));
+                }
+            }
+
+            if response.hovered() {
+                scroll -=
+                    ui.input(|input| input.smooth_scroll_delta.x + input.smooth_scroll_delta.y);
+            }
+        }
+
+        let max_scroll = (total_width - tabs_rect.width()).max(0.0);
+        scroll = scroll.clamp(0.0, max_scroll);
+
+        let painter = ui.painter_at(tabs_rect);
+        let rounding = ui.style().visuals.widgets.inactive.rounding;
+
+        let mut selected = None;
+        let mut closed = None;
+        let mut tab_left = 0.0;
+
+        for (index, (galley, tab_width)) in galleys.into_iter().zip(tab_widths).enumerate() {
+            let tab_offset = tab_left;
+            tab_left += tab_width;
+
+            let tab_rect = Rect::from_min_size(
+                pos2(tabs_rect.left() + tab_offset - scroll, tabs_rect.top()),
+                vec2(tab_width, height),
+            );
+
+            // Clipped tabs only interact with their visible part
+            let visible_rect = tab_rect.intersect(tabs_rect);
+            if !visible_rect.is_positive() {
+                continue;
+            }
+
+            let tab_response = ui.interact(
+                visible_rect,
+                response.id.with(("tab", index)),
+                Sense::click(),
+            );
+
+            let close_rect = Rect::from_center_size(
+                pos2(
+                    tab_rect.right() - padding - close_size / 2.0,
+                    tab_rect.center().y,
+                ),
+                Vec2::splat(close_size),
+            );
+
+            let close_response =
+                (self.closeable && tabs_rect.contains_rect(close_rect)).then(|| {
+                    ui.interact(
+                        close_rect,
+                        response.id.with(("close", index)),
+                        Sense::click(),
+                    )
+                    .on_hover_text("Close")
+                });
+
+            if close_response.as_ref().is_some_and(Response::clicked) {
+                closed = Some(index);
+            } else if tab_response.clicked() {
+                selected = Some((index, tab_offset, tab_width));
+            }
+
+            let is_current = self.items[index] == *self.current;
+
+            let (fill, text_color) = if is_current {
+                (
+                    ui.style().visuals.selection.bg_fill,
+                    ui.style().visuals.selection.stroke.color,
+                )
+            } else {
+                let visuals = ui.style().interact(&tab_response);
+                (visuals.weak_bg_fill, visuals.text_color())
+            };
+
+            painter.rect(
+                tab_rect.shrink2(vec2(1.0, 0.0)),
+                Rounding {
+                    nw: rounding.nw,
+                    ne: rounding.ne,
+                    sw: 0.0,
+                    se: 0.0,
+                },
+                fill,
+                Stroke::NONE,
+            );
+
+            painter.galley(
+                pos2(
+                    tab_rect.left() + padding,
+                    tab_rect.center().y - galley.size().y / 2.0,
+                ),
+                galley,
+                text_color,
+            );
+
+            if let Some(close_response) = &close_response {
+                let stroke = Stroke::new(
+                    ui.style().interact(close_response).fg_stroke.width,
+                    if close_response.hovered() {
+                        ui.style().visuals.error_fg_color
+                    } else {
+                        text_color
+                    },
+                );
+                let cross_rect = close_rect.shrink(close_size / 4.0);
+
+                painter.line_segment([cross_rect.left_top(), cross_rect.right_bottom()], stroke);
+                painter.line_segment([cross_rect.right_top(), cross_rect.left_bottom()], stroke);
+            }
+
+            response = response.union(tab_response);
+        }
+
+        if ui.is_rect_visible(rect) {
+            ui.painter().hline(
+                rect.x_range(),
+                rect.bottom(),
+                ui.style().visuals.widgets.noninteractive.bg_stroke,
+            );
+        }
+
+        if let Some((index, tab_offset, tab_width)) = selected {
+            if self.items[index] != *self.current {
+                *self.current = self.items[index].clone();
+                response.mark_changed();
+            }
+
+            // Scroll the whole selected tab into view
+            scroll = scroll
+                .max(tab_offset + tab_width - tabs_rect.width())
+                .min(tab_offset)
+                .clamp(0.0, max_scroll);
+        }
+
+        if let Some(index) = closed {
+            if let Some(on_close) = &mut self.on_close {
+                on_close(&self.items[index]);
+            }
+        }
+
+        if scroll != old_scroll {
+            ui.memory_mut(|memory| memory.data.insert_temp(scroll_id, scroll));
+            ui.ctx().request_repaint();
+        }
+
+        response
+    }
+}
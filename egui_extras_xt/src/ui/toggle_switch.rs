@@ -0,0 +1,279 @@
+use egui::{
+    lerp, pos2, vec2, Color32, FontSelection, Rect, Response, Rgba, Sense, Ui, Widget, WidgetInfo,
+    WidgetType,
+};
+
+// ----------------------------------------------------------------------------
+
+/// Combined into one function (rather than two) to make it easier
+/// for the borrow checker.
+type GetSetValue<'a> = Box<dyn 'a + FnMut(Option<bool>) -> bool>;
+
+fn get(get_set_value: &mut GetSetValue<'_>) -> bool {
+    (get_set_value)(None)
+}
+
+fn set(get_set_value: &mut GetSetValue<'_>, value: bool) {
+    (get_set_value)(Some(value));
+}
+
+// ----------------------------------------------------------------------------
+
+#[must_use = "You should put this widget in an ui with `ui.add(widget);`"]
+pub struct ToggleSwitch<'a> {
+    get_set_value: GetSetValue<'a>,
+    height: Option<f32>,
+    on_label: Option<String>,
+    off_label: Option<String>,
+    thumb_color: Option<Color32>,
+    track_on_color: Option<Color32>,
+    track_off_color: Option<Color32>,
+    animated: bool,
+    interactive: bool,
+}
+
+impl<'a> ToggleSwitch<'a> {
+    pub fn new(value: &'a mut bool) -> Self {
+        Self::from_get_set(move |v: Option<bool>| {
+            if let Some(v) = v {
+                *value = v;
+            }
+            *value
+        })
+    }
+
+    pub fn from_get_set(get_set_value: impl 'a + FnMut(Option<bool>) -> bool) -> Self {
+        Self {
+            get_set_value: Box::new(get_set_value),
+            height: None,
+            on_label: None,
+            off_label: None,
+            thumb_color: None,
+            track_on_color: None,
+            track_off_color: None,
+            animated: true,
+            interactive: true,
+        }
+    }
+
+    /// Height of the switch, defaults to the interaction size of the ui.
+    /// The track is twice as wide as it is high.
+    pub fn height(mut self, height: impl Into<f32>) -> Self {
+        self.height = Some(height.into());
+        self
+    }
+
+    pub fn on_label(mut self, on_label: impl ToString) -> Self {
+        self.on_label = Some(on_label.to_string());
+        self
+    }
+
+    pub fn off_label(mut self, off_label: impl ToString) -> Self {
+        self.off_label = Some(off_label.to_string());
+        self
+    }
+
+    pub fn thumb_color(mut self, thumb_color: Color32) -> Self {
+        self.thumb_color = Some(thumb_color);
+        self
+    }
+
+    /// Sets the color of the track in the on (`true`) or off (`false`) state.
+    pub fn track_color(mut self, state: bool, track_color: Color32) -> Self {
+        if state {
+            self.track_on_color = Some(track_color);
+        } else {
+            self.track_off_color = Some(track_color);
+        }
+        self
+    }
+
+    pub fn animated(mut self, animated: bool) -> Self {
+        self.animated = animated;
+        self
+    }
+
+    pub fn interactive(mut self, interactive: bool) -> Self {
+        self.interactive = interactive;
+        self
+    }
+}
+
+impl<'a> Widget for ToggleSwitch<'a> {
+    fn ui(mut self, ui: &mut Ui) -> Response {
+        let height = self.height.unwrap_or(ui.spacing().interact_size.y);
+        let track_size = vec2(height * 2.0, height);
+
+        let value = get(&mut self.get_set_value);
+
+        // Reserve room for the wider label, so the widget doesn't change size when toggled
+        let label_width = [&self.on_label, &self.off_label]
+            .into_iter()
+            .flatten()
+            .map(|label| {
+                let font_id = FontSelection::Default.resolve(ui.style());
+                ui.painter()
+                    .layout_no_wrap(label.clone(), font_id, Color32::PLACEHOLDER)
+                    .size()
+                    .x
+            })
+            .reduce(f32::max);
+
+        let desired_size = match label_width {
+            Some(label_width) => track_size + vec2(ui.spacing().item_spacing.x + label_width, 0.0),
+            None => track_size,
+        };
+
+        let (rect, mut response) = ui.allocate_exact_size(
+            desired_size,
+            if self.interactive {
+                Sense::click()
+            } else {
+                Sense::hover()
+            },
+        );
+
+        if response.clicked() {
+            set(&mut self.get_set_value, !value);
+            response.mark_changed();
+        }
+
+        let value = get(&mut self.get_set_value);
+        response.widget_info(|| WidgetInfo::selected(WidgetType::Checkbox, true, value, ""));
+
+        if ui.is_rect_visible(rect) {
+            let visuals = ui.style().interact_selectable(&response, value);
+
+            let position = if self.animated {
+                ui.ctx().animate_bool(response.id, value)
+            } else if value {
+                1.0
+            } else {
+                0.0
+            };
+
+            let track_rect = Rect::from_min_size(
+                pos2(rect.left(), rect.center().y - height / 2.0),
+                track_size,
+            );
+            let radius = height / 2.0;
+
+            let track_color = {
+                let on_color = self
+                    .track_on_color
+                    .unwrap_or(ui.style().visuals.selection.bg_fill);
+                let off_color = self
+                    .track_off_color
+                    .unwrap_or(ui.style().visuals.widgets.inactive.bg_fill);
+                Color32::from(lerp(Rgba::from(off_color)..=Rgba::from(on_color), position))
+            };
+
+            ui.painter()
+                .rect(track_rect, radius, track_color, visuals.bg_stroke);
+
+            let thumb_center = pos2(
+                lerp(
+                    (track_rect.left() + radius)..=(track_rect.right() - radius),
+                    position,
+                ),
+                track_rect.center().y,
+            );
+
+            ui.painter().circle(
+                thumb_center,
+                radius * 0.75,
+                self.thumb_color.unwrap_or(visuals.fg_stroke.color),
+                visuals.fg_stroke,
+            );
+
+            // Laid out after the click was handled, to show the label of the new state
+            let label = if value {
+                &self.on_label
+            } else {
+                &self.off_label
+            };
+
+            if let Some(label) = label {
+                let font_id = FontSelection::Default.resolve(ui.style());
+                let galley = ui.painter().layout_no_wrap(
+                    label.clone(),
+                    font_id,
+                    ui.style().visuals.text_color(),
+                );
+
+                let label_pos = pos2(
+                    track_rect.right() + ui.spacing().item_spacing.x,
+                    rect.center().y - galley.size().y / 2.0,
+                );
+                ui.painter()
+                    .galley(label_pos, galley, ui.style().visuals.text_color());
+            }
+        }
+
+        response
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use egui::{CentralPanel, Context, Event, PointerButton, Pos2, RawInput, Shape};
+
+    use super::*;
+
+    /// Runs a frame with a labeled switch, returning its rect and the painted texts.
+    fn run_frame(
+        ctx: &Context,
+        value: &mut bool,
+        frame: usize,
+        events: Vec<Event>,
+    ) -> (Rect, Vec<String>) {
+        let input = RawInput {
+            time: Some(frame as f64 / 60.0),
+            events,
+            ..Default::default()
+        };
+
+        let mut rect = Rect::NOTHING;
+        let output = ctx.run(input, |ctx| {
+            CentralPanel::default().show(ctx, |ui| {
+                rect = ui
+                    .add(ToggleSwitch::new(value).on_label("ON").off_label("OFF"))
+                    .rect;
+            });
+        });
+
+        let texts = output
+            .shapes
+            .into_iter()
+            .filter_map(|clipped_shape| match clipped_shape.shape {
+                Shape::Text(text) => Some(text.galley.text().to_owned()),
+                _ => None,
+            })
+            .collect();
+        (rect, texts)
+    }
+
+    #[test]
+    fn label_follows_the_click_in_the_same_frame() {
+        let ctx = Context::default();
+        let mut value = false;
+
+        let (rect, texts) = run_frame(&ctx, &mut value, 0, Vec::new());
+        assert_eq!(texts, ["OFF"]);
+
+        let pos: Pos2 = rect.left_center() + vec2(4.0, 0.0);
+        let button = |pressed| Event::PointerButton {
+            pos,
+            button: PointerButton::Primary,
+            pressed,
+            modifiers: Default::default(),
+        };
+
+        let _ = run_frame(&ctx, &mut value, 1, vec![Event::PointerMoved(pos)]);
+        let _ = run_frame(&ctx, &mut value, 2, vec![button(true)]);
+        let (_, texts) = run_frame(&ctx, &mut value, 3, vec![button(false)]);
+
+        assert!(value);
+        assert_eq!(texts, ["ON"]);
+    }
+}
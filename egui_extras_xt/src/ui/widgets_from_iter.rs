@@ -1,6 +1,10 @@
 use std::fmt::Display;
 
-use egui::{ComboBox, Response, Ui, WidgetText};
+use egui::text::{LayoutJob, TextFormat};
+use egui::{
+    pos2, Button, Color32, ComboBox, Id, Image, Rect, Response, TextStyle, TextureId, Ui, Vec2,
+    WidgetText,
+};
 
 // ----------------------------------------------------------------------------
 
@@ -10,6 +14,24 @@ pub trait SelectableValueFromIter<Value> {
         current_value: &mut Value,
         values: impl Iterator<Item = Value>,
     ) -> Response;
+
+    /// Like [`SelectableValueFromIter::selectable_value_from_iter`], showing the text
+    /// returned by `tooltip_fn` when hovering over an item.
+    fn selectable_value_from_iter_with_tooltip(
+        &mut self,
+        current_value: &mut Value,
+        values: impl Iterator<Item = Value>,
+        tooltip_fn: impl Fn(&Value) -> String,
+    ) -> Response;
+
+    /// Like [`SelectableValueFromIter::selectable_value_from_iter`], showing the icon
+    /// returned by `icon_fn` before the text of each item.
+    fn selectable_icon_value_from_iter(
+        &mut self,
+        current_value: &mut Value,
+        values: impl Iterator<Item = Value>,
+        icon_fn: impl Fn(&Value) -> TextureId,
+    ) -> Response;
 }
 
 impl<Value> SelectableValueFromIter<Value> for Ui
@@ -28,6 +50,65 @@ where
                 self.colored_label(self.style().visuals.error_fg_color, "\u{1F525} No items")
             })
     }
+
+    fn selectable_value_from_iter_with_tooltip(
+        &mut self,
+        current_value: &mut Value,
+        values: impl Iterator<Item = Value>,
+        tooltip_fn: impl Fn(&Value) -> String,
+    ) -> Response {
+        values
+            .map(|value| {
+                self.selectable_value(current_value, value, format!("{value}"))
+                    .on_hover_text(tooltip_fn(&value))
+            })
+            .reduce(|result, response| result.union(response))
+            .unwrap_or_else(|| {
+                self.colored_label(self.style().visuals.error_fg_color, "\u{1F525} No items")
+            })
+    }
+
+    fn selectable_icon_value_from_iter(
+        &mut self,
+        current_value: &mut Value,
+        values: impl Iterator<Item = Value>,
+        icon_fn: impl Fn(&Value) -> TextureId,
+    ) -> Response {
+        values
+            .map(|value| selectable_icon_value(self, current_value, value, &icon_fn))
+            .reduce(|result, response| result.union(response))
+            .unwrap_or_else(|| {
+                self.colored_label(self.style().visuals.error_fg_color, "\u{1F525} No items")
+            })
+    }
+}
+
+fn icon_image(ui: &Ui, texture_id: TextureId) -> Image<'static> {
+    Image::new((texture_id, Vec2::splat(ui.spacing().icon_width)))
+}
+
+/// Icon and text counterpart of [`Ui::selectable_value`].
+fn selectable_icon_value<Value>(
+    ui: &mut Ui,
+    current_value: &mut Value,
+    value: Value,
+    icon_fn: impl Fn(&Value) -> TextureId,
+) -> Response
+where
+    Value: PartialEq + Display,
+{
+    let button = Button::image_and_text(icon_image(ui, icon_fn(&value)), format!("{value}"))
+        .frame(false)
+        .selected(*current_value == value);
+
+    let mut response = ui.add(button);
+
+    if response.clicked() && *current_value != value {
+        *current_value = value;
+        response.mark_changed();
+    }
+
+    response
 }
 
 // ----------------------------------------------------------------------------
@@ -38,6 +119,15 @@ pub trait RadioValueFromIter<Value> {
         current_value: &mut Value,
         values: impl Iterator<Item = Value>,
     ) -> Response;
+
+    /// Like [`RadioValueFromIter::radio_value_from_iter`], showing the text returned by
+    /// `tooltip_fn` when hovering over an item.
+    fn radio_value_from_iter_with_tooltip(
+        &mut self,
+        current_value: &mut Value,
+        values: impl Iterator<Item = Value>,
+        tooltip_fn: impl Fn(&Value) -> String,
+    ) -> Response;
 }
 
 impl<Value> RadioValueFromIter<Value> for Ui
@@ -56,6 +146,23 @@ where
                 self.colored_label(self.style().visuals.error_fg_color, "\u{1F525} No items")
             })
     }
+
+    fn radio_value_from_iter_with_tooltip(
+        &mut self,
+        current_value: &mut Value,
+        values: impl Iterator<Item = Value>,
+        tooltip_fn: impl Fn(&Value) -> String,
+    ) -> Response {
+        values
+            .map(|value| {
+                self.radio_value(current_value, value, format!("{value}"))
+                    .on_hover_text(tooltip_fn(&value))
+            })
+            .reduce(|result, response| result.union(response))
+            .unwrap_or_else(|| {
+                self.colored_label(self.style().visuals.error_fg_color, "\u{1F525} No items")
+            })
+    }
 }
 
 // ----------------------------------------------------------------------------
@@ -67,6 +174,28 @@ pub trait ComboBoxFromIter<Value> {
         current_value: &mut Value,
         values: impl Iterator<Item = Value>,
     ) -> Response;
+
+    /// Like [`ComboBoxFromIter::combobox_from_iter`], showing the text returned by
+    /// `tooltip_fn` when hovering over an item in the dropdown, or over the closed
+    /// combobox for the current value.
+    fn combobox_from_iter_with_tooltip(
+        &mut self,
+        label: impl Into<WidgetText>,
+        current_value: &mut Value,
+        values: impl Iterator<Item = Value>,
+        tooltip_fn: impl Fn(&Value) -> String,
+    ) -> Response;
+
+    /// Like [`ComboBoxFromIter::combobox_from_iter`], showing the icon returned by
+    /// `icon_fn` before the text of each item in the dropdown, and before the text of
+    /// the current value inside the closed combobox.
+    fn combobox_icon_value_from_iter(
+        &mut self,
+        label: impl Into<WidgetText>,
+        current_value: &mut Value,
+        values: impl Iterator<Item = Value>,
+        icon_fn: impl Fn(&Value) -> TextureId,
+    ) -> Response;
 }
 
 impl<Value> ComboBoxFromIter<Value> for Ui
@@ -94,4 +223,142 @@ where
             .inner
             .unwrap_or(combobox_response.response)
     }
+
+    fn combobox_from_iter_with_tooltip(
+        &mut self,
+        label: impl Into<WidgetText>,
+        current_value: &mut Value,
+        values: impl Iterator<Item = Value>,
+        tooltip_fn: impl Fn(&Value) -> String,
+    ) -> Response {
+        let combobox_response = ComboBox::from_label(label)
+            .selected_text(format!("{current_value}"))
+            .show_ui(self, |ui| {
+                values
+                    .map(|value| {
+                        ui.selectable_value(current_value, value, format!("{value}"))
+                            .on_hover_text(tooltip_fn(&value))
+                    })
+                    .reduce(|result, response| result.union(response))
+                    .unwrap_or_else(|| {
+                        ui.colored_label(ui.style().visuals.error_fg_color, "\u{1F525} No items")
+                    })
+            });
+
+        let closed_response = combobox_response
+            .response
+            .on_hover_text(tooltip_fn(current_value));
+
+        combobox_response.inner.unwrap_or(closed_response)
+    }
+
+    fn combobox_icon_value_from_iter(
+        &mut self,
+        label: impl Into<WidgetText>,
+        current_value: &mut Value,
+        values: impl Iterator<Item = Value>,
+        icon_fn: impl Fn(&Value) -> TextureId,
+    ) -> Response {
+        let label = label.into();
+        let selected_value = *current_value;
+
+        // Same as the id `ComboBox::from_label` gives to the closed combobox
+        let button_id = self.make_persistent_id(Id::new(label.text()));
+
+        // Leave room for the icon in front of the text of the closed combobox
+        let icon_size = Vec2::splat(self.spacing().icon_width);
+        let selected_text = {
+            let mut job = LayoutJob::default();
+            job.append(
+                &format!("{selected_value}"),
+                icon_size.x + self.spacing().icon_spacing,
+                TextFormat::simple(
+                    TextStyle::Button.resolve(self.style()),
+                    Color32::PLACEHOLDER,
+                ),
+            );
+            job
+        };
+
+        let combobox_response = ComboBox::from_label(label)
+            .selected_text(selected_text)
+            .show_ui(self, |ui| {
+                values
+                    .map(|value| selectable_icon_value(ui, current_value, value, &icon_fn))
+                    .reduce(|result, response| result.union(response))
+                    .unwrap_or_else(|| {
+                        ui.colored_label(ui.style().visuals.error_fg_color, "\u{1F525} No items")
+                    })
+            });
+
+        if let Some(button_response) = self.ctx().read_response(button_id) {
+            let icon_rect = Rect::from_min_size(
+                pos2(
+                    button_response.rect.left() + self.spacing().button_padding.x,
+                    button_response.rect.center().y - icon_size.y / 2.0,
+                ),
+                icon_size,
+            );
+
+            if self.is_rect_visible(icon_rect) {
+                icon_image(self, icon_fn(&selected_value)).paint_at(self, icon_rect);
+            }
+        }
+
+        combobox_response
+            .inner
+            .unwrap_or(combobox_response.response)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use egui::{CentralPanel, Context, RawInput, Shape};
+
+    use super::*;
+
+    #[test]
+    fn combobox_icon_is_drawn_inside_the_closed_combobox() {
+        let ctx = Context::default();
+        let icon = TextureId::User(1);
+        let mut value = 1;
+        let mut combobox_rect = Rect::NOTHING;
+
+        let output = ctx.run(RawInput::default(), |ctx| {
+            CentralPanel::default().show(ctx, |ui| {
+                combobox_rect = ui
+                    .combobox_icon_value_from_iter("Value", &mut value, 1..=3, |_| icon)
+                    .rect;
+            });
+        });
+
+        let icon_rect = output
+            .shapes
+            .iter()
+            .find_map(|clipped_shape| match &clipped_shape.shape {
+                Shape::Rect(rect_shape) if rect_shape.fill_texture_id == icon => {
+                    Some(rect_shape.rect)
+                }
+                _ => None,
+            })
+            .expect("icon is painted");
+
+        let text_rect = output
+            .shapes
+            .iter()
+            .find_map(|clipped_shape| match &clipped_shape.shape {
+                Shape::Text(text) if text.galley.text() == "1" => {
+                    Some(text.galley.rect.translate(text.pos.to_vec2()))
+                }
+                _ => None,
+            })
+            .expect("selected text is painted");
+
+        assert!(combobox_rect.contains_rect(icon_rect));
+
+        // The icon sits in the room left in front of the text
+        let text_start = text_rect.left() + ctx.style().spacing.icon_width;
+        assert!(icon_rect.left() >= text_rect.left() - 0.5);
+        assert!(icon_rect.right() <= text_start + 0.5);
+    }
 }
@@ -1,4 +1,8 @@
-use eframe::egui::{self, global_dark_light_mode_switch};
+use std::f32::consts::TAU;
+
+use eframe::egui::{
+    self, global_dark_light_mode_switch, ColorImage, TextureHandle, TextureOptions,
+};
 
 use egui_extras_xt::compasses::{CompassMarker, CompassMarkerShape, LinearCompass, PolarCompass};
 use egui_extras_xt::ui::standard_buttons::StandardButtons;
@@ -28,6 +32,7 @@ struct CompassWidgetsExample {
     heading: f32,
     gps_position: GpsPosition,
     targets: Vec<(GpsPosition, String)>,
+    waypoint_icon: Option<TextureHandle>,
 }
 
 impl Default for CompassWidgetsExample {
@@ -63,10 +68,35 @@ impl Default for CompassWidgetsExample {
                 target!(47.5314, 21.6242, "Debrecen"      ),
                 target!(47.9555, 21.7166, "Nyíregyháza"   ),
             ],
+            waypoint_icon: None,
         }
     }
 }
 
+fn waypoint_icon_image() -> ColorImage {
+    const ICON_SIZE: usize = 32;
+
+    let pixels = (0..ICON_SIZE * ICON_SIZE)
+        .flat_map(|index| {
+            let (x, y) = (index % ICON_SIZE, index / ICON_SIZE);
+            let (dx, dy) = (
+                x as f32 + 0.5 - ICON_SIZE as f32 / 2.0,
+                y as f32 + 0.5 - ICON_SIZE as f32 / 2.0,
+            );
+            let distance = (dx * dx + dy * dy).sqrt() / (ICON_SIZE as f32 / 2.0);
+
+            // Concentric rings, the compass tints the white pixels with the marker color
+            if distance < 0.3 || (0.6..0.9).contains(&distance) {
+                [0xFF, 0xFF, 0xFF, 0xFF]
+            } else {
+                [0x00, 0x00, 0x00, 0x00]
+            }
+        })
+        .collect::<Vec<u8>>();
+
+    ColorImage::from_rgba_unmultiplied([ICON_SIZE, ICON_SIZE], &pixels)
+}
+
 impl eframe::App for CompassWidgetsExample {
     fn update(&mut self, ctx: &egui::Context, _frame: &mut eframe::Frame) {
         egui::CentralPanel::default().show(ctx, |ui| {
@@ -86,6 +116,14 @@ impl eframe::App for CompassWidgetsExample {
                 ui.drag_angle(&mut self.gps_position.1);
             });
 
+            let waypoint_icon = self.waypoint_icon.get_or_insert_with(|| {
+                ctx.load_texture(
+                    "waypoint_icon",
+                    waypoint_icon_image(),
+                    TextureOptions::LINEAR,
+                )
+            });
+
             let markers = self
                 .targets
                 .iter()
@@ -94,6 +132,10 @@ impl eframe::App for CompassWidgetsExample {
                         .distance(self.gps_position.distance_to(target_gps_position))
                         .label(target_name)
                 })
+                .chain([CompassMarker::new(0.0)
+                    .distance(5000.0)
+                    .shape(CompassMarkerShape::Texture(waypoint_icon.id(), None))
+                    .label("North Pole")])
                 .collect::<Vec<CompassMarker>>();
 
             ui.add(
@@ -114,7 +156,28 @@ impl eframe::App for CompassWidgetsExample {
                     .axis_labels(["N", "E", "S", "W"].into())
                     .width(512.0 + 24.0 * 2.0)
                     .default_marker_shape(CompassMarkerShape::Star(5, 0.5))
-                    .markers(&markers),
+                    .markers_in_range(|visible_range| {
+                        self.targets
+                            .iter()
+                            .enumerate()
+                            .map(|(target_index, (target_gps_position, target_name))| {
+                                (
+                                    target_index,
+                                    self.gps_position.bearing_to(target_gps_position),
+                                    target_name,
+                                )
+                            })
+                            .filter(move |(_, bearing, _)| {
+                                // Move the bearing to the first turn after the range start
+                                let turns = ((visible_range.start() - bearing) / TAU).ceil();
+                                visible_range.contains(&(bearing + turns * TAU))
+                            })
+                            .map(|(target_index, bearing, target_name)| {
+                                CompassMarker::new(bearing)
+                                    .label(target_name)
+                                    .id(target_index)
+                            })
+                    }),
             );
         });
     }
@@ -0,0 +1,66 @@
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use eframe::egui;
+
+use egui_extras_xt::displays::{DisplayStylePreset, SegmentedDisplayWidget};
+
+#[derive(Default)]
+struct DigitalClockExample {
+    show_seconds: bool,
+}
+
+impl eframe::App for DigitalClockExample {
+    fn update(&mut self, ctx: &egui::Context, _frame: &mut eframe::Frame) {
+        egui::CentralPanel::default().show(ctx, |ui| {
+            // Standard library only knows about UTC, use a date-time crate for local time
+            let since_epoch = SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .unwrap_or_default();
+
+            let seconds_of_day = since_epoch.as_secs() % (24 * 60 * 60);
+            let (hours, minutes, seconds) = (
+                seconds_of_day / 3600,
+                (seconds_of_day / 60) % 60,
+                seconds_of_day % 60,
+            );
+
+            let time_str = if self.show_seconds {
+                format!("{hours:02}:{minutes:02}:{seconds:02}")
+            } else {
+                format!("{hours:02}:{minutes:02}")
+            };
+
+            // The colons blink on their own, only wake up when the displayed time changes
+            let until_next_tick = if self.show_seconds { 1.0 } else { 60.0 }
+                - (since_epoch.as_secs_f64() % if self.show_seconds { 1.0 } else { 60.0 });
+            ctx.request_repaint_after_secs(until_next_tick as f32);
+
+            ui.add(
+                SegmentedDisplayWidget::seven_segment(time_str)
+                    .style_preset(DisplayStylePreset::DeLoreanRed)
+                    .blink_colons(true)
+                    .show_dots(false)
+                    .show_apostrophes(false)
+                    .digit_height(96.0),
+            );
+
+            ui.horizontal(|ui| {
+                ui.label("UTC");
+                ui.checkbox(&mut self.show_seconds, "Show seconds");
+            });
+        });
+    }
+}
+
+fn main() -> Result<(), eframe::Error> {
+    let options = eframe::NativeOptions {
+        viewport: egui::ViewportBuilder::default().with_inner_size([480.0, 180.0]),
+        ..Default::default()
+    };
+
+    eframe::run_native(
+        "Digital Clock",
+        options,
+        Box::new(|_| Ok(Box::<DigitalClockExample>::default())),
+    )
+}
@@ -26,11 +26,15 @@ impl eframe::App for GlyphEditorApp {
     fn update(&mut self, ctx: &egui::Context, _frame: &mut eframe::Frame) {
         egui::CentralPanel::default().show(ctx, |ui| {
             ui.horizontal(|ui| {
-                ui.add(
-                    SegmentedDisplayWidget::new(self.display_kind)
-                        .digit_height(192.0)
-                        .push_digit(self.digit),
-                );
+                {
+                    let mut glyphs = vec![self.digit.glyph];
+                    ui.add(
+                        SegmentedDisplayWidget::new(self.display_kind)
+                            .digit_height(192.0)
+                            .editable(&mut glyphs),
+                    );
+                    self.digit.glyph = glyphs[0];
+                }
 
                 ui.separator();
 
@@ -70,10 +74,7 @@ impl eframe::App for GlyphEditorApp {
                         .add(
                             SegmentedDisplayWidget::new(self.display_kind)
                                 .digit_height(64.0)
-                                .push_digit(DisplayDigit {
-                                    glyph: 1 << segment_index,
-                                    ..Default::default()
-                                }),
+                                .push_digit(DisplayDigit::new(1 << segment_index)),
                         )
                         .clicked()
                     {
@@ -1,4 +1,7 @@
-use eframe::egui::{self, DragValue, Response};
+use eframe::egui::{
+    self, Color32, ColorImage, DragValue, Key, KeyboardShortcut, Modifiers, Response,
+    TextureHandle, TextureId, TextureOptions,
+};
 
 use strum::{Display, EnumIter, IntoEnumIterator};
 
@@ -31,11 +34,60 @@ enum SevenSecretWeapons {
     BugSweeper,
 }
 
+impl SevenSecretWeapons {
+    fn description(&self) -> String {
+        match self {
+            Self::Missile => "Fires a missile from the nose of the car",
+            Self::MetalDetector => "Locates buried metal objects",
+            Self::FishingPole => "Casts a line into the water for fishing",
+            Self::MrAnalysis => "Analyzes items with the onboard computer",
+            Self::Magnet => "Pulls metal objects towards the car",
+            Self::BugSweeper => "Detects hidden listening devices",
+        }
+        .to_owned()
+    }
+
+    fn icon_color(&self) -> Color32 {
+        match self {
+            Self::Missile => Color32::RED,
+            Self::MetalDetector => Color32::GRAY,
+            Self::FishingPole => Color32::BROWN,
+            Self::MrAnalysis => Color32::LIGHT_BLUE,
+            Self::Magnet => Color32::DARK_RED,
+            Self::BugSweeper => Color32::GREEN,
+        }
+    }
+}
+
 struct UiExtensionsExample {
     optional_usize: Option<usize>,
     optional_string: Option<String>,
+    optional_f32: Option<f32>,
+    compression_threshold: Option<f32>,
+    optional_fade_usize: Option<usize>,
     secret_weapon: SevenSecretWeapons,
     coffee_count: usize,
+    weapon_icons: Option<Vec<TextureHandle>>,
+}
+
+impl UiExtensionsExample {
+    fn weapon_icons(&mut self, ctx: &egui::Context) -> Vec<TextureId> {
+        self.weapon_icons
+            .get_or_insert_with(|| {
+                SevenSecretWeapons::iter()
+                    .map(|weapon| {
+                        ctx.load_texture(
+                            format!("weapon_icon_{weapon}"),
+                            ColorImage::new([16, 16], weapon.icon_color()),
+                            TextureOptions::default(),
+                        )
+                    })
+                    .collect()
+            })
+            .iter()
+            .map(TextureHandle::id)
+            .collect()
+    }
 }
 
 impl Default for UiExtensionsExample {
@@ -43,8 +95,12 @@ impl Default for UiExtensionsExample {
         Self {
             optional_usize: Some(1234),
             optional_string: Some("Test".to_owned()),
+            optional_f32: Some(0.5),
+            compression_threshold: None,
+            optional_fade_usize: Some(42),
             secret_weapon: SevenSecretWeapons::MetalDetector,
             coffee_count: 1,
+            weapon_icons: None,
         }
     }
 }
@@ -75,6 +131,42 @@ impl eframe::App for UiExtensionsExample {
                             ui.text_edit_singleline(value)
                         }),
                     );
+
+                    ui.separator();
+
+                    debug_print_response(
+                        "optional_value_widget_with_shortcut",
+                        ui.optional_value_widget_with_shortcut(
+                            &mut self.optional_f32,
+                            KeyboardShortcut::new(Modifiers::COMMAND, Key::E),
+                            |ui, value| ui.add(DragValue::new(value).speed(0.01)),
+                        ),
+                    );
+
+                    ui.separator();
+
+                    debug_print_response(
+                        "optional_value_widget_labeled",
+                        ui.optional_value_widget_labeled(
+                            &mut self.compression_threshold,
+                            "Enable compression",
+                            |ui, value| {
+                                ui.label("Threshold:");
+                                ui.add(DragValue::new(value).suffix(" dB").range(-60.0..=0.0))
+                            },
+                        ),
+                    );
+
+                    ui.separator();
+
+                    debug_print_response(
+                        "optional_value_widget_with_fade",
+                        ui.optional_value_widget_with_fade(
+                            &mut self.optional_fade_usize,
+                            0.5,
+                            |ui, value| ui.add(DragValue::new(value)),
+                        ),
+                    );
                 });
             });
             ui.add_space(16.0);
@@ -119,6 +211,82 @@ impl eframe::App for UiExtensionsExample {
             });
             ui.add_space(16.0);
 
+            ui.push_id("from_iter_tooltip", |ui| {
+                ui.group(|ui| {
+                    ui.horizontal_wrapped(|ui| {
+                        debug_print_response(
+                            "selectable_value_from_iter_with_tooltip",
+                            ui.selectable_value_from_iter_with_tooltip(
+                                &mut self.secret_weapon,
+                                SevenSecretWeapons::iter(),
+                                SevenSecretWeapons::description,
+                            ),
+                        );
+                    });
+
+                    ui.separator();
+
+                    ui.horizontal_wrapped(|ui| {
+                        debug_print_response(
+                            "combobox_from_iter_with_tooltip",
+                            ui.combobox_from_iter_with_tooltip(
+                                "Secret weapon",
+                                &mut self.secret_weapon,
+                                SevenSecretWeapons::iter(),
+                                SevenSecretWeapons::description,
+                            ),
+                        );
+                    });
+
+                    ui.separator();
+
+                    ui.horizontal_wrapped(|ui| {
+                        debug_print_response(
+                            "radio_value_from_iter_with_tooltip",
+                            ui.radio_value_from_iter_with_tooltip(
+                                &mut self.secret_weapon,
+                                SevenSecretWeapons::iter(),
+                                SevenSecretWeapons::description,
+                            ),
+                        );
+                    });
+                });
+            });
+            ui.add_space(16.0);
+
+            let weapon_icons = self.weapon_icons(ctx);
+            let icon_fn = |weapon: &SevenSecretWeapons| weapon_icons[*weapon as usize];
+
+            ui.push_id("from_iter_icon", |ui| {
+                ui.group(|ui| {
+                    ui.horizontal_wrapped(|ui| {
+                        debug_print_response(
+                            "selectable_icon_value_from_iter",
+                            ui.selectable_icon_value_from_iter(
+                                &mut self.secret_weapon,
+                                SevenSecretWeapons::iter(),
+                                icon_fn,
+                            ),
+                        );
+                    });
+
+                    ui.separator();
+
+                    ui.horizontal_wrapped(|ui| {
+                        debug_print_response(
+                            "combobox_icon_value_from_iter",
+                            ui.combobox_icon_value_from_iter(
+                                "Secret weapon",
+                                &mut self.secret_weapon,
+                                SevenSecretWeapons::iter(),
+                                icon_fn,
+                            ),
+                        );
+                    });
+                });
+            });
+            ui.add_space(16.0);
+
             ui.push_id("from_slice", |ui| {
                 ui.group(|ui| {
                     ui.horizontal_wrapped(|ui| {
@@ -0,0 +1,98 @@
+use std::ops::RangeInclusive;
+
+use eframe::egui::{DragValue, Grid, Slider, Ui};
+use egui_extras_xt::music::{AdsrTimeScale, AdsrWidget};
+use egui_extras_xt::ui::drag_rangeinclusive::DragRangeInclusive;
+use egui_extras_xt::ui::widgets_from_iter::SelectableValueFromIter;
+use strum::IntoEnumIterator;
+
+use crate::pages::PageImpl;
+
+pub struct AdsrWidgetPage {
+    attack: f32,
+    decay: f32,
+    sustain: f32,
+    release: f32,
+    width: f32,
+    height: f32,
+    time_range: RangeInclusive<f32>,
+    time_scale: AdsrTimeScale,
+    interactive: bool,
+}
+
+impl Default for AdsrWidgetPage {
+    fn default() -> AdsrWidgetPage {
+        AdsrWidgetPage {
+            attack: 0.05,
+            decay: 0.3,
+            sustain: 0.6,
+            release: 0.8,
+            width: 320.0,
+            height: 120.0,
+            time_range: 0.001..=2.0,
+            time_scale: AdsrTimeScale::Linear,
+            interactive: true,
+        }
+    }
+}
+
+impl PageImpl for AdsrWidgetPage {
+    fn ui(&mut self, ui: &mut Ui) {
+        ui.add(
+            AdsrWidget::new(
+                &mut self.attack,
+                &mut self.decay,
+                &mut self.sustain,
+                &mut self.release,
+            )
+            .size([self.width, self.height])
+            .time_range(self.time_range.clone())
+            .time_scale(self.time_scale)
+            .interactive(self.interactive),
+        );
+        ui.separator();
+
+        Grid::new("adsr_widget_properties")
+            .num_columns(2)
+            .spacing([20.0, 10.0])
+            .striped(true)
+            .show(ui, |ui| {
+                ui.label("Attack");
+                ui.add(DragValue::new(&mut self.attack).speed(0.01).suffix(" s"));
+                ui.end_row();
+
+                ui.label("Decay");
+                ui.add(DragValue::new(&mut self.decay).speed(0.01).suffix(" s"));
+                ui.end_row();
+
+                ui.label("Sustain");
+                ui.add(Slider::new(&mut self.sustain, 0.0..=1.0));
+                ui.end_row();
+
+                ui.label("Release");
+                ui.add(DragValue::new(&mut self.release).speed(0.01).suffix(" s"));
+                ui.end_row();
+
+                ui.label("Size");
+                ui.horizontal(|ui| {
+                    ui.add(DragValue::new(&mut self.width));
+                    ui.add(DragValue::new(&mut self.height));
+                });
+                ui.end_row();
+
+                ui.label("Time range");
+                ui.drag_rangeinclusive(&mut self.time_range);
+                ui.end_row();
+
+                ui.label("Time scale");
+                ui.horizontal(|ui| {
+                    ui.selectable_value_from_iter(&mut self.time_scale, AdsrTimeScale::iter());
+                });
+                ui.end_row();
+
+                ui.label("Interactive");
+                ui.checkbox(&mut self.interactive, "");
+                ui.end_row();
+            });
+    }
+}
@@ -1,7 +1,7 @@
 use std::ops::RangeInclusive;
 
 use eframe::egui::{DragValue, Grid, Ui};
-use egui_extras_xt::common::{Orientation, WidgetShape, Winding};
+use egui_extras_xt::common::{with_legacy_polygon_fill, Orientation, WidgetShape, Winding};
 use egui_extras_xt::knobs::AudioKnob;
 use egui_extras_xt::ui::drag_rangeinclusive::DragRangeInclusive;
 use egui_extras_xt::ui::optional_value_widget::OptionalValueWidget;
@@ -22,9 +22,11 @@ pub struct AudioKnobPage {
     spread: f32,
     thickness: f32,
     shape: WidgetShape,
+    shape_resolution: Option<usize>,
     animated: bool,
     snap: Option<f32>,
     shift_snap: Option<f32>,
+    compare_legacy_fill: bool,
 }
 
 impl Default for AudioKnobPage {
@@ -40,30 +42,57 @@ impl Default for AudioKnobPage {
             spread: 1.0,
             thickness: 0.66,
             shape: WidgetShape::Squircle(4.0),
+            shape_resolution: None,
             animated: true,
             snap: None,
             shift_snap: None,
+            compare_legacy_fill: false,
         }
     }
 }
 
+impl AudioKnobPage {
+    fn audio_knob<'a>(&self, value: &'a mut f32) -> AudioKnob<'a> {
+        AudioKnob::new(value)
+            .interactive(self.interactive)
+            .diameter(self.diameter)
+            .drag_length(self.drag_length)
+            .orientation(self.orientation)
+            .winding(self.winding)
+            .range(self.range.clone())
+            .spread(self.spread)
+            .thickness(self.thickness)
+            .shape(self.shape.clone())
+            .shape_resolution(self.shape_resolution)
+            .animated(self.animated)
+            .snap(self.snap)
+            .shift_snap(self.shift_snap)
+    }
+}
+
 impl PageImpl for AudioKnobPage {
     fn ui(&mut self, ui: &mut Ui) {
-        ui.add(
-            AudioKnob::new(&mut self.value)
-                .interactive(self.interactive)
-                .diameter(self.diameter)
-                .drag_length(self.drag_length)
-                .orientation(self.orientation)
-                .winding(self.winding)
-                .range(self.range.clone())
-                .spread(self.spread)
-                .thickness(self.thickness)
-                .shape(self.shape.clone())
-                .animated(self.animated)
-                .snap(self.snap)
-                .shift_snap(self.shift_snap),
-        );
+        let mut value = self.value;
+
+        if self.compare_legacy_fill {
+            ui.horizontal(|ui| {
+                ui.vertical(|ui| {
+                    ui.label("Mesh fill");
+                    ui.add(self.audio_knob(&mut value));
+                });
+
+                ui.separator();
+
+                ui.vertical(|ui| {
+                    ui.label("Legacy polygon fill");
+                    with_legacy_polygon_fill(|| ui.add(self.audio_knob(&mut value)));
+                });
+            });
+        } else {
+            ui.add(self.audio_knob(&mut value));
+        }
+
+        self.value = value;
         ui.separator();
 
         Grid::new("audio_knob_properties")
@@ -113,6 +142,12 @@ impl PageImpl for AudioKnobPage {
                 widget_shape_ui(ui, &mut self.shape);
                 ui.end_row();
 
+                ui.label("Shape resolution");
+                ui.optional_value_widget(&mut self.shape_resolution, |ui, value| {
+                    ui.add(DragValue::new(value).range(3..=512))
+                });
+                ui.end_row();
+
                 ui.label("Animated");
                 ui.checkbox(&mut self.animated, "");
                 ui.end_row();
@@ -126,6 +161,10 @@ impl PageImpl for AudioKnobPage {
                     ui.add(DragValue::new(value))
                 });
                 ui.end_row();
+
+                ui.label("Compare with legacy fill");
+                ui.checkbox(&mut self.compare_legacy_fill, "");
+                ui.end_row();
             });
     }
 }
@@ -0,0 +1,89 @@
+use eframe::egui::{DragValue, Grid, Ui};
+use eframe::epaint::Color32;
+use egui_extras_xt::displays::BinaryDisplay;
+use egui_extras_xt::indicators::LedShape;
+use egui_extras_xt::ui::widgets_from_iter::SelectableValueFromIter;
+use strum::IntoEnumIterator;
+
+use crate::pages::PageImpl;
+
+pub struct BinaryDisplayPage {
+    value: u64,
+    bit_count: usize,
+    bit_size: f32,
+    bit_gap: f32,
+    shape: LedShape,
+    one_color: Color32,
+    zero_color: Color32,
+    show_bit_labels: bool,
+}
+
+impl Default for BinaryDisplayPage {
+    fn default() -> BinaryDisplayPage {
+        BinaryDisplayPage {
+            value: 0xA5,
+            bit_count: 8,
+            bit_size: 12.0,
+            bit_gap: 4.0,
+            shape: LedShape::Circle,
+            one_color: Color32::from_rgb(0x40, 0xE0, 0x40),
+            zero_color: Color32::from_rgb(0x10, 0x30, 0x10),
+            show_bit_labels: true,
+        }
+    }
+}
+
+impl PageImpl for BinaryDisplayPage {
+    fn ui(&mut self, ui: &mut Ui) {
+        ui.add(
+            BinaryDisplay::new(self.value, self.bit_count)
+                .bit_size(self.bit_size)
+                .bit_gap(self.bit_gap)
+                .shape(self.shape)
+                .one_color(self.one_color)
+                .zero_color(self.zero_color)
+                .show_bit_labels(self.show_bit_labels),
+        );
+        ui.separator();
+
+        Grid::new("binary_display_properties")
+            .num_columns(2)
+            .spacing([20.0, 10.0])
+            .striped(true)
+            .show(ui, |ui| {
+                ui.label("Value");
+                ui.add(DragValue::new(&mut self.value).hexadecimal(2, false, true));
+                ui.end_row();
+
+                ui.label("Bit count");
+                ui.add(DragValue::new(&mut self.bit_count).range(0..=64));
+                ui.end_row();
+
+                ui.label("Bit size");
+                ui.add(DragValue::new(&mut self.bit_size));
+                ui.end_row();
+
+                ui.label("Bit gap");
+                ui.add(DragValue::new(&mut self.bit_gap));
+                ui.end_row();
+
+                ui.label("Shape");
+                ui.horizontal(|ui| {
+                    ui.selectable_value_from_iter(&mut self.shape, LedShape::iter());
+                });
+                ui.end_row();
+
+                ui.label("One color");
+                ui.color_edit_button_srgba(&mut self.one_color);
+                ui.end_row();
+
+                ui.label("Zero color");
+                ui.color_edit_button_srgba(&mut self.zero_color);
+                ui.end_row();
+
+                ui.label("Show bit labels");
+                ui.checkbox(&mut self.show_bit_labels, "");
+                ui.end_row();
+            });
+    }
+}
@@ -0,0 +1,190 @@
+use std::path::{Path, PathBuf, MAIN_SEPARATOR};
+
+use eframe::egui::{Color32, DragValue, Grid, Ui};
+use egui_extras_xt::filesystem::{
+    BreadcrumbBar, BreadcrumbBarState, BreadcrumbDisplay, BreadcrumbSeparator,
+};
+use egui_extras_xt::ui::widgets_from_slice::ComboBoxFromSlice;
+use itertools::Itertools;
+
+use crate::pages::PageImpl;
+
+use crate::pages::ui::pathbuf_ui;
+
+pub struct BreadcrumbBarPage {
+    root_path: PathBuf,
+    selected_path: PathBuf,
+    hide_file_extensions: bool,
+    allow_navigation: bool,
+    existing_paths_only: bool,
+    path_completion: bool,
+    history: BreadcrumbBarState,
+    separator: BreadcrumbSeparator,
+    separator_color: Color32,
+    separator_size: f32,
+    clicked_segment: Option<PathBuf>,
+}
+
+impl Default for BreadcrumbBarPage {
+    fn default() -> BreadcrumbBarPage {
+        BreadcrumbBarPage {
+            root_path: PathBuf::from("/"),
+            selected_path: std::env::current_dir().unwrap_or_else(|_| PathBuf::from("/")),
+            hide_file_extensions: false,
+            allow_navigation: true,
+            existing_paths_only: true,
+            path_completion: true,
+            history: BreadcrumbBarState::default(),
+            separator: BreadcrumbSeparator::default(),
+            separator_color: Color32::GRAY,
+            separator_size: 14.0,
+            clicked_segment: None,
+        }
+    }
+}
+
+impl PageImpl for BreadcrumbBarPage {
+    fn ui(&mut self, ui: &mut Ui) {
+        let existing_paths_only = self.existing_paths_only;
+
+        let mut breadcrumb_bar = BreadcrumbBar::new(&mut self.selected_path, &self.root_path)
+            .hide_file_extensions(self.hide_file_extensions)
+            .allow_navigation(self.allow_navigation)
+            .validate(move |path| !existing_paths_only || path.exists())
+            .history(&mut self.history)
+            .separator(self.separator.clone())
+            .separator_color(self.separator_color)
+            .separator_size(self.separator_size);
+
+        if self.path_completion {
+            breadcrumb_bar = breadcrumb_bar.completion(local_path_completions);
+        }
+
+        let breadcrumb_bar_response = breadcrumb_bar.show(ui);
+        ui.separator();
+
+        if let Some(clicked_segment) = &breadcrumb_bar_response.clicked_segment {
+            self.clicked_segment = Some(clicked_segment.clone());
+        }
+
+        Grid::new("breadcrumb_bar_properties")
+            .num_columns(2)
+            .spacing([20.0, 10.0])
+            .striped(true)
+            .show(ui, |ui| {
+                ui.label("Root path");
+                pathbuf_ui(ui, &mut self.root_path);
+                ui.end_row();
+
+                ui.label("Selected path");
+                pathbuf_ui(ui, &mut self.selected_path);
+                ui.end_row();
+
+                ui.label("Hide file extensions");
+                ui.checkbox(&mut self.hide_file_extensions, "");
+                ui.end_row();
+
+                ui.label("Allow navigation");
+                ui.checkbox(&mut self.allow_navigation, "");
+                ui.end_row();
+
+                ui.label("Accept existing paths only");
+                ui.checkbox(&mut self.existing_paths_only, "");
+                ui.end_row();
+
+                ui.label("Path completion");
+                ui.checkbox(&mut self.path_completion, "");
+                ui.end_row();
+
+                ui.label("Separator");
+                ui.combobox_from_slice(
+                    "",
+                    &mut self.separator,
+                    &[
+                        BreadcrumbSeparator::Slash,
+                        BreadcrumbSeparator::Backslash,
+                        BreadcrumbSeparator::Chevron,
+                        BreadcrumbSeparator::Custom("\u{203A}".to_owned()),
+                    ],
+                );
+                ui.end_row();
+
+                ui.label("Separator color");
+                ui.color_edit_button_srgba(&mut self.separator_color);
+                ui.end_row();
+
+                ui.label("Separator size");
+                ui.add(DragValue::new(&mut self.separator_size).range(4.0..=64.0));
+                ui.end_row();
+
+                ui.label("Read-only display");
+                ui.add(
+                    BreadcrumbDisplay::new(&self.selected_path)
+                        .root_directory(&self.root_path)
+                        .separator(self.separator.clone())
+                        .separator_color(self.separator_color)
+                        .separator_size(self.separator_size),
+                );
+                ui.end_row();
+
+                ui.label("History size");
+                let mut history_size = self.history.capacity();
+                if ui
+                    .add(DragValue::new(&mut history_size).range(1..=256))
+                    .changed()
+                {
+                    self.history.set_capacity(history_size);
+                }
+                ui.end_row();
+
+                ui.label("Hovered segment");
+                match &breadcrumb_bar_response.hovered_segment {
+                    Some(hovered_segment) => ui.label(hovered_segment.display().to_string()),
+                    None => ui.weak("None"),
+                };
+                ui.end_row();
+
+                ui.label("Last clicked segment");
+                match &self.clicked_segment {
+                    Some(clicked_segment) => ui.label(clicked_segment.display().to_string()),
+                    None => ui.weak("None"),
+                };
+                ui.end_row();
+            });
+    }
+}
+
+/// Entries of the local directory starting with the last component of the prefix,
+/// directories end with a separator for continuing with their contents.
+fn local_path_completions(prefix: &Path) -> Vec<PathBuf> {
+    let (directory, name_prefix) = if prefix
+        .as_os_str()
+        .to_string_lossy()
+        .ends_with(MAIN_SEPARATOR)
+    {
+        (prefix, String::new())
+    } else {
+        (
+            prefix.parent().unwrap_or(prefix),
+            prefix
+                .file_name()
+                .map(|file_name| file_name.to_string_lossy().into_owned())
+                .unwrap_or_default(),
+        )
+    };
+
+    let Ok(entries) = std::fs::read_dir(directory) else {
+        return Vec::new();
+    };
+
+    entries
+        .filter_map(Result::ok)
+        .map(|entry| entry.path())
+        .filter(|path| {
+            path.file_name()
+                .is_some_and(|file_name| file_name.to_string_lossy().starts_with(&name_prefix))
+        })
+        .map(|path| if path.is_dir() { path.join("") } else { path })
+        .sorted()
+        .collect()
+}
@@ -0,0 +1,103 @@
+use eframe::egui::{Color32, DragValue, Grid, Ui};
+use eframe::epaint::Hsva;
+use egui_extras_xt::music::ButtonMatrix;
+
+use crate::pages::PageImpl;
+
+pub struct ButtonMatrixPage {
+    states: Vec<Vec<bool>>,
+    rows: usize,
+    columns: usize,
+    cell_size: f32,
+    cell_gap: f32,
+    active_color: Color32,
+    rainbow: bool,
+    multi_select: bool,
+    last_toggled: Option<(usize, usize)>,
+}
+
+impl Default for ButtonMatrixPage {
+    fn default() -> ButtonMatrixPage {
+        ButtonMatrixPage {
+            states: vec![vec![false; 8]; 8],
+            rows: 8,
+            columns: 8,
+            cell_size: 32.0,
+            cell_gap: 4.0,
+            active_color: Color32::from_rgb(0xE6, 0x8C, 0x28),
+            rainbow: true,
+            multi_select: true,
+            last_toggled: None,
+        }
+    }
+}
+
+impl PageImpl for ButtonMatrixPage {
+    fn ui(&mut self, ui: &mut Ui) {
+        self.states.resize(self.rows, Vec::new());
+        for row_states in &mut self.states {
+            row_states.resize(self.columns, false);
+        }
+
+        let mut button_matrix = ButtonMatrix::new(&mut self.states)
+            .cell_size([self.cell_size, self.cell_size])
+            .cell_gap(self.cell_gap)
+            .active_color(self.active_color)
+            .multi_select(self.multi_select);
+
+        if self.rainbow {
+            let (rows, columns) = (self.rows, self.columns);
+            button_matrix = button_matrix.color_fn(move |row, column| {
+                let hue = (row + column) as f32 / (rows + columns).max(1) as f32;
+                Color32::from(Hsva::new(hue, 0.8, 0.9, 1.0))
+            });
+        }
+
+        let button_matrix_response = button_matrix.show(ui);
+        ui.separator();
+
+        if button_matrix_response.last_toggled.is_some() {
+            self.last_toggled = button_matrix_response.last_toggled;
+        }
+
+        Grid::new("button_matrix_properties")
+            .num_columns(2)
+            .spacing([20.0, 10.0])
+            .striped(true)
+            .show(ui, |ui| {
+                ui.label("Rows");
+                ui.add(DragValue::new(&mut self.rows).range(0..=32));
+                ui.end_row();
+
+                ui.label("Columns");
+                ui.add(DragValue::new(&mut self.columns).range(0..=32));
+                ui.end_row();
+
+                ui.label("Cell size");
+                ui.add(DragValue::new(&mut self.cell_size));
+                ui.end_row();
+
+                ui.label("Cell gap");
+                ui.add(DragValue::new(&mut self.cell_gap));
+                ui.end_row();
+
+                ui.label("Active color");
+                ui.horizontal(|ui| {
+                    ui.color_edit_button_srgba(&mut self.active_color);
+                    ui.checkbox(&mut self.rainbow, "Rainbow");
+                });
+                ui.end_row();
+
+                ui.label("Multi-select");
+                ui.checkbox(&mut self.multi_select, "Hold Shift and drag");
+                ui.end_row();
+
+                ui.label("Last toggled");
+                ui.label(match self.last_toggled {
+                    Some((row, column)) => format!("Row {row}, column {column}"),
+                    None => "None".to_owned(),
+                });
+                ui.end_row();
+            });
+    }
+}
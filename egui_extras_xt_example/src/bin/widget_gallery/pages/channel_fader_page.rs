@@ -0,0 +1,104 @@
+use eframe::egui::{DragValue, Grid, TextEdit, Ui};
+use egui_extras_xt::knobs::ChannelFader;
+
+use crate::pages::PageImpl;
+
+pub struct ChannelFaderPage {
+    position: f32,
+    max_db: f32,
+    channel_label: String,
+    show_value_tooltip: bool,
+    width: f32,
+    height: f32,
+    cap_width: f32,
+    cap_height: f32,
+    interactive: bool,
+    animated: bool,
+}
+
+impl Default for ChannelFaderPage {
+    fn default() -> ChannelFaderPage {
+        ChannelFaderPage {
+            position: ChannelFader::db_to_position(0.0, 6.0),
+            max_db: 6.0,
+            channel_label: "CH 1".to_owned(),
+            show_value_tooltip: true,
+            width: 64.0,
+            height: 192.0,
+            cap_width: 28.0,
+            cap_height: 12.0,
+            interactive: true,
+            animated: false,
+        }
+    }
+}
+
+impl PageImpl for ChannelFaderPage {
+    fn ui(&mut self, ui: &mut Ui) {
+        ui.add(
+            ChannelFader::new(&mut self.position)
+                .max_db(self.max_db)
+                .channel_label(&self.channel_label)
+                .show_value_tooltip(self.show_value_tooltip)
+                .size([self.width, self.height])
+                .cap_size([self.cap_width, self.cap_height])
+                .interactive(self.interactive)
+                .animated(self.animated),
+        );
+        ui.separator();
+
+        Grid::new("channel_fader_properties")
+            .num_columns(2)
+            .spacing([20.0, 10.0])
+            .striped(true)
+            .show(ui, |ui| {
+                ui.label("Position");
+                ui.horizontal(|ui| {
+                    ui.add(
+                        DragValue::new(&mut self.position)
+                            .speed(0.01)
+                            .range(0.0..=1.0),
+                    );
+                    ui.label(format!(
+                        "{:.1} dB",
+                        ChannelFader::position_to_db(self.position, self.max_db)
+                    ));
+                });
+                ui.end_row();
+
+                ui.label("Maximum level");
+                ui.add(DragValue::new(&mut self.max_db).suffix(" dB"));
+                ui.end_row();
+
+                ui.label("Channel label");
+                ui.add(TextEdit::singleline(&mut self.channel_label));
+                ui.end_row();
+
+                ui.label("Show value tooltip");
+                ui.checkbox(&mut self.show_value_tooltip, "");
+                ui.end_row();
+
+                ui.label("Size");
+                ui.horizontal(|ui| {
+                    ui.add(DragValue::new(&mut self.width));
+                    ui.add(DragValue::new(&mut self.height));
+                });
+                ui.end_row();
+
+                ui.label("Cap size");
+                ui.horizontal(|ui| {
+                    ui.add(DragValue::new(&mut self.cap_width));
+                    ui.add(DragValue::new(&mut self.cap_height));
+                });
+                ui.end_row();
+
+                ui.label("Interactive");
+                ui.checkbox(&mut self.interactive, "");
+                ui.end_row();
+
+                ui.label("Animated");
+                ui.checkbox(&mut self.animated, "");
+                ui.end_row();
+            });
+    }
+}
@@ -0,0 +1,101 @@
+use eframe::egui::{Color32, DragValue, Grid, Slider, Ui};
+use egui_extras_xt::knobs::CrossFader;
+
+use crate::pages::PageImpl;
+
+pub struct CrossFaderPage {
+    value: f32,
+    detent_threshold: f32,
+    width: f32,
+    height: f32,
+    track_thickness: f32,
+    cap_width: f32,
+    left_color: Color32,
+    right_color: Color32,
+    interactive: bool,
+    animated: bool,
+}
+
+impl Default for CrossFaderPage {
+    fn default() -> CrossFaderPage {
+        CrossFaderPage {
+            value: 0.0,
+            detent_threshold: 0.03,
+            width: 192.0,
+            height: 32.0,
+            track_thickness: 6.0,
+            cap_width: 16.0,
+            left_color: Color32::from_rgb(0x28, 0x8C, 0xE6),
+            right_color: Color32::from_rgb(0xE6, 0x8C, 0x28),
+            interactive: true,
+            animated: false,
+        }
+    }
+}
+
+impl PageImpl for CrossFaderPage {
+    fn ui(&mut self, ui: &mut Ui) {
+        ui.add(
+            CrossFader::new(&mut self.value)
+                .detent_threshold(self.detent_threshold)
+                .size([self.width, self.height])
+                .track_thickness(self.track_thickness)
+                .cap_width(self.cap_width)
+                .left_color(self.left_color)
+                .right_color(self.right_color)
+                .interactive(self.interactive)
+                .animated(self.animated),
+        );
+        ui.separator();
+
+        Grid::new("crossfader_properties")
+            .num_columns(2)
+            .spacing([20.0, 10.0])
+            .striped(true)
+            .show(ui, |ui| {
+                ui.label("Value");
+                ui.add(Slider::new(&mut self.value, -1.0..=1.0));
+                ui.end_row();
+
+                ui.label("Detent threshold");
+                ui.add(
+                    DragValue::new(&mut self.detent_threshold)
+                        .speed(0.001)
+                        .range(0.0..=1.0),
+                );
+                ui.end_row();
+
+                ui.label("Width");
+                ui.add(DragValue::new(&mut self.width));
+                ui.end_row();
+
+                ui.label("Height");
+                ui.add(DragValue::new(&mut self.height));
+                ui.end_row();
+
+                ui.label("Track thickness");
+                ui.add(DragValue::new(&mut self.track_thickness));
+                ui.end_row();
+
+                ui.label("Cap width");
+                ui.add(DragValue::new(&mut self.cap_width));
+                ui.end_row();
+
+                ui.label("Left color");
+                ui.color_edit_button_srgba(&mut self.left_color);
+                ui.end_row();
+
+                ui.label("Right color");
+                ui.color_edit_button_srgba(&mut self.right_color);
+                ui.end_row();
+
+                ui.label("Interactive");
+                ui.checkbox(&mut self.interactive, "");
+                ui.end_row();
+
+                ui.label("Animated");
+                ui.checkbox(&mut self.animated, "");
+                ui.end_row();
+            });
+    }
+}
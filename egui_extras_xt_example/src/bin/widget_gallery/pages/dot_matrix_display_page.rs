@@ -0,0 +1,95 @@
+use eframe::egui::{DragValue, Grid, Ui};
+use egui_extras_xt::displays::{
+    DisplayStyle, DisplayStylePreset, DotMatrixDisplayWidget, DotMatrixKind, DotMatrixMetrics,
+};
+use egui_extras_xt::ui::widgets_from_iter::SelectableValueFromIter;
+use strum::IntoEnumIterator;
+
+use crate::pages::ui::display_style_ui;
+use crate::pages::PageImpl;
+
+pub struct DotMatrixDisplayPage {
+    value: String,
+    display_kind: DotMatrixKind,
+    dot_size: f32,
+    metrics: DotMatrixMetrics,
+    style: DisplayStyle,
+    style_preset: DisplayStylePreset,
+}
+
+impl Default for DotMatrixDisplayPage {
+    fn default() -> DotMatrixDisplayPage {
+        DotMatrixDisplayPage {
+            value: "egui_extras_xt \u{2665}".to_owned(),
+            display_kind: DotMatrixKind::FiveBySeven,
+            dot_size: 4.0,
+            metrics: DotMatrixMetrics::default(),
+            style: DisplayStylePreset::YamahaMU2000.style(),
+            style_preset: DisplayStylePreset::YamahaMU2000,
+        }
+    }
+}
+
+impl PageImpl for DotMatrixDisplayPage {
+    fn ui(&mut self, ui: &mut Ui) {
+        ui.add(
+            DotMatrixDisplayWidget::new(self.display_kind)
+                .dot_size(self.dot_size)
+                .metrics(self.metrics)
+                .style(self.style)
+                .custom_glyph('\u{2665}', [0x00, 0x0A, 0x1F, 0x1F, 0x0E, 0x04, 0x00, 0x00])
+                .push_string(&self.value),
+        );
+        ui.separator();
+
+        Grid::new("dot_matrix_display_properties")
+            .num_columns(2)
+            .spacing([20.0, 10.0])
+            .striped(true)
+            .show(ui, |ui| {
+                ui.label("Value");
+                ui.text_edit_singleline(&mut self.value);
+                ui.end_row();
+
+                ui.label("Display kind");
+                ui.horizontal(|ui| {
+                    ui.selectable_value_from_iter(&mut self.display_kind, DotMatrixKind::iter());
+                });
+                ui.end_row();
+
+                ui.label("Dot size");
+                ui.add(DragValue::new(&mut self.dot_size));
+                ui.end_row();
+
+                ui.label("Metrics");
+                ui.vertical(|ui| {
+                    ui.add(
+                        DragValue::new(&mut self.metrics.dot_spacing)
+                            .speed(0.01)
+                            .prefix("Dot spacing: "),
+                    );
+                    ui.add(
+                        DragValue::new(&mut self.metrics.character_spacing)
+                            .speed(0.01)
+                            .prefix("Character spacing: "),
+                    );
+                    ui.add(
+                        DragValue::new(&mut self.metrics.margin_horizontal)
+                            .speed(0.01)
+                            .prefix("Horizontal margin: "),
+                    );
+                    ui.add(
+                        DragValue::new(&mut self.metrics.margin_vertical)
+                            .speed(0.01)
+                            .prefix("Vertical margin: "),
+                    );
+                    ui.checkbox(&mut self.metrics.round_dots, "Round dots");
+                });
+                ui.end_row();
+
+                ui.label("Style");
+                display_style_ui(ui, &mut self.style, &mut self.style_preset);
+                ui.end_row();
+            });
+    }
+}
@@ -0,0 +1,50 @@
+use eframe::egui::{Grid, Ui};
+use egui_extras_xt::ui::drag_list::DragAndDropList;
+
+use crate::pages::PageImpl;
+
+pub struct DragListPage {
+    effects: Vec<String>,
+    removable: bool,
+    new_effect: String,
+}
+
+impl Default for DragListPage {
+    fn default() -> DragListPage {
+        DragListPage {
+            effects: ["Compressor", "Equalizer", "Chorus", "Delay", "Reverb"]
+                .into_iter()
+                .map(str::to_owned)
+                .collect(),
+            removable: true,
+            new_effect: "Distortion".to_owned(),
+        }
+    }
+}
+
+impl PageImpl for DragListPage {
+    fn ui(&mut self, ui: &mut Ui) {
+        ui.add(DragAndDropList::new(&mut self.effects).removable(self.removable));
+        ui.separator();
+
+        Grid::new("drag_list_properties")
+            .num_columns(2)
+            .spacing([20.0, 10.0])
+            .striped(true)
+            .show(ui, |ui| {
+                ui.label("Removable");
+                ui.checkbox(&mut self.removable, "");
+                ui.end_row();
+
+                ui.label("New item");
+                ui.horizontal(|ui| {
+                    ui.text_edit_singleline(&mut self.new_effect);
+
+                    if ui.button("Add").clicked() {
+                        self.effects.push(self.new_effect.clone());
+                    }
+                });
+                ui.end_row();
+            });
+    }
+}
@@ -0,0 +1,134 @@
+use std::ops::RangeInclusive;
+
+use eframe::egui::{DragValue, Grid, Ui};
+use egui_extras_xt::common::{Orientation, WidgetShape, Winding};
+use egui_extras_xt::knobs::DualKnob;
+use egui_extras_xt::ui::drag_rangeinclusive::DragRangeInclusive;
+use egui_extras_xt::ui::widgets_from_iter::SelectableValueFromIter;
+use strum::IntoEnumIterator;
+
+use crate::pages::ui::{widget_orientation_ui, widget_shape_ui};
+use crate::pages::PageImpl;
+
+pub struct DualKnobPage {
+    inner_value: f32,
+    outer_value: f32,
+    inner_range: RangeInclusive<f32>,
+    outer_range: RangeInclusive<f32>,
+    inner_label: String,
+    outer_label: String,
+    interactive: bool,
+    diameter: f32,
+    drag_length: f32,
+    winding: Winding,
+    orientation: Orientation,
+    spread: f32,
+    shape: WidgetShape,
+    animated: bool,
+}
+
+impl Default for DualKnobPage {
+    fn default() -> DualKnobPage {
+        DualKnobPage {
+            inner_value: 0.25,
+            outer_value: 0.75,
+            inner_range: 0.0..=1.0,
+            outer_range: 0.0..=1.0,
+            inner_label: "Fine".to_owned(),
+            outer_label: "Coarse".to_owned(),
+            interactive: true,
+            diameter: 64.0,
+            drag_length: 1.0,
+            winding: Winding::Clockwise,
+            orientation: Orientation::Top,
+            spread: 1.0,
+            shape: WidgetShape::Squircle(4.0),
+            animated: true,
+        }
+    }
+}
+
+impl PageImpl for DualKnobPage {
+    fn ui(&mut self, ui: &mut Ui) {
+        ui.add(
+            DualKnob::new(&mut self.inner_value, &mut self.outer_value)
+                .inner_range(self.inner_range.clone())
+                .outer_range(self.outer_range.clone())
+                .inner_label(&self.inner_label)
+                .outer_label(&self.outer_label)
+                .interactive(self.interactive)
+                .diameter(self.diameter)
+                .drag_length(self.drag_length)
+                .winding(self.winding)
+                .orientation(self.orientation)
+                .spread(self.spread)
+                .shape(self.shape.clone())
+                .animated(self.animated),
+        );
+        ui.separator();
+
+        Grid::new("dual_knob_properties")
+            .num_columns(2)
+            .spacing([20.0, 10.0])
+            .striped(true)
+            .show(ui, |ui| {
+                ui.label("Inner value");
+                ui.add(DragValue::new(&mut self.inner_value));
+                ui.end_row();
+
+                ui.label("Outer value");
+                ui.add(DragValue::new(&mut self.outer_value));
+                ui.end_row();
+
+                ui.label("Inner range");
+                ui.drag_rangeinclusive(&mut self.inner_range);
+                ui.end_row();
+
+                ui.label("Outer range");
+                ui.drag_rangeinclusive(&mut self.outer_range);
+                ui.end_row();
+
+                ui.label("Inner label");
+                ui.text_edit_singleline(&mut self.inner_label);
+                ui.end_row();
+
+                ui.label("Outer label");
+                ui.text_edit_singleline(&mut self.outer_label);
+                ui.end_row();
+
+                ui.label("Interactive");
+                ui.checkbox(&mut self.interactive, "");
+                ui.end_row();
+
+                ui.label("Diameter");
+                ui.add(DragValue::new(&mut self.diameter));
+                ui.end_row();
+
+                ui.label("Drag length");
+                ui.add(DragValue::new(&mut self.drag_length));
+                ui.end_row();
+
+                ui.label("Winding");
+                ui.horizontal(|ui| {
+                    ui.selectable_value_from_iter(&mut self.winding, Winding::iter());
+                });
+                ui.end_row();
+
+                ui.label("Orientation");
+                widget_orientation_ui(ui, &mut self.orientation);
+                ui.end_row();
+
+                ui.label("Spread");
+                ui.add(DragValue::new(&mut self.spread));
+                ui.end_row();
+
+                ui.label("Shape");
+                widget_shape_ui(ui, &mut self.shape);
+                ui.end_row();
+
+                ui.label("Animated");
+                ui.checkbox(&mut self.animated, "");
+                ui.end_row();
+            });
+    }
+}
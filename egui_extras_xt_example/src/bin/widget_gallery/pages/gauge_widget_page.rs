@@ -0,0 +1,130 @@
+use std::f32::consts::TAU;
+use std::ops::RangeInclusive;
+
+use eframe::egui::{Color32, DragValue, Grid, Slider, Ui};
+use egui_extras_xt::common::{Orientation, WidgetShape};
+use egui_extras_xt::meters::GaugeWidget;
+use egui_extras_xt::ui::drag_rangeinclusive::DragRangeInclusive;
+
+use crate::pages::ui::{widget_orientation_ui, widget_shape_ui};
+use crate::pages::PageImpl;
+
+const COLOR_ZONES: &[(f32, f32, Color32)] = &[
+    (6000.0, 7000.0, Color32::from_rgb(0xE6, 0xC8, 0x28)),
+    (7000.0, 8000.0, Color32::from_rgb(0xE6, 0x3C, 0x28)),
+];
+
+pub struct GaugeWidgetPage {
+    value: f32,
+    range: RangeInclusive<f32>,
+    diameter: f32,
+    orientation: Orientation,
+    sweep: f32,
+    shape: WidgetShape,
+    major_ticks: usize,
+    minor_ticks: usize,
+    show_tick_labels: bool,
+    show_color_zones: bool,
+    label: String,
+    animated: bool,
+}
+
+impl Default for GaugeWidgetPage {
+    fn default() -> GaugeWidgetPage {
+        GaugeWidgetPage {
+            value: 3500.0,
+            range: 0.0..=8000.0,
+            diameter: 200.0,
+            orientation: Orientation::Top,
+            sweep: TAU / 2.0,
+            shape: WidgetShape::Circle,
+            major_ticks: 8,
+            minor_ticks: 5,
+            show_tick_labels: true,
+            show_color_zones: true,
+            label: "RPM".to_owned(),
+            animated: true,
+        }
+    }
+}
+
+impl PageImpl for GaugeWidgetPage {
+    fn ui(&mut self, ui: &mut Ui) {
+        ui.add(
+            GaugeWidget::new(self.value)
+                .range(self.range.clone())
+                .diameter(self.diameter)
+                .orientation(self.orientation)
+                .sweep(self.sweep)
+                .shape(self.shape.clone())
+                .major_ticks(self.major_ticks)
+                .minor_ticks(self.minor_ticks)
+                .show_tick_labels(self.show_tick_labels)
+                .color_zones(if self.show_color_zones {
+                    COLOR_ZONES
+                } else {
+                    &[]
+                })
+                .label(&self.label)
+                .animated(self.animated),
+        );
+        ui.separator();
+
+        Grid::new("gauge_widget_properties")
+            .num_columns(2)
+            .spacing([20.0, 10.0])
+            .striped(true)
+            .show(ui, |ui| {
+                ui.label("Value");
+                ui.add(Slider::new(
+                    &mut self.value,
+                    *self.range.start()..=*self.range.end(),
+                ));
+                ui.end_row();
+
+                ui.label("Range");
+                ui.drag_rangeinclusive(&mut self.range);
+                ui.end_row();
+
+                ui.label("Diameter");
+                ui.add(DragValue::new(&mut self.diameter));
+                ui.end_row();
+
+                ui.label("Orientation");
+                widget_orientation_ui(ui, &mut self.orientation);
+                ui.end_row();
+
+                ui.label("Sweep");
+                ui.drag_angle(&mut self.sweep);
+                ui.end_row();
+
+                ui.label("Shape");
+                widget_shape_ui(ui, &mut self.shape);
+                ui.end_row();
+
+                ui.label("Major ticks");
+                ui.add(DragValue::new(&mut self.major_ticks).range(1..=50));
+                ui.end_row();
+
+                ui.label("Minor ticks");
+                ui.add(DragValue::new(&mut self.minor_ticks).range(1..=20));
+                ui.end_row();
+
+                ui.label("Show tick labels");
+                ui.checkbox(&mut self.show_tick_labels, "");
+                ui.end_row();
+
+                ui.label("Show color zones");
+                ui.checkbox(&mut self.show_color_zones, "");
+                ui.end_row();
+
+                ui.label("Label");
+                ui.text_edit_singleline(&mut self.label);
+                ui.end_row();
+
+                ui.label("Animated");
+                ui.checkbox(&mut self.animated, "");
+                ui.end_row();
+            });
+    }
+}
@@ -0,0 +1,126 @@
+use std::ops::RangeInclusive;
+
+use eframe::egui::{DragValue, Grid, Ui};
+use egui_extras_xt::common::{Orientation, WidgetShape, Winding};
+use egui_extras_xt::knobs::KnobMatrix;
+use egui_extras_xt::ui::drag_rangeinclusive::DragRangeInclusive;
+use egui_extras_xt::ui::widgets_from_iter::SelectableValueFromIter;
+use strum::IntoEnumIterator;
+
+use crate::pages::ui::{widget_orientation_ui, widget_shape_ui};
+use crate::pages::PageImpl;
+
+const ROW_LABELS: &[&str] = &["Osc 1", "Osc 2", "Filter"];
+const COLUMN_LABELS: &[&str] = &["Level", "Tune", "Attack", "Release"];
+
+pub struct KnobMatrixPage {
+    values: Vec<Vec<f32>>,
+    range: RangeInclusive<f32>,
+    default_value: f32,
+    show_row_labels: bool,
+    show_column_labels: bool,
+    show_value_labels: bool,
+    diameter: f32,
+    winding: Winding,
+    orientation: Orientation,
+    shape: WidgetShape,
+    last_changed_cell: Option<(usize, usize)>,
+}
+
+impl Default for KnobMatrixPage {
+    fn default() -> KnobMatrixPage {
+        KnobMatrixPage {
+            values: vec![vec![0.5; COLUMN_LABELS.len()]; ROW_LABELS.len()],
+            range: 0.0..=1.0,
+            default_value: 0.5,
+            show_row_labels: true,
+            show_column_labels: true,
+            show_value_labels: true,
+            diameter: 32.0,
+            winding: Winding::Clockwise,
+            orientation: Orientation::Top,
+            shape: WidgetShape::Squircle(4.0),
+            last_changed_cell: None,
+        }
+    }
+}
+
+impl PageImpl for KnobMatrixPage {
+    fn ui(&mut self, ui: &mut Ui) {
+        let knob_matrix_response = KnobMatrix::new(&mut self.values)
+            .range(self.range.clone())
+            .default_value(self.default_value)
+            .row_labels(if self.show_row_labels {
+                ROW_LABELS
+            } else {
+                &[]
+            })
+            .column_labels(if self.show_column_labels {
+                COLUMN_LABELS
+            } else {
+                &[]
+            })
+            .show_value_labels(self.show_value_labels)
+            .diameter(self.diameter)
+            .winding(self.winding)
+            .orientation(self.orientation)
+            .shape(self.shape.clone())
+            .show(ui);
+        ui.separator();
+
+        if knob_matrix_response.changed_cell.is_some() {
+            self.last_changed_cell = knob_matrix_response.changed_cell;
+        }
+
+        Grid::new("knob_matrix_properties")
+            .num_columns(2)
+            .spacing([20.0, 10.0])
+            .striped(true)
+            .show(ui, |ui| {
+                ui.label("Range");
+                ui.drag_rangeinclusive(&mut self.range);
+                ui.end_row();
+
+                ui.label("Default value");
+                ui.add(DragValue::new(&mut self.default_value).speed(0.01));
+                ui.end_row();
+
+                ui.label("Show row labels");
+                ui.checkbox(&mut self.show_row_labels, "");
+                ui.end_row();
+
+                ui.label("Show column labels");
+                ui.checkbox(&mut self.show_column_labels, "");
+                ui.end_row();
+
+                ui.label("Show value labels");
+                ui.checkbox(&mut self.show_value_labels, "");
+                ui.end_row();
+
+                ui.label("Diameter");
+                ui.add(DragValue::new(&mut self.diameter));
+                ui.end_row();
+
+                ui.label("Winding");
+                ui.horizontal(|ui| {
+                    ui.selectable_value_from_iter(&mut self.winding, Winding::iter());
+                });
+                ui.end_row();
+
+                ui.label("Orientation");
+                widget_orientation_ui(ui, &mut self.orientation);
+                ui.end_row();
+
+                ui.label("Shape");
+                widget_shape_ui(ui, &mut self.shape);
+                ui.end_row();
+
+                ui.label("Last changed cell");
+                ui.label(match self.last_changed_cell {
+                    Some((row, column)) => format!("Row {row}, column {column}"),
+                    None => "None".to_owned(),
+                });
+                ui.end_row();
+            });
+    }
+}
@@ -0,0 +1,87 @@
+use eframe::egui::{DragValue, Grid, Ui};
+use eframe::epaint::Color32;
+use egui_extras_xt::indicators::{LedIndicator, LedShape};
+use egui_extras_xt::ui::widgets_from_iter::SelectableValueFromIter;
+use strum::IntoEnumIterator;
+
+use crate::pages::PageImpl;
+
+pub struct LedIndicatorPage {
+    state: bool,
+    diameter: f32,
+    shape: LedShape,
+    on_color: Color32,
+    off_color: Color32,
+    blink_rate: f32,
+    show_highlight: bool,
+}
+
+impl Default for LedIndicatorPage {
+    fn default() -> LedIndicatorPage {
+        LedIndicatorPage {
+            state: true,
+            diameter: 16.0,
+            shape: LedShape::Circle,
+            on_color: Color32::from_rgb(0x40, 0xE0, 0x40),
+            off_color: Color32::from_rgb(0x10, 0x30, 0x10),
+            blink_rate: 0.0,
+            show_highlight: true,
+        }
+    }
+}
+
+impl PageImpl for LedIndicatorPage {
+    fn ui(&mut self, ui: &mut Ui) {
+        ui.add(
+            LedIndicator::new(self.state)
+                .diameter(self.diameter)
+                .shape(self.shape)
+                .on_color(self.on_color)
+                .off_color(self.off_color)
+                .blink_rate(self.blink_rate)
+                .show_highlight(self.show_highlight),
+        );
+        ui.separator();
+
+        Grid::new("led_indicator_properties")
+            .num_columns(2)
+            .spacing([20.0, 10.0])
+            .striped(true)
+            .show(ui, |ui| {
+                ui.label("State");
+                ui.checkbox(&mut self.state, "");
+                ui.end_row();
+
+                ui.label("Diameter");
+                ui.add(DragValue::new(&mut self.diameter));
+                ui.end_row();
+
+                ui.label("Shape");
+                ui.horizontal(|ui| {
+                    ui.selectable_value_from_iter(&mut self.shape, LedShape::iter());
+                });
+                ui.end_row();
+
+                ui.label("On color");
+                ui.color_edit_button_srgba(&mut self.on_color);
+                ui.end_row();
+
+                ui.label("Off color");
+                ui.color_edit_button_srgba(&mut self.off_color);
+                ui.end_row();
+
+                ui.label("Blink rate");
+                ui.add(
+                    DragValue::new(&mut self.blink_rate)
+                        .speed(0.1)
+                        .range(0.0..=f32::MAX)
+                        .suffix(" Hz"),
+                );
+                ui.end_row();
+
+                ui.label("Show highlight");
+                ui.checkbox(&mut self.show_highlight, "");
+                ui.end_row();
+            });
+    }
+}
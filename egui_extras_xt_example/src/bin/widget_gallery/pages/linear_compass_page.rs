@@ -1,6 +1,8 @@
+use std::f32::consts::TAU;
+
 use eframe::egui::{DragValue, Grid, Ui};
 use eframe::epaint::Color32;
-use egui_extras_xt::common::{Winding, WrapMode};
+use egui_extras_xt::common::{WidgetShape, Winding, WrapMode};
 use egui_extras_xt::compasses::{
     CompassAxisLabels, CompassMarker, CompassMarkerShape, DefaultCompassMarkerColor, LinearCompass,
 };
@@ -19,6 +21,7 @@ pub struct LinearCompassPage {
     wrap: WrapMode,
     winding: Winding,
     width: f32,
+    fill_width: bool,
     height: f32,
     spread: f32,
     axis_labels: Vec<String>,
@@ -28,8 +31,14 @@ pub struct LinearCompassPage {
     max: Option<f32>,
     animated: bool,
     show_cursor: bool,
+    show_reciprocal: bool,
     show_ticks: bool,
+    ticks: Option<(f32, f32)>,
     show_axes: bool,
+    cluster_threshold: Option<f32>,
+    track_on_marker_click: bool,
+    clicked_marker: Option<usize>,
+    relative_bearing_to: Option<usize>,
     default_marker_color: DefaultCompassMarkerColor,
     default_marker_shape: CompassMarkerShape,
 }
@@ -42,6 +51,7 @@ impl Default for LinearCompassPage {
             wrap: WrapMode::Unsigned,
             winding: Winding::Clockwise,
             width: 512.0,
+            fill_width: false,
             height: 48.0,
             spread: 180.0f32.to_radians(),
             axis_labels: vec![
@@ -56,8 +66,14 @@ impl Default for LinearCompassPage {
             max: None,
             animated: false,
             show_cursor: true,
+            show_reciprocal: false,
             show_ticks: true,
+            ticks: None,
             show_axes: true,
+            cluster_threshold: None,
+            track_on_marker_click: false,
+            clicked_marker: None,
+            relative_bearing_to: None,
             default_marker_color: DefaultCompassMarkerColor::HsvByAngle {
                 hue_phase: 0.0,
                 saturation: 1.0,
@@ -70,92 +86,118 @@ impl Default for LinearCompassPage {
 
 impl PageImpl for LinearCompassPage {
     fn ui(&mut self, ui: &mut Ui) {
-        ui.add(
-            LinearCompass::new(&mut self.value)
-                .interactive(self.interactive)
-                .wrap(self.wrap)
-                .winding(self.winding)
-                .width(self.width)
-                .height(self.height)
-                .spread(self.spread)
-                .snap(self.snap)
-                .axis_labels(CompassAxisLabels::from_slice(&self.axis_labels))
-                .shift_snap(self.shift_snap)
-                .min(self.min)
-                .max(self.max)
-                .animated(self.animated)
-                .show_cursor(self.show_cursor)
-                .show_ticks(self.show_ticks)
-                .show_axes(self.show_axes)
-                .default_marker_color(self.default_marker_color)
-                .default_marker_shape(self.default_marker_shape)
-                .markers(&[
-                    CompassMarker::new(0.0f32.to_radians()).label("Default"),
-                    // Grand Theft Auto style markers
-                    CompassMarker::new(70.0f32.to_radians())
-                        .shape(CompassMarkerShape::Square)
-                        .label("Sweet")
-                        .color(Color32::from_rgb(0x00, 0x00, 0xFF)),
-                    CompassMarker::new(85.0f32.to_radians())
-                        .shape(CompassMarkerShape::DownArrow)
-                        .label("Reece's")
-                        .color(Color32::from_rgb(0xFF, 0xFF, 0x00)),
-                    CompassMarker::new(100.0f32.to_radians())
-                        .shape(CompassMarkerShape::UpArrow)
-                        .label("Big Smoke")
-                        .color(Color32::from_rgb(0xFF, 0x00, 0x00)),
-                    // Emoji markers
-                    CompassMarker::new(553.0f32.to_radians())
-                        .shape(CompassMarkerShape::Emoji('🐱'))
-                        .label("Cat")
-                        .color(Color32::from_rgb(0xF8, 0xE9, 0xFF)),
-                    CompassMarker::new(563.0f32.to_radians())
-                        .shape(CompassMarkerShape::Emoji('🐶'))
-                        .label("Dog")
-                        .color(Color32::from_rgb(0xC0, 0x8C, 0x85)),
-                    // All marker shapes
-                    CompassMarker::new(240.0f32.to_radians())
-                        .shape(CompassMarkerShape::Square)
-                        .label("A"),
-                    CompassMarker::new(250.0f32.to_radians())
-                        .shape(CompassMarkerShape::Circle)
-                        .label("B"),
-                    CompassMarker::new(260.0f32.to_radians())
-                        .shape(CompassMarkerShape::RightArrow)
-                        .label("C"),
-                    CompassMarker::new(270.0f32.to_radians())
-                        .shape(CompassMarkerShape::UpArrow)
-                        .label("D"),
-                    CompassMarker::new(280.0f32.to_radians())
-                        .shape(CompassMarkerShape::LeftArrow)
-                        .label("E"),
-                    CompassMarker::new(290.0f32.to_radians())
-                        .shape(CompassMarkerShape::DownArrow)
-                        .label("F"),
-                    CompassMarker::new(300.0f32.to_radians())
-                        .shape(CompassMarkerShape::Diamond)
-                        .label("G"),
-                    CompassMarker::new(310.0f32.to_radians())
-                        .shape(CompassMarkerShape::Star(5, 0.5))
-                        .label("H"),
-                    CompassMarker::new(320.0f32.to_radians())
-                        .shape(CompassMarkerShape::Emoji('🗿'))
-                        .label("I"),
-                    // Transparent colors
-                    CompassMarker::new(30.0f32.to_radians())
-                        .shape(CompassMarkerShape::Square)
-                        .label("Near")
-                        .color(Color32::from_rgb(0x40, 0x80, 0x80).linear_multiply(1.0)),
-                    CompassMarker::new(40.0f32.to_radians())
-                        .shape(CompassMarkerShape::Square)
-                        .label("Far")
-                        .color(Color32::from_rgb(0x40, 0x80, 0x80).linear_multiply(0.5)),
-                    CompassMarker::new(50.0f32.to_radians())
-                        .shape(CompassMarkerShape::Square)
-                        .label("Very far")
-                        .color(Color32::from_rgb(0x40, 0x80, 0x80).linear_multiply(0.25)),
-                ]),
-        );
+        let mut linear_compass = LinearCompass::new(&mut self.value)
+            .interactive(self.interactive)
+            .wrap(self.wrap)
+            .winding(self.winding)
+            .width(self.width)
+            .fill_width(self.fill_width)
+            .height(self.height)
+            .spread(self.spread)
+            .snap(self.snap)
+            .axis_labels(CompassAxisLabels::from_slice(&self.axis_labels))
+            .shift_snap(self.shift_snap)
+            .min(self.min)
+            .max(self.max)
+            .animated(self.animated)
+            .show_cursor(self.show_cursor)
+            .show_reciprocal(self.show_reciprocal)
+            .show_ticks(self.show_ticks)
+            .show_axes(self.show_axes)
+            .cluster_threshold(self.cluster_threshold)
+            .selected_marker(self.clicked_marker)
+            .clicked_marker(&mut self.clicked_marker)
+            .track_on_marker_click(self.track_on_marker_click)
+            .relative_bearing_to(self.relative_bearing_to)
+            .default_marker_color(self.default_marker_color)
+            .default_marker_shape(self.default_marker_shape.clone())
+            .markers_from([
+                CompassMarker::new(0.0f32.to_radians()).label("Default"),
+                // Grand Theft Auto style markers
+                CompassMarker::new(70.0f32.to_radians())
+                    .shape(CompassMarkerShape::Square)
+                    .label("Sweet")
+                    .color(Color32::from_rgb(0x00, 0x00, 0xFF)),
+                CompassMarker::new(85.0f32.to_radians())
+                    .shape(CompassMarkerShape::RegularPolygon(3, TAU / 4.0))
+                    .label("Reece's")
+                    .color(Color32::from_rgb(0xFF, 0xFF, 0x00)),
+                CompassMarker::new(100.0f32.to_radians())
+                    .shape(CompassMarkerShape::RegularPolygon(3, -TAU / 4.0))
+                    .label("Big Smoke")
+                    .color(Color32::from_rgb(0xFF, 0x00, 0x00)),
+                // Emoji markers
+                CompassMarker::new(553.0f32.to_radians())
+                    .shape(CompassMarkerShape::Emoji('🐱'))
+                    .label("Cat")
+                    .color(Color32::from_rgb(0xF8, 0xE9, 0xFF)),
+                CompassMarker::new(563.0f32.to_radians())
+                    .shape(CompassMarkerShape::Emoji('🐶'))
+                    .label("Dog")
+                    .color(Color32::from_rgb(0xC0, 0x8C, 0x85)),
+                // All marker shapes
+                CompassMarker::new(240.0f32.to_radians())
+                    .shape(CompassMarkerShape::Square)
+                    .label("A"),
+                CompassMarker::new(250.0f32.to_radians())
+                    .shape(CompassMarkerShape::Circle)
+                    .label("B"),
+                CompassMarker::new(260.0f32.to_radians())
+                    .shape(CompassMarkerShape::RegularPolygon(3, 0.0))
+                    .label("C"),
+                CompassMarker::new(270.0f32.to_radians())
+                    .shape(CompassMarkerShape::RegularPolygon(3, -TAU / 4.0))
+                    .label("D"),
+                CompassMarker::new(280.0f32.to_radians())
+                    .shape(CompassMarkerShape::RegularPolygon(3, TAU / 2.0))
+                    .label("E"),
+                CompassMarker::new(290.0f32.to_radians())
+                    .shape(CompassMarkerShape::RegularPolygon(3, TAU / 4.0))
+                    .label("F"),
+                CompassMarker::new(300.0f32.to_radians())
+                    .shape(CompassMarkerShape::Diamond)
+                    .label("G"),
+                CompassMarker::new(310.0f32.to_radians())
+                    .shape(CompassMarkerShape::Star(5, 0.5))
+                    .label("H"),
+                CompassMarker::new(320.0f32.to_radians())
+                    .shape(CompassMarkerShape::Emoji('🗿'))
+                    .label("I"),
+                CompassMarker::new(330.0f32.to_radians())
+                    .shape(CompassMarkerShape::Cross(0.1))
+                    .label("J"),
+                CompassMarker::new(340.0f32.to_radians())
+                    .shape(CompassMarkerShape::Plus(0.1))
+                    .label("K"),
+                CompassMarker::new(350.0f32.to_radians())
+                    .shape(CompassMarkerShape::Pin)
+                    .label("L"),
+                CompassMarker::new(10.0f32.to_radians())
+                    .shape(CompassMarkerShape::Flag)
+                    .label("M"),
+                CompassMarker::new(20.0f32.to_radians())
+                    .shape(CompassMarkerShape::custom(WidgetShape::Star(6, 0.6)).unwrap())
+                    .label("N"),
+                // Transparent colors
+                CompassMarker::new(30.0f32.to_radians())
+                    .shape(CompassMarkerShape::Square)
+                    .label("Near")
+                    .color(Color32::from_rgb(0x40, 0x80, 0x80).linear_multiply(1.0)),
+                CompassMarker::new(40.0f32.to_radians())
+                    .shape(CompassMarkerShape::Square)
+                    .label("Far")
+                    .color(Color32::from_rgb(0x40, 0x80, 0x80).linear_multiply(0.5)),
+                CompassMarker::new(50.0f32.to_radians())
+                    .shape(CompassMarkerShape::Square)
+                    .label("Very far")
+                    .color(Color32::from_rgb(0x40, 0x80, 0x80).linear_multiply(0.25)),
+            ]);
+
+        if let Some((major_step, minor_step)) = self.ticks {
+            linear_compass = linear_compass.ticks(major_step, minor_step);
+        }
+
+        ui.add(linear_compass);
         ui.separator();
 
         Grid::new("linear_compass_properties")
@@ -187,6 +229,10 @@ impl PageImpl for LinearCompassPage {
                 ui.add(DragValue::new(&mut self.width));
                 ui.end_row();
 
+                ui.label("Fill width");
+                ui.checkbox(&mut self.fill_width, "");
+                ui.end_row();
+
                 ui.label("Height");
                 ui.add(DragValue::new(&mut self.height));
                 ui.end_row();
@@ -223,14 +269,46 @@ impl PageImpl for LinearCompassPage {
                 ui.checkbox(&mut self.show_cursor, "");
                 ui.end_row();
 
+                ui.label("Show reciprocal");
+                ui.checkbox(&mut self.show_reciprocal, "");
+                ui.end_row();
+
                 ui.label("Show ticks");
                 ui.checkbox(&mut self.show_ticks, "");
                 ui.end_row();
 
+                ui.label("Ticks");
+                ui.optional_value_widget(&mut self.ticks, |ui, (major_step, minor_step)| {
+                    ui.add(DragValue::new(major_step).prefix("Major: ").suffix("°"))
+                        | ui.add(DragValue::new(minor_step).prefix("Minor: ").suffix("°"))
+                });
+                ui.end_row();
+
                 ui.label("Show axes");
                 ui.checkbox(&mut self.show_axes, "");
                 ui.end_row();
 
+                ui.label("Cluster threshold");
+                ui.optional_value_widget(&mut self.cluster_threshold, Ui::drag_angle);
+                ui.end_row();
+
+                ui.label("Track on marker click");
+                ui.checkbox(&mut self.track_on_marker_click, "");
+                ui.end_row();
+
+                ui.label("Clicked marker");
+                ui.label(match self.clicked_marker {
+                    Some(marker_index) => marker_index.to_string(),
+                    None => "\u{2014}".to_owned(),
+                });
+                ui.end_row();
+
+                ui.label("Relative bearing to");
+                ui.optional_value_widget(&mut self.relative_bearing_to, |ui, marker_index| {
+                    ui.add(DragValue::new(marker_index))
+                });
+                ui.end_row();
+
                 ui.label("Default marker color");
                 default_compass_marker_color_ui(ui, &mut self.default_marker_color);
                 ui.end_row();
@@ -5,6 +5,9 @@ use strum::{Display, EnumIter, EnumProperty};
 
 // ----------------------------------------------------------------------------
 
+mod adsr_widget_page;
+use adsr_widget_page::AdsrWidgetPage;
+
 mod angle_knob_page;
 use angle_knob_page::AngleKnobPage;
 
@@ -14,42 +17,114 @@ use audio_knob_page::AudioKnobPage;
 mod barcode_page;
 use barcode_page::BarcodePage;
 
+mod binary_display_page;
+use binary_display_page::BinaryDisplayPage;
+
+mod breadcrumb_bar_page;
+use breadcrumb_bar_page::BreadcrumbBarPage;
+
+mod button_matrix_page;
+use button_matrix_page::ButtonMatrixPage;
+
+mod channel_fader_page;
+use channel_fader_page::ChannelFaderPage;
+
+mod crossfader_page;
+use crossfader_page::CrossFaderPage;
+
 mod datamatrix_page;
 use datamatrix_page::DataMatrixPage;
 
 mod directory_tree_view_page;
 use directory_tree_view_page::DirectoryTreeViewPage;
 
+mod dot_matrix_display_page;
+use dot_matrix_display_page::DotMatrixDisplayPage;
+
+mod drag_list_page;
+use drag_list_page::DragListPage;
+
+mod dual_knob_page;
+use dual_knob_page::DualKnobPage;
+
+mod gauge_widget_page;
+use gauge_widget_page::GaugeWidgetPage;
+
 mod hyperlink_with_icon_page;
 use hyperlink_with_icon_page::HyperlinkWithIconPage;
 
 mod indicator_button_page;
 use indicator_button_page::IndicatorButtonPage;
 
+mod knob_matrix_page;
+use knob_matrix_page::KnobMatrixPage;
+
 mod led_display_page;
 use led_display_page::LedDisplayPage;
 
+mod led_indicator_page;
+use led_indicator_page::LedIndicatorPage;
+
 mod linear_compass_page;
 use linear_compass_page::LinearCompassPage;
 
+mod numeric_stepper_page;
+use numeric_stepper_page::NumericStepperPage;
+
+mod oscilloscope_widget_page;
+use oscilloscope_widget_page::OscilloscopeWidgetPage;
+
+mod patchbay_widget_page;
+use patchbay_widget_page::PatchbayWidgetPage;
+
+mod piano_keyboard_page;
+use piano_keyboard_page::PianoKeyboardPage;
+
 mod polar_compass_page;
 use polar_compass_page::PolarCompassPage;
 
+mod progress_ring_page;
+use progress_ring_page::ProgressRingPage;
+
 mod qrcode_page;
 use qrcode_page::QrCodePage;
 
+mod rotary_switch_page;
+use rotary_switch_page::RotarySwitchPage;
+
 mod rotated_label_page;
 use rotated_label_page::RotatedLabelPage;
 
+mod searchable_combo_page;
+use searchable_combo_page::SearchableComboPage;
+
 mod segmented_display_page;
 use segmented_display_page::SegmentedDisplayPage;
 
+mod spectrum_analyzer_page;
+use spectrum_analyzer_page::SpectrumAnalyzerPage;
+
 mod standard_buttons_page;
 use standard_buttons_page::StandardButtonsPage;
 
+mod step_sequencer_page;
+use step_sequencer_page::StepSequencerPage;
+
+mod tab_bar_page;
+use tab_bar_page::TabBarPage;
+
 mod thumbstick_widget_page;
 use thumbstick_widget_page::ThumbstickWidgetPage;
 
+mod toggle_switch_page;
+use toggle_switch_page::ToggleSwitchPage;
+
+mod vu_meter_page;
+use vu_meter_page::VuMeterPage;
+
+mod xy_pad_page;
+use xy_pad_page::XyPadPage;
+
 mod welcome_page;
 use welcome_page::WelcomePage;
 
@@ -62,6 +137,10 @@ pub trait PageImpl {
 #[allow(clippy::enum_variant_names)]
 #[derive(Clone, Copy, Display, EnumIter, EnumProperty, Eq, Hash, PartialEq)]
 pub enum PageId {
+    #[strum(to_string = "AdsrWidget")]
+    #[strum(props(feature = "music"))]
+    AdsrWidgetPage,
+
     #[strum(to_string = "AngleKnob")]
     #[strum(props(feature = "knobs"))]
     AngleKnobPage,
@@ -74,6 +153,26 @@ pub enum PageId {
     #[strum(props(feature = "barcodes"))]
     BarcodePage,
 
+    #[strum(to_string = "BinaryDisplay")]
+    #[strum(props(feature = "displays"))]
+    BinaryDisplayPage,
+
+    #[strum(to_string = "BreadcrumbBar")]
+    #[strum(props(feature = "filesystem"))]
+    BreadcrumbBarPage,
+
+    #[strum(to_string = "ButtonMatrix")]
+    #[strum(props(feature = "music"))]
+    ButtonMatrixPage,
+
+    #[strum(to_string = "ChannelFader")]
+    #[strum(props(feature = "knobs"))]
+    ChannelFaderPage,
+
+    #[strum(to_string = "CrossFader")]
+    #[strum(props(feature = "knobs"))]
+    CrossFaderPage,
+
     #[strum(to_string = "DataMatrixWidget")]
     #[strum(props(feature = "barcodes"))]
     DataMatrixPage,
@@ -82,6 +181,22 @@ pub enum PageId {
     #[strum(props(feature = "filesystem"))]
     DirectoryTreeViewPage,
 
+    #[strum(to_string = "DotMatrixDisplayWidget")]
+    #[strum(props(feature = "displays"))]
+    DotMatrixDisplayPage,
+
+    #[strum(to_string = "DragAndDropList")]
+    #[strum(props(feature = "ui"))]
+    DragListPage,
+
+    #[strum(to_string = "DualKnob")]
+    #[strum(props(feature = "knobs"))]
+    DualKnobPage,
+
+    #[strum(to_string = "GaugeWidget")]
+    #[strum(props(feature = "meters"))]
+    GaugeWidgetPage,
+
     #[strum(to_string = "HyperlinkWithIcon")]
     #[strum(props(feature = "ui"))]
     HyperlinkWithIconPage,
@@ -90,38 +205,98 @@ pub enum PageId {
     #[strum(props(feature = "displays"))]
     IndicatorButtonPage,
 
+    #[strum(to_string = "KnobMatrix")]
+    #[strum(props(feature = "knobs"))]
+    KnobMatrixPage,
+
     #[strum(to_string = "LedDisplay")]
     #[strum(props(feature = "displays"))]
     LedDisplayPage,
 
+    #[strum(to_string = "LedIndicator")]
+    #[strum(props(feature = "indicators"))]
+    LedIndicatorPage,
+
     #[strum(to_string = "LinearCompass")]
     #[strum(props(feature = "compasses"))]
     LinearCompassPage,
 
+    #[strum(to_string = "NumericStepper")]
+    #[strum(props(feature = "ui"))]
+    NumericStepperPage,
+
+    #[strum(to_string = "OscilloscopeWidget")]
+    #[strum(props(feature = "displays"))]
+    OscilloscopeWidgetPage,
+
+    #[strum(to_string = "PatchbayWidget")]
+    #[strum(props(feature = "music"))]
+    PatchbayWidgetPage,
+
+    #[strum(to_string = "PianoKeyboard")]
+    #[strum(props(feature = "music"))]
+    PianoKeyboardPage,
+
     #[strum(to_string = "PolarCompass")]
     #[strum(props(feature = "compasses"))]
     PolarCompassPage,
 
+    #[strum(to_string = "ProgressRing")]
+    #[strum(props(feature = "indicators"))]
+    ProgressRingPage,
+
     #[strum(to_string = "QrCodeWidget")]
     #[strum(props(feature = "barcodes"))]
     QrCodePage,
 
+    #[strum(to_string = "RotarySwitch")]
+    #[strum(props(feature = "knobs"))]
+    RotarySwitchPage,
+
     #[strum(to_string = "RotatedLabel")]
     #[strum(props(feature = "ui"))]
     RotatedLabelPage,
 
+    #[strum(to_string = "SearchableCombobox")]
+    #[strum(props(feature = "ui"))]
+    SearchableComboPage,
+
     #[strum(to_string = "SegmentedDisplayWidget")]
     #[strum(props(feature = "displays"))]
     SegmentedDisplayPage,
 
+    #[strum(to_string = "SpectrumAnalyzer")]
+    #[strum(props(feature = "meters"))]
+    SpectrumAnalyzerPage,
+
     #[strum(to_string = "StandardButtons")]
     #[strum(props(feature = "ui"))]
     StandardButtonsPage,
 
+    #[strum(to_string = "StepSequencer")]
+    #[strum(props(feature = "music"))]
+    StepSequencerPage,
+
+    #[strum(to_string = "TabBar")]
+    #[strum(props(feature = "ui"))]
+    TabBarPage,
+
     #[strum(to_string = "ThumbstickWidget")]
     #[strum(props(feature = "knobs"))]
     ThumbstickWidgetPage,
 
+    #[strum(to_string = "ToggleSwitch")]
+    #[strum(props(feature = "ui"))]
+    ToggleSwitchPage,
+
+    #[strum(to_string = "VuMeter")]
+    #[strum(props(feature = "meters"))]
+    VuMeterPage,
+
+    #[strum(to_string = "XyPad")]
+    #[strum(props(feature = "knobs"))]
+    XyPadPage,
+
     #[strum(to_string = "Welcome")]
     WelcomePage,
 }
@@ -129,21 +304,46 @@ pub enum PageId {
 impl PageId {
     pub fn create_page(&self) -> Box<dyn PageImpl> {
         match *self {
+            PageId::AdsrWidgetPage => Box::<AdsrWidgetPage>::default(),
             PageId::AngleKnobPage => Box::<AngleKnobPage>::default(),
             PageId::AudioKnobPage => Box::<AudioKnobPage>::default(),
             PageId::BarcodePage => Box::<BarcodePage>::default(),
+            PageId::BinaryDisplayPage => Box::<BinaryDisplayPage>::default(),
+            PageId::BreadcrumbBarPage => Box::<BreadcrumbBarPage>::default(),
+            PageId::ButtonMatrixPage => Box::<ButtonMatrixPage>::default(),
+            PageId::ChannelFaderPage => Box::<ChannelFaderPage>::default(),
+            PageId::CrossFaderPage => Box::<CrossFaderPage>::default(),
             PageId::DataMatrixPage => Box::<DataMatrixPage>::default(),
             PageId::DirectoryTreeViewPage => Box::<DirectoryTreeViewPage>::default(),
+            PageId::DotMatrixDisplayPage => Box::<DotMatrixDisplayPage>::default(),
+            PageId::DragListPage => Box::<DragListPage>::default(),
+            PageId::DualKnobPage => Box::<DualKnobPage>::default(),
+            PageId::GaugeWidgetPage => Box::<GaugeWidgetPage>::default(),
             PageId::HyperlinkWithIconPage => Box::<HyperlinkWithIconPage>::default(),
             PageId::IndicatorButtonPage => Box::<IndicatorButtonPage>::default(),
+            PageId::KnobMatrixPage => Box::<KnobMatrixPage>::default(),
             PageId::LedDisplayPage => Box::<LedDisplayPage>::default(),
+            PageId::LedIndicatorPage => Box::<LedIndicatorPage>::default(),
             PageId::LinearCompassPage => Box::<LinearCompassPage>::default(),
+            PageId::NumericStepperPage => Box::<NumericStepperPage>::default(),
+            PageId::OscilloscopeWidgetPage => Box::<OscilloscopeWidgetPage>::default(),
+            PageId::PatchbayWidgetPage => Box::<PatchbayWidgetPage>::default(),
+            PageId::PianoKeyboardPage => Box::<PianoKeyboardPage>::default(),
             PageId::PolarCompassPage => Box::<PolarCompassPage>::default(),
+            PageId::ProgressRingPage => Box::<ProgressRingPage>::default(),
             PageId::QrCodePage => Box::<QrCodePage>::default(),
+            PageId::RotarySwitchPage => Box::<RotarySwitchPage>::default(),
             PageId::RotatedLabelPage => Box::<RotatedLabelPage>::default(),
+            PageId::SearchableComboPage => Box::<SearchableComboPage>::default(),
             PageId::SegmentedDisplayPage => Box::<SegmentedDisplayPage>::default(),
+            PageId::SpectrumAnalyzerPage => Box::<SpectrumAnalyzerPage>::default(),
             PageId::StandardButtonsPage => Box::<StandardButtonsPage>::default(),
+            PageId::StepSequencerPage => Box::<StepSequencerPage>::default(),
+            PageId::TabBarPage => Box::<TabBarPage>::default(),
             PageId::ThumbstickWidgetPage => Box::<ThumbstickWidgetPage>::default(),
+            PageId::ToggleSwitchPage => Box::<ToggleSwitchPage>::default(),
+            PageId::VuMeterPage => Box::<VuMeterPage>::default(),
+            PageId::XyPadPage => Box::<XyPadPage>::default(),
             PageId::WelcomePage => Box::<WelcomePage>::default(),
         }
     }
@@ -0,0 +1,90 @@
+use std::time::Duration;
+
+use eframe::egui::{DragValue, Grid, Ui};
+use egui_extras_xt::ui::numeric_stepper::NumericStepper;
+
+use crate::pages::PageImpl;
+
+pub struct NumericStepperPage {
+    value: i32,
+    step: i32,
+    min: i32,
+    max: i32,
+    unit: String,
+    repeat_delay: u64,
+    repeat_rate: u64,
+    repeat_acceleration: f32,
+}
+
+impl Default for NumericStepperPage {
+    fn default() -> NumericStepperPage {
+        NumericStepperPage {
+            value: 440,
+            step: 1,
+            min: 20,
+            max: 20000,
+            unit: "Hz".to_owned(),
+            repeat_delay: 500,
+            repeat_rate: 100,
+            repeat_acceleration: 1.0,
+        }
+    }
+}
+
+impl PageImpl for NumericStepperPage {
+    fn ui(&mut self, ui: &mut Ui) {
+        ui.add(
+            NumericStepper::new(&mut self.value)
+                .step(self.step)
+                .min(self.min)
+                .max(self.max)
+                .format(|value| format!("{value} {}", self.unit))
+                .repeat_delay(Duration::from_millis(self.repeat_delay))
+                .repeat_rate(Duration::from_millis(self.repeat_rate))
+                .repeat_acceleration(self.repeat_acceleration),
+        );
+        ui.separator();
+
+        Grid::new("numeric_stepper_properties")
+            .num_columns(2)
+            .spacing([20.0, 10.0])
+            .striped(true)
+            .show(ui, |ui| {
+                ui.label("Value");
+                ui.add(DragValue::new(&mut self.value));
+                ui.end_row();
+
+                ui.label("Step");
+                ui.add(DragValue::new(&mut self.step));
+                ui.end_row();
+
+                ui.label("Minimum");
+                ui.add(DragValue::new(&mut self.min));
+                ui.end_row();
+
+                ui.label("Maximum");
+                ui.add(DragValue::new(&mut self.max));
+                ui.end_row();
+
+                ui.label("Unit");
+                ui.text_edit_singleline(&mut self.unit);
+                ui.end_row();
+
+                ui.label("Repeat delay");
+                ui.add(DragValue::new(&mut self.repeat_delay).suffix(" ms"));
+                ui.end_row();
+
+                ui.label("Repeat rate");
+                ui.add(DragValue::new(&mut self.repeat_rate).suffix(" ms"));
+                ui.end_row();
+
+                ui.label("Repeat acceleration");
+                ui.add(
+                    DragValue::new(&mut self.repeat_acceleration)
+                        .speed(0.01)
+                        .range(1.0..=2.0),
+                );
+                ui.end_row();
+            });
+    }
+}
@@ -0,0 +1,146 @@
+use std::f32::consts::TAU;
+
+use eframe::egui::{Color32, DragValue, Grid, Slider, Ui};
+use egui_extras_xt::displays::{OscilloscopeMode, OscilloscopeWidget};
+use itertools::Itertools;
+
+use crate::pages::PageImpl;
+
+const SAMPLE_COUNT: usize = 512;
+
+pub struct OscilloscopeWidgetPage {
+    xy_mode: bool,
+    frequency_x: f32,
+    frequency_y: f32,
+    drift: f32,
+    width: f32,
+    height: f32,
+    trace_width: f32,
+    trace_color: Color32,
+    background_color: Color32,
+    show_grid: bool,
+    grid_divisions: (usize, usize),
+    persistence: f32,
+}
+
+impl Default for OscilloscopeWidgetPage {
+    fn default() -> OscilloscopeWidgetPage {
+        OscilloscopeWidgetPage {
+            xy_mode: true,
+            frequency_x: 3.0,
+            frequency_y: 2.0,
+            drift: 0.25,
+            width: 256.0,
+            height: 192.0,
+            trace_width: 1.5,
+            trace_color: Color32::from_rgb(0x33, 0xFF, 0x66),
+            background_color: Color32::from_rgb(0x0A, 0x12, 0x0C),
+            show_grid: true,
+            grid_divisions: (10, 8),
+            persistence: 0.15,
+        }
+    }
+}
+
+impl PageImpl for OscilloscopeWidgetPage {
+    fn ui(&mut self, ui: &mut Ui) {
+        let phase = ui.input(|input| input.time) as f32 * self.drift * TAU;
+        ui.ctx().request_repaint();
+
+        let signal = |frequency: f32, phase: f32| {
+            (0..SAMPLE_COUNT)
+                .map(|index| {
+                    let t = index as f32 / (SAMPLE_COUNT - 1) as f32;
+                    (t * frequency * TAU + phase).sin() * 0.9
+                })
+                .collect_vec()
+        };
+
+        let x_samples = signal(self.frequency_x, 0.0);
+        let y_samples = signal(self.frequency_y, phase);
+
+        let mode = if self.xy_mode {
+            OscilloscopeMode::XY(&x_samples, &y_samples)
+        } else {
+            OscilloscopeMode::Waveform(&y_samples)
+        };
+
+        ui.add(
+            OscilloscopeWidget::new(mode)
+                .width(self.width)
+                .height(self.height)
+                .trace_stroke((self.trace_width, self.trace_color))
+                .background_color(self.background_color)
+                .show_grid(self.show_grid)
+                .grid_divisions(self.grid_divisions.0, self.grid_divisions.1)
+                .persistence(self.persistence),
+        );
+        ui.separator();
+
+        Grid::new("oscilloscope_properties")
+            .num_columns(2)
+            .spacing([20.0, 10.0])
+            .striped(true)
+            .show(ui, |ui| {
+                ui.label("Mode");
+                ui.horizontal(|ui| {
+                    ui.selectable_value(&mut self.xy_mode, false, "Waveform");
+                    ui.selectable_value(&mut self.xy_mode, true, "XY");
+                });
+                ui.end_row();
+
+                ui.label("Frequencies");
+                ui.horizontal(|ui| {
+                    ui.add(
+                        DragValue::new(&mut self.frequency_x)
+                            .speed(0.1)
+                            .prefix("X: "),
+                    );
+                    ui.add(
+                        DragValue::new(&mut self.frequency_y)
+                            .speed(0.1)
+                            .prefix("Y: "),
+                    );
+                });
+                ui.end_row();
+
+                ui.label("Phase drift");
+                ui.add(Slider::new(&mut self.drift, -1.0..=1.0));
+                ui.end_row();
+
+                ui.label("Width");
+                ui.add(DragValue::new(&mut self.width));
+                ui.end_row();
+
+                ui.label("Height");
+                ui.add(DragValue::new(&mut self.height));
+                ui.end_row();
+
+                ui.label("Trace stroke");
+                ui.horizontal(|ui| {
+                    ui.add(DragValue::new(&mut self.trace_width).speed(0.1));
+                    ui.color_edit_button_srgba(&mut self.trace_color);
+                });
+                ui.end_row();
+
+                ui.label("Background color");
+                ui.color_edit_button_srgba(&mut self.background_color);
+                ui.end_row();
+
+                ui.label("Show grid");
+                ui.checkbox(&mut self.show_grid, "");
+                ui.end_row();
+
+                ui.label("Grid divisions");
+                ui.horizontal(|ui| {
+                    ui.add(DragValue::new(&mut self.grid_divisions.0).range(1..=20));
+                    ui.add(DragValue::new(&mut self.grid_divisions.1).range(1..=20));
+                });
+                ui.end_row();
+
+                ui.label("Persistence");
+                ui.add(Slider::new(&mut self.persistence, 0.0..=1.0).suffix(" s"));
+                ui.end_row();
+            });
+    }
+}
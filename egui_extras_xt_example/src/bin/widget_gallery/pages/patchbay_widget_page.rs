@@ -0,0 +1,82 @@
+use eframe::egui::{Color32, DragValue, Grid, Ui};
+use egui_extras_xt::music::PatchbayWidget;
+
+use crate::pages::PageImpl;
+
+const SOURCE_LABELS: &[&str] = &["Keyboard", "Drum pads", "Sequencer", "Arpeggiator"];
+const DESTINATION_LABELS: &[&str] = &["Synth", "Sampler", "Drum machine", "Recorder", "Monitor"];
+
+pub struct PatchbayWidgetPage {
+    connections: Vec<Vec<bool>>,
+    show_labels: bool,
+    exclusive_columns: bool,
+    junction_spacing: f32,
+    junction_radius: f32,
+    connected_color: Color32,
+}
+
+impl Default for PatchbayWidgetPage {
+    fn default() -> PatchbayWidgetPage {
+        PatchbayWidgetPage {
+            connections: (0..SOURCE_LABELS.len())
+                .map(|row| {
+                    (0..DESTINATION_LABELS.len())
+                        .map(|column| row == column)
+                        .collect()
+                })
+                .collect(),
+            show_labels: true,
+            exclusive_columns: false,
+            junction_spacing: 24.0,
+            junction_radius: 6.0,
+            connected_color: Color32::from_rgb(0xE6, 0x8C, 0x28),
+        }
+    }
+}
+
+impl PageImpl for PatchbayWidgetPage {
+    fn ui(&mut self, ui: &mut Ui) {
+        let (source_labels, destination_labels) = if self.show_labels {
+            (SOURCE_LABELS, DESTINATION_LABELS)
+        } else {
+            (&[] as &[&str], &[] as &[&str])
+        };
+
+        ui.add(
+            PatchbayWidget::new(&mut self.connections)
+                .row_labels(source_labels)
+                .column_labels(destination_labels)
+                .exclusive_columns(self.exclusive_columns)
+                .junction_spacing(self.junction_spacing)
+                .junction_radius(self.junction_radius)
+                .connected_color(self.connected_color),
+        );
+        ui.separator();
+
+        Grid::new("patchbay_widget_properties")
+            .num_columns(2)
+            .spacing([20.0, 10.0])
+            .striped(true)
+            .show(ui, |ui| {
+                ui.label("Show labels");
+                ui.checkbox(&mut self.show_labels, "");
+                ui.end_row();
+
+                ui.label("Exclusive columns");
+                ui.checkbox(&mut self.exclusive_columns, "");
+                ui.end_row();
+
+                ui.label("Junction spacing");
+                ui.add(DragValue::new(&mut self.junction_spacing));
+                ui.end_row();
+
+                ui.label("Junction radius");
+                ui.add(DragValue::new(&mut self.junction_radius));
+                ui.end_row();
+
+                ui.label("Connected color");
+                ui.color_edit_button_srgba(&mut self.connected_color);
+                ui.end_row();
+            });
+    }
+}
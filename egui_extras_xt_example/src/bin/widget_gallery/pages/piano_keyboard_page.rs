@@ -0,0 +1,108 @@
+use std::ops::RangeInclusive;
+
+use eframe::egui::{Color32, DragValue, Grid, Ui};
+use egui_extras_xt::music::PianoKeyboard;
+use egui_extras_xt::ui::standard_buttons::StandardButtons;
+
+use crate::pages::PageImpl;
+
+pub struct PianoKeyboardPage {
+    note_range: RangeInclusive<u8>,
+    active_notes: Vec<u8>,
+    white_key_width: f32,
+    white_key_height: f32,
+    white_key_color: Color32,
+    black_key_color: Color32,
+    active_color: Color32,
+    show_note_names: bool,
+}
+
+impl Default for PianoKeyboardPage {
+    fn default() -> PianoKeyboardPage {
+        PianoKeyboardPage {
+            note_range: 48..=72,
+            active_notes: vec![60, 64, 67],
+            white_key_width: 24.0,
+            white_key_height: 96.0,
+            white_key_color: Color32::from_gray(0xF0),
+            black_key_color: Color32::from_gray(0x20),
+            active_color: Color32::from_rgb(0x3C, 0x8C, 0xE6),
+            show_note_names: true,
+        }
+    }
+}
+
+impl PageImpl for PianoKeyboardPage {
+    fn ui(&mut self, ui: &mut Ui) {
+        let mut clicked_note = None;
+
+        ui.add(
+            PianoKeyboard::new(self.note_range.clone())
+                .active_notes(&self.active_notes)
+                .on_note_click(|note| clicked_note = Some(note))
+                .white_key_size([self.white_key_width, self.white_key_height])
+                .white_key_color(self.white_key_color)
+                .black_key_color(self.black_key_color)
+                .active_color(self.active_color)
+                .show_note_names(self.show_note_names),
+        );
+
+        // Clicking a key toggles its note
+        if let Some(note) = clicked_note {
+            if let Some(index) = self.active_notes.iter().position(|&n| n == note) {
+                self.active_notes.remove(index);
+            } else {
+                self.active_notes.push(note);
+            }
+        }
+
+        ui.separator();
+
+        Grid::new("piano_keyboard_properties")
+            .num_columns(2)
+            .spacing([20.0, 10.0])
+            .striped(true)
+            .show(ui, |ui| {
+                ui.label("Note range");
+                ui.horizontal(|ui| {
+                    let (mut start, mut end) = (*self.note_range.start(), *self.note_range.end());
+                    ui.add(DragValue::new(&mut start).range(0..=127));
+                    ui.add(DragValue::new(&mut end).range(0..=127));
+                    self.note_range = start..=end.max(start);
+                });
+                ui.end_row();
+
+                ui.label("Active notes");
+                ui.horizontal(|ui| {
+                    ui.label(format!("{:?}", self.active_notes));
+                    if ui.reset_button().clicked() {
+                        self.active_notes.clear();
+                    }
+                });
+                ui.end_row();
+
+                ui.label("White key size");
+                ui.horizontal(|ui| {
+                    ui.add(DragValue::new(&mut self.white_key_width));
+                    ui.add(DragValue::new(&mut self.white_key_height));
+                });
+                ui.end_row();
+
+                ui.label("White key color");
+                ui.color_edit_button_srgba(&mut self.white_key_color);
+                ui.end_row();
+
+                ui.label("Black key color");
+                ui.color_edit_button_srgba(&mut self.black_key_color);
+                ui.end_row();
+
+                ui.label("Active color");
+                ui.color_edit_button_srgba(&mut self.active_color);
+                ui.end_row();
+
+                ui.label("Show note names");
+                ui.checkbox(&mut self.show_note_names, "");
+                ui.end_row();
+            });
+    }
+}
@@ -1,3 +1,5 @@
+use std::f32::consts::TAU;
+
 use eframe::egui::{DragValue, Grid, Ui};
 use eframe::epaint::Color32;
 use egui_extras_xt::common::{Orientation, Winding, WrapMode};
@@ -38,6 +40,7 @@ pub struct PolarCompassPage {
     show_axes: bool,
     show_rings: bool,
     show_cursor: bool,
+    show_reciprocal: bool,
     show_marker_labels: bool,
     show_marker_lines: bool,
     default_marker_color: DefaultCompassMarkerColor,
@@ -74,6 +77,7 @@ impl Default for PolarCompassPage {
             show_axes: true,
             show_rings: true,
             show_cursor: true,
+            show_reciprocal: false,
             show_marker_labels: true,
             show_marker_lines: true,
             default_marker_color: DefaultCompassMarkerColor::HsvByAngle {
@@ -111,10 +115,11 @@ impl PageImpl for PolarCompassPage {
                 .show_axes(self.show_axes)
                 .show_rings(self.show_rings)
                 .show_cursor(self.show_cursor)
+                .show_reciprocal(self.show_reciprocal)
                 .show_marker_labels(self.show_marker_labels)
                 .show_marker_lines(self.show_marker_lines)
                 .default_marker_color(self.default_marker_color)
-                .default_marker_shape(self.default_marker_shape)
+                .default_marker_shape(self.default_marker_shape.clone())
                 .markers(&[
                     CompassMarker::new(0.0f32.to_radians())
                         .distance(10.0)
@@ -124,7 +129,7 @@ impl PageImpl for PolarCompassPage {
                     CompassMarker::new(15.0f32.to_radians())
                         .distance(100.0)
                         .color(Color32::from_rgb(0x9C, 0xCF, 0xEE))
-                        .shape(CompassMarkerShape::DownArrow)
+                        .shape(CompassMarkerShape::RegularPolygon(3, TAU / 4.0))
                         .label("Mitsuhiko"),
                     CompassMarker::new(30.0f32.to_radians())
                         .distance(1000.0)
@@ -134,7 +139,7 @@ impl PageImpl for PolarCompassPage {
                     CompassMarker::new(45.0f32.to_radians())
                         .distance(10000.0)
                         .color(Color32::from_rgb(0xEF, 0xBB, 0xC4))
-                        .shape(CompassMarkerShape::UpArrow)
+                        .shape(CompassMarkerShape::RegularPolygon(3, -TAU / 4.0))
                         .label("Ayumi"),
                     // Markers with unset colors and shapes
                     CompassMarker::new(135.0f32.to_radians())
@@ -270,6 +275,10 @@ impl PageImpl for PolarCompassPage {
                 ui.checkbox(&mut self.show_cursor, "");
                 ui.end_row();
 
+                ui.label("Show reciprocal");
+                ui.checkbox(&mut self.show_reciprocal, "");
+                ui.end_row();
+
                 ui.label("Show marker labels");
                 ui.checkbox(&mut self.show_marker_labels, "");
                 ui.end_row();
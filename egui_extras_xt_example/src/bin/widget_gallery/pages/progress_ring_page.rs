@@ -0,0 +1,99 @@
+use eframe::egui::{Color32, DragValue, Grid, Slider, Ui};
+use eframe::epaint::Hsva;
+use egui_extras_xt::common::{Orientation, WidgetShape, Winding};
+use egui_extras_xt::indicators::ProgressRing;
+use egui_extras_xt::ui::widgets_from_iter::SelectableValueFromIter;
+use strum::IntoEnumIterator;
+
+use crate::pages::ui::{widget_orientation_ui, widget_shape_ui};
+use crate::pages::PageImpl;
+
+pub struct ProgressRingPage {
+    progress: f32,
+    diameter: f32,
+    thickness: f32,
+    orientation: Orientation,
+    winding: Winding,
+    shape: WidgetShape,
+    rainbow: bool,
+    indeterminate: bool,
+}
+
+impl Default for ProgressRingPage {
+    fn default() -> ProgressRingPage {
+        ProgressRingPage {
+            progress: 0.66,
+            diameter: 64.0,
+            thickness: 0.25,
+            orientation: Orientation::Top,
+            winding: Winding::Clockwise,
+            shape: WidgetShape::Circle,
+            rainbow: false,
+            indeterminate: false,
+        }
+    }
+}
+
+impl PageImpl for ProgressRingPage {
+    fn ui(&mut self, ui: &mut Ui) {
+        let mut progress_ring = ProgressRing::new(self.progress)
+            .diameter(self.diameter)
+            .thickness(self.thickness)
+            .orientation(self.orientation)
+            .winding(self.winding)
+            .shape(self.shape.clone())
+            .indeterminate(self.indeterminate);
+
+        if self.rainbow {
+            progress_ring =
+                progress_ring.gradient(|t| Color32::from(Hsva::new(t * 0.8, 0.8, 0.9, 1.0)));
+        }
+
+        ui.add(progress_ring);
+        ui.separator();
+
+        Grid::new("progress_ring_properties")
+            .num_columns(2)
+            .spacing([20.0, 10.0])
+            .striped(true)
+            .show(ui, |ui| {
+                ui.label("Progress");
+                ui.add(Slider::new(&mut self.progress, 0.0..=1.0));
+                ui.end_row();
+
+                ui.label("Diameter");
+                ui.add(DragValue::new(&mut self.diameter));
+                ui.end_row();
+
+                ui.label("Thickness");
+                ui.add(
+                    DragValue::new(&mut self.thickness)
+                        .speed(0.01)
+                        .range(0.0..=1.0),
+                );
+                ui.end_row();
+
+                ui.label("Orientation");
+                widget_orientation_ui(ui, &mut self.orientation);
+                ui.end_row();
+
+                ui.label("Winding");
+                ui.horizontal(|ui| {
+                    ui.selectable_value_from_iter(&mut self.winding, Winding::iter());
+                });
+                ui.end_row();
+
+                ui.label("Shape");
+                widget_shape_ui(ui, &mut self.shape);
+                ui.end_row();
+
+                ui.label("Rainbow gradient");
+                ui.checkbox(&mut self.rainbow, "");
+                ui.end_row();
+
+                ui.label("Indeterminate");
+                ui.checkbox(&mut self.indeterminate, "");
+                ui.end_row();
+            });
+    }
+}
@@ -0,0 +1,110 @@
+use eframe::egui::{DragValue, Grid, Ui};
+use egui_extras_xt::common::Orientation;
+use egui_extras_xt::knobs::RotarySwitch;
+use egui_extras_xt::ui::widgets_from_iter::SelectableValueFromIter;
+use strum::{Display, EnumIter, IntoEnumIterator};
+
+use crate::pages::ui::widget_orientation_ui;
+use crate::pages::PageImpl;
+
+#[derive(Clone, Copy, Display, EnumIter, PartialEq)]
+enum MeterMode {
+    #[strum(to_string = "Off")]
+    Off,
+
+    #[strum(to_string = "DC voltage")]
+    DcVoltage,
+
+    #[strum(to_string = "AC voltage")]
+    AcVoltage,
+
+    #[strum(to_string = "DC current")]
+    DcCurrent,
+
+    #[strum(to_string = "Resistance")]
+    Resistance,
+
+    #[strum(to_string = "Continuity")]
+    Continuity,
+}
+
+pub struct RotarySwitchPage {
+    value: MeterMode,
+    interactive: bool,
+    diameter: f32,
+    orientation: Orientation,
+    sweep: f32,
+    change_count: usize,
+}
+
+impl Default for RotarySwitchPage {
+    fn default() -> RotarySwitchPage {
+        RotarySwitchPage {
+            value: MeterMode::Off,
+            interactive: true,
+            diameter: 96.0,
+            orientation: Orientation::Top,
+            sweep: 270.0f32.to_radians(),
+            change_count: 0,
+        }
+    }
+}
+
+impl PageImpl for RotarySwitchPage {
+    fn ui(&mut self, ui: &mut Ui) {
+        let response = ui.add(
+            RotarySwitch::new(
+                &mut self.value,
+                vec![
+                    (MeterMode::Off, "OFF"),
+                    (MeterMode::DcVoltage, "V="),
+                    (MeterMode::AcVoltage, "V~"),
+                    (MeterMode::DcCurrent, "A="),
+                    (MeterMode::Resistance, "\u{3A9}"),
+                    (MeterMode::Continuity, "\u{1F50A}"),
+                ],
+            )
+            .interactive(self.interactive)
+            .diameter(self.diameter)
+            .orientation(self.orientation)
+            .sweep(self.sweep),
+        );
+
+        if response.changed() {
+            self.change_count += 1;
+        }
+        ui.separator();
+
+        Grid::new("rotary_switch_properties")
+            .num_columns(2)
+            .spacing([20.0, 10.0])
+            .striped(true)
+            .show(ui, |ui| {
+                ui.label("Value");
+                ui.horizontal(|ui| {
+                    ui.selectable_value_from_iter(&mut self.value, MeterMode::iter());
+                });
+                ui.end_row();
+
+                ui.label("Changes");
+                ui.label(self.change_count.to_string());
+                ui.end_row();
+
+                ui.label("Interactive");
+                ui.checkbox(&mut self.interactive, "");
+                ui.end_row();
+
+                ui.label("Diameter");
+                ui.add(DragValue::new(&mut self.diameter));
+                ui.end_row();
+
+                ui.label("Orientation");
+                widget_orientation_ui(ui, &mut self.orientation);
+                ui.end_row();
+
+                ui.label("Sweep");
+                ui.drag_angle(&mut self.sweep);
+                ui.end_row();
+            });
+    }
+}
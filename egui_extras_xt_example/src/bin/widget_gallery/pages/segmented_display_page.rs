@@ -1,25 +1,57 @@
-use eframe::egui::{DragValue, Grid, Ui};
-use egui_extras_xt::displays::segmented_display::DisplayMetricsPreset;
+use std::ops::RangeInclusive;
+
+use eframe::egui::{Align, DragValue, Grid, Slider, TextEdit, Ui};
+use egui_extras_xt::displays::segmented_display::{
+    DigitTransition, DisplayFont, DisplayMetricsPreset, FillDirection, MarqueeMode,
+    SegmentCapStyle, UnknownCharPolicy,
+};
 use egui_extras_xt::displays::{
-    DisplayKind, DisplayMetrics, DisplayStyle, DisplayStylePreset, SegmentedDisplayWidget,
+    DisplayGlow, DisplayKind, DisplayMetrics, DisplayStyle, DisplayStylePreset,
+    SegmentedDisplayWidget,
 };
+use egui_extras_xt::ui::drag_rangeinclusive::DragRangeInclusive;
+use egui_extras_xt::ui::optional_value_widget::OptionalValueWidget;
 use egui_extras_xt::ui::widgets_from_iter::SelectableValueFromIter;
 use strum::IntoEnumIterator;
 
-use crate::pages::ui::{display_metrics_ui, display_style_ui};
+use crate::pages::ui::{
+    digit_transition_ui, display_metrics_ui, display_style_ui, marquee_mode_ui,
+    unknown_char_policy_ui,
+};
 use crate::pages::PageImpl;
 
 pub struct SegmentedDisplayPage {
     value: String,
     display_kind: DisplayKind,
-    digit_height: f32,
+    digit_height: Option<f32>,
+    auto_size: bool,
+    auto_size_range: RangeInclusive<f32>,
     metrics: DisplayMetrics,
     metrics_preset: DisplayMetricsPreset,
     style: DisplayStyle,
     style_preset: DisplayStylePreset,
+    glow: Option<DisplayGlow>,
     show_dots: bool,
     show_colons: bool,
     show_apostrophes: bool,
+    blinking: bool,
+    blink_colons: bool,
+    blink_period: f32,
+    blink_duty_cycle: f32,
+    brightness: f32,
+    dim_background: bool,
+    persistence: f32,
+    transition: DigitTransition,
+    digit_count: Option<usize>,
+    marquee: MarqueeMode,
+    marquee_pause_on_hover: bool,
+    align: Align,
+    fill_direction: FillDirection,
+    pad_with: char,
+    highlighted_digit: Option<usize>,
+    overflow_pattern: Option<char>,
+    unknown_char: UnknownCharPolicy,
+    uppercase_input: bool,
 }
 
 impl Default for SegmentedDisplayPage {
@@ -27,30 +59,98 @@ impl Default for SegmentedDisplayPage {
         SegmentedDisplayPage {
             value: "EGUI_EXTRAS_XT".to_owned(),
             display_kind: DisplayKind::SixteenSegment,
-            digit_height: 80.0,
+            digit_height: Some(80.0),
+            auto_size: false,
+            auto_size_range: 20.0..=160.0,
             metrics: DisplayMetricsPreset::Default.metrics(),
             metrics_preset: DisplayMetricsPreset::Default,
             style: DisplayStylePreset::Default.style(),
             style_preset: DisplayStylePreset::Default,
+            glow: DisplayStylePreset::Default.glow(),
             show_dots: true,
             show_colons: true,
             show_apostrophes: true,
+            blinking: false,
+            blink_colons: false,
+            blink_period: 1.0,
+            blink_duty_cycle: 0.5,
+            brightness: 1.0,
+            dim_background: false,
+            persistence: 0.0,
+            transition: DigitTransition::None,
+            digit_count: None,
+            marquee: MarqueeMode::Off,
+            marquee_pause_on_hover: true,
+            align: Align::Min,
+            fill_direction: FillDirection::LeftToRight,
+            pad_with: ' ',
+            highlighted_digit: None,
+            overflow_pattern: None,
+            unknown_char: UnknownCharPolicy::Skip,
+            uppercase_input: false,
         }
     }
 }
 
 impl PageImpl for SegmentedDisplayPage {
     fn ui(&mut self, ui: &mut Ui) {
-        ui.add(
-            SegmentedDisplayWidget::new(self.display_kind)
-                .digit_height(self.digit_height)
-                .metrics(self.metrics)
-                .style(self.style)
-                .show_dots(self.show_dots)
-                .show_colons(self.show_colons)
-                .show_apostrophes(self.show_apostrophes)
-                .push_string(&self.value),
-        );
+        let mut segmented_display = SegmentedDisplayWidget::new(self.display_kind)
+            .auto_size(self.auto_size)
+            .auto_size_range(self.auto_size_range.clone())
+            .metrics(self.metrics)
+            .style(self.style)
+            .glow(self.glow)
+            .show_dots(self.show_dots)
+            .show_colons(self.show_colons)
+            .show_apostrophes(self.show_apostrophes)
+            .blinking(self.blinking)
+            .blink_colons(self.blink_colons)
+            .blink_period(self.blink_period)
+            .blink_duty_cycle(self.blink_duty_cycle)
+            .brightness(self.brightness)
+            .dim_background(self.dim_background)
+            .persistence(self.persistence)
+            .transition(self.transition)
+            .digit_count(self.digit_count)
+            .marquee(self.marquee)
+            .marquee_pause_on_hover(self.marquee_pause_on_hover)
+            .align(self.align)
+            .fill_direction(self.fill_direction)
+            .pad_with(self.pad_with)
+            .overflow_pattern(self.overflow_pattern)
+            .unknown_char(self.unknown_char)
+            .uppercase_input(self.uppercase_input)
+            .push_string(&self.value);
+
+        if let Some(digit_height) = self.digit_height {
+            segmented_display = segmented_display.digit_height(digit_height);
+        }
+
+        if let Some(highlighted_digit) = self.highlighted_digit {
+            segmented_display = segmented_display
+                .digit_style(highlighted_digit, DisplayStylePreset::KnightRider.style());
+        }
+
+        let measured_size = segmented_display.measure(ui);
+        let allocated_size = ui.add(segmented_display).rect.size();
+        ui.separator();
+
+        // Cap styles side by side, with the current metrics otherwise
+        ui.horizontal(|ui| {
+            for segment_cap_style in SegmentCapStyle::iter() {
+                ui.vertical(|ui| {
+                    ui.label(segment_cap_style.to_string());
+                    ui.add(
+                        SegmentedDisplayWidget::new(self.display_kind)
+                            .metrics(self.metrics)
+                            .segment_cap_style(segment_cap_style)
+                            .style(self.style)
+                            .digit_height(48.0)
+                            .push_string("AB8"),
+                    );
+                });
+            }
+        });
         ui.separator();
 
         Grid::new("segmented_display_properties")
@@ -62,6 +162,26 @@ impl PageImpl for SegmentedDisplayPage {
                 ui.text_edit_singleline(&mut self.value);
                 ui.end_row();
 
+                ui.label("Unsupported characters");
+                {
+                    let font = DisplayFont::builtin(self.display_kind);
+                    let unsupported = self
+                        .value
+                        .chars()
+                        .filter(|&c| !font.supports(c) && !matches!(c, '.' | ',' | ':' | '\''))
+                        .collect::<String>();
+                    ui.label(format!("{unsupported:?}"));
+                }
+                ui.end_row();
+
+                ui.label("Unknown characters");
+                unknown_char_policy_ui(ui, &mut self.unknown_char);
+                ui.end_row();
+
+                ui.label("Uppercase input");
+                ui.checkbox(&mut self.uppercase_input, "");
+                ui.end_row();
+
                 ui.label("Display kind");
                 ui.horizontal(|ui| {
                     ui.selectable_value_from_iter(&mut self.display_kind, DisplayKind::iter());
@@ -69,7 +189,16 @@ impl PageImpl for SegmentedDisplayPage {
                 ui.end_row();
 
                 ui.label("Digit height");
-                ui.add(DragValue::new(&mut self.digit_height));
+                ui.optional_value_widget(&mut self.digit_height, |ui, value| {
+                    ui.add(DragValue::new(value))
+                });
+                ui.end_row();
+
+                ui.label("Auto size");
+                ui.horizontal(|ui| {
+                    ui.checkbox(&mut self.auto_size, "");
+                    ui.drag_rangeinclusive(&mut self.auto_size_range);
+                });
                 ui.end_row();
 
                 ui.label("Metrics");
@@ -77,7 +206,22 @@ impl PageImpl for SegmentedDisplayPage {
                 ui.end_row();
 
                 ui.label("Style");
+                let previous_style_preset = self.style_preset;
                 display_style_ui(ui, &mut self.style, &mut self.style_preset);
+                if self.style_preset != previous_style_preset {
+                    self.glow = self.style_preset.glow();
+                }
+                ui.end_row();
+
+                ui.label("Glow");
+                ui.optional_value_widget(&mut self.glow, |ui, glow| {
+                    ui.add(DragValue::new(&mut glow.width).speed(0.01).range(0.0..=2.0))
+                        | ui.add(
+                            DragValue::new(&mut glow.opacity)
+                                .speed(0.01)
+                                .range(0.0..=1.0),
+                        )
+                });
                 ui.end_row();
 
                 ui.label("Show dots");
@@ -91,6 +235,101 @@ impl PageImpl for SegmentedDisplayPage {
                 ui.label("Show apostrophes");
                 ui.checkbox(&mut self.show_apostrophes, "");
                 ui.end_row();
+
+                ui.label("Digit count");
+                ui.optional_value_widget(&mut self.digit_count, |ui, value| {
+                    ui.add(DragValue::new(value))
+                });
+                ui.end_row();
+
+                ui.label("Alignment");
+                ui.horizontal(|ui| {
+                    ui.selectable_value(&mut self.align, Align::Min, "Left");
+                    ui.selectable_value(&mut self.align, Align::Center, "Center");
+                    ui.selectable_value(&mut self.align, Align::Max, "Right");
+                });
+                ui.end_row();
+
+                ui.label("Fill direction");
+                ui.horizontal(|ui| {
+                    ui.selectable_value_from_iter(&mut self.fill_direction, FillDirection::iter());
+                });
+                ui.end_row();
+
+                ui.label("Pad with");
+                {
+                    let mut tmp = self.pad_with.to_string();
+                    ui.add(TextEdit::singleline(&mut tmp).desired_width(25.0));
+                    self.pad_with = tmp.chars().next().unwrap_or(' ');
+                }
+                ui.end_row();
+
+                ui.label("Overflow pattern");
+                ui.optional_value_widget(&mut self.overflow_pattern, |ui, value| {
+                    let mut tmp = value.to_string();
+                    let response = ui.add(TextEdit::singleline(&mut tmp).desired_width(25.0));
+                    *value = tmp.chars().next().unwrap_or(' ');
+                    response
+                });
+                ui.end_row();
+
+                ui.label("Highlighted digit");
+                ui.optional_value_widget(&mut self.highlighted_digit, |ui, value| {
+                    ui.add(DragValue::new(value))
+                });
+                ui.end_row();
+
+                ui.label("Marquee");
+                marquee_mode_ui(ui, &mut self.marquee);
+                ui.end_row();
+
+                ui.label("Pause marquee on hover");
+                ui.checkbox(&mut self.marquee_pause_on_hover, "");
+                ui.end_row();
+
+                ui.label("Blinking");
+                ui.horizontal(|ui| {
+                    ui.checkbox(&mut self.blinking, "");
+                    ui.checkbox(&mut self.blink_colons, "Colons only");
+                    ui.add(
+                        DragValue::new(&mut self.blink_period)
+                            .speed(0.01)
+                            .range(0.1..=10.0)
+                            .suffix(" s"),
+                    );
+                    ui.add(
+                        DragValue::new(&mut self.blink_duty_cycle)
+                            .speed(0.01)
+                            .range(0.0..=1.0),
+                    );
+                });
+                ui.end_row();
+
+                ui.label("Brightness");
+                ui.horizontal(|ui| {
+                    ui.add(Slider::new(&mut self.brightness, 0.0..=1.0));
+                    ui.checkbox(&mut self.dim_background, "Dim background");
+                });
+                ui.end_row();
+
+                ui.label("Persistence");
+                ui.add(Slider::new(&mut self.persistence, 0.0..=1.0).suffix(" s"));
+                ui.end_row();
+
+                ui.label("Transition");
+                digit_transition_ui(ui, &mut self.transition);
+                ui.end_row();
+
+                ui.label("Measured size");
+                ui.label(format!(
+                    "{:.1} × {:.1} (allocated {:.1} × {:.1})",
+                    measured_size.x, measured_size.y, allocated_size.x, allocated_size.y
+                ));
+                ui.end_row();
+
+                ui.label("Geometry rebuilds");
+                ui.label(SegmentedDisplayWidget::geometry_rebuild_count().to_string());
+                ui.end_row();
             });
     }
 }
@@ -0,0 +1,116 @@
+use std::ops::RangeInclusive;
+use std::time::Duration;
+
+use eframe::egui::{DragValue, Grid, Ui};
+use egui_extras_xt::meters::SpectrumAnalyzer;
+use egui_extras_xt::ui::drag_rangeinclusive::DragRangeInclusive;
+use itertools::Itertools;
+
+use crate::pages::PageImpl;
+
+pub struct SpectrumAnalyzerPage {
+    band_count: usize,
+    range: RangeInclusive<f32>,
+    log_frequency_axis: bool,
+    width: f32,
+    height: f32,
+    bar_gap: f32,
+    peak_hold: bool,
+    peak_hold_duration: f32,
+    peak_hold_decay_rate: f32,
+}
+
+impl Default for SpectrumAnalyzerPage {
+    fn default() -> SpectrumAnalyzerPage {
+        SpectrumAnalyzerPage {
+            band_count: 32,
+            range: -60.0..=0.0,
+            log_frequency_axis: false,
+            width: 256.0,
+            height: 96.0,
+            bar_gap: 1.0,
+            peak_hold: true,
+            peak_hold_duration: 1.0,
+            peak_hold_decay_rate: 30.0,
+        }
+    }
+}
+
+impl PageImpl for SpectrumAnalyzerPage {
+    fn ui(&mut self, ui: &mut Ui) {
+        let time = ui.input(|input| input.time) as f32;
+        ui.ctx().request_repaint();
+
+        // Pink-ish noise floor with a few wobbling partials
+        let magnitudes = (0..self.band_count)
+            .map(|band| {
+                let band = band as f32;
+                let slope = -30.0 * (band + 1.0).log10() / (self.band_count as f32).log10();
+                let wobble = ((time * 3.1 + band * 0.7).sin() * (time * 1.3 + band * 0.3).cos())
+                    .abs()
+                    * 24.0;
+                slope + wobble - 24.0
+            })
+            .collect_vec();
+
+        ui.add(
+            SpectrumAnalyzer::new(&magnitudes)
+                .range(self.range.clone())
+                .log_frequency_axis(self.log_frequency_axis)
+                .width(self.width)
+                .height(self.height)
+                .bar_gap(self.bar_gap)
+                .peak_hold(self.peak_hold)
+                .peak_hold_duration(Duration::from_secs_f32(self.peak_hold_duration.max(0.0)))
+                .peak_hold_decay_rate(self.peak_hold_decay_rate),
+        );
+        ui.separator();
+
+        Grid::new("spectrum_analyzer_properties")
+            .num_columns(2)
+            .spacing([20.0, 10.0])
+            .striped(true)
+            .show(ui, |ui| {
+                ui.label("Band count");
+                ui.add(DragValue::new(&mut self.band_count).range(1..=1024));
+                ui.end_row();
+
+                ui.label("Range");
+                ui.drag_rangeinclusive(&mut self.range);
+                ui.end_row();
+
+                ui.label("Logarithmic frequency axis");
+                ui.checkbox(&mut self.log_frequency_axis, "");
+                ui.end_row();
+
+                ui.label("Width");
+                ui.add(DragValue::new(&mut self.width));
+                ui.end_row();
+
+                ui.label("Height");
+                ui.add(DragValue::new(&mut self.height));
+                ui.end_row();
+
+                ui.label("Bar gap");
+                ui.add(DragValue::new(&mut self.bar_gap).speed(0.1));
+                ui.end_row();
+
+                ui.label("Peak hold");
+                ui.checkbox(&mut self.peak_hold, "");
+                ui.end_row();
+
+                ui.label("Peak hold duration");
+                ui.add(
+                    DragValue::new(&mut self.peak_hold_duration)
+                        .speed(0.01)
+                        .range(0.0..=f32::MAX)
+                        .suffix(" s"),
+                );
+                ui.end_row();
+
+                ui.label("Peak hold decay rate");
+                ui.add(DragValue::new(&mut self.peak_hold_decay_rate).suffix(" dB/s"));
+                ui.end_row();
+            });
+    }
+}
@@ -0,0 +1,102 @@
+use eframe::egui::{Color32, DragValue, Grid, Ui};
+use egui_extras_xt::music::StepSequencer;
+use egui_extras_xt::ui::optional_value_widget::OptionalValueWidget;
+
+use crate::pages::PageImpl;
+
+const ROW_LABELS: &[&str] = &["Kick", "Snare", "Closed hat", "Open hat"];
+
+pub struct StepSequencerPage {
+    steps: Vec<Vec<bool>>,
+    current_step: Option<usize>,
+    show_row_labels: bool,
+    show_step_numbers: bool,
+    cell_size: f32,
+    cell_gap: f32,
+    active_color: Color32,
+    current_step_color: Color32,
+    toggle_count: usize,
+}
+
+impl Default for StepSequencerPage {
+    fn default() -> StepSequencerPage {
+        StepSequencerPage {
+            steps: (0..ROW_LABELS.len())
+                .map(|row| (0..16).map(|step| step % (4 + row) == 0).collect())
+                .collect(),
+            current_step: Some(0),
+            show_row_labels: true,
+            show_step_numbers: true,
+            cell_size: 20.0,
+            cell_gap: 2.0,
+            active_color: Color32::from_rgb(0xE6, 0x8C, 0x28),
+            current_step_color: Color32::from_white_alpha(0x30),
+            toggle_count: 0,
+        }
+    }
+}
+
+impl PageImpl for StepSequencerPage {
+    fn ui(&mut self, ui: &mut Ui) {
+        let mut toggle_count = self.toggle_count;
+
+        ui.add(
+            StepSequencer::new(&mut self.steps)
+                .current_step(self.current_step)
+                .on_toggle(|_, _, _| toggle_count += 1)
+                .row_labels(if self.show_row_labels {
+                    ROW_LABELS
+                } else {
+                    &[]
+                })
+                .show_step_numbers(self.show_step_numbers)
+                .cell_size([self.cell_size, self.cell_size])
+                .cell_gap(self.cell_gap)
+                .active_color(self.active_color)
+                .current_step_color(self.current_step_color),
+        );
+        ui.separator();
+
+        self.toggle_count = toggle_count;
+
+        Grid::new("step_sequencer_properties")
+            .num_columns(2)
+            .spacing([20.0, 10.0])
+            .striped(true)
+            .show(ui, |ui| {
+                ui.label("Current step");
+                ui.optional_value_widget(&mut self.current_step, |ui, value| {
+                    ui.add(DragValue::new(value).range(0..=15))
+                });
+                ui.end_row();
+
+                ui.label("Show row labels");
+                ui.checkbox(&mut self.show_row_labels, "");
+                ui.end_row();
+
+                ui.label("Show step numbers");
+                ui.checkbox(&mut self.show_step_numbers, "");
+                ui.end_row();
+
+                ui.label("Cell size");
+                ui.add(DragValue::new(&mut self.cell_size));
+                ui.end_row();
+
+                ui.label("Cell gap");
+                ui.add(DragValue::new(&mut self.cell_gap));
+                ui.end_row();
+
+                ui.label("Active color");
+                ui.color_edit_button_srgba(&mut self.active_color);
+                ui.end_row();
+
+                ui.label("Current step color");
+                ui.color_edit_button_srgba(&mut self.current_step_color);
+                ui.end_row();
+
+                ui.label("Toggled cells");
+                ui.label(self.toggle_count.to_string());
+                ui.end_row();
+            });
+    }
+}
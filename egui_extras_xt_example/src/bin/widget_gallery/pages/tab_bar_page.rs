@@ -0,0 +1,71 @@
+use eframe::egui::{Grid, Ui};
+use egui_extras_xt::ui::tab_bar::TabBar;
+
+use crate::pages::PageImpl;
+
+pub struct TabBarPage {
+    items: Vec<String>,
+    current: String,
+    closeable: bool,
+    next_index: usize,
+}
+
+impl Default for TabBarPage {
+    fn default() -> TabBarPage {
+        let items = (1..=5)
+            .map(|index| format!("Track {index}"))
+            .collect::<Vec<_>>();
+
+        TabBarPage {
+            current: items[0].clone(),
+            items,
+            closeable: true,
+            next_index: 6,
+        }
+    }
+}
+
+impl PageImpl for TabBarPage {
+    fn ui(&mut self, ui: &mut Ui) {
+        let mut closed_item = None;
+
+        ui.add(
+            TabBar::new(&mut self.current, &self.items)
+                .closeable(self.closeable)
+                .on_close(|item: &String| closed_item = Some(item.clone())),
+        );
+
+        if let Some(closed_item) = closed_item {
+            self.items.retain(|item| *item != closed_item);
+        }
+
+        ui.label(format!("Current tab: {}", self.current));
+        ui.separator();
+
+        Grid::new("tab_bar_properties")
+            .num_columns(2)
+            .spacing([20.0, 10.0])
+            .striped(true)
+            .show(ui, |ui| {
+                ui.label("Closeable");
+                ui.checkbox(&mut self.closeable, "");
+                ui.end_row();
+
+                ui.label("Tabs");
+                ui.horizontal(|ui| {
+                    if ui.button("Add tab").clicked() {
+                        self.items.push(format!("Track {}", self.next_index));
+                        self.next_index += 1;
+                    }
+
+                    if ui.button("Add 10 tabs").clicked() {
+                        for _ in 0..10 {
+                            self.items.push(format!("Track {}", self.next_index));
+                            self.next_index += 1;
+                        }
+                    }
+                });
+                ui.end_row();
+            });
+    }
+}
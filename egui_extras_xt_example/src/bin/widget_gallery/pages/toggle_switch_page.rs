@@ -0,0 +1,92 @@
+use eframe::egui::{DragValue, Grid, Ui};
+use eframe::epaint::Color32;
+use egui_extras_xt::ui::toggle_switch::ToggleSwitch;
+
+use crate::pages::PageImpl;
+
+pub struct ToggleSwitchPage {
+    value: bool,
+    height: f32,
+    on_label: String,
+    off_label: String,
+    thumb_color: Color32,
+    track_on_color: Color32,
+    track_off_color: Color32,
+    animated: bool,
+    interactive: bool,
+}
+
+impl Default for ToggleSwitchPage {
+    fn default() -> ToggleSwitchPage {
+        ToggleSwitchPage {
+            value: false,
+            height: 20.0,
+            on_label: "ON".to_owned(),
+            off_label: "OFF".to_owned(),
+            thumb_color: Color32::WHITE,
+            track_on_color: Color32::from_rgb(0x30, 0xA0, 0x50),
+            track_off_color: Color32::from_gray(0x60),
+            animated: true,
+            interactive: true,
+        }
+    }
+}
+
+impl PageImpl for ToggleSwitchPage {
+    fn ui(&mut self, ui: &mut Ui) {
+        ui.add(
+            ToggleSwitch::new(&mut self.value)
+                .height(self.height)
+                .on_label(&self.on_label)
+                .off_label(&self.off_label)
+                .thumb_color(self.thumb_color)
+                .track_color(true, self.track_on_color)
+                .track_color(false, self.track_off_color)
+                .animated(self.animated)
+                .interactive(self.interactive),
+        );
+        ui.separator();
+
+        Grid::new("toggle_switch_properties")
+            .num_columns(2)
+            .spacing([20.0, 10.0])
+            .striped(true)
+            .show(ui, |ui| {
+                ui.label("Value");
+                ui.checkbox(&mut self.value, "");
+                ui.end_row();
+
+                ui.label("Height");
+                ui.add(DragValue::new(&mut self.height));
+                ui.end_row();
+
+                ui.label("On label");
+                ui.text_edit_singleline(&mut self.on_label);
+                ui.end_row();
+
+                ui.label("Off label");
+                ui.text_edit_singleline(&mut self.off_label);
+                ui.end_row();
+
+                ui.label("Thumb color");
+                ui.color_edit_button_srgba(&mut self.thumb_color);
+                ui.end_row();
+
+                ui.label("Track color (on)");
+                ui.color_edit_button_srgba(&mut self.track_on_color);
+                ui.end_row();
+
+                ui.label("Track color (off)");
+                ui.color_edit_button_srgba(&mut self.track_off_color);
+                ui.end_row();
+
+                ui.label("Animated");
+                ui.checkbox(&mut self.animated, "");
+                ui.end_row();
+
+                ui.label("Interactive");
+                ui.checkbox(&mut self.interactive, "");
+                ui.end_row();
+            });
+    }
+}
@@ -1,17 +1,20 @@
+use std::f32::consts::TAU;
 use std::path::PathBuf;
 use std::str::FromStr;
 
-use eframe::egui::{DragValue, Grid, Response, TextEdit, Ui};
+use eframe::egui::{DragValue, FontId, Grid, Response, TextEdit, Ui};
 use eframe::epaint::Color32;
 
 use egui_extras_xt::common::{Orientation, WidgetShape};
 use egui_extras_xt::compasses::{CompassMarkerShape, DefaultCompassMarkerColor};
-use egui_extras_xt::displays::segmented_display::DisplayMetricsPreset;
+use egui_extras_xt::displays::segmented_display::{
+    DigitTransition, DisplayMetricsPreset, MarqueeMode, SegmentCapStyle, UnknownCharPolicy,
+};
 use egui_extras_xt::displays::{DisplayMetrics, DisplayStyle, DisplayStylePreset};
 use egui_extras_xt::knobs::{ThumbstickDeadZone, ThumbstickSnap};
 use egui_extras_xt::ui::standard_buttons::StandardButtons;
 use egui_extras_xt::ui::widgets_from_iter::ComboBoxFromIter;
-use egui_extras_xt::ui::widgets_from_slice::{ComboBoxFromSlice, SelectableValueFromSlice};
+use egui_extras_xt::ui::widgets_from_slice::ComboBoxFromSlice;
 
 use strum::IntoEnumIterator;
 
@@ -132,9 +135,19 @@ pub fn display_metrics_ui(
             ui.label("Colon separation");
             ui.add(DragValue::new(&mut metrics.colon_separation));
             ui.end_row();
+
+            ui.label("Segment cap style");
+            ui.push_id("segment_cap_style_combo", |ui| {
+                ui.combobox_from_iter("", &mut metrics.segment_cap_style, SegmentCapStyle::iter());
+            });
+            ui.end_row();
         });
 }
 
+fn flower_shape(theta: f32) -> f32 {
+    0.85 + 0.15 * (theta * 5.0).cos()
+}
+
 pub fn widget_shape_ui(ui: &mut Ui, value: &mut WidgetShape) {
     ui.group(|ui| {
         ui.horizontal_top(|ui| {
@@ -148,6 +161,8 @@ pub fn widget_shape_ui(ui: &mut Ui, value: &mut WidgetShape) {
                         WidgetShape::Squircle(4.0),
                         WidgetShape::Polygon(6),
                         WidgetShape::SuperPolygon(6, 1.5),
+                        WidgetShape::Star(5, 0.5),
+                        WidgetShape::RoundedPolygon(6, 0.25),
                         WidgetShape::Rotated(Box::new(WidgetShape::Square), 0.0f32.to_radians()),
                         WidgetShape::Scaled(Box::new(WidgetShape::Square), 1.0),
                         WidgetShape::Mix(
@@ -163,6 +178,7 @@ pub fn widget_shape_ui(ui: &mut Ui, value: &mut WidgetShape) {
                             Box::new(WidgetShape::Circle),
                             Box::new(WidgetShape::Square),
                         ),
+                        WidgetShape::from_fn(flower_shape),
                     ],
                 );
             });
@@ -171,6 +187,7 @@ pub fn widget_shape_ui(ui: &mut Ui, value: &mut WidgetShape) {
             match value {
                 WidgetShape::Circle => {}
                 WidgetShape::Square => {}
+                WidgetShape::Custom(_) => {}
                 WidgetShape::Squircle(factor) => {
                     ui.add(DragValue::new(factor));
                 }
@@ -183,6 +200,18 @@ pub fn widget_shape_ui(ui: &mut Ui, value: &mut WidgetShape) {
                         ui.add(DragValue::new(factor));
                     });
                 }
+                WidgetShape::Star(n, inner_ratio) => {
+                    ui.vertical(|ui| {
+                        ui.add(DragValue::new(n).range(3..=32));
+                        ui.add(DragValue::new(inner_ratio).range(0.05..=1.0).speed(0.01));
+                    });
+                }
+                WidgetShape::RoundedPolygon(n, corner_radius) => {
+                    ui.vertical(|ui| {
+                        ui.add(DragValue::new(n).range(3..=32));
+                        ui.add(DragValue::new(corner_radius).range(0.0..=1.0).speed(0.01));
+                    });
+                }
                 WidgetShape::Rotated(shape, rotation) => {
                     ui.vertical(|ui| {
                         widget_shape_ui(ui, shape);
@@ -216,15 +245,9 @@ pub fn widget_shape_ui(ui: &mut Ui, value: &mut WidgetShape) {
 
 pub fn widget_orientation_ui(ui: &mut Ui, value: &mut Orientation) {
     ui.horizontal_centered(|ui| {
-        ui.selectable_value_from_slice(
-            value,
-            &[
-                Orientation::Top,
-                Orientation::Bottom,
-                Orientation::Left,
-                Orientation::Right,
-            ],
-        );
+        for orientation in Orientation::iter_fixed() {
+            ui.selectable_value(value, orientation, orientation.to_string());
+        }
 
         ui.group(|ui| {
             ui.horizontal(|ui| {
@@ -310,13 +333,16 @@ pub fn default_compass_marker_shape_ui(ui: &mut Ui, value: &mut CompassMarkerSha
                 &[
                     CompassMarkerShape::Square,
                     CompassMarkerShape::Circle,
-                    CompassMarkerShape::RightArrow,
-                    CompassMarkerShape::UpArrow,
-                    CompassMarkerShape::LeftArrow,
-                    CompassMarkerShape::DownArrow,
+                    CompassMarkerShape::RegularPolygon(3, -TAU / 4.0),
                     CompassMarkerShape::Diamond,
                     CompassMarkerShape::Star(5, 0.5),
+                    CompassMarkerShape::Cross(0.1),
+                    CompassMarkerShape::Plus(0.1),
+                    CompassMarkerShape::Pin,
+                    CompassMarkerShape::Flag,
+                    CompassMarkerShape::Custom(WidgetShape::Squircle(4.0)),
                     CompassMarkerShape::Emoji('?'),
+                    CompassMarkerShape::TextLabel("WP01".to_owned(), FontId::proportional(8.0)),
                 ],
             );
         });
@@ -326,18 +352,30 @@ pub fn default_compass_marker_shape_ui(ui: &mut Ui, value: &mut CompassMarkerSha
                 ui.add(DragValue::new(rays));
                 ui.add(DragValue::new(ratio));
             }
+            CompassMarkerShape::RegularPolygon(sides, rotation) => {
+                ui.add(DragValue::new(sides).range(3..=usize::MAX));
+                ui.drag_angle(rotation);
+            }
+            CompassMarkerShape::Cross(margin) | CompassMarkerShape::Plus(margin) => {
+                ui.add(DragValue::new(margin).speed(0.01).range(0.0..=0.5));
+            }
             CompassMarkerShape::Emoji(emoji) => {
                 let mut tmp = emoji.to_string();
                 ui.add(TextEdit::singleline(&mut tmp).desired_width(25.0));
                 *emoji = tmp.chars().next().unwrap_or(' ');
             }
+            CompassMarkerShape::TextLabel(text, font_id) => {
+                ui.add(TextEdit::singleline(text).desired_width(50.0));
+                ui.add(DragValue::new(&mut font_id.size).range(1.0..=f32::MAX));
+            }
             CompassMarkerShape::Square
             | CompassMarkerShape::Circle
-            | CompassMarkerShape::RightArrow
-            | CompassMarkerShape::UpArrow
-            | CompassMarkerShape::LeftArrow
-            | CompassMarkerShape::DownArrow
-            | CompassMarkerShape::Diamond => {}
+            | CompassMarkerShape::Diamond
+            | CompassMarkerShape::Pin
+            | CompassMarkerShape::Flag => {}
+            CompassMarkerShape::Custom(shape) => {
+                widget_shape_ui(ui, shape);
+            }
             _ => unimplemented!(),
         }
     });
@@ -351,6 +389,96 @@ pub fn compass_axis_labels_ui(ui: &mut Ui, axis_labels: &mut Vec<String>) {
     });
 }
 
+pub fn marquee_mode_ui(ui: &mut Ui, value: &mut MarqueeMode) {
+    ui.horizontal_centered(|ui| {
+        ui.push_id("marquee_mode_combo", |ui| {
+            ui.combobox_from_slice(
+                "",
+                value,
+                &[
+                    MarqueeMode::Off,
+                    MarqueeMode::Loop {
+                        speed_chars_per_sec: 4.0,
+                        gap: 2,
+                    },
+                    MarqueeMode::Bounce {
+                        speed_chars_per_sec: 4.0,
+                    },
+                ],
+            );
+        });
+
+        match value {
+            MarqueeMode::Off => {}
+            MarqueeMode::Loop {
+                speed_chars_per_sec,
+                gap,
+            } => {
+                ui.add(DragValue::new(speed_chars_per_sec).speed(0.1));
+                ui.add(DragValue::new(gap));
+            }
+            MarqueeMode::Bounce {
+                speed_chars_per_sec,
+            } => {
+                ui.add(DragValue::new(speed_chars_per_sec).speed(0.1));
+            }
+            _ => unimplemented!(),
+        }
+    });
+}
+
+pub fn digit_transition_ui(ui: &mut Ui, value: &mut DigitTransition) {
+    ui.horizontal_centered(|ui| {
+        ui.push_id("digit_transition_combo", |ui| {
+            ui.combobox_from_slice(
+                "",
+                value,
+                &[
+                    DigitTransition::None,
+                    DigitTransition::Fade { duration: 0.25 },
+                    DigitTransition::Roll { duration: 0.25 },
+                ],
+            );
+        });
+
+        match value {
+            DigitTransition::None => {}
+            DigitTransition::Fade { duration } | DigitTransition::Roll { duration } => {
+                ui.add(
+                    DragValue::new(duration)
+                        .speed(0.01)
+                        .range(0.0..=f32::INFINITY)
+                        .suffix(" s"),
+                );
+            }
+            _ => unimplemented!(),
+        }
+    });
+}
+
+pub fn unknown_char_policy_ui(ui: &mut Ui, value: &mut UnknownCharPolicy) {
+    ui.horizontal_centered(|ui| {
+        ui.push_id("unknown_char_policy_combo", |ui| {
+            ui.combobox_from_slice(
+                "",
+                value,
+                &[
+                    UnknownCharPolicy::Skip,
+                    UnknownCharPolicy::Blank,
+                    UnknownCharPolicy::Replace('?'),
+                    UnknownCharPolicy::AllSegments,
+                ],
+            );
+        });
+
+        if let UnknownCharPolicy::Replace(replacement) = value {
+            let mut tmp = replacement.to_string();
+            ui.add(TextEdit::singleline(&mut tmp).desired_width(25.0));
+            *replacement = tmp.chars().next().unwrap_or(' ');
+        }
+    });
+}
+
 pub fn thumbstick_snap_ui(ui: &mut Ui, value: &mut ThumbstickSnap) {
     ui.horizontal_centered(|ui| {
         ui.push_id("thumbstick_snap_combo", |ui| {
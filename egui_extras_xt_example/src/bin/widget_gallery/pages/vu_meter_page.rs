@@ -0,0 +1,109 @@
+use std::ops::RangeInclusive;
+use std::time::Duration;
+
+use eframe::egui::{DragValue, Grid, Slider, Ui};
+use egui_extras_xt::common::Orientation;
+use egui_extras_xt::meters::VuMeter;
+use egui_extras_xt::ui::drag_rangeinclusive::DragRangeInclusive;
+
+use crate::pages::ui::widget_orientation_ui;
+use crate::pages::PageImpl;
+
+pub struct VuMeterPage {
+    value: f32,
+    range: RangeInclusive<f32>,
+    orientation: Orientation,
+    segment_count: usize,
+    segment_gap: f32,
+    length: f32,
+    thickness: f32,
+    show_peak: bool,
+    peak_hold_duration: f32,
+    peak_hold_decay_rate: f32,
+}
+
+impl Default for VuMeterPage {
+    fn default() -> VuMeterPage {
+        VuMeterPage {
+            value: -12.0,
+            range: -60.0..=0.0,
+            orientation: Orientation::Top,
+            segment_count: 24,
+            segment_gap: 2.0,
+            length: 160.0,
+            thickness: 12.0,
+            show_peak: true,
+            peak_hold_duration: 1.5,
+            peak_hold_decay_rate: 20.0,
+        }
+    }
+}
+
+impl PageImpl for VuMeterPage {
+    fn ui(&mut self, ui: &mut Ui) {
+        ui.add(
+            VuMeter::new(self.value)
+                .range(self.range.clone())
+                .orientation(self.orientation)
+                .segment_count(self.segment_count)
+                .segment_gap(self.segment_gap)
+                .length(self.length)
+                .thickness(self.thickness)
+                .show_peak(self.show_peak)
+                .peak_hold_duration(Duration::from_secs_f32(self.peak_hold_duration.max(0.0)))
+                .peak_hold_decay_rate(self.peak_hold_decay_rate),
+        );
+        ui.separator();
+
+        Grid::new("vu_meter_properties")
+            .num_columns(2)
+            .spacing([20.0, 10.0])
+            .striped(true)
+            .show(ui, |ui| {
+                ui.label("Value");
+                ui.add(Slider::new(&mut self.value, self.range.clone()).suffix(" dBFS"));
+                ui.end_row();
+
+                ui.label("Range");
+                ui.drag_rangeinclusive(&mut self.range);
+                ui.end_row();
+
+                ui.label("Orientation");
+                widget_orientation_ui(ui, &mut self.orientation);
+                ui.end_row();
+
+                ui.label("Segment count");
+                ui.add(DragValue::new(&mut self.segment_count).range(1..=usize::MAX));
+                ui.end_row();
+
+                ui.label("Segment gap");
+                ui.add(DragValue::new(&mut self.segment_gap));
+                ui.end_row();
+
+                ui.label("Length");
+                ui.add(DragValue::new(&mut self.length));
+                ui.end_row();
+
+                ui.label("Thickness");
+                ui.add(DragValue::new(&mut self.thickness));
+                ui.end_row();
+
+                ui.label("Show peak");
+                ui.checkbox(&mut self.show_peak, "");
+                ui.end_row();
+
+                ui.label("Peak hold duration");
+                ui.add(
+                    DragValue::new(&mut self.peak_hold_duration)
+                        .speed(0.01)
+                        .range(0.0..=f32::MAX)
+                        .suffix(" s"),
+                );
+                ui.end_row();
+
+                ui.label("Peak hold decay rate");
+                ui.add(DragValue::new(&mut self.peak_hold_decay_rate).suffix(" dB/s"));
+                ui.end_row();
+            });
+    }
+}
@@ -0,0 +1,135 @@
+use std::ops::RangeInclusive;
+
+use eframe::egui::{DragValue, Grid, Ui};
+use egui_extras_xt::common::WrapMode;
+use egui_extras_xt::knobs::XyPad;
+use egui_extras_xt::ui::drag_rangeinclusive::DragRangeInclusive;
+use egui_extras_xt::ui::optional_value_widget::OptionalValueWidget;
+use egui_extras_xt::ui::widgets_from_iter::SelectableValueFromIter;
+use strum::IntoEnumIterator;
+
+use crate::pages::PageImpl;
+
+pub struct XyPadPage {
+    x: f32,
+    y: f32,
+    range_x: RangeInclusive<f32>,
+    range_y: RangeInclusive<f32>,
+    wrap_x: WrapMode,
+    wrap_y: WrapMode,
+    grid: Option<f32>,
+    show_grid: bool,
+    snap_to_grid: bool,
+    interactive: bool,
+    size: f32,
+    cursor_radius: f32,
+    animated: bool,
+}
+
+impl Default for XyPadPage {
+    fn default() -> XyPadPage {
+        XyPadPage {
+            x: 0.0,
+            y: 0.0,
+            range_x: -1.0..=1.0,
+            range_y: -1.0..=1.0,
+            wrap_x: WrapMode::Clamped,
+            wrap_y: WrapMode::Clamped,
+            grid: Some(0.25),
+            show_grid: true,
+            snap_to_grid: false,
+            interactive: true,
+            size: 128.0,
+            cursor_radius: 6.0,
+            animated: false,
+        }
+    }
+}
+
+impl PageImpl for XyPadPage {
+    fn ui(&mut self, ui: &mut Ui) {
+        ui.add(
+            XyPad::new(&mut self.x, &mut self.y)
+                .range_x(self.range_x.clone())
+                .range_y(self.range_y.clone())
+                .wrap_x(self.wrap_x)
+                .wrap_y(self.wrap_y)
+                .grid(self.grid)
+                .show_grid(self.show_grid)
+                .snap_to_grid(self.snap_to_grid)
+                .interactive(self.interactive)
+                .size([self.size, self.size])
+                .cursor_radius(self.cursor_radius)
+                .animated(self.animated),
+        );
+        ui.separator();
+
+        Grid::new("xy_pad_properties")
+            .num_columns(2)
+            .spacing([20.0, 10.0])
+            .striped(true)
+            .show(ui, |ui| {
+                ui.label("X position");
+                ui.add(DragValue::new(&mut self.x).speed(0.01));
+                ui.end_row();
+
+                ui.label("Y position");
+                ui.add(DragValue::new(&mut self.y).speed(0.01));
+                ui.end_row();
+
+                ui.label("X range");
+                ui.drag_rangeinclusive(&mut self.range_x);
+                ui.end_row();
+
+                ui.label("Y range");
+                ui.drag_rangeinclusive(&mut self.range_y);
+                ui.end_row();
+
+                ui.label("X wrap");
+                ui.horizontal(|ui| {
+                    ui.push_id("wrap_x", |ui| {
+                        ui.selectable_value_from_iter(&mut self.wrap_x, WrapMode::iter());
+                    });
+                });
+                ui.end_row();
+
+                ui.label("Y wrap");
+                ui.horizontal(|ui| {
+                    ui.push_id("wrap_y", |ui| {
+                        ui.selectable_value_from_iter(&mut self.wrap_y, WrapMode::iter());
+                    });
+                });
+                ui.end_row();
+
+                ui.label("Grid");
+                ui.optional_value_widget(&mut self.grid, |ui, value| {
+                    ui.add(DragValue::new(value).speed(0.01))
+                });
+                ui.end_row();
+
+                ui.label("Show grid");
+                ui.checkbox(&mut self.show_grid, "");
+                ui.end_row();
+
+                ui.label("Snap to grid");
+                ui.checkbox(&mut self.snap_to_grid, "");
+                ui.end_row();
+
+                ui.label("Interactive");
+                ui.checkbox(&mut self.interactive, "");
+                ui.end_row();
+
+                ui.label("Size");
+                ui.add(DragValue::new(&mut self.size));
+                ui.end_row();
+
+                ui.label("Cursor radius");
+                ui.add(DragValue::new(&mut self.cursor_radius));
+                ui.end_row();
+
+                ui.label("Animated");
+                ui.checkbox(&mut self.animated, "");
+                ui.end_row();
+            });
+    }
+}